@@ -0,0 +1,52 @@
+//! Postgres runs in production. SQLite is supported for local development and tests, so
+//! migrations go through these where the two differ.
+
+use sea_orm_migration::prelude::{
+    extension::postgres::{TypeAlterStatement, TypeCreateStatement, TypeDropStatement},
+    sea_orm::DbBackend,
+    DbErr, SchemaManager, TableAlterStatement,
+};
+
+pub fn is_postgres(manager: &SchemaManager) -> bool {
+    manager.get_database_backend() == DbBackend::Postgres
+}
+
+/// Enums are types of their own only on Postgres. SQLite stores them as text.
+pub async fn create_type(
+    manager: &SchemaManager<'_>,
+    stmt: TypeCreateStatement,
+) -> Result<(), DbErr> {
+    match is_postgres(manager) {
+        true => manager.create_type(stmt).await,
+        false => Ok(()),
+    }
+}
+
+pub async fn alter_type(
+    manager: &SchemaManager<'_>,
+    stmt: TypeAlterStatement,
+) -> Result<(), DbErr> {
+    match is_postgres(manager) {
+        true => manager.alter_type(stmt).await,
+        false => Ok(()),
+    }
+}
+
+pub async fn drop_type(manager: &SchemaManager<'_>, stmt: TypeDropStatement) -> Result<(), DbErr> {
+    match is_postgres(manager) {
+        true => manager.drop_type(stmt).await,
+        false => Ok(()),
+    }
+}
+
+/// SQLite can change the type or nullability of a column only by rebuilding the table, so such
+/// changes are left to the entities there.
+pub async fn modify_column(
+    manager: &SchemaManager<'_>,
+    stmt: TableAlterStatement,
+) -> Result<(), DbErr> {
+    match is_postgres(manager) {
+        true => manager.alter_table(stmt).await,
+        false => Ok(()),
+    }
+}
@@ -1,5 +1,7 @@
 pub use sea_orm_migration::prelude::{async_trait, MigrationTrait, MigratorTrait};
 
+mod backend;
+
 mod m20240722_000001_create_users_table;
 mod m20240927_000001_create_ambitions_table;
 mod m20240927_000002_create_objectives_table;
@@ -50,6 +52,14 @@ mod m20260122_000001_add_focused_desired_state_to_notification_type;
 mod m20260131_000001_modify_actions_table_discipline_and_memo;
 mod m20260131_000002_remove_focus_from_desired_state_table;
 mod m20260131_000003_rename_desired_states_to_directions_table;
+mod m20261019_000001_add_updated_at_to_sync_target_tables;
+mod m20261019_000002_create_tombstones_table;
+mod m20261019_000003_add_deleted_at_to_trashable_tables;
+mod m20261019_000004_create_revisions_table;
+mod m20261019_000005_add_search_vectors;
+mod m20261101_000001_enable_row_level_security;
+mod m20261101_000002_create_my_way_link_tables;
+mod m20261101_000003_add_parent_id_to_direction_category;
 mod m_seed_data;
 
 pub struct Migrator;
@@ -113,6 +123,14 @@ impl MigratorTrait for Migrator {
             Box::new(m20260131_000001_modify_actions_table_discipline_and_memo::Migration),
             Box::new(m20260131_000002_remove_focus_from_desired_state_table::Migration),
             Box::new(m20260131_000003_rename_desired_states_to_directions_table::Migration),
+            Box::new(m20261019_000001_add_updated_at_to_sync_target_tables::Migration),
+            Box::new(m20261019_000002_create_tombstones_table::Migration),
+            Box::new(m20261019_000003_add_deleted_at_to_trashable_tables::Migration),
+            Box::new(m20261019_000004_create_revisions_table::Migration),
+            Box::new(m20261019_000005_add_search_vectors::Migration),
+            Box::new(m20261101_000001_enable_row_level_security::Migration),
+            Box::new(m20261101_000002_create_my_way_link_tables::Migration),
+            Box::new(m20261101_000003_add_parent_id_to_direction_category::Migration),
         ]
     }
 }
@@ -1,3 +1,4 @@
+use crate::backend::{create_type, drop_type};
 use sea_orm_migration::{
     prelude::{
         async_trait,
@@ -16,14 +17,14 @@ pub struct Migration;
 #[async_trait::async_trait]
 impl MigrationTrait for Migration {
     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
-        manager
-            .create_type(
-                Type::create()
-                    .as_enum(TimezoneEnum)
-                    .values(TimezoneVariants::iter())
-                    .to_owned(),
-            )
-            .await?;
+        create_type(
+            manager,
+            Type::create()
+                .as_enum(TimezoneEnum)
+                .values(TimezoneVariants::iter())
+                .to_owned(),
+        )
+        .await?;
         manager
             .create_table(
                 Table::create()
@@ -75,9 +76,11 @@ impl MigrationTrait for Migration {
         manager
             .drop_table(Table::drop().table(User::Table).to_owned())
             .await?;
-        manager
-            .drop_type(Type::drop().if_exists().name(TimezoneEnum).to_owned())
-            .await?;
+        drop_type(
+            manager,
+            Type::drop().if_exists().name(TimezoneEnum).to_owned(),
+        )
+        .await?;
         Ok(())
     }
 }
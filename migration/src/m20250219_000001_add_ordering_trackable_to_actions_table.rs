@@ -18,6 +18,13 @@ impl MigrationTrait for Migration {
                 Table::alter()
                     .table(Action::Table)
                     .add_column_if_not_exists(integer_null(Action::Ordering))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Action::Table)
                     .add_column_if_not_exists(boolean(Action::Trackable).default(true))
                     .to_owned(),
             )
@@ -31,6 +38,13 @@ impl MigrationTrait for Migration {
                 Table::alter()
                     .table(Action::Table)
                     .drop_column(Action::Ordering)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Action::Table)
                     .drop_column(Action::Trackable)
                     .to_owned(),
             )
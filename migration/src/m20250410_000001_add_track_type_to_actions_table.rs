@@ -1,3 +1,4 @@
+use crate::backend::{create_type, drop_type};
 use sea_orm_migration::prelude::{
     async_trait,
     extension::postgres::Type,
@@ -12,9 +13,11 @@ pub struct Migration;
 impl MigrationTrait for Migration {
     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
         let schema = Schema::new(DbBackend::Postgres);
-        manager
-            .create_type(schema.create_enum_from_active_enum::<ActionTrackType>())
-            .await?;
+        create_type(
+            manager,
+            schema.create_enum_from_active_enum::<ActionTrackType>(),
+        )
+        .await?;
         manager
             .alter_table(
                 Table::alter()
@@ -40,14 +43,14 @@ impl MigrationTrait for Migration {
                     .to_owned(),
             )
             .await?;
-        manager
-            .drop_type(
-                Type::drop()
-                    .if_exists()
-                    .name(ActionTrackType::name())
-                    .to_owned(),
-            )
-            .await?;
+        drop_type(
+            manager,
+            Type::drop()
+                .if_exists()
+                .name(ActionTrackType::name())
+                .to_owned(),
+        )
+        .await?;
         Ok(())
     }
 }
@@ -1,3 +1,4 @@
+use crate::backend::modify_column;
 use sea_orm_migration::{
     prelude::{
         async_trait,
@@ -13,26 +14,26 @@ pub struct Migration;
 #[async_trait::async_trait]
 impl MigrationTrait for Migration {
     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
-        manager
-            .alter_table(
-                Table::alter()
-                    .table(ActionTrack::Table)
-                    .modify_column(uuid(ActionTrack::ActionId))
-                    .to_owned(),
-            )
-            .await?;
+        modify_column(
+            manager,
+            Table::alter()
+                .table(ActionTrack::Table)
+                .modify_column(uuid(ActionTrack::ActionId))
+                .to_owned(),
+        )
+        .await?;
         Ok(())
     }
 
     async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
-        manager
-            .alter_table(
-                Table::alter()
-                    .table(ActionTrack::Table)
-                    .modify_column(uuid_null(ActionTrack::ActionId))
-                    .to_owned(),
-            )
-            .await?;
+        modify_column(
+            manager,
+            Table::alter()
+                .table(ActionTrack::Table)
+                .modify_column(uuid_null(ActionTrack::ActionId))
+                .to_owned(),
+        )
+        .await?;
         Ok(())
     }
 }
@@ -1,3 +1,4 @@
+use crate::backend::is_postgres;
 use sea_orm_migration::{
     prelude::{
         async_trait,
@@ -47,32 +48,42 @@ impl MigrationTrait for Migration {
                     .to_owned(),
             )
             .await?;
-        manager
-            .alter_table(
-                Table::alter()
-                    .table(Tag::Table)
-                    .add_column_if_not_exists(uuid_null(Tag::MindsetId).unique_key())
-                    .add_foreign_key(
-                        TableForeignKey::new()
-                            .name(TAGS_MINDSET_FOREIGN_KEY_NAME)
-                            .from_tbl(Tag::Table)
-                            .from_col(Tag::MindsetId)
-                            .to_tbl(Mindset::Table)
-                            .to_col(Mindset::Id)
-                            .on_delete(ForeignKeyAction::Cascade),
-                    )
-                    .to_owned(),
-            )
-            .await?;
+        // The column is dropped again in m20250614_000001, which SQLite cannot do to a column
+        // with a foreign key or a unique constraint.
+        let mut add_mindset_id = Table::alter().table(Tag::Table).to_owned();
+        match is_postgres(manager) {
+            true => add_mindset_id
+                .add_column_if_not_exists(uuid_null(Tag::MindsetId).unique_key())
+                .add_foreign_key(
+                    TableForeignKey::new()
+                        .name(TAGS_MINDSET_FOREIGN_KEY_NAME)
+                        .from_tbl(Tag::Table)
+                        .from_col(Tag::MindsetId)
+                        .to_tbl(Mindset::Table)
+                        .to_col(Mindset::Id)
+                        .on_delete(ForeignKeyAction::Cascade),
+                ),
+            false => add_mindset_id.add_column_if_not_exists(uuid_null(Tag::MindsetId)),
+        };
+        manager.alter_table(add_mindset_id).await?;
         Ok(())
     }
 
     async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if is_postgres(manager) {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Tag::Table)
+                        .drop_foreign_key(Alias::new(TAGS_MINDSET_FOREIGN_KEY_NAME))
+                        .to_owned(),
+                )
+                .await?;
+        }
         manager
             .alter_table(
                 Table::alter()
                     .table(Tag::Table)
-                    .drop_foreign_key(Alias::new(TAGS_MINDSET_FOREIGN_KEY_NAME))
                     .drop_column(Tag::MindsetId)
                     .to_owned(),
             )
@@ -1,9 +1,10 @@
+use crate::backend::is_postgres;
 use sea_orm_migration::{
     prelude::{
         async_trait,
         sea_orm::{self, DeriveIden},
-        Alias, DbErr, DeriveMigrationName, ForeignKey, ForeignKeyAction, Index, MigrationTrait,
-        SchemaManager, Table, TableForeignKey,
+        Alias, ConnectionTrait, DbErr, DeriveMigrationName, ForeignKey, ForeignKeyAction, Index,
+        MigrationTrait, SchemaManager, Table, TableForeignKey,
     },
     schema::{integer_null, string, uuid, uuid_null},
 };
@@ -46,23 +47,38 @@ impl MigrationTrait for Migration {
                     .to_owned(),
             )
             .await?;
-        manager
-            .alter_table(
-                Table::alter()
-                    .table(DesiredState::Table)
-                    .add_column_if_not_exists(uuid_null(DesiredState::CategoryId))
-                    .add_foreign_key(
-                        TableForeignKey::new()
-                            .name(DESIRED_STATE_CATEGORY_FOREIGN_KEY_NAME)
-                            .from_tbl(DesiredState::Table)
-                            .from_col(DesiredState::CategoryId)
-                            .to_tbl(DesiredStateCategory::Table)
-                            .to_col(DesiredStateCategory::Id)
-                            .on_delete(ForeignKeyAction::SetNull),
+        match is_postgres(manager) {
+            true => {
+                manager
+                    .alter_table(
+                        Table::alter()
+                            .table(DesiredState::Table)
+                            .add_column_if_not_exists(uuid_null(DesiredState::CategoryId))
+                            .add_foreign_key(
+                                TableForeignKey::new()
+                                    .name(DESIRED_STATE_CATEGORY_FOREIGN_KEY_NAME)
+                                    .from_tbl(DesiredState::Table)
+                                    .from_col(DesiredState::CategoryId)
+                                    .to_tbl(DesiredStateCategory::Table)
+                                    .to_col(DesiredStateCategory::Id)
+                                    .on_delete(ForeignKeyAction::SetNull),
+                            )
+                            .to_owned(),
                     )
-                    .to_owned(),
-            )
-            .await?;
+                    .await?;
+            }
+            // SQLite cannot add a foreign key to an existing table, but a new column can
+            // reference another table in its definition.
+            false => {
+                manager
+                    .get_connection()
+                    .execute_unprepared(
+                        r#"ALTER TABLE "desired_state" ADD COLUMN "category_id" uuid_text
+                            REFERENCES "desired_state_category" ("id") ON DELETE SET NULL"#,
+                    )
+                    .await?;
+            }
+        }
         manager
             .create_index(
                 Index::create()
@@ -79,11 +95,20 @@ impl MigrationTrait for Migration {
         manager
             .drop_index(Index::drop().name(CATEGORY_ID_INDEX_NAME).to_owned())
             .await?;
+        if is_postgres(manager) {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(DesiredState::Table)
+                        .drop_foreign_key(Alias::new(DESIRED_STATE_CATEGORY_FOREIGN_KEY_NAME))
+                        .to_owned(),
+                )
+                .await?;
+        }
         manager
             .alter_table(
                 Table::alter()
                     .table(DesiredState::Table)
-                    .drop_foreign_key(Alias::new(DESIRED_STATE_CATEGORY_FOREIGN_KEY_NAME))
                     .drop_column(DesiredState::CategoryId)
                     .to_owned(),
             )
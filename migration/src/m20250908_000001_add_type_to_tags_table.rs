@@ -1,3 +1,4 @@
+use crate::backend::{create_type, drop_type, is_postgres};
 use sea_orm_migration::{
     prelude::{
         async_trait,
@@ -16,9 +17,7 @@ pub struct Migration;
 impl MigrationTrait for Migration {
     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
         let schema = Schema::new(DbBackend::Postgres);
-        manager
-            .create_type(schema.create_enum_from_active_enum::<TagType>())
-            .await?;
+        create_type(manager, schema.create_enum_from_active_enum::<TagType>()).await?;
         manager
             .alter_table(
                 Table::alter()
@@ -34,6 +33,23 @@ impl MigrationTrait for Migration {
             .await?;
 
         let db = manager.get_connection();
+        if !is_postgres(manager) {
+            // SQLite has neither enum types nor a way to add a check constraint to an existing
+            // table.
+            db.execute_unprepared(
+                "UPDATE tag
+                    SET type =
+                        CASE
+                            WHEN ambition_id is not NULL THEN 'Ambition'
+                            WHEN desired_state_id is not NULL THEN 'DesiredState'
+                            WHEN action_id is not NULL THEN 'Action'
+                            ELSE 'Plain'
+                        END;
+                ",
+            )
+            .await?;
+            return Ok(());
+        }
         db.execute_unprepared(
             "UPDATE tag
                 SET type =
@@ -62,9 +78,13 @@ impl MigrationTrait for Migration {
     }
 
     async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
-        let db = manager.get_connection();
-        db.execute_unprepared("ALTER TABLE tag DROP CONSTRAINT type_foreign_keys_compatibility;")
+        if is_postgres(manager) {
+            let db = manager.get_connection();
+            db.execute_unprepared(
+                "ALTER TABLE tag DROP CONSTRAINT type_foreign_keys_compatibility;",
+            )
             .await?;
+        }
         manager
             .alter_table(
                 Table::alter()
@@ -73,9 +93,11 @@ impl MigrationTrait for Migration {
                     .to_owned(),
             )
             .await?;
-        manager
-            .drop_type(Type::drop().if_exists().name(TagType::name()).to_owned())
-            .await?;
+        drop_type(
+            manager,
+            Type::drop().if_exists().name(TagType::name()).to_owned(),
+        )
+        .await?;
         Ok(())
     }
 }
@@ -1,3 +1,4 @@
+use crate::backend::{create_type, drop_type};
 use sea_orm_migration::{
     prelude::{
         async_trait,
@@ -20,9 +21,11 @@ pub struct Migration;
 impl MigrationTrait for Migration {
     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
         let schema = Schema::new(DbBackend::Postgres);
-        manager
-            .create_type(schema.create_enum_from_active_enum::<NotificationType>())
-            .await?;
+        create_type(
+            manager,
+            schema.create_enum_from_active_enum::<NotificationType>(),
+        )
+        .await?;
         manager
             .create_table(
                 Table::create()
@@ -124,14 +127,14 @@ impl MigrationTrait for Migration {
         manager
             .drop_table(Table::drop().table(WebPushSubscription::Table).to_owned())
             .await?;
-        manager
-            .drop_type(
-                Type::drop()
-                    .if_exists()
-                    .name(NotificationType::name())
-                    .to_owned(),
-            )
-            .await?;
+        drop_type(
+            manager,
+            Type::drop()
+                .if_exists()
+                .name(NotificationType::name())
+                .to_owned(),
+        )
+        .await?;
         Ok(())
     }
 }
@@ -1,3 +1,4 @@
+use crate::backend::alter_type;
 use sea_orm_migration::prelude::{
     async_trait,
     extension::postgres::Type,
@@ -11,14 +12,14 @@ pub struct Migration;
 #[async_trait::async_trait]
 impl MigrationTrait for Migration {
     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
-        manager
-            .alter_type(
-                Type::alter()
-                    .name(NotificationType::name())
-                    .add_value("FocusedDesiredState")
-                    .if_not_exists(),
-            )
-            .await?;
+        alter_type(
+            manager,
+            Type::alter()
+                .name(NotificationType::name())
+                .add_value("FocusedDesiredState")
+                .if_not_exists(),
+        )
+        .await?;
         Ok(())
     }
 
@@ -1,4 +1,5 @@
 use crate::{
+    backend::{alter_type, create_type, drop_type, is_postgres, modify_column},
     m20250908_000001_add_type_to_tags_table::TagType as OldTagType,
     m20250928_000001_create_web_push_subscriptions_and_notification_rules_table::NotificationType as OldNotificationType,
 };
@@ -108,20 +109,28 @@ async fn notification_type<'a>(manager: &SchemaManager<'_>, up: bool) -> Result<
                     .to_owned(),
             )
             .await?;
-        manager
-            .drop_type(Type::drop().name(OldNotificationType::name()).to_owned())
-            .await?;
-        manager
-            .create_type(schema.create_enum_from_active_enum::<NotificationType>())
-            .await?;
+        drop_type(
+            manager,
+            Type::drop().name(OldNotificationType::name()).to_owned(),
+        )
+        .await?;
+        create_type(
+            manager,
+            schema.create_enum_from_active_enum::<NotificationType>(),
+        )
+        .await?;
         Ok(())
     } else {
-        manager
-            .drop_type(Type::drop().name(NotificationType::name()).to_owned())
-            .await?;
-        manager
-            .create_type(schema.create_enum_from_active_enum::<OldNotificationType>())
-            .await?;
+        drop_type(
+            manager,
+            Type::drop().name(NotificationType::name()).to_owned(),
+        )
+        .await?;
+        create_type(
+            manager,
+            schema.create_enum_from_active_enum::<OldNotificationType>(),
+        )
+        .await?;
         manager
             .alter_table(
                 Table::alter()
@@ -141,12 +150,12 @@ async fn notification_type<'a>(manager: &SchemaManager<'_>, up: bool) -> Result<
 async fn tag_type<'a>(manager: &SchemaManager<'_>, up: bool) -> Result<(), DbErr> {
     let schema = Schema::new(DbBackend::Postgres);
     if up {
-        manager
-            .alter_type(Type::alter().name("tag_type").rename_to("tag_type_old"))
-            .await?;
-        manager
-            .create_type(schema.create_enum_from_active_enum::<TagType>())
-            .await?;
+        alter_type(
+            manager,
+            Type::alter().name("tag_type").rename_to("tag_type_old"),
+        )
+        .await?;
+        create_type(manager, schema.create_enum_from_active_enum::<TagType>()).await?;
         manager
             .alter_table(
                 Table::alter()
@@ -170,17 +179,30 @@ async fn tag_type<'a>(manager: &SchemaManager<'_>, up: bool) -> Result<(), DbErr
             .await?;
 
         let db = manager.get_connection();
-        db.execute_unprepared(
-            "UPDATE tag
-                SET type =
-                    CASE
-                        WHEN type_old = 'Ambition'::tag_type_old THEN 'Ambition'::tag_type
-                        WHEN type_old = 'DesiredState'::tag_type_old THEN 'Direction'::tag_type
-                        WHEN type_old = 'Action'::tag_type_old THEN 'Action'::tag_type
-                        ELSE 'Plain'::tag_type
-                    END;
-            ",
-        )
+        match is_postgres(manager) {
+            true => db.execute_unprepared(
+                "UPDATE tag
+                    SET type =
+                        CASE
+                            WHEN type_old = 'Ambition'::tag_type_old THEN 'Ambition'::tag_type
+                            WHEN type_old = 'DesiredState'::tag_type_old THEN 'Direction'::tag_type
+                            WHEN type_old = 'Action'::tag_type_old THEN 'Action'::tag_type
+                            ELSE 'Plain'::tag_type
+                        END;
+                ",
+            ),
+            false => db.execute_unprepared(
+                "UPDATE tag
+                    SET type =
+                        CASE
+                            WHEN type_old = 'Ambition' THEN 'Ambition'
+                            WHEN type_old = 'DesiredState' THEN 'Direction'
+                            WHEN type_old = 'Action' THEN 'Action'
+                            ELSE 'Plain'
+                        END;
+                ",
+            ),
+        }
         .await?;
 
         manager
@@ -191,22 +213,18 @@ async fn tag_type<'a>(manager: &SchemaManager<'_>, up: bool) -> Result<(), DbErr
                     .to_owned(),
             )
             .await?;
-        manager
-            .drop_type(Type::drop().name("tag_type_old").to_owned())
-            .await?;
+        drop_type(manager, Type::drop().name("tag_type_old").to_owned()).await?;
 
         Ok(())
     } else {
-        manager
-            .alter_type(
-                Type::alter()
-                    .name(TagType::name())
-                    .rename_to("tag_type_new"),
-            )
-            .await?;
-        manager
-            .create_type(schema.create_enum_from_active_enum::<OldTagType>())
-            .await?;
+        alter_type(
+            manager,
+            Type::alter()
+                .name(TagType::name())
+                .rename_to("tag_type_new"),
+        )
+        .await?;
+        create_type(manager, schema.create_enum_from_active_enum::<OldTagType>()).await?;
         manager
             .alter_table(
                 Table::alter()
@@ -225,17 +243,30 @@ async fn tag_type<'a>(manager: &SchemaManager<'_>, up: bool) -> Result<(), DbErr
             .await?;
 
         let db = manager.get_connection();
-        db.execute_unprepared(
-            "UPDATE tag
-                SET type =
-                    CASE
-                        WHEN type_new = 'Ambition'::tag_type_new THEN 'Ambition'::tag_type
-                        WHEN type_new = 'Direction'::tag_type_new THEN 'DesiredState'::tag_type
-                        WHEN type_new = 'Action'::tag_type_new THEN 'Action'::tag_type
-                        ELSE 'Plain'::tag_type
-                    END;
-            ",
-        )
+        match is_postgres(manager) {
+            true => db.execute_unprepared(
+                "UPDATE tag
+                    SET type =
+                        CASE
+                            WHEN type_new = 'Ambition'::tag_type_new THEN 'Ambition'::tag_type
+                            WHEN type_new = 'Direction'::tag_type_new THEN 'DesiredState'::tag_type
+                            WHEN type_new = 'Action'::tag_type_new THEN 'Action'::tag_type
+                            ELSE 'Plain'::tag_type
+                        END;
+                ",
+            ),
+            false => db.execute_unprepared(
+                "UPDATE tag
+                    SET type =
+                        CASE
+                            WHEN type_new = 'Ambition' THEN 'Ambition'
+                            WHEN type_new = 'Direction' THEN 'DesiredState'
+                            WHEN type_new = 'Action' THEN 'Action'
+                            ELSE 'Plain'
+                        END;
+                ",
+            ),
+        }
         .await?;
 
         manager
@@ -246,21 +277,19 @@ async fn tag_type<'a>(manager: &SchemaManager<'_>, up: bool) -> Result<(), DbErr
                     .to_owned(),
             )
             .await?;
-        manager
-            .drop_type(Type::drop().name("tag_type_new").to_owned())
-            .await?;
-        manager
-            .alter_table(
-                Table::alter()
-                    .table(Tag::Table)
-                    .modify_column(
-                        ColumnDef::new(Tag::Type)
-                            .custom(OldTagType::name())
-                            .not_null(),
-                    )
-                    .to_owned(),
-            )
-            .await?;
+        drop_type(manager, Type::drop().name("tag_type_new").to_owned()).await?;
+        modify_column(
+            manager,
+            Table::alter()
+                .table(Tag::Table)
+                .modify_column(
+                    ColumnDef::new(Tag::Type)
+                        .custom(OldTagType::name())
+                        .not_null(),
+                )
+                .to_owned(),
+        )
+        .await?;
 
         Ok(())
     }
@@ -0,0 +1,123 @@
+use crate::backend::is_postgres;
+use sea_orm_migration::{
+    prelude::{
+        async_trait,
+        sea_orm::{self, DeriveIden},
+        ConnectionTrait, DbErr, DeriveMigrationName, DynIden, Expr, IntoIden, MigrationTrait,
+        SchemaManager, Table,
+    },
+    schema::timestamp_with_time_zone,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Tables which are returned by the sync endpoint but did not have updated_at yet.
+fn tables() -> Vec<DynIden> {
+    vec![
+        ActionGoal::Table.into_iden(),
+        ActionTrack::Table.into_iden(),
+        Diary::Table.into_iden(),
+        DirectionCategory::Table.into_iden(),
+        Tag::Table.into_iden(),
+    ]
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in tables() {
+            // SQLite cannot add a column whose default is not a constant, so existing rows are
+            // stamped by an update and new ones by a trigger there instead.
+            let default = match is_postgres(manager) {
+                true => Expr::current_timestamp().into(),
+                false => Expr::value(SQLITE_UNSET),
+            };
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table.clone())
+                        .add_column_if_not_exists(
+                            timestamp_with_time_zone(UpdatedAt).default(default),
+                        )
+                        .to_owned(),
+                )
+                .await?;
+            if !is_postgres(manager) {
+                let table = table.to_string();
+                let db = manager.get_connection();
+                db.execute_unprepared(&format!(
+                    r#"UPDATE "{table}" SET "updated_at" = {SQLITE_NOW}"#
+                ))
+                .await?;
+                db.execute_unprepared(&format!(
+                    r#"CREATE TRIGGER IF NOT EXISTS "{table}_updated_at_on_insert"
+                        AFTER INSERT ON "{table}" WHEN NEW."updated_at" = '{SQLITE_UNSET}'
+                        BEGIN
+                            UPDATE "{table}" SET "updated_at" = {SQLITE_NOW}
+                                WHERE rowid = NEW.rowid;
+                        END"#
+                ))
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in tables() {
+            if !is_postgres(manager) {
+                manager
+                    .get_connection()
+                    .execute_unprepared(&format!(
+                        r#"DROP TRIGGER IF EXISTS "{table}_updated_at_on_insert""#,
+                        table = table.to_string()
+                    ))
+                    .await?;
+            }
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .drop_column(UpdatedAt)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+/// Default of updated_at on SQLite, replaced by the insert trigger.
+const SQLITE_UNSET: &str = "1970-01-01T00:00:00+00:00";
+/// The current time in the RFC 3339 format sqlx stores timestamps in, so that they compare as
+/// text with the ones bound by the adapters.
+const SQLITE_NOW: &str = "strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')";
+
+#[derive(DeriveIden)]
+struct UpdatedAt;
+
+#[derive(DeriveIden)]
+pub enum ActionGoal {
+    Table,
+}
+
+#[derive(DeriveIden)]
+pub enum ActionTrack {
+    Table,
+}
+
+#[derive(DeriveIden)]
+pub enum Diary {
+    Table,
+}
+
+#[derive(DeriveIden)]
+pub enum DirectionCategory {
+    Table,
+}
+
+#[derive(DeriveIden)]
+pub enum Tag {
+    Table,
+}
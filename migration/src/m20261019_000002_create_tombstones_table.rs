@@ -0,0 +1,76 @@
+use sea_orm_migration::{
+    prelude::{
+        async_trait,
+        sea_orm::{self, DeriveIden},
+        DbErr, DeriveMigrationName, Expr, ForeignKey, ForeignKeyAction, Index, MigrationTrait,
+        SchemaManager, Table,
+    },
+    schema::{string, timestamp_with_time_zone, uuid},
+};
+
+use crate::m20240722_000001_create_users_table::User;
+
+const INDEX_NAME: &str = "tombstones_user_id_deleted_at_index";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Tombstone::Table)
+                    .if_not_exists()
+                    .col(uuid(Tombstone::Id).primary_key())
+                    .col(uuid(Tombstone::UserId))
+                    .col(string(Tombstone::TableName))
+                    .col(uuid(Tombstone::RecordId))
+                    .col(
+                        timestamp_with_time_zone(Tombstone::DeletedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-tombstones-user_id")
+                            .from(Tombstone::Table, Tombstone::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(INDEX_NAME)
+                    .table(Tombstone::Table)
+                    .col(Tombstone::UserId)
+                    .col(Tombstone::DeletedAt)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name(INDEX_NAME).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Tombstone::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Tombstone {
+    Table,
+    Id,
+    UserId,
+    TableName,
+    RecordId,
+    DeletedAt,
+}
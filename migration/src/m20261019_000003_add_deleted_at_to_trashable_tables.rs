@@ -0,0 +1,89 @@
+use sea_orm_migration::{
+    prelude::{
+        async_trait,
+        sea_orm::{self, DeriveIden},
+        DbErr, DeriveMigrationName, DynIden, IntoIden, MigrationTrait, SchemaManager, Table,
+    },
+    schema::timestamp_with_time_zone_null,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Tables whose records go to the trash on delete instead of being deleted right away.
+/// Trashed rows stay in the table, so a unique index on any of these would need `WHERE deleted_at IS NULL`.
+/// None has one: diaries_user_id_date_unique_index was dropped in m20250712_000001.
+fn tables() -> Vec<DynIden> {
+    vec![
+        Ambition::Table.into_iden(),
+        Direction::Table.into_iden(),
+        Action::Table.into_iden(),
+        Diary::Table.into_iden(),
+        ReadingNote::Table.into_iden(),
+        ThinkingNote::Table.into_iden(),
+    ]
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in tables() {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .add_column_if_not_exists(timestamp_with_time_zone_null(DeletedAt))
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in tables() {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .drop_column(DeletedAt)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+struct DeletedAt;
+
+#[derive(DeriveIden)]
+pub enum Ambition {
+    Table,
+}
+
+#[derive(DeriveIden)]
+pub enum Direction {
+    Table,
+}
+
+#[derive(DeriveIden)]
+pub enum Action {
+    Table,
+}
+
+#[derive(DeriveIden)]
+pub enum Diary {
+    Table,
+}
+
+#[derive(DeriveIden)]
+pub enum ReadingNote {
+    Table,
+}
+
+#[derive(DeriveIden)]
+pub enum ThinkingNote {
+    Table,
+}
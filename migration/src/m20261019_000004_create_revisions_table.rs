@@ -0,0 +1,79 @@
+use sea_orm_migration::{
+    prelude::{
+        async_trait,
+        sea_orm::{self, DeriveIden},
+        DbErr, DeriveMigrationName, Expr, ForeignKey, ForeignKeyAction, Index, MigrationTrait,
+        SchemaManager, Table,
+    },
+    schema::{json_binary, string, timestamp_with_time_zone, uuid},
+};
+
+use crate::m20240722_000001_create_users_table::User;
+
+const INDEX_NAME: &str = "revisions_table_name_record_id_created_at_index";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Revision::Table)
+                    .if_not_exists()
+                    .col(uuid(Revision::Id).primary_key())
+                    .col(uuid(Revision::UserId))
+                    .col(string(Revision::TableName))
+                    .col(uuid(Revision::RecordId))
+                    .col(json_binary(Revision::Snapshot))
+                    .col(
+                        timestamp_with_time_zone(Revision::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-revisions-user_id")
+                            .from(Revision::Table, Revision::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(INDEX_NAME)
+                    .table(Revision::Table)
+                    .col(Revision::TableName)
+                    .col(Revision::RecordId)
+                    .col(Revision::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name(INDEX_NAME).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Revision::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Revision {
+    Table,
+    Id,
+    UserId,
+    TableName,
+    RecordId,
+    Snapshot,
+    CreatedAt,
+}
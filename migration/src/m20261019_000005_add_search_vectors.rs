@@ -0,0 +1,117 @@
+use crate::backend::is_postgres;
+use sea_orm_migration::prelude::{
+    async_trait, ConnectionTrait, DbErr, DeriveMigrationName, MigrationTrait, SchemaManager,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// (table, expression of its search document). Weight A is for names, titles and questions,
+/// B for the rest of the text.
+const SEARCHABLE_TABLES: [(&str, &str); 7] = [
+    (
+        "ambition",
+        "search_document(name, 'A') || search_document(description, 'B')",
+    ),
+    (
+        "direction",
+        "search_document(name, 'A') || search_document(description, 'B')",
+    ),
+    (
+        "action",
+        "search_document(name, 'A') || search_document(discipline, 'B') || search_document(memo, 'B')",
+    ),
+    ("tag", "search_document(name, 'A')"),
+    ("diary", "search_document(text, 'B')"),
+    (
+        "reading_note",
+        "search_document(title, 'A') || search_document(text, 'B')",
+    ),
+    (
+        "thinking_note",
+        "search_document(question, 'A') || search_document(thought, 'B') || search_document(answer, 'B')",
+    ),
+];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SQLite has no tsvector. Search falls back to LIKE there, see db_adapters::search_adapter.
+        if !is_postgres(manager) {
+            return Ok(());
+        }
+        let db = manager.get_connection();
+        // Japanese has no spaces between words, so text search parsers see a whole sentence as
+        // one word. Runs of CJK characters are indexed as overlapping bigrams instead, hex encoded
+        // so that each bigram stays a single lexeme whatever the locale of the database is.
+        db.execute_unprepared(
+            r"CREATE OR REPLACE FUNCTION search_cjk_bigrams(input text) RETURNS text
+                LANGUAGE sql IMMUTABLE PARALLEL SAFE
+                AS $$
+                    SELECT coalesce(string_agg(
+                        encode(convert_to(
+                            CASE WHEN char_length(runs.run[1]) = 1 THEN runs.run[1]
+                                ELSE substr(runs.run[1], i, 2)
+                            END,
+                            'UTF8'
+                        ), 'hex'),
+                        ' ' ORDER BY runs.n, i
+                    ), '')
+                    FROM regexp_matches(
+                        coalesce(input, ''),
+                        '[\u3005-\u3007\u3040-\u30ff\u3400-\u4dbf\u4e00-\u9fff\uac00-\ud7af\uf900-\ufaff\uff66-\uff9f]+',
+                        'g'
+                    ) WITH ORDINALITY AS runs(run, n),
+                    generate_series(1, greatest(char_length(runs.run[1]) - 1, 1)) AS i
+                $$;
+            ",
+        )
+        .await?;
+        db.execute_unprepared(
+            r#"CREATE OR REPLACE FUNCTION search_document(input text, weight "char") RETURNS tsvector
+                LANGUAGE sql IMMUTABLE PARALLEL SAFE
+                AS $$
+                    SELECT setweight(
+                        to_tsvector('simple', coalesce(input, '') || ' ' || search_cjk_bigrams(input)),
+                        weight
+                    )
+                $$;
+            "#,
+        )
+        .await?;
+        for (table, document) in SEARCHABLE_TABLES {
+            db.execute_unprepared(&format!(
+                "ALTER TABLE {table}
+                    ADD COLUMN IF NOT EXISTS search_vector tsvector
+                    GENERATED ALWAYS AS ({document}) STORED;
+                CREATE INDEX IF NOT EXISTS {table}_search_vector_index
+                    ON {table} USING GIN (search_vector);
+                "
+            ))
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if !is_postgres(manager) {
+            return Ok(());
+        }
+        let db = manager.get_connection();
+        for (table, _) in SEARCHABLE_TABLES {
+            db.execute_unprepared(&format!(
+                "DROP INDEX IF EXISTS {table}_search_vector_index;
+                ALTER TABLE {table} DROP COLUMN IF EXISTS search_vector;
+                "
+            ))
+            .await?;
+        }
+        db.execute_unprepared(
+            r#"DROP FUNCTION IF EXISTS search_document(text, "char");
+            DROP FUNCTION IF EXISTS search_cjk_bigrams(text);
+            "#,
+        )
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use crate::backend::is_postgres;
+use sea_orm_migration::prelude::{
+    async_trait, ConnectionTrait, DbErr, DeriveMigrationName, MigrationTrait, SchemaManager,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Tables whose rows belong to a user through their user_id column. Link tables between them
+/// have no user_id, and are reachable only through ids of the rows they link.
+const USER_OWNED_TABLES: [&str; 14] = [
+    "action",
+    "action_goal",
+    "action_track",
+    "ambition",
+    "diary",
+    "direction",
+    "direction_category",
+    "notification_rule",
+    "reading_note",
+    "revision",
+    "tag",
+    "thinking_note",
+    "tombstone",
+    "web_push_subscription",
+];
+
+/// Role of web requests, which sees the rows of the user in the `app.user_id` setting only.
+/// See common::db::DbRole.
+const APP_ROLE: &str = "life_tracker_app";
+/// Role of cron processes and admin commands, which sees the rows of every user.
+const BYPASS_ROLE: &str = "life_tracker_bypass_rls";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SQLite has no row-level security. Adapters filtering by user are all there is there.
+        if !is_postgres(manager) {
+            return Ok(());
+        }
+        let db = manager.get_connection();
+        for role in [APP_ROLE, BYPASS_ROLE] {
+            // Roles belong to the server rather than to a database, so another database may
+            // have created them already.
+            db.execute_unprepared(&format!(
+                "DO $$ BEGIN
+                    CREATE ROLE {role} NOLOGIN;
+                EXCEPTION WHEN duplicate_object OR unique_violation THEN NULL;
+                END $$;
+                GRANT {role} TO CURRENT_USER;
+                GRANT USAGE ON SCHEMA public TO {role};
+                GRANT SELECT, INSERT, UPDATE, DELETE ON ALL TABLES IN SCHEMA public TO {role};
+                GRANT USAGE, SELECT ON ALL SEQUENCES IN SCHEMA public TO {role};
+                ALTER DEFAULT PRIVILEGES IN SCHEMA public
+                    GRANT SELECT, INSERT, UPDATE, DELETE ON TABLES TO {role};
+                ALTER DEFAULT PRIVILEGES IN SCHEMA public
+                    GRANT USAGE, SELECT ON SEQUENCES TO {role};
+                "
+            ))
+            .await?;
+        }
+        // NOTE: Not forced, so that the owner running migrations still sees every row.
+        for table in USER_OWNED_TABLES {
+            db.execute_unprepared(&format!(
+                "ALTER TABLE {table} ENABLE ROW LEVEL SECURITY;
+                CREATE POLICY {table}_user_isolation ON {table}
+                    USING (user_id = NULLIF(current_setting('app.user_id', true), '')::uuid);
+                CREATE POLICY {table}_bypass_rls ON {table} TO {BYPASS_ROLE}
+                    USING (true) WITH CHECK (true);
+                "
+            ))
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if !is_postgres(manager) {
+            return Ok(());
+        }
+        let db = manager.get_connection();
+        for table in USER_OWNED_TABLES {
+            db.execute_unprepared(&format!(
+                "DROP POLICY IF EXISTS {table}_bypass_rls ON {table};
+                DROP POLICY IF EXISTS {table}_user_isolation ON {table};
+                ALTER TABLE {table} DISABLE ROW LEVEL SECURITY;
+                "
+            ))
+            .await?;
+        }
+        // The roles are left, since other databases of the server may still use them.
+        for role in [APP_ROLE, BYPASS_ROLE] {
+            db.execute_unprepared(&format!(
+                "ALTER DEFAULT PRIVILEGES IN SCHEMA public
+                    REVOKE USAGE, SELECT ON SEQUENCES FROM {role};
+                ALTER DEFAULT PRIVILEGES IN SCHEMA public
+                    REVOKE SELECT, INSERT, UPDATE, DELETE ON TABLES FROM {role};
+                REVOKE USAGE, SELECT ON ALL SEQUENCES IN SCHEMA public FROM {role};
+                REVOKE SELECT, INSERT, UPDATE, DELETE ON ALL TABLES IN SCHEMA public FROM {role};
+                REVOKE USAGE ON SCHEMA public FROM {role};
+                "
+            ))
+            .await?;
+        }
+        Ok(())
+    }
+}
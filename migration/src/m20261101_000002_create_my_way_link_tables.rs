@@ -0,0 +1,123 @@
+use sea_orm_migration::{
+    prelude::{
+        async_trait,
+        sea_orm::{self, DeriveIden},
+        DbErr, DeriveMigrationName, ForeignKey, ForeignKeyAction, Index, MigrationTrait,
+        SchemaManager, Table,
+    },
+    schema::uuid,
+};
+
+/// Brings back the links between the levels of my_way, which
+/// m20250412_000001_drop_my_way_link_tables dropped along with desired states.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AmbitionsDirections::Table)
+                    .if_not_exists()
+                    .col(uuid(AmbitionsDirections::AmbitionId))
+                    .col(uuid(AmbitionsDirections::DirectionId))
+                    .primary_key(
+                        Index::create()
+                            .name("pk-ambitions_directions")
+                            .col(AmbitionsDirections::AmbitionId)
+                            .col(AmbitionsDirections::DirectionId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-ambitions_directions-ambition_id")
+                            .from(AmbitionsDirections::Table, AmbitionsDirections::AmbitionId)
+                            .to(Ambition::Table, Ambition::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-ambitions_directions-direction_id")
+                            .from(AmbitionsDirections::Table, AmbitionsDirections::DirectionId)
+                            .to(Direction::Table, Direction::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_table(
+                Table::create()
+                    .table(DirectionsActions::Table)
+                    .if_not_exists()
+                    .col(uuid(DirectionsActions::DirectionId))
+                    .col(uuid(DirectionsActions::ActionId))
+                    .primary_key(
+                        Index::create()
+                            .name("pk-directions_actions")
+                            .col(DirectionsActions::DirectionId)
+                            .col(DirectionsActions::ActionId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-directions_actions-direction_id")
+                            .from(DirectionsActions::Table, DirectionsActions::DirectionId)
+                            .to(Direction::Table, Direction::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-directions_actions-action_id")
+                            .from(DirectionsActions::Table, DirectionsActions::ActionId)
+                            .to(Action::Table, Action::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(DirectionsActions::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(AmbitionsDirections::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Ambition {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+pub enum Direction {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+pub enum Action {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+pub enum AmbitionsDirections {
+    Table,
+    AmbitionId,
+    DirectionId,
+}
+
+#[derive(DeriveIden)]
+pub enum DirectionsActions {
+    Table,
+    DirectionId,
+    ActionId,
+}
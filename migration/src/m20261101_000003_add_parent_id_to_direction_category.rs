@@ -0,0 +1,96 @@
+use crate::backend::is_postgres;
+use sea_orm_migration::{
+    prelude::{
+        async_trait,
+        sea_orm::{self, DeriveIden},
+        Alias, ConnectionTrait, DbErr, DeriveMigrationName, ForeignKeyAction, Index,
+        MigrationTrait, SchemaManager, Table, TableForeignKey,
+    },
+    schema::uuid_null,
+};
+
+const PARENT_ID_INDEX_NAME: &str = "direction_category_parent_id_index";
+const PARENT_ID_FOREIGN_KEY_NAME: &str = "fk-direction_category-parent_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // NOTE: Deleting a category moves its children up to its parent in the adapter.
+        //       SET NULL only guards rows deleted otherwise, e.g. along with the user.
+        match is_postgres(manager) {
+            true => {
+                manager
+                    .alter_table(
+                        Table::alter()
+                            .table(DirectionCategory::Table)
+                            .add_column_if_not_exists(uuid_null(DirectionCategory::ParentId))
+                            .add_foreign_key(
+                                TableForeignKey::new()
+                                    .name(PARENT_ID_FOREIGN_KEY_NAME)
+                                    .from_tbl(DirectionCategory::Table)
+                                    .from_col(DirectionCategory::ParentId)
+                                    .to_tbl(DirectionCategory::Table)
+                                    .to_col(DirectionCategory::Id)
+                                    .on_delete(ForeignKeyAction::SetNull),
+                            )
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+            false => {
+                manager
+                    .get_connection()
+                    .execute_unprepared(
+                        r#"ALTER TABLE "direction_category" ADD COLUMN "parent_id" uuid_text
+                            REFERENCES "direction_category" ("id") ON DELETE SET NULL"#,
+                    )
+                    .await?;
+            }
+        }
+        manager
+            .create_index(
+                Index::create()
+                    .name(PARENT_ID_INDEX_NAME)
+                    .table(DirectionCategory::Table)
+                    .col(DirectionCategory::ParentId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name(PARENT_ID_INDEX_NAME).to_owned())
+            .await?;
+        if is_postgres(manager) {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(DirectionCategory::Table)
+                        .drop_foreign_key(Alias::new(PARENT_ID_FOREIGN_KEY_NAME))
+                        .to_owned(),
+                )
+                .await?;
+        }
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(DirectionCategory::Table)
+                    .drop_column(DirectionCategory::ParentId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum DirectionCategory {
+    Table,
+    Id,
+    ParentId,
+}
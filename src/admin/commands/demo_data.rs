@@ -0,0 +1,136 @@
+use chrono::NaiveDate;
+use common::factory::{insert_demo_user, DemoUserParams};
+use db_adapters::user_adapter::{UserAdapter, UserQuery};
+use sea_orm::DbConn;
+
+use crate::commands::users::hash_password;
+
+pub async fn seed(
+    db: &DbConn,
+    email: String,
+    password: String,
+    seed: u64,
+    days: u32,
+    end_date: Option<NaiveDate>,
+) -> Result<(), String> {
+    if UserAdapter::init(db)
+        .get_by_email(email.clone())
+        .await
+        .map_err(|e| format!("Error on getting user: {:?}", e))?
+        .is_some()
+    {
+        return Err(format!("User with email {} already exists.", email));
+    }
+    let user = insert_demo_user(
+        db,
+        DemoUserParams {
+            email,
+            hashed_password: hash_password(&password)?,
+            seed,
+            days,
+            end_date,
+        },
+    )
+    .await
+    .map_err(|e| format!("Error on seeding demo data: {:?}", e))?;
+    println!(
+        "Seeded demo user {} ({}) with {} days of data.",
+        user.email, user.id, days
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, FixedOffset};
+    use common::{db::test_db::init_test_db, settings::get_test_settings};
+    use entities::{
+        action, action_goal, action_track, diary, sea_orm_active_enums::ActionTrackType,
+    };
+    use sea_orm::{ColumnTrait, DbErr, EntityTrait, QueryFilter, QueryOrder};
+    use uuid::Uuid;
+
+    use super::*;
+
+    type Content = (
+        Vec<(String, ActionTrackType)>,
+        Vec<(NaiveDate, Option<NaiveDate>, Option<i32>, Option<i32>)>,
+        Vec<(DateTime<FixedOffset>, Option<i64>)>,
+        Vec<(NaiveDate, Option<String>)>,
+    );
+
+    async fn seed_content(db: &DbConn, seed: u64) -> Result<Content, DbErr> {
+        let user = insert_demo_user(
+            db,
+            DemoUserParams {
+                email: format!("{}@test.com", Uuid::now_v7()),
+                hashed_password: "password".to_string(),
+                seed,
+                days: 90,
+                end_date: NaiveDate::from_ymd_opt(2026, 3, 31),
+            },
+        )
+        .await?;
+        let actions = action::Entity::find()
+            .filter(action::Column::UserId.eq(user.id))
+            .order_by_asc(action::Column::Ordering)
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|action| (action.name, action.track_type))
+            .collect();
+        // NOTE: Sorted by content rather than ids, which differ between seedings.
+        let mut action_goals = action_goal::Entity::find()
+            .filter(action_goal::Column::UserId.eq(user.id))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|goal| {
+                (
+                    goal.from_date,
+                    goal.to_date,
+                    goal.duration_seconds,
+                    goal.count,
+                )
+            })
+            .collect::<Vec<_>>();
+        action_goals.sort();
+        let mut action_tracks = action_track::Entity::find()
+            .filter(action_track::Column::UserId.eq(user.id))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|track| (track.started_at, track.duration))
+            .collect::<Vec<_>>();
+        action_tracks.sort();
+        let diaries = diary::Entity::find()
+            .filter(diary::Column::UserId.eq(user.id))
+            .order_by_asc(diary::Column::Date)
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|diary| (diary.date, diary.text))
+            .collect();
+        Ok((actions, action_goals, action_tracks, diaries))
+    }
+
+    #[tokio::test]
+    async fn same_seed_gives_same_data() -> Result<(), DbErr> {
+        let settings = get_test_settings();
+        let db = init_test_db(&settings).await;
+
+        let content = seed_content(&db, 42).await?;
+        let (actions, action_goals, action_tracks, diaries) = &content;
+        assert!(actions.contains(&("Running".to_string(), ActionTrackType::TimeSpan)));
+        assert!(actions.contains(&("Meditation".to_string(), ActionTrackType::Count)));
+        // The goal of each action changes once.
+        assert_eq!(action_goals.len(), actions.len() * 2);
+        assert!(action_tracks.len() > 90);
+        assert!(diaries.len() > 30);
+
+        assert_eq!(seed_content(&db, 42).await?, content);
+        assert_ne!(seed_content(&db, 43).await?.2, content.2);
+
+        Ok(())
+    }
+}
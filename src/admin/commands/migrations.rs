@@ -0,0 +1,20 @@
+use common::db::{get_migration_statuses, run_migrations};
+use sea_orm::DbConn;
+
+pub async fn up(db: &DbConn) -> Result<(), String> {
+    run_migrations(db)
+        .await
+        .map_err(|e| format!("Error on running migrations: {:?}", e))?;
+    println!("All migrations are applied.");
+    Ok(())
+}
+
+pub async fn status(db: &DbConn) -> Result<(), String> {
+    let statuses = get_migration_statuses(db)
+        .await
+        .map_err(|e| format!("Error on getting migration statuses: {:?}", e))?;
+    for (name, status) in statuses {
+        println!("{:<8} {}", status, name);
+    }
+    Ok(())
+}
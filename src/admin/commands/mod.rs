@@ -0,0 +1,55 @@
+use std::{
+    env,
+    io::{self, BufRead, IsTerminal, Write},
+};
+
+use db_adapters::user_adapter::{UserAdapter, UserQuery};
+use entities::user;
+use sea_orm::DbConn;
+
+pub mod demo_data;
+pub mod migrations;
+pub mod push;
+pub mod stats;
+pub mod user_data;
+pub mod users;
+
+const PASSWORD_ENV_VAR: &str = "ADMIN_USER_PASSWORD";
+
+async fn get_user_by_email(db: &DbConn, email: String) -> Result<user::Model, String> {
+    UserAdapter::init(db)
+        .get_by_email(email.clone())
+        .await
+        .map_err(|e| format!("Error on getting user: {:?}", e))?
+        .ok_or(format!("User with email {} does not exist.", email))
+}
+
+/// Passwords are not taken as arguments, which `ps` and shell history would show.
+/// Reads ADMIN_USER_PASSWORD if set, otherwise the first line of stdin, e.g. piped from a secret store.
+pub fn read_password() -> Result<String, String> {
+    let password = match env::var(PASSWORD_ENV_VAR) {
+        Ok(password) => password,
+        Err(_) => {
+            let stdin = io::stdin();
+            if stdin.is_terminal() {
+                eprint!("Password: ");
+                io::stderr()
+                    .flush()
+                    .map_err(|e| format!("Error on prompting password: {:?}", e))?;
+            }
+            let mut line = String::new();
+            stdin
+                .lock()
+                .read_line(&mut line)
+                .map_err(|e| format!("Error on reading password: {:?}", e))?;
+            line.trim_end_matches(['\r', '\n']).to_string()
+        }
+    };
+    match password.is_empty() {
+        true => Err(format!(
+            "Password is empty. Set {} or pass it through stdin.",
+            PASSWORD_ENV_VAR
+        )),
+        false => Ok(password),
+    }
+}
@@ -0,0 +1,26 @@
+use common::settings::types::Settings;
+use cron_processes::notification::send_test_message;
+use db_adapters::web_push_subscription_adapter::{
+    WebPushSubscriptionAdapter, WebPushSubscriptionQuery,
+};
+use sea_orm::DbConn;
+
+use crate::commands::get_user_by_email;
+
+pub async fn send_test_push(settings: &Settings, db: &DbConn, email: String) -> Result<(), String> {
+    let user = get_user_by_email(db, email).await?;
+    if WebPushSubscriptionAdapter::init(db)
+        .get_by_user(&user)
+        .await
+        .map_err(|e| format!("Error on getting web_push_subscription: {:?}", e))?
+        .is_none()
+    {
+        return Err(format!("User {} has no web_push_subscription.", user.email));
+    }
+    send_test_message(settings, db, user.id).await;
+    println!(
+        "Sent a test push to {}. Check the logs if it does not arrive.",
+        user.email
+    );
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use entities::{
+    action, action_track, ambition, diary, direction, notification_rule, reading_note,
+    thinking_note, user, web_push_subscription,
+};
+use sea_orm::{ColumnTrait, DbConn, DbErr, EntityTrait, PaginatorTrait, QueryFilter};
+
+pub async fn print_stats(db: &DbConn) -> Result<(), String> {
+    let stats = get_stats(db)
+        .await
+        .map_err(|e| format!("Error on getting stats: {:?}", e))?;
+    for (name, count) in stats {
+        println!("{:<24} {}", name, count);
+    }
+    Ok(())
+}
+
+async fn get_stats(db: &DbConn) -> Result<Vec<(&'static str, u64)>, DbErr> {
+    Ok(vec![
+        ("users", user::Entity::find().count(db).await?),
+        (
+            "active_users",
+            user::Entity::find()
+                .filter(user::Column::IsActive.eq(true))
+                .count(db)
+                .await?,
+        ),
+        ("ambitions", ambition::Entity::find().count(db).await?),
+        ("directions", direction::Entity::find().count(db).await?),
+        ("actions", action::Entity::find().count(db).await?),
+        (
+            "action_tracks",
+            action_track::Entity::find().count(db).await?,
+        ),
+        ("diaries", diary::Entity::find().count(db).await?),
+        (
+            "reading_notes",
+            reading_note::Entity::find().count(db).await?,
+        ),
+        (
+            "thinking_notes",
+            thinking_note::Entity::find().count(db).await?,
+        ),
+        (
+            "notification_rules",
+            notification_rule::Entity::find().count(db).await?,
+        ),
+        (
+            "web_push_subscriptions",
+            web_push_subscription::Entity::find().count(db).await?,
+        ),
+    ])
+}
@@ -0,0 +1,433 @@
+use std::{fs, path::PathBuf};
+
+use chrono::{DateTime, Utc};
+use entities::{
+    action, action_goal, action_track, ambition, ambitions_directions, diaries_tags, diary,
+    direction, direction_category, directions_actions, notification_rule, reading_note,
+    reading_notes_tags, revision, tag, thinking_note, thinking_note_tags, tombstone,
+};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbConn, DbErr, EntityTrait, IntoActiveModel,
+    QueryFilter, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::commands::get_user_by_email;
+
+const EXPORT_FORMAT_VERSION: u32 = 1;
+/// Keeps the number of bind parameters of one INSERT well below Postgres' limit.
+const INSERT_CHUNK_SIZE: usize = 1000;
+
+/// Everything a user owns, except for web_push_subscriptions which only make sense on the device.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct UserDataExport {
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub ambitions: Vec<ambition::Model>,
+    pub direction_categories: Vec<direction_category::Model>,
+    pub directions: Vec<direction::Model>,
+    pub actions: Vec<action::Model>,
+    /// Defaults to empty, for exports from before the links existed.
+    #[serde(default)]
+    pub ambitions_directions: Vec<ambitions_directions::Model>,
+    #[serde(default)]
+    pub directions_actions: Vec<directions_actions::Model>,
+    pub action_goals: Vec<action_goal::Model>,
+    pub action_tracks: Vec<action_track::Model>,
+    pub tags: Vec<tag::Model>,
+    pub diaries: Vec<diary::Model>,
+    pub diaries_tags: Vec<diaries_tags::Model>,
+    pub reading_notes: Vec<reading_note::Model>,
+    pub reading_notes_tags: Vec<reading_notes_tags::Model>,
+    pub thinking_notes: Vec<thinking_note::Model>,
+    pub thinking_note_tags: Vec<thinking_note_tags::Model>,
+    pub notification_rules: Vec<notification_rule::Model>,
+    /// Kept so that the history of items and deletions known to sync clients survive a restore.
+    /// Defaults to empty, for exports from before they were exported.
+    #[serde(default)]
+    pub revisions: Vec<revision::Model>,
+    #[serde(default)]
+    pub tombstones: Vec<tombstone::Model>,
+}
+
+pub async fn export(db: &DbConn, email: String, output: Option<PathBuf>) -> Result<(), String> {
+    let user = get_user_by_email(db, email).await?;
+    let data = collect_user_data(db, user.id)
+        .await
+        .map_err(|e| format!("Error on collecting user data: {:?}", e))?;
+    let json = serde_json::to_string_pretty(&data)
+        .map_err(|e| format!("Error on serializing user data: {:?}", e))?;
+    match output {
+        Some(path) => {
+            fs::write(&path, json).map_err(|e| format!("Error on writing file: {:?}", e))?;
+            eprintln!("Exported data of {} to {}.", user.email, path.display());
+        }
+        None => println!("{}", json),
+    }
+    Ok(())
+}
+
+pub async fn import(db: &DbConn, email: String, input: PathBuf) -> Result<(), String> {
+    let user = get_user_by_email(db, email).await?;
+    let json = fs::read_to_string(&input).map_err(|e| format!("Error on reading file: {:?}", e))?;
+    let data: UserDataExport =
+        serde_json::from_str(&json).map_err(|e| format!("Error on parsing user data: {:?}", e))?;
+    if data.version != EXPORT_FORMAT_VERSION {
+        return Err(format!(
+            "Unsupported export version {}, expected {}.",
+            data.version, EXPORT_FORMAT_VERSION
+        ));
+    }
+    insert_user_data(db, user.id, data)
+        .await
+        .map_err(|e| format!("Error on importing user data: {:?}", e))?;
+    println!("Imported data into {}.", user.email);
+    Ok(())
+}
+
+async fn collect_user_data(db: &DbConn, user_id: Uuid) -> Result<UserDataExport, DbErr> {
+    Ok(UserDataExport {
+        version: EXPORT_FORMAT_VERSION,
+        exported_at: Utc::now(),
+        ambitions: ambition::Entity::find()
+            .filter(ambition::Column::UserId.eq(user_id))
+            .all(db)
+            .await?,
+        direction_categories: direction_category::Entity::find()
+            .filter(direction_category::Column::UserId.eq(user_id))
+            .all(db)
+            .await?,
+        directions: direction::Entity::find()
+            .filter(direction::Column::UserId.eq(user_id))
+            .all(db)
+            .await?,
+        actions: action::Entity::find()
+            .filter(action::Column::UserId.eq(user_id))
+            .all(db)
+            .await?,
+        ambitions_directions: ambitions_directions::Entity::find()
+            .inner_join(ambition::Entity)
+            .filter(ambition::Column::UserId.eq(user_id))
+            .all(db)
+            .await?,
+        directions_actions: directions_actions::Entity::find()
+            .inner_join(direction::Entity)
+            .filter(direction::Column::UserId.eq(user_id))
+            .all(db)
+            .await?,
+        action_goals: action_goal::Entity::find()
+            .filter(action_goal::Column::UserId.eq(user_id))
+            .all(db)
+            .await?,
+        action_tracks: action_track::Entity::find()
+            .filter(action_track::Column::UserId.eq(user_id))
+            .all(db)
+            .await?,
+        tags: tag::Entity::find()
+            .filter(tag::Column::UserId.eq(user_id))
+            .all(db)
+            .await?,
+        diaries: diary::Entity::find()
+            .filter(diary::Column::UserId.eq(user_id))
+            .all(db)
+            .await?,
+        diaries_tags: diaries_tags::Entity::find()
+            .inner_join(diary::Entity)
+            .filter(diary::Column::UserId.eq(user_id))
+            .all(db)
+            .await?,
+        reading_notes: reading_note::Entity::find()
+            .filter(reading_note::Column::UserId.eq(user_id))
+            .all(db)
+            .await?,
+        reading_notes_tags: reading_notes_tags::Entity::find()
+            .inner_join(reading_note::Entity)
+            .filter(reading_note::Column::UserId.eq(user_id))
+            .all(db)
+            .await?,
+        thinking_notes: thinking_note::Entity::find()
+            .filter(thinking_note::Column::UserId.eq(user_id))
+            .all(db)
+            .await?,
+        thinking_note_tags: thinking_note_tags::Entity::find()
+            .inner_join(thinking_note::Entity)
+            .filter(thinking_note::Column::UserId.eq(user_id))
+            .all(db)
+            .await?,
+        notification_rules: notification_rule::Entity::find()
+            .filter(notification_rule::Column::UserId.eq(user_id))
+            .all(db)
+            .await?,
+        revisions: revision::Entity::find()
+            .filter(revision::Column::UserId.eq(user_id))
+            .all(db)
+            .await?,
+        tombstones: tombstone::Entity::find()
+            .filter(tombstone::Column::UserId.eq(user_id))
+            .all(db)
+            .await?,
+    })
+}
+
+/// Inserts exported records as they are, except for user_id. Ids are kept so that links stay valid,
+/// which means importing into the same DB as the export fails on duplicates and is rolled back.
+async fn insert_user_data(db: &DbConn, user_id: Uuid, data: UserDataExport) -> Result<(), DbErr> {
+    let txn = db.begin().await?;
+
+    insert_all(
+        &txn,
+        data.ambitions
+            .into_iter()
+            .map(|m| ambition::Model { user_id, ..m }),
+    )
+    .await?;
+    insert_all(
+        &txn,
+        parents_first(data.direction_categories)
+            .into_iter()
+            .map(|m| direction_category::Model { user_id, ..m }),
+    )
+    .await?;
+    insert_all(
+        &txn,
+        data.directions
+            .into_iter()
+            .map(|m| direction::Model { user_id, ..m }),
+    )
+    .await?;
+    insert_all(
+        &txn,
+        data.actions
+            .into_iter()
+            .map(|m| action::Model { user_id, ..m }),
+    )
+    .await?;
+    insert_all(&txn, data.ambitions_directions).await?;
+    insert_all(&txn, data.directions_actions).await?;
+    insert_all(
+        &txn,
+        data.action_goals
+            .into_iter()
+            .map(|m| action_goal::Model { user_id, ..m }),
+    )
+    .await?;
+    insert_all(
+        &txn,
+        data.action_tracks
+            .into_iter()
+            .map(|m| action_track::Model { user_id, ..m }),
+    )
+    .await?;
+    insert_all(
+        &txn,
+        data.tags.into_iter().map(|m| tag::Model { user_id, ..m }),
+    )
+    .await?;
+    insert_all(
+        &txn,
+        data.diaries
+            .into_iter()
+            .map(|m| diary::Model { user_id, ..m }),
+    )
+    .await?;
+    insert_all(&txn, data.diaries_tags).await?;
+    insert_all(
+        &txn,
+        data.reading_notes
+            .into_iter()
+            .map(|m| reading_note::Model { user_id, ..m }),
+    )
+    .await?;
+    insert_all(&txn, data.reading_notes_tags).await?;
+    insert_all(
+        &txn,
+        data.thinking_notes
+            .into_iter()
+            .map(|m| thinking_note::Model { user_id, ..m }),
+    )
+    .await?;
+    insert_all(&txn, data.thinking_note_tags).await?;
+    insert_all(
+        &txn,
+        data.notification_rules
+            .into_iter()
+            .map(|m| notification_rule::Model { user_id, ..m }),
+    )
+    .await?;
+    insert_all(
+        &txn,
+        data.revisions
+            .into_iter()
+            .map(|m| revision::Model { user_id, ..m }),
+    )
+    .await?;
+    insert_all(
+        &txn,
+        data.tombstones
+            .into_iter()
+            .map(|m| tombstone::Model { user_id, ..m }),
+    )
+    .await?;
+
+    txn.commit().await
+}
+
+/// Categories in an order where each comes after its parent, which it references by a foreign
+/// key. Any left over, which cannot happen unless the parents form a cycle, come last.
+fn parents_first(mut categories: Vec<direction_category::Model>) -> Vec<direction_category::Model> {
+    let mut sorted: Vec<direction_category::Model> = Vec::with_capacity(categories.len());
+    loop {
+        let (ready, rest): (Vec<_>, Vec<_>) = categories.into_iter().partition(|category| {
+            category
+                .parent_id
+                .is_none_or(|parent_id| sorted.iter().any(|sorted| sorted.id == parent_id))
+        });
+        categories = rest;
+        if ready.is_empty() {
+            break;
+        }
+        sorted.extend(ready);
+    }
+    sorted.extend(categories);
+    sorted
+}
+
+async fn insert_all<M, A, C>(db: &C, models: impl IntoIterator<Item = M>) -> Result<(), DbErr>
+where
+    M: IntoActiveModel<A>,
+    A: ActiveModelTrait + Send,
+    <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
+    C: ConnectionTrait,
+{
+    let active_models = models
+        .into_iter()
+        .map(|model| model.into_active_model().reset_all())
+        .collect::<Vec<A>>();
+    for chunk in active_models.chunks(INSERT_CHUNK_SIZE) {
+        A::Entity::insert_many(chunk.to_vec())
+            .exec_without_returning(db)
+            .await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{Set, TryIntoModel};
+
+    use common::{
+        db::test_db::init_test_db,
+        factory::{self, *},
+        settings::get_test_settings,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn export_and_import_into_another_user() -> Result<(), DbErr> {
+        let settings = get_test_settings();
+        let db = init_test_db(&settings).await;
+        let user = factory::user().insert(&db).await?;
+        let ambition = factory::ambition(user.id).insert(&db).await?;
+        let action = factory::action(user.id).insert(&db).await?;
+        let action_track = factory::action_track(user.id)
+            .action_id(action.id)
+            .insert(&db)
+            .await?;
+        let tag = factory::tag(user.id).insert(&db).await?;
+        let diary = factory::diary(user.id).insert(&db).await?;
+        let diary_tag = factory::link_diary_tag(&db, diary.id, tag.id).await?;
+        let revision = revision::ActiveModel {
+            id: Set(Uuid::now_v7()),
+            user_id: Set(user.id),
+            table_name: Set("ambition".to_string()),
+            record_id: Set(ambition.id),
+            snapshot: Set(serde_json::to_value(&ambition).unwrap()),
+            created_at: Set(Utc::now().into()),
+        }
+        .insert(&db)
+        .await?;
+        let tombstone = tombstone::ActiveModel {
+            id: Set(Uuid::now_v7()),
+            user_id: Set(user.id),
+            table_name: Set("ambition".to_string()),
+            record_id: Set(Uuid::now_v7()),
+            deleted_at: Set(Utc::now().into()),
+        }
+        .insert(&db)
+        .await?;
+
+        let exported = collect_user_data(&db, user.id).await?;
+        assert_eq!(exported.ambitions, vec![ambition.clone()]);
+        assert_eq!(exported.actions, vec![action.clone()]);
+        assert_eq!(exported.action_tracks, vec![action_track.clone()]);
+        assert_eq!(exported.tags, vec![tag.clone()]);
+        assert_eq!(exported.diaries, vec![diary.clone()]);
+        assert_eq!(exported.diaries_tags, vec![diary_tag.clone()]);
+        assert_eq!(exported.revisions, vec![revision.clone()]);
+        assert_eq!(exported.tombstones, vec![tombstone.clone()]);
+
+        // Ids are kept, so import into another user after removing the original records.
+        let exported: UserDataExport =
+            serde_json::from_str(&serde_json::to_string(&exported).unwrap()).unwrap();
+        let another_user = factory::user().insert(&db).await?;
+        entities::user::Entity::delete_by_id(user.id)
+            .exec(&db)
+            .await?;
+        insert_user_data(&db, another_user.id, exported).await?;
+
+        let imported = collect_user_data(&db, another_user.id).await?;
+        assert_eq!(
+            imported.ambitions,
+            vec![ambition::Model {
+                user_id: another_user.id,
+                ..ambition
+            }]
+        );
+        assert_eq!(
+            imported.action_tracks,
+            vec![action_track::Model {
+                user_id: another_user.id,
+                ..action_track
+            }]
+        );
+        assert_eq!(imported.diaries_tags, vec![diary_tag]);
+        assert_eq!(
+            imported.revisions,
+            vec![revision::Model {
+                user_id: another_user.id,
+                ..revision
+            }]
+        );
+        assert_eq!(
+            imported.tombstones,
+            vec![tombstone::Model {
+                user_id: another_user.id,
+                ..tombstone
+            }]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parents_first_puts_categories_after_their_parents() {
+        let user_id = Uuid::now_v7();
+        let root = factory::direction_category(user_id)
+            .try_into_model()
+            .unwrap();
+        let child = factory::direction_category(user_id)
+            .parent_id(Some(root.id))
+            .try_into_model()
+            .unwrap();
+        let grandchild = factory::direction_category(user_id)
+            .parent_id(Some(child.id))
+            .try_into_model()
+            .unwrap();
+
+        let sorted = parents_first(vec![grandchild.clone(), child.clone(), root.clone()]);
+
+        assert_eq!(sorted, vec![root, child, grandchild]);
+    }
+}
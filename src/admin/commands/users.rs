@@ -0,0 +1,55 @@
+use common::password;
+use db_adapters::user_adapter::{CreateUserParams, UserAdapter, UserMutation};
+use sea_orm::DbConn;
+
+use crate::commands::get_user_by_email;
+
+pub async fn create_user(
+    db: &DbConn,
+    email: String,
+    password: String,
+    first_name: String,
+    last_name: String,
+    is_active: bool,
+) -> Result<(), String> {
+    let user = UserAdapter::init(db)
+        .create(CreateUserParams {
+            email,
+            password: hash_password(&password)?,
+            first_name,
+            last_name,
+            is_active,
+        })
+        .await
+        .map_err(|e| format!("Error on creating user: {:?}", e))?;
+    println!("Created user {} ({}).", user.email, user.id);
+    Ok(())
+}
+
+pub async fn activate_user(db: &DbConn, email: String) -> Result<(), String> {
+    let user = get_user_by_email(db, email).await?;
+    if user.is_active {
+        println!("User {} is already active.", user.email);
+        return Ok(());
+    }
+    let user = UserAdapter::init(db)
+        .activate(user)
+        .await
+        .map_err(|e| format!("Error on activating user: {:?}", e))?;
+    println!("Activated user {}.", user.email);
+    Ok(())
+}
+
+pub async fn reset_password(db: &DbConn, email: String, password: String) -> Result<(), String> {
+    let user = get_user_by_email(db, email).await?;
+    let user = UserAdapter::init(db)
+        .update_password(user, hash_password(&password)?)
+        .await
+        .map_err(|e| format!("Error on updating password: {:?}", e))?;
+    println!("Reset password of user {}.", user.email);
+    Ok(())
+}
+
+pub(super) fn hash_password(password: &str) -> Result<String, String> {
+    password::hash(password.as_bytes()).map_err(|e| format!("Error on hashing password: {:?}", e))
+}
@@ -0,0 +1,165 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use chrono::NaiveDate;
+use clap::{Parser, Subcommand};
+use common::{
+    db::{connect_db, init_db_bypassing_rls},
+    settings::get_settings,
+};
+
+mod commands;
+
+/// Operational tasks for LifeTracker, run directly against the DB.
+#[derive(Parser)]
+#[command(name = "admin")]
+struct Cli {
+    /// Env file to read settings from.
+    #[arg(long, default_value = ".env")]
+    env_file: String,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create a user. The user is activated with --active.
+    /// The password is read from ADMIN_USER_PASSWORD or stdin.
+    CreateUser {
+        #[arg(long)]
+        email: String,
+        #[arg(long)]
+        first_name: String,
+        #[arg(long)]
+        last_name: String,
+        #[arg(long)]
+        active: bool,
+    },
+    /// Activate a user who has not confirmed the registration email.
+    ActivateUser {
+        #[arg(long)]
+        email: String,
+    },
+    /// Overwrite a user's password with one read from ADMIN_USER_PASSWORD or stdin.
+    ResetPassword {
+        #[arg(long)]
+        email: String,
+    },
+    /// Run or inspect migrations.
+    Migrate {
+        #[command(subcommand)]
+        command: MigrateCommand,
+    },
+    /// Export all data of a user as JSON.
+    ExportUserData {
+        #[arg(long)]
+        email: String,
+        /// Writes to stdout if omitted.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Import data exported by export-user-data into an existing user.
+    ImportUserData {
+        #[arg(long)]
+        email: String,
+        #[arg(long)]
+        input: PathBuf,
+    },
+    /// Send a test web push notification to a user.
+    SendTestPush {
+        #[arg(long)]
+        email: String,
+    },
+    /// Print the numbers of users and records.
+    Stats,
+    /// Create a user with months of demo data. The same seed gives the same data.
+    /// The password is read from ADMIN_USER_PASSWORD or stdin.
+    SeedDemoData {
+        #[arg(long)]
+        email: String,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        #[arg(long, default_value_t = 120)]
+        days: u32,
+        /// Last day of the data as YYYY-MM-DD. Yesterday if omitted.
+        #[arg(long)]
+        end_date: Option<NaiveDate>,
+    },
+}
+
+#[derive(Subcommand)]
+enum MigrateCommand {
+    /// Apply all pending migrations.
+    Up,
+    /// List migrations with their status.
+    Status,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let settings = match get_settings(&cli.env_file) {
+        Ok(settings) => settings,
+        Err(e) => {
+            eprintln!("Error on getting settings: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Migrations run as the DB owner, other commands as the role which bypasses row-level security.
+    let db = match cli.command {
+        Command::Migrate { .. } => connect_db(&settings).await,
+        _ => init_db_bypassing_rls(&settings).await,
+    };
+    let res = match cli.command {
+        Command::Migrate { command } => match command {
+            MigrateCommand::Up => commands::migrations::up(&db).await,
+            MigrateCommand::Status => commands::migrations::status(&db).await,
+        },
+        Command::CreateUser {
+            email,
+            first_name,
+            last_name,
+            active,
+        } => match commands::read_password() {
+            Ok(password) => {
+                commands::users::create_user(&db, email, password, first_name, last_name, active)
+                    .await
+            }
+            Err(e) => Err(e),
+        },
+        Command::ActivateUser { email } => commands::users::activate_user(&db, email).await,
+        Command::ResetPassword { email } => match commands::read_password() {
+            Ok(password) => commands::users::reset_password(&db, email, password).await,
+            Err(e) => Err(e),
+        },
+        Command::ExportUserData { email, output } => {
+            commands::user_data::export(&db, email, output).await
+        }
+        Command::ImportUserData { email, input } => {
+            commands::user_data::import(&db, email, input).await
+        }
+        Command::SendTestPush { email } => {
+            commands::push::send_test_push(&settings, &db, email).await
+        }
+        Command::Stats => commands::stats::print_stats(&db).await,
+        Command::SeedDemoData {
+            email,
+            seed,
+            days,
+            end_date,
+        } => match commands::read_password() {
+            Ok(password) => {
+                commands::demo_data::seed(&db, email, password, seed, days, end_date).await
+            }
+            Err(e) => Err(e),
+        },
+    };
+
+    match res {
+        Ok(_) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -1,17 +1,56 @@
 mod encryptor;
+mod row_level_security;
+#[cfg(feature = "test_db")]
+pub mod test_db;
 
+use chrono::{DateTime, FixedOffset};
 use migration::{Migrator, MigratorTrait};
-use sea_orm::{ConnectionTrait, Database, DbBackend, DbConn};
+use sea_orm::{ConnectionTrait, Database, DbBackend, DbConn, DbErr, Statement};
 
 use crate::settings::types::Settings;
 
 pub use encryptor::{decode_and_decrypt, encrypt_and_encode};
+pub use row_level_security::{with_app_user_id, DbRole};
 
+/// For web requests, whose queries see the records of the user of `with_app_user_id` only.
 pub async fn init_db(settings: &Settings) -> DbConn {
+    init_db_as(settings, DbRole::App).await
+}
+
+/// For cron processes and admin commands, whose queries see the records of every user.
+pub async fn init_db_bypassing_rls(settings: &Settings) -> DbConn {
+    init_db_as(settings, DbRole::BypassRls).await
+}
+
+async fn init_db_as(settings: &Settings, role: DbRole) -> DbConn {
+    let db_conn = connect_db(settings).await;
+    Migrator::up(&db_conn, None).await.unwrap();
+    match db_conn.get_database_backend() {
+        DbBackend::Postgres => {
+            db_conn.close().await.unwrap();
+            connect_db_as(&settings.database.url, role)
+                .await
+                .expect("Failed to open DB connection.")
+        }
+        _ => db_conn,
+    }
+}
+
+/// Connects as `role` on Postgres. Row-level security is Postgres only, so the connection is
+/// the same as `connect_db` on the other backends.
+pub async fn connect_db_as(url: &str, role: DbRole) -> Result<DbConn, DbErr> {
+    match url.starts_with("postgres") {
+        true => row_level_security::connect_postgres_as(url, role).await,
+        false => Database::connect(url).await,
+    }
+}
+
+/// Same as init_db, but leaves migrations as they are.
+pub async fn connect_db(settings: &Settings) -> DbConn {
     let db = Database::connect(&settings.database.url)
         .await
         .expect("Failed to open DB connection.");
-    let db_conn = match db.get_database_backend() {
+    match db.get_database_backend() {
         DbBackend::MySql => Database::connect(&settings.database.url)
             .await
             .expect("Failed to open DB connection."),
@@ -19,7 +58,49 @@ pub async fn init_db(settings: &Settings) -> DbConn {
             .await
             .expect("Failed to open DB connection."),
         DbBackend::Sqlite => db,
-    };
-    Migrator::up(&db_conn, None).await.unwrap();
-    db_conn
+    }
+}
+
+pub async fn run_migrations(db: &DbConn) -> Result<(), DbErr> {
+    Migrator::up(db, None).await
+}
+
+/// Returns (name, status) of every migration in the order they are applied.
+pub async fn get_migration_statuses(db: &DbConn) -> Result<Vec<(String, String)>, DbErr> {
+    Migrator::get_migration_with_status(db)
+        .await
+        .map(|migrations| {
+            migrations
+                .iter()
+                .map(|migration| (migration.name().to_string(), migration.status().to_string()))
+                .collect()
+        })
+}
+
+/// Reads the table of applied migrations as is. Unlike `Migrator::get_pending_migrations`, it
+/// never creates the table, which the app role has no privilege to do.
+pub async fn get_pending_migrations_count(db: &DbConn) -> Result<usize, DbErr> {
+    let applied = db
+        .query_all(Statement::from_string(
+            db.get_database_backend(),
+            format!(
+                "SELECT version FROM {}",
+                Migrator::migration_table_name().to_string()
+            ),
+        ))
+        .await?
+        .iter()
+        .map(|row| row.try_get::<String>("", "version"))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Migrator::migrations()
+        .iter()
+        .filter(|migration| !applied.contains(&migration.name().to_string()))
+        .count())
+}
+
+/// Timestamps as they are written and compared. SQLite keeps them as RFC 3339 text in the offset
+/// they are bound with and compares them as text, so they are all put in UTC there to be ordered
+/// by the instant as on Postgres.
+pub fn in_utc(datetime: DateTime<FixedOffset>) -> DateTime<FixedOffset> {
+    datetime.to_utc().fixed_offset()
 }
@@ -0,0 +1,82 @@
+//! Row-level security of Postgres, as defense in depth behind adapters filtering by user. See
+//! the migration enabling it for the policies.
+//!
+//! Connections act as a role of `DbRole` by SET ROLE, so the configured user needs to be a
+//! member of the roles, which the migration grants. Migrations run as the configured user
+//! itself, which owns the tables and so sees every row.
+
+use std::future::Future;
+
+use sea_orm::{
+    sqlx::{
+        self,
+        postgres::{PgConnection, PgPoolOptions},
+        Executor,
+    },
+    DbConn, DbErr, RuntimeErr, SqlxPostgresConnector,
+};
+use uuid::Uuid;
+
+tokio::task_local! {
+    static APP_USER_ID: Uuid;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DbRole {
+    /// Sees the rows of the user `with_app_user_id` runs for only, and no rows outside of it.
+    App,
+    /// Sees the rows of every user, for cron processes and admin commands.
+    BypassRls,
+}
+
+impl DbRole {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::App => "life_tracker_app",
+            Self::BypassRls => "life_tracker_bypass_rls",
+        }
+    }
+}
+
+/// Runs `future` for the user, so that the queries it makes through `DbRole::App` connections
+/// see the rows of that user only.
+pub async fn with_app_user_id<F: Future>(user_id: Uuid, future: F) -> F::Output {
+    APP_USER_ID.scope(user_id, future).await
+}
+
+pub(super) async fn connect_postgres_as(url: &str, role: DbRole) -> Result<DbConn, DbErr> {
+    let mut options = PgPoolOptions::new().after_connect(move |conn, _| {
+        Box::pin(async move {
+            conn.execute(format!("SET ROLE {}", role.name()).as_str())
+                .await?;
+            match role {
+                DbRole::App => set_app_user_id(conn).await,
+                DbRole::BypassRls => Ok(()),
+            }
+        })
+    });
+    if role == DbRole::App {
+        // NOTE: Set on every acquisition rather than once per connection, so that a pooled
+        //       connection never keeps the user of the previous request.
+        options = options.before_acquire(|conn, _| {
+            Box::pin(async move { set_app_user_id(conn).await.map(|_| true) })
+        });
+    }
+    let pool = options
+        .connect(url)
+        .await
+        .map_err(|e| DbErr::Conn(RuntimeErr::SqlxError(e)))?;
+    Ok(SqlxPostgresConnector::from_sqlx_postgres_pool(pool))
+}
+
+/// Empty out of `with_app_user_id`, which the policies match no rows with.
+async fn set_app_user_id(conn: &mut PgConnection) -> Result<(), sqlx::Error> {
+    let user_id = APP_USER_ID
+        .try_with(|user_id| user_id.to_string())
+        .unwrap_or_default();
+    sqlx::query("SELECT set_config('app.user_id', $1, false)")
+        .bind(user_id)
+        .execute(conn)
+        .await
+        .map(|_| ())
+}
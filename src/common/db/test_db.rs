@@ -0,0 +1,272 @@
+//! A database of its own for each test, so that tests running in parallel never see each
+//! other's records and may delete whatever they like.
+//!
+//! Migrations are applied once per test run to a template which every test database is a copy
+//! of. On Postgres the template is a `<name>__template` database next to the configured one,
+//! and the copies are `<name>__<uuid>` databases. On SQLite the configured file is the
+//! template, and the copies are files in a `<file>.tests` directory next to it.
+//!
+//! Postgres copies are dropped once no connection uses them any more. The rest are removed by
+//! a later run once they are older than `STALE_COPY_AGE`, judging by the time in their UUIDv7,
+//! so that a copy which a parallel test process has just created is never removed.
+
+use std::{
+    fs,
+    sync::Mutex,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use migration::{Migrator, MigratorTrait};
+use sea_orm::{ConnectOptions, ConnectionTrait, Database, DbBackend, DbConn, DbErr, Statement};
+use tokio::sync::OnceCell;
+use uuid::Uuid;
+
+use super::{connect_db_as, DbRole};
+use crate::settings::types::Settings;
+
+/// Set once the template is migrated in this process.
+static TEMPLATE_READY: OnceCell<()> = OnceCell::const_new();
+/// Postgres copies created by this process and not dropped yet.
+static COPIES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+/// Copies left over by earlier runs are removed once they are this old.
+const STALE_COPY_AGE: Duration = Duration::from_secs(60 * 60);
+
+/// Same as init_db, but connects to a fresh copy of the configured database.
+pub async fn init_test_db(settings: &Settings) -> DbConn {
+    let url = &settings.database.url;
+    let result = match url.starts_with("sqlite:") {
+        true => sqlite_copy(url).await,
+        false => postgres_copy(url).await,
+    };
+    result.expect("Failed to prepare a test database.")
+}
+
+/// Connects to the same copy as `db` as `role`, e.g. for the app under test, while tests
+/// prepare and check records through `db` itself.
+pub async fn connect_test_db_as(settings: &Settings, db: &DbConn, role: DbRole) -> DbConn {
+    if db.get_database_backend() != DbBackend::Postgres {
+        return db.clone();
+    }
+    let (prefix, _, options) = split_url(&settings.database.url);
+    let copy = db
+        .query_one(Statement::from_string(
+            DbBackend::Postgres,
+            "SELECT current_database() AS name",
+        ))
+        .await
+        .and_then(|row| row.ok_or(DbErr::RecordNotFound("current_database".to_string())))
+        .and_then(|row| row.try_get::<String>("", "name"))
+        .expect("Failed to get the name of the test database.");
+    connect_db_as(&format!("{prefix}{copy}{options}"), role)
+        .await
+        .expect("Failed to connect to the test database.")
+}
+
+async fn postgres_copy(url: &str) -> Result<DbConn, DbErr> {
+    let (prefix, name, options) = split_url(url);
+    let template = format!("{name}__template");
+    // The configured database is only used to create and drop the others, since nobody may
+    // be connected to the template while it is copied.
+    let admin = connect_one(url).await?;
+    TEMPLATE_READY
+        .get_or_try_init(|| prepare_postgres_template(&admin, prefix, name, options))
+        .await?;
+    drop_unused_copies(&admin).await?;
+
+    let copy = format!("{name}__{}", Uuid::now_v7().simple());
+    admin
+        .execute_unprepared(&format!(
+            r#"CREATE DATABASE "{copy}" TEMPLATE "{template}""#
+        ))
+        .await?;
+    admin.close().await?;
+
+    let db = Database::connect(format!("{prefix}{copy}{options}")).await?;
+    // Registered only once connected, so that a parallel test does not drop it in between.
+    COPIES.lock().unwrap().push(copy);
+    Ok(db)
+}
+
+async fn prepare_postgres_template(
+    admin: &DbConn,
+    prefix: &str,
+    name: &str,
+    options: &str,
+) -> Result<(), DbErr> {
+    let template = format!("{name}__template");
+    // Another test process may be preparing the template at the same time.
+    admin
+        .execute_unprepared("SELECT pg_advisory_lock(hashtext('test_db_template'))")
+        .await?;
+
+    let databases = admin
+        .query_all(Statement::from_string(
+            DbBackend::Postgres,
+            "SELECT datname FROM pg_database",
+        ))
+        .await?
+        .iter()
+        .map(|row| row.try_get::<String>("", "datname"))
+        .collect::<Result<Vec<_>, _>>()?;
+    for stale_copy in databases.iter().filter(|database| {
+        database
+            .strip_prefix(&format!("{name}__"))
+            .is_some_and(is_stale_copy)
+    }) {
+        // Fails if a test of another process still uses it, which is fine.
+        let _ = admin
+            .execute_unprepared(&format!(r#"DROP DATABASE IF EXISTS "{stale_copy}""#))
+            .await;
+    }
+    if !databases.contains(&template) {
+        // With the encoding and locale of the configured database rather than the defaults of
+        // the server, which search depends on.
+        let settings = admin
+            .query_one(Statement::from_string(
+                DbBackend::Postgres,
+                "SELECT pg_encoding_to_char(encoding) AS encoding, datcollate, datctype
+                    FROM pg_database WHERE datname = current_database()",
+            ))
+            .await?
+            .ok_or(DbErr::RecordNotFound(name.to_string()))?;
+        admin
+            .execute_unprepared(&format!(
+                r#"CREATE DATABASE "{template}" TEMPLATE template0 ENCODING '{}'
+                    LC_COLLATE '{}' LC_CTYPE '{}'"#,
+                settings.try_get::<String>("", "encoding")?,
+                settings.try_get::<String>("", "datcollate")?,
+                settings.try_get::<String>("", "datctype")?,
+            ))
+            .await?;
+    }
+
+    let template_db = connect_one(&format!("{prefix}{template}{options}")).await?;
+    Migrator::up(&template_db, None).await?;
+    template_db.close().await?;
+
+    admin
+        .execute_unprepared("SELECT pg_advisory_unlock(hashtext('test_db_template'))")
+        .await?;
+    Ok(())
+}
+
+/// Drops the copies of finished tests. Copies still connected to are left, since dropping
+/// them would wait for the connections to close.
+async fn drop_unused_copies(admin: &DbConn) -> Result<(), DbErr> {
+    let copies = COPIES.lock().unwrap().clone();
+    if copies.is_empty() {
+        return Ok(());
+    }
+    let in_use = admin
+        .query_all(Statement::from_string(
+            DbBackend::Postgres,
+            "SELECT DISTINCT datname FROM pg_stat_activity WHERE datname IS NOT NULL",
+        ))
+        .await?
+        .iter()
+        .map(|row| row.try_get::<String>("", "datname"))
+        .collect::<Result<Vec<_>, _>>()?;
+    for copy in copies.iter().filter(|copy| !in_use.contains(copy)) {
+        if admin
+            .execute_unprepared(&format!(r#"DROP DATABASE IF EXISTS "{copy}""#))
+            .await
+            .is_ok()
+        {
+            COPIES.lock().unwrap().retain(|c| c != copy);
+        }
+    }
+    Ok(())
+}
+
+async fn sqlite_copy(url: &str) -> Result<DbConn, DbErr> {
+    let (path, options) = match url.split_once('?') {
+        Some((path, options)) => (path, format!("?{options}")),
+        None => (url, String::new()),
+    };
+    let path = path.trim_start_matches("sqlite:").trim_start_matches("//");
+    let copies_dir = format!("{path}.tests");
+    TEMPLATE_READY
+        .get_or_try_init(|| async {
+            let template_db = Database::connect(url).await?;
+            Migrator::up(&template_db, None).await?;
+            // Closing checkpoints the write-ahead log, so that the file alone is a whole copy.
+            template_db.close().await?;
+            fs::create_dir_all(&copies_dir).map_err(|e| DbErr::Custom(e.to_string()))?;
+            for entry in fs::read_dir(&copies_dir)
+                .map_err(|e| DbErr::Custom(e.to_string()))?
+                .flatten()
+            {
+                // Along with the -wal and -shm files of the copy.
+                let file_name = entry.file_name();
+                let file_name = file_name.to_string_lossy();
+                let id = file_name.split('.').next().unwrap_or_default();
+                if is_stale_copy(id) {
+                    let _ = fs::remove_file(entry.path());
+                }
+            }
+            Ok::<(), DbErr>(())
+        })
+        .await?;
+
+    let copy = format!("{copies_dir}/{}.db", Uuid::now_v7().simple());
+    fs::copy(path, &copy).map_err(|e| DbErr::Custom(e.to_string()))?;
+    Database::connect(format!("sqlite://{copy}{options}")).await
+}
+
+/// Whether a copy was created longer than `STALE_COPY_AGE` ago, by the UUIDv7 in its name.
+/// Anything else, e.g. the template, is never stale.
+fn is_stale_copy(id: &str) -> bool {
+    let Some((seconds, nanos)) = Uuid::try_parse(id)
+        .ok()
+        .and_then(|id| id.get_timestamp())
+        .map(|timestamp| timestamp.to_unix())
+    else {
+        return false;
+    };
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH + Duration::new(seconds, nanos))
+        .is_ok_and(|age| age > STALE_COPY_AGE)
+}
+
+async fn connect_one(url: &str) -> Result<DbConn, DbErr> {
+    let mut options = ConnectOptions::new(url);
+    options.max_connections(1).sqlx_logging(false);
+    Database::connect(options).await
+}
+
+/// e.g. "postgresql://user@host:5432/name?sslmode=disable"
+/// => ("postgresql://user@host:5432/", "name", "?sslmode=disable")
+fn split_url(url: &str) -> (&str, &str, &str) {
+    let options_start = url.find('?').unwrap_or(url.len());
+    let name_start = url[..options_start].rfind('/').map_or(0, |i| i + 1);
+    (
+        &url[..name_start],
+        &url[name_start..options_start],
+        &url[options_start..],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::{NoContext, Timestamp};
+
+    use super::*;
+
+    #[test]
+    fn only_copies_older_than_stale_copy_age_are_stale() {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+        let created_before = |age: Duration| {
+            let created_at = now - age;
+            Uuid::new_v7(Timestamp::from_unix(NoContext, created_at.as_secs(), 0))
+                .simple()
+                .to_string()
+        };
+
+        assert!(is_stale_copy(&created_before(
+            STALE_COPY_AGE + Duration::from_secs(60)
+        )));
+        assert!(!is_stale_copy(&created_before(Duration::from_secs(1))));
+        assert!(!is_stale_copy(&Uuid::now_v7().simple().to_string()));
+        assert!(!is_stale_copy("template"));
+    }
+}
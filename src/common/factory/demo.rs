@@ -0,0 +1,575 @@
+//! A demo user with months of believable data, for trying the app out and for demonstrations.
+//! The same seed gives the same data for the same end date, apart from ids and creation times.
+
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveTime, TimeZone, Utc};
+use entities::{
+    action, action_track,
+    custom_methods::user::UserTimezoneTrait,
+    sea_orm_active_enums::{ActionTrackType, NotificationType},
+    user,
+};
+use sea_orm::{ActiveModelTrait, DbConn, DbErr, EntityTrait, IntoActiveModel, Set};
+use uuid::Uuid;
+
+use super::*;
+use crate::db::in_utc;
+
+const INSERT_CHUNK_SIZE: usize = 100;
+
+pub struct DemoUserParams {
+    pub email: String,
+    pub hashed_password: String,
+    pub seed: u64,
+    /// Number of days the data spans.
+    pub days: u32,
+    /// The last day of the data in the timezone of the user. Yesterday if None, so that no
+    /// track is in the future.
+    pub end_date: Option<NaiveDate>,
+}
+
+struct DemoAmbition {
+    name: &'static str,
+    description: &'static str,
+}
+
+struct DemoDirection {
+    name: &'static str,
+    description: &'static str,
+    category: &'static str,
+    /// Names of the ambitions the direction is linked to.
+    ambitions: &'static [&'static str],
+}
+
+struct DemoAction {
+    name: &'static str,
+    /// Names of the directions the action is linked to.
+    directions: &'static [&'static str],
+    color: &'static str,
+    track_type: ActionTrackType,
+    /// Chance of being tracked on a day.
+    frequency: f64,
+    /// Seconds of a track of TimeSpan, or tracks of a day of Count.
+    amount: (i64, i64),
+    /// Hours of the day in which tracks start.
+    hours: (i64, i64),
+    /// Goals of the first and the second half of the days, in the unit of `amount`.
+    goals: (i32, i32),
+}
+
+/// Names of categories and their parents, with parents first.
+const CATEGORIES: [(&str, Option<&str>); 5] = [
+    ("Health", None),
+    ("Exercise", Some("Health")),
+    ("Mind", Some("Health")),
+    ("Career", None),
+    ("Learning", None),
+];
+
+const AMBITIONS: [DemoAmbition; 3] = [
+    DemoAmbition {
+        name: "Stay healthy for decades",
+        description: "A body that still lets me go hiking at 80.",
+    },
+    DemoAmbition {
+        name: "Become an engineer people trust",
+        description: "Someone whose code and words others can rely on.",
+    },
+    DemoAmbition {
+        name: "Keep learning for life",
+        description: "Stay curious, and understand the world a little better every year.",
+    },
+];
+
+const DIRECTIONS: [DemoDirection; 5] = [
+    DemoDirection {
+        name: "Move every day",
+        description: "Some exercise beats the perfect plan.",
+        category: "Exercise",
+        ambitions: &["Stay healthy for decades"],
+    },
+    DemoDirection {
+        name: "Keep the mind calm",
+        description: "Notice stress before it piles up.",
+        category: "Mind",
+        ambitions: &["Stay healthy for decades"],
+    },
+    DemoDirection {
+        name: "Ship small, ship often",
+        description: "Small changes are easy to review and easy to revert.",
+        category: "Career",
+        ambitions: &["Become an engineer people trust"],
+    },
+    DemoDirection {
+        name: "Learn Rust deeply",
+        description: "Know why the borrow checker complains, not only how to quiet it.",
+        category: "Learning",
+        ambitions: &["Become an engineer people trust", "Keep learning for life"],
+    },
+    DemoDirection {
+        name: "Read a book a month",
+        description: "Fiction counts too.",
+        category: "Learning",
+        ambitions: &["Keep learning for life"],
+    },
+];
+
+const ACTIONS: [DemoAction; 5] = [
+    DemoAction {
+        name: "Running",
+        directions: &["Move every day"],
+        color: "#2e7d32",
+        track_type: ActionTrackType::TimeSpan,
+        frequency: 0.5,
+        amount: (1200, 3600),
+        hours: (6, 8),
+        goals: (1800, 2400),
+    },
+    DemoAction {
+        name: "Deep work",
+        directions: &["Ship small, ship often", "Learn Rust deeply"],
+        color: "#1565c0",
+        track_type: ActionTrackType::TimeSpan,
+        frequency: 0.75,
+        amount: (3600, 10800),
+        hours: (9, 14),
+        goals: (7200, 9000),
+    },
+    DemoAction {
+        name: "Reading",
+        directions: &["Read a book a month"],
+        color: "#6a1b9a",
+        track_type: ActionTrackType::TimeSpan,
+        frequency: 0.6,
+        amount: (900, 3600),
+        hours: (21, 23),
+        goals: (1200, 1800),
+    },
+    DemoAction {
+        name: "Push-up sets",
+        directions: &["Move every day"],
+        color: "#c62828",
+        track_type: ActionTrackType::Count,
+        frequency: 0.7,
+        amount: (1, 4),
+        hours: (7, 22),
+        goals: (2, 3),
+    },
+    DemoAction {
+        name: "Meditation",
+        directions: &["Keep the mind calm"],
+        color: "#f9a825",
+        track_type: ActionTrackType::Count,
+        frequency: 0.55,
+        amount: (1, 2),
+        hours: (6, 23),
+        goals: (1, 2),
+    },
+];
+
+const PLAIN_TAGS: [&str; 3] = ["Family", "Ideas", "Travel"];
+
+const DIARY_SENTENCES: [&str; 12] = [
+    "Woke up early and felt rested.",
+    "The morning run was harder than expected.",
+    "Finished the refactoring I had been putting off.",
+    "Code review took most of the afternoon.",
+    "Had dinner with the family and talked for hours.",
+    "Could not focus after lunch, so I went for a walk.",
+    "Read a few chapters before bed.",
+    "A small bug in production kept me busy.",
+    "Tried a new recipe, which turned out fine.",
+    "Felt anxious in the evening, meditation helped.",
+    "Paired with a colleague and learned a new trick.",
+    "Rainy day, stayed in and cleaned up my notes.",
+];
+
+/// Title and pages.
+const BOOKS: [(&str, i16); 4] = [
+    ("The Pragmatic Programmer", 352),
+    ("Thinking, Fast and Slow", 499),
+    ("Programming Rust", 738),
+    ("The Remains of the Day", 258),
+];
+
+const READING_NOTE_TEXTS: [&str; 6] = [
+    "Worth rereading this part slowly.",
+    "This contradicts what I believed until now.",
+    "A good example to show the team.",
+    "Not convinced, the argument skips a step.",
+    "Short chapter, but the key idea of the book.",
+    "Reminds me of a conversation last month.",
+];
+
+/// Question, thought and answer if resolved.
+const THINKING_NOTES: [(&str, &str, Option<&str>); 6] = [
+    (
+        "Should I run in the evening instead?",
+        "Mornings are busy on weekdays.",
+        Some("No, mornings set the tone of the day."),
+    ),
+    (
+        "Why do I stop reading in the middle of books?",
+        "Maybe I choose books I think I should read.",
+        None,
+    ),
+    (
+        "How much deep work is realistic in a day?",
+        "Four hours seems the upper limit.",
+        Some("Three hours, with meetings after lunch."),
+    ),
+    (
+        "Is it worth writing a blog about Rust?",
+        "Writing would force me to understand.",
+        None,
+    ),
+    (
+        "What makes a code review feel helpful?",
+        "Questions rather than orders.",
+        Some("Explaining the why behind each comment."),
+    ),
+    (
+        "When do I feel most calm?",
+        "After exercise, and on days without notifications.",
+        None,
+    ),
+];
+
+/// SplitMix64, so that the data of a seed does not change with versions of random crates.
+struct DemoRng(u64);
+
+impl DemoRng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// In low..=high.
+    fn between(&mut self, low: i64, high: i64) -> i64 {
+        low + (self.next_u64() % (high - low + 1) as u64) as i64
+    }
+
+    fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.between(0, items.len() as i64 - 1) as usize]
+    }
+}
+
+pub async fn insert_demo_user(db: &DbConn, params: DemoUserParams) -> Result<user::Model, DbErr> {
+    let mut rng = DemoRng(params.seed);
+    let user = user()
+        .email(params.email)
+        .password(&params.hashed_password)
+        .insert(db)
+        .await?;
+    let offset = FixedOffset::east_opt(user.get_user_timezone_offset() * 3600).unwrap();
+    let end_date = params
+        .end_date
+        .unwrap_or(user.to_user_timezone(Utc::now()).date_naive() - Duration::days(1));
+    let dates = (0..params.days as i64)
+        .rev()
+        .map(|days_ago| end_date - Duration::days(days_ago))
+        .collect::<Vec<_>>();
+
+    let mut my_way_tag_ids = vec![];
+    let mut ambition_ids = vec![];
+    for (i, demo) in AMBITIONS.iter().enumerate() {
+        let (ambition, tag) = ambition(user.id)
+            .name(demo.name.to_string())
+            .description(Some(demo.description.to_string()))
+            .ordering(Some(i as i32))
+            .insert_with_tag(db)
+            .await?;
+        my_way_tag_ids.push(tag.id);
+        ambition_ids.push((demo.name, ambition.id));
+    }
+    let mut category_ids = vec![];
+    for (name, parent) in CATEGORIES {
+        let parent_id = category_ids
+            .iter()
+            .find(|(category, _, _)| Some(*category) == parent)
+            .map(|(_, id, _)| *id);
+        // Ordering is within the parent.
+        let ordering = category_ids
+            .iter()
+            .filter(|(_, _, category_parent_id)| *category_parent_id == parent_id)
+            .count();
+        let category = direction_category(user.id)
+            .name(name.to_string())
+            .ordering(Some(ordering as i32))
+            .parent_id(parent_id)
+            .insert(db)
+            .await?;
+        category_ids.push((name, category.id, parent_id));
+    }
+    let mut reading_tag_id = None;
+    let mut direction_ids = vec![];
+    for (i, demo) in DIRECTIONS.iter().enumerate() {
+        let category_id = category_ids
+            .iter()
+            .find(|(name, _, _)| *name == demo.category)
+            .map(|(_, id, _)| *id);
+        let (direction, tag) = direction(user.id)
+            .name(demo.name.to_string())
+            .description(Some(demo.description.to_string()))
+            .ordering(Some(i as i32))
+            .category_id(category_id)
+            .insert_with_tag(db)
+            .await?;
+        for (name, ambition_id) in &ambition_ids {
+            if demo.ambitions.contains(name) {
+                link_ambition_direction(db, *ambition_id, direction.id).await?;
+            }
+        }
+        if direction.name == "Read a book a month" {
+            reading_tag_id = Some(tag.id);
+        }
+        my_way_tag_ids.push(tag.id);
+        direction_ids.push((demo.name, direction.id));
+    }
+    let mut actions = vec![];
+    for (i, demo) in ACTIONS.iter().enumerate() {
+        let (action, tag) = action(user.id)
+            .name(demo.name.to_string())
+            .color(demo.color.to_string())
+            .track_type(demo.track_type.clone())
+            .ordering(Some(i as i32))
+            .insert_with_tag(db)
+            .await?;
+        for (name, direction_id) in &direction_ids {
+            if demo.directions.contains(name) {
+                link_direction_action(db, *direction_id, action.id).await?;
+            }
+        }
+        my_way_tag_ids.push(tag.id);
+        actions.push((action, demo));
+    }
+    let mut plain_tag_ids = vec![];
+    for name in PLAIN_TAGS {
+        plain_tag_ids.push(
+            tag(user.id)
+                .name(Some(name.to_string()))
+                .insert(db)
+                .await?
+                .id,
+        );
+    }
+
+    insert_action_goals(db, &user, &actions, &dates).await?;
+    let first_track_at =
+        insert_action_tracks(db, &mut rng, &user, offset, &actions, &dates).await?;
+    insert_diaries(db, &mut rng, &user, &dates, &my_way_tag_ids, &plain_tag_ids).await?;
+    insert_reading_notes(db, &mut rng, &user, &dates, reading_tag_id).await?;
+    insert_thinking_notes(db, &mut rng, &user, offset, &dates, &my_way_tag_ids).await?;
+    // 07:30 and 09:00 in Asia/Tokyo, which is what the factory user lives in.
+    create_everyday_rules(
+        user.id,
+        db,
+        NotificationType::AmbitionOrDirection,
+        NaiveTime::from_hms_opt(22, 30, 0).unwrap(),
+    )
+    .await?;
+    create_weekend_rules(
+        user.id,
+        db,
+        NotificationType::Ambition,
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
+        false,
+    )
+    .await?;
+
+    let mut user = user.into_active_model();
+    user.first_track_at = Set(first_track_at.map(in_utc));
+    user.update(db).await
+}
+
+/// The goal of each action changes once, in the middle of the days.
+async fn insert_action_goals(
+    db: &DbConn,
+    user: &user::Model,
+    actions: &[(action::Model, &DemoAction)],
+    dates: &[NaiveDate],
+) -> Result<(), DbErr> {
+    let (Some(first_date), Some(middle_date)) = (dates.first(), dates.get(dates.len() / 2)) else {
+        return Ok(());
+    };
+    for (action, demo) in actions {
+        for (from_date, to_date, goal) in [
+            (
+                *first_date,
+                Some(*middle_date - Duration::days(1)),
+                demo.goals.0,
+            ),
+            (*middle_date, None, demo.goals.1),
+        ] {
+            let action_goal = action_goal(user.id, action.id)
+                .from_date(from_date)
+                .to_date(to_date);
+            let action_goal = match demo.track_type {
+                ActionTrackType::TimeSpan => action_goal.duration_seconds(Some(goal)),
+                ActionTrackType::Count => action_goal.count(Some(goal)),
+            };
+            action_goal.insert(db).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns when the first track started.
+async fn insert_action_tracks(
+    db: &DbConn,
+    rng: &mut DemoRng,
+    user: &user::Model,
+    offset: FixedOffset,
+    actions: &[(action::Model, &DemoAction)],
+    dates: &[NaiveDate],
+) -> Result<Option<DateTime<FixedOffset>>, DbErr> {
+    let mut action_tracks = vec![];
+    let mut first_track_at = None;
+    for date in dates {
+        for (action, demo) in actions {
+            if !rng.chance(demo.frequency) {
+                continue;
+            }
+            let (tracks, durations) = match demo.track_type {
+                ActionTrackType::TimeSpan => (1, demo.amount),
+                ActionTrackType::Count => (rng.between(demo.amount.0, demo.amount.1), (0, 0)),
+            };
+            // Distinct, since tracks of an action cannot start at the same time.
+            let started_times = (0..tracks)
+                .map(|_| {
+                    let hour = rng.between(demo.hours.0, demo.hours.1);
+                    let minute = rng.between(0, 59);
+                    NaiveTime::from_hms_opt(hour as u32, minute as u32, 0).unwrap()
+                })
+                .collect::<BTreeSet<_>>();
+            for started_time in started_times {
+                let started_at = offset
+                    .from_local_datetime(&date.and_time(started_time))
+                    .unwrap();
+                first_track_at = Some(
+                    first_track_at
+                        .map_or(started_at, |at: DateTime<FixedOffset>| at.min(started_at)),
+                );
+                action_tracks.push(
+                    action_track(user.id)
+                        .action_id(action.id)
+                        .started_at(started_at)
+                        .duration(Some(rng.between(durations.0, durations.1))),
+                );
+            }
+        }
+    }
+    for chunk in action_tracks.chunks(INSERT_CHUNK_SIZE) {
+        action_track::Entity::insert_many(chunk.to_vec())
+            .exec_without_returning(db)
+            .await?;
+    }
+    Ok(first_track_at)
+}
+
+async fn insert_diaries(
+    db: &DbConn,
+    rng: &mut DemoRng,
+    user: &user::Model,
+    dates: &[NaiveDate],
+    my_way_tag_ids: &[Uuid],
+    plain_tag_ids: &[Uuid],
+) -> Result<(), DbErr> {
+    for date in dates {
+        if !rng.chance(0.7) {
+            continue;
+        }
+        let text = (0..rng.between(1, 3))
+            .map(|_| *rng.pick(&DIARY_SENTENCES))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let diary = diary(user.id)
+            .text(Some(text))
+            .date(*date)
+            .insert(db)
+            .await?;
+        if rng.chance(0.4) {
+            link_diary_tag(db, diary.id, *rng.pick(my_way_tag_ids)).await?;
+        }
+        if rng.chance(0.2) {
+            link_diary_tag(db, diary.id, *rng.pick(plain_tag_ids)).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Books are read one after another, with notes on some of the days.
+async fn insert_reading_notes(
+    db: &DbConn,
+    rng: &mut DemoRng,
+    user: &user::Model,
+    dates: &[NaiveDate],
+    tag_id: Option<Uuid>,
+) -> Result<(), DbErr> {
+    let mut books = BOOKS.iter().cycle();
+    let (mut title, mut pages) = books.next().unwrap();
+    let mut page_number = 0;
+    for date in dates {
+        if !rng.chance(0.3) {
+            continue;
+        }
+        page_number += rng.between(5, 40) as i16;
+        if page_number > pages {
+            (title, pages) = *books.next().unwrap();
+            page_number = rng.between(5, 40) as i16;
+        }
+        let reading_note = reading_note(user.id)
+            .title(title.to_string())
+            .page_number(page_number)
+            .text(rng.pick(&READING_NOTE_TEXTS).to_string())
+            .date(*date)
+            .insert(db)
+            .await?;
+        if let Some(tag_id) = tag_id {
+            link_reading_note_tag(db, reading_note.id, tag_id).await?;
+        }
+    }
+    Ok(())
+}
+
+async fn insert_thinking_notes(
+    db: &DbConn,
+    rng: &mut DemoRng,
+    user: &user::Model,
+    offset: FixedOffset,
+    dates: &[NaiveDate],
+    my_way_tag_ids: &[Uuid],
+) -> Result<(), DbErr> {
+    let Some(last_date) = dates.last() else {
+        return Ok(());
+    };
+    for (question, thought, answer) in THINKING_NOTES {
+        let date = *rng.pick(dates);
+        let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+        let asked_at = offset.from_local_datetime(&date.and_time(noon)).unwrap();
+        let resolved_date = (date + Duration::days(rng.between(1, 14))).min(*last_date);
+        let resolved_at = answer.map(|_| {
+            offset
+                .from_local_datetime(&resolved_date.and_time(noon))
+                .unwrap()
+        });
+        let thinking_note = thinking_note(user.id)
+            .question(Some(question.to_string()))
+            .thought(Some(thought.to_string()))
+            .answer(answer.map(|answer| answer.to_string()))
+            .resolved_at(resolved_at)
+            .updated_at(resolved_at.unwrap_or(asked_at))
+            .insert(db)
+            .await?;
+        link_thinking_note_tag(db, thinking_note.id, *rng.pick(my_way_tag_ids)).await?;
+    }
+    Ok(())
+}
@@ -1,20 +1,25 @@
-use chrono::{NaiveDate, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
 use entities::diary;
 use sea_orm::Set;
 use uuid::Uuid;
 
+use crate::db::in_utc;
+
 pub fn diary(user_id: Uuid) -> diary::ActiveModel {
     diary::ActiveModel {
         id: Set(uuid::Uuid::now_v7()),
         text: Set(Some("diary".to_string())),
         date: Set(Utc::now().date_naive()),
         user_id: Set(user_id),
+        updated_at: Set(Utc::now().into()),
+        deleted_at: Set(None),
     }
 }
 
 pub trait DiaryFactory {
     fn text(self, text: Option<String>) -> diary::ActiveModel;
     fn date(self, date: NaiveDate) -> diary::ActiveModel;
+    fn deleted_at(self, deleted_at: Option<DateTime<FixedOffset>>) -> diary::ActiveModel;
 }
 
 impl DiaryFactory for diary::ActiveModel {
@@ -27,4 +32,9 @@ impl DiaryFactory for diary::ActiveModel {
         self.date = Set(date);
         self
     }
+
+    fn deleted_at(mut self, deleted_at: Option<DateTime<FixedOffset>>) -> diary::ActiveModel {
+        self.deleted_at = Set(deleted_at.map(in_utc));
+        self
+    }
 }
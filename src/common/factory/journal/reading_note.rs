@@ -1,8 +1,10 @@
-use chrono::{NaiveDate, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
 use entities::reading_note;
 use sea_orm::Set;
 use uuid::Uuid;
 
+use crate::db::in_utc;
+
 pub fn reading_note(user_id: Uuid) -> reading_note::ActiveModel {
     let now = Utc::now();
     reading_note::ActiveModel {
@@ -14,12 +16,16 @@ pub fn reading_note(user_id: Uuid) -> reading_note::ActiveModel {
         user_id: Set(user_id),
         created_at: Set(now.into()),
         updated_at: Set(now.into()),
+        deleted_at: Set(None),
     }
 }
 
 pub trait ReadingNoteFactory {
     fn title(self, title: String) -> reading_note::ActiveModel;
+    fn page_number(self, page_number: i16) -> reading_note::ActiveModel;
+    fn text(self, text: String) -> reading_note::ActiveModel;
     fn date(self, date: NaiveDate) -> reading_note::ActiveModel;
+    fn deleted_at(self, deleted_at: Option<DateTime<FixedOffset>>) -> reading_note::ActiveModel;
 }
 
 impl ReadingNoteFactory for reading_note::ActiveModel {
@@ -28,8 +34,26 @@ impl ReadingNoteFactory for reading_note::ActiveModel {
         self
     }
 
+    fn page_number(mut self, page_number: i16) -> reading_note::ActiveModel {
+        self.page_number = Set(page_number);
+        self
+    }
+
+    fn text(mut self, text: String) -> reading_note::ActiveModel {
+        self.text = Set(text);
+        self
+    }
+
     fn date(mut self, date: NaiveDate) -> reading_note::ActiveModel {
         self.date = Set(date);
         self
     }
+
+    fn deleted_at(
+        mut self,
+        deleted_at: Option<DateTime<FixedOffset>>,
+    ) -> reading_note::ActiveModel {
+        self.deleted_at = Set(deleted_at.map(in_utc));
+        self
+    }
 }
@@ -3,6 +3,8 @@ use entities::thinking_note;
 use sea_orm::Set;
 use uuid::Uuid;
 
+use crate::db::in_utc;
+
 pub fn thinking_note(user_id: Uuid) -> thinking_note::ActiveModel {
     let now = Utc::now();
     thinking_note::ActiveModel {
@@ -14,13 +16,17 @@ pub fn thinking_note(user_id: Uuid) -> thinking_note::ActiveModel {
         resolved_at: Set(None),
         created_at: Set(now.into()),
         updated_at: Set(now.into()),
+        deleted_at: Set(None),
     }
 }
 
 pub trait ThinkingNoteFactory {
     fn question(self, question: Option<String>) -> thinking_note::ActiveModel;
+    fn thought(self, thought: Option<String>) -> thinking_note::ActiveModel;
+    fn answer(self, answer: Option<String>) -> thinking_note::ActiveModel;
     fn resolved_at(self, resolved_at: Option<DateTime<FixedOffset>>) -> thinking_note::ActiveModel;
     fn updated_at(self, updated_at: DateTime<FixedOffset>) -> thinking_note::ActiveModel;
+    fn deleted_at(self, deleted_at: Option<DateTime<FixedOffset>>) -> thinking_note::ActiveModel;
 }
 
 impl ThinkingNoteFactory for thinking_note::ActiveModel {
@@ -29,16 +35,34 @@ impl ThinkingNoteFactory for thinking_note::ActiveModel {
         self
     }
 
+    fn thought(mut self, thought: Option<String>) -> thinking_note::ActiveModel {
+        self.thought = Set(thought);
+        self
+    }
+
+    fn answer(mut self, answer: Option<String>) -> thinking_note::ActiveModel {
+        self.answer = Set(answer);
+        self
+    }
+
     fn resolved_at(
         mut self,
         resolved_at: Option<DateTime<FixedOffset>>,
     ) -> thinking_note::ActiveModel {
-        self.resolved_at = Set(resolved_at);
+        self.resolved_at = Set(resolved_at.map(in_utc));
         self
     }
 
     fn updated_at(mut self, updated_at: DateTime<FixedOffset>) -> thinking_note::ActiveModel {
-        self.updated_at = Set(updated_at);
+        self.updated_at = Set(in_utc(updated_at));
+        self
+    }
+
+    fn deleted_at(
+        mut self,
+        deleted_at: Option<DateTime<FixedOffset>>,
+    ) -> thinking_note::ActiveModel {
+        self.deleted_at = Set(deleted_at.map(in_utc));
         self
     }
 }
@@ -1,9 +1,11 @@
+mod demo;
 mod journal;
 mod my_way;
 mod notification;
 mod tag;
 mod user;
 
+pub use demo::*;
 pub use journal::diary::*;
 pub use journal::link::*;
 pub use journal::reading_note::*;
@@ -14,6 +16,7 @@ pub use my_way::action_track::*;
 pub use my_way::ambition::*;
 pub use my_way::direction::*;
 pub use my_way::direction_category::*;
+pub use my_way::link::*;
 pub use notification::notification_rule::*;
 pub use notification::web_push_subscription::*;
 pub use tag::*;
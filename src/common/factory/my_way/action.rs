@@ -1,4 +1,4 @@
-use chrono::Utc;
+use chrono::{DateTime, FixedOffset, Utc};
 use entities::{
     action,
     sea_orm_active_enums::{ActionTrackType, TagType},
@@ -8,6 +8,8 @@ use sea_orm::{ActiveModelTrait, ActiveValue::NotSet, DbConn, DbErr, Set};
 use std::future::Future;
 use uuid::Uuid;
 
+use crate::db::in_utc;
+
 pub fn action(user_id: Uuid) -> action::ActiveModel {
     let now = Utc::now();
     action::ActiveModel {
@@ -22,12 +24,14 @@ pub fn action(user_id: Uuid) -> action::ActiveModel {
         track_type: Set(ActionTrackType::TimeSpan),
         created_at: Set(now.into()),
         updated_at: Set(now.into()),
+        deleted_at: Set(None),
     }
 }
 
 pub trait ActionFactory {
     fn name(self, name: String) -> action::ActiveModel;
     fn discipline(self, discipline: Option<String>) -> action::ActiveModel;
+    fn color(self, color: String) -> action::ActiveModel;
     fn archived(self, archived: bool) -> action::ActiveModel;
     fn ordering(self, ordering: Option<i32>) -> action::ActiveModel;
     fn track_type(self, track_type: ActionTrackType) -> action::ActiveModel;
@@ -35,6 +39,7 @@ pub trait ActionFactory {
         self,
         db: &DbConn,
     ) -> impl Future<Output = Result<(action::Model, tag::Model), DbErr>> + Send;
+    fn deleted_at(self, deleted_at: Option<DateTime<FixedOffset>>) -> action::ActiveModel;
 }
 
 impl ActionFactory for action::ActiveModel {
@@ -48,6 +53,11 @@ impl ActionFactory for action::ActiveModel {
         self
     }
 
+    fn color(mut self, color: String) -> action::ActiveModel {
+        self.color = Set(color);
+        self
+    }
+
     fn archived(mut self, archived: bool) -> action::ActiveModel {
         self.archived = Set(archived);
         self
@@ -70,10 +80,17 @@ impl ActionFactory for action::ActiveModel {
             user_id: Set(action.user_id),
             action_id: Set(Some(action.id)),
             r#type: Set(TagType::Action),
+            created_at: Set(action.created_at),
+            updated_at: Set(action.created_at),
             ..Default::default()
         }
         .insert(db)
         .await?;
         Ok((action, tag))
     }
+
+    fn deleted_at(mut self, deleted_at: Option<DateTime<FixedOffset>>) -> action::ActiveModel {
+        self.deleted_at = Set(deleted_at.map(in_utc));
+        self
+    }
 }
@@ -13,6 +13,7 @@ pub fn action_goal(user_id: Uuid, action_id: Uuid) -> action_goal::ActiveModel {
         to_date: NotSet,
         duration_seconds: NotSet,
         count: NotSet,
+        updated_at: Set(now.into()),
     }
 }
 
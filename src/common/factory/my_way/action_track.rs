@@ -1,8 +1,10 @@
-use entities::action_track;
 use chrono::{Duration, SubsecRound, Utc};
+use entities::action_track;
 use sea_orm::{ActiveValue::NotSet, Set};
 use uuid::Uuid;
 
+use crate::db::in_utc;
+
 pub fn action_track(user_id: Uuid) -> action_track::ActiveModel {
     action_track::ActiveModel {
         id: Set(Uuid::now_v7()),
@@ -11,6 +13,7 @@ pub fn action_track(user_id: Uuid) -> action_track::ActiveModel {
         started_at: Set(Utc::now().trunc_subsecs(0).into()),
         ended_at: NotSet,
         duration: NotSet,
+        updated_at: Set(Utc::now().into()),
     }
 }
 
@@ -46,7 +49,7 @@ impl ActionTrackFactory for action_track::ActiveModel {
         mut self,
         started_at: chrono::DateTime<chrono::FixedOffset>,
     ) -> action_track::ActiveModel {
-        self.started_at = Set(started_at);
+        self.started_at = Set(in_utc(started_at));
         if self.duration == NotSet {
             return self;
         }
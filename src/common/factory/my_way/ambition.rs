@@ -1,9 +1,11 @@
-use chrono::Utc;
+use chrono::{DateTime, FixedOffset, Utc};
 use entities::{ambition, sea_orm_active_enums::TagType, tag};
 use sea_orm::{ActiveModelTrait, ActiveValue::NotSet, DbConn, DbErr, Set};
 use std::future::Future;
 use uuid::Uuid;
 
+use crate::db::in_utc;
+
 pub fn ambition(user_id: Uuid) -> ambition::ActiveModel {
     let now = Utc::now();
     ambition::ActiveModel {
@@ -15,6 +17,7 @@ pub fn ambition(user_id: Uuid) -> ambition::ActiveModel {
         ordering: NotSet,
         created_at: Set(now.into()),
         updated_at: Set(now.into()),
+        deleted_at: Set(None),
     }
 }
 
@@ -27,6 +30,7 @@ pub trait AmbitionFactory {
         self,
         db: &DbConn,
     ) -> impl Future<Output = Result<(ambition::Model, tag::Model), DbErr>> + Send;
+    fn deleted_at(self, deleted_at: Option<DateTime<FixedOffset>>) -> ambition::ActiveModel;
 }
 
 impl AmbitionFactory for ambition::ActiveModel {
@@ -57,10 +61,17 @@ impl AmbitionFactory for ambition::ActiveModel {
             user_id: Set(ambition.user_id),
             ambition_id: Set(Some(ambition.id)),
             r#type: Set(TagType::Ambition),
+            created_at: Set(ambition.created_at),
+            updated_at: Set(ambition.created_at),
             ..Default::default()
         }
         .insert(db)
         .await?;
         Ok((ambition, tag))
     }
+
+    fn deleted_at(mut self, deleted_at: Option<DateTime<FixedOffset>>) -> ambition::ActiveModel {
+        self.deleted_at = Set(deleted_at.map(in_utc));
+        self
+    }
 }
@@ -1,9 +1,11 @@
-use chrono::Utc;
+use chrono::{DateTime, FixedOffset, Utc};
 use entities::{direction, sea_orm_active_enums::TagType, tag};
 use sea_orm::{ActiveModelTrait, ActiveValue::NotSet, DbConn, DbErr, Set};
 use std::future::Future;
 use uuid::Uuid;
 
+use crate::db::in_utc;
+
 pub fn direction(user_id: Uuid) -> direction::ActiveModel {
     let now = Utc::now();
     direction::ActiveModel {
@@ -16,6 +18,7 @@ pub fn direction(user_id: Uuid) -> direction::ActiveModel {
         category_id: NotSet,
         created_at: Set(now.into()),
         updated_at: Set(now.into()),
+        deleted_at: Set(None),
     }
 }
 
@@ -29,6 +32,7 @@ pub trait DirectionFactory {
         self,
         db: &DbConn,
     ) -> impl Future<Output = Result<(direction::Model, tag::Model), DbErr>> + Send;
+    fn deleted_at(self, deleted_at: Option<DateTime<FixedOffset>>) -> direction::ActiveModel;
 }
 
 impl DirectionFactory for direction::ActiveModel {
@@ -64,10 +68,17 @@ impl DirectionFactory for direction::ActiveModel {
             user_id: Set(direction.user_id),
             direction_id: Set(Some(direction.id)),
             r#type: Set(TagType::Direction),
+            created_at: Set(direction.created_at),
+            updated_at: Set(direction.created_at),
             ..Default::default()
         }
         .insert(db)
         .await?;
         Ok((direction, tag))
     }
+
+    fn deleted_at(mut self, deleted_at: Option<DateTime<FixedOffset>>) -> direction::ActiveModel {
+        self.deleted_at = Set(deleted_at.map(in_utc));
+        self
+    }
 }
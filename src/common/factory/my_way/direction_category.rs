@@ -1,3 +1,4 @@
+use chrono::Utc;
 use entities::direction_category;
 use sea_orm::Set;
 use uuid::Uuid;
@@ -9,12 +10,15 @@ pub fn direction_category(user_id: Uuid) -> direction_category::ActiveModel {
         user_id: Set(user_id),
         name: Set(format!("category-{}", id)),
         ordering: Set(None),
+        updated_at: Set(Utc::now().into()),
+        parent_id: Set(None),
     }
 }
 
 pub trait DirectionCategoryFactory {
     fn name(self, name: String) -> direction_category::ActiveModel;
     fn ordering(self, ordering: Option<i32>) -> direction_category::ActiveModel;
+    fn parent_id(self, parent_id: Option<Uuid>) -> direction_category::ActiveModel;
 }
 
 impl DirectionCategoryFactory for direction_category::ActiveModel {
@@ -27,4 +31,9 @@ impl DirectionCategoryFactory for direction_category::ActiveModel {
         self.ordering = Set(ordering);
         self
     }
+
+    fn parent_id(mut self, parent_id: Option<Uuid>) -> direction_category::ActiveModel {
+        self.parent_id = Set(parent_id);
+        self
+    }
 }
@@ -0,0 +1,30 @@
+use sea_orm::{ActiveModelTrait, DbConn, DbErr, Set};
+use uuid::Uuid;
+
+use entities::{ambitions_directions, directions_actions};
+
+pub async fn link_ambition_direction(
+    db: &DbConn,
+    ambition_id: Uuid,
+    direction_id: Uuid,
+) -> Result<ambitions_directions::Model, DbErr> {
+    ambitions_directions::ActiveModel {
+        ambition_id: Set(ambition_id),
+        direction_id: Set(direction_id),
+    }
+    .insert(db)
+    .await
+}
+
+pub async fn link_direction_action(
+    db: &DbConn,
+    direction_id: Uuid,
+    action_id: Uuid,
+) -> Result<directions_actions::Model, DbErr> {
+    directions_actions::ActiveModel {
+        direction_id: Set(direction_id),
+        action_id: Set(action_id),
+    }
+    .insert(db)
+    .await
+}
@@ -4,3 +4,4 @@ pub mod action_track;
 pub mod ambition;
 pub mod direction;
 pub mod direction_category;
+pub mod link;
@@ -1,12 +1,16 @@
+use chrono::Utc;
 use entities::tag;
 use sea_orm::Set;
 use uuid::Uuid;
 
 pub fn tag(user_id: Uuid) -> tag::ActiveModel {
+    let now = Utc::now();
     tag::ActiveModel {
         id: Set(Uuid::now_v7()),
         user_id: Set(user_id),
         name: Set(Some("plain_tag".to_string())),
+        created_at: Set(now.into()),
+        updated_at: Set(now.into()),
         ..Default::default()
     }
 }
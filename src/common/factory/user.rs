@@ -2,6 +2,8 @@ use chrono::{DateTime, FixedOffset, Utc};
 use entities::user;
 use sea_orm::Set;
 
+use crate::db::in_utc;
+
 pub fn user() -> user::ActiveModel {
     use entities::sea_orm_active_enums::TimezoneEnum;
 
@@ -21,12 +23,18 @@ pub fn user() -> user::ActiveModel {
 }
 
 pub trait UserFactory {
+    fn email(self, email: String) -> user::ActiveModel;
     fn is_active(self, is_active: bool) -> user::ActiveModel;
     fn password(self, hashed_password: &str) -> user::ActiveModel;
     fn first_track_at(self, first_track_at: Option<DateTime<FixedOffset>>) -> user::ActiveModel;
 }
 
 impl UserFactory for user::ActiveModel {
+    fn email(mut self, email: String) -> user::ActiveModel {
+        self.email = Set(email);
+        self
+    }
+
     fn is_active(mut self, is_active: bool) -> user::ActiveModel {
         self.is_active = Set(is_active);
         self
@@ -41,7 +49,7 @@ impl UserFactory for user::ActiveModel {
         mut self,
         first_track_at: Option<DateTime<FixedOffset>>,
     ) -> user::ActiveModel {
-        self.first_track_at = Set(first_track_at);
+        self.first_track_at = Set(first_track_at.map(in_utc));
         self
     }
 }
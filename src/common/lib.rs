@@ -2,6 +2,8 @@
 pub mod db;
 #[cfg(feature = "factory")]
 pub mod factory;
+#[cfg(feature = "password")]
+pub mod password;
 #[cfg(feature = "redis")]
 pub mod redis;
 #[cfg(feature = "settings")]
@@ -0,0 +1,163 @@
+use deadpool_redis::{
+    redis::{self, aio::PubSubStream, AsyncCommands, RedisError},
+    Pool, PoolError,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+const CHANGE_EVENTS_CHANNEL_PREFIX: &str = "change_events";
+
+#[derive(Debug)]
+pub enum ChangeEventError {
+    Pool(PoolError),
+    Redis(RedisError),
+    Serde(serde_json::Error),
+}
+
+impl From<PoolError> for ChangeEventError {
+    fn from(e: PoolError) -> Self {
+        Self::Pool(e)
+    }
+}
+
+impl From<RedisError> for ChangeEventError {
+    fn from(e: RedisError) -> Self {
+        Self::Redis(e)
+    }
+}
+
+impl From<serde_json::Error> for ChangeEventError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Serde(e)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangedResource {
+    Ambition,
+    Direction,
+    DirectionCategory,
+    Action,
+    ActionGoal,
+    ActionTrack,
+    Diary,
+    ReadingNote,
+    ThinkingNote,
+    Tag,
+    /// Links between ambitions, directions and actions, identified by the ambition or direction.
+    MyWayLink,
+}
+
+impl ChangedResource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ambition => "ambition",
+            Self::Direction => "direction",
+            Self::DirectionCategory => "direction_category",
+            Self::Action => "action",
+            Self::ActionGoal => "action_goal",
+            Self::ActionTrack => "action_track",
+            Self::Diary => "diary",
+            Self::ReadingNote => "reading_note",
+            Self::ThinkingNote => "thinking_note",
+            Self::Tag => "tag",
+            Self::MyWayLink => "my_way_link",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Created,
+    Updated,
+    Deleted,
+}
+
+impl ChangeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Created => "created",
+            Self::Updated => "updated",
+            Self::Deleted => "deleted",
+        }
+    }
+}
+
+/// `data` is the response body of the request that made the change, if any.
+/// `id` is None for changes to several records, e.g. bulk ordering updates.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ChangeEvent {
+    pub resource: ChangedResource,
+    pub kind: ChangeKind,
+    pub id: Option<Uuid>,
+    pub data: Option<serde_json::Value>,
+}
+
+impl ChangeEvent {
+    /// e.g. "action_track.created"
+    pub fn name(&self) -> String {
+        format!("{}.{}", self.resource.as_str(), self.kind.as_str())
+    }
+}
+
+pub fn channel(user_id: Uuid) -> String {
+    format!("{}:{}", CHANGE_EVENTS_CHANNEL_PREFIX, user_id)
+}
+
+/// Sends the event to the user's subscribers on every server instance.
+pub async fn publish(
+    pool: &Pool,
+    user_id: Uuid,
+    event: &ChangeEvent,
+) -> Result<(), ChangeEventError> {
+    let mut con = pool.get().await?;
+    let payload = serde_json::to_string(event)?;
+    let _: i64 = con.publish(channel(user_id), payload).await?;
+    Ok(())
+}
+
+/// Opens a dedicated connection, because a subscribed connection cannot be returned to the pool.
+/// Messages are raw JSON; parse them with `serde_json::from_slice::<ChangeEvent>`.
+pub async fn subscribe(redis_url: &str, user_id: Uuid) -> Result<PubSubStream, RedisError> {
+    let mut pubsub = redis::Client::open(redis_url)?.get_async_pubsub().await?;
+    pubsub.subscribe(channel(user_id)).await?;
+    Ok(pubsub.into_on_message())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn name_joins_resource_and_kind() {
+        let event = ChangeEvent {
+            resource: ChangedResource::ActionTrack,
+            kind: ChangeKind::Created,
+            id: None,
+            data: None,
+        };
+        assert_eq!(event.name(), "action_track.created");
+    }
+
+    #[test]
+    fn serializes_in_snake_case() {
+        let id = Uuid::now_v7();
+        let event = ChangeEvent {
+            resource: ChangedResource::DirectionCategory,
+            kind: ChangeKind::Deleted,
+            id: Some(id),
+            data: None,
+        };
+        assert_eq!(
+            serde_json::to_value(&event).unwrap(),
+            serde_json::json!({
+                "resource": "direction_category",
+                "kind": "deleted",
+                "id": id,
+                "data": null,
+            })
+        );
+    }
+}
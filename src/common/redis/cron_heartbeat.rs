@@ -0,0 +1,97 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use deadpool_redis::{
+    redis::{AsyncCommands, RedisError},
+    Pool, PoolError,
+};
+
+const CRON_STARTED_AT_KEY: &str = "cron_started_at";
+const CRON_LAST_RUN_KEY_PREFIX: &str = "cron_last_run_";
+
+/// A cron job whose liveness is reported through Redis,
+/// so that it can be checked from any server instance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonitoredJob {
+    pub name: &'static str,
+    /// The job is considered stalled if it has not run for this long.
+    pub max_interval_seconds: i64,
+}
+
+/// Runs every minute only to prove that the scheduler itself is alive.
+pub const SCHEDULER_HEARTBEAT_JOB: MonitoredJob = MonitoredJob {
+    name: "scheduler_heartbeat",
+    max_interval_seconds: 3 * 60,
+};
+
+pub const MY_WAY_REMINDER_JOB: MonitoredJob = MonitoredJob {
+    name: "my_way_reminder",
+    max_interval_seconds: 2 * 10 * 60,
+};
+
+/// Runs once a day, so a missed run is only reported after the next one is missed too.
+pub const TRASH_PURGE_JOB: MonitoredJob = MonitoredJob {
+    name: "trash_purge",
+    max_interval_seconds: 2 * 24 * 60 * 60,
+};
+
+pub const MONITORED_JOBS: [MonitoredJob; 3] = [
+    SCHEDULER_HEARTBEAT_JOB,
+    MY_WAY_REMINDER_JOB,
+    TRASH_PURGE_JOB,
+];
+
+#[derive(Debug)]
+pub enum CronHeartbeatError {
+    Pool(PoolError),
+    Redis(RedisError),
+}
+
+impl From<PoolError> for CronHeartbeatError {
+    fn from(e: PoolError) -> Self {
+        Self::Pool(e)
+    }
+}
+
+impl From<RedisError> for CronHeartbeatError {
+    fn from(e: RedisError) -> Self {
+        Self::Redis(e)
+    }
+}
+
+pub fn unix_timestamp_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+fn last_run_key(job: &MonitoredJob) -> String {
+    format!("{}{}", CRON_LAST_RUN_KEY_PREFIX, job.name)
+}
+
+pub async fn record_started(pool: &Pool) -> Result<(), CronHeartbeatError> {
+    let mut con = pool.get().await?;
+    con.set::<&str, i64, ()>(CRON_STARTED_AT_KEY, unix_timestamp_now())
+        .await?;
+    Ok(())
+}
+
+pub async fn record_run(pool: &Pool, job: &MonitoredJob) -> Result<(), CronHeartbeatError> {
+    let mut con = pool.get().await?;
+    con.set::<String, i64, ()>(last_run_key(job), unix_timestamp_now())
+        .await?;
+    Ok(())
+}
+
+pub async fn get_started_at(pool: &Pool) -> Result<Option<i64>, CronHeartbeatError> {
+    let mut con = pool.get().await?;
+    Ok(con.get::<&str, Option<i64>>(CRON_STARTED_AT_KEY).await?)
+}
+
+pub async fn get_last_run_at(
+    pool: &Pool,
+    job: &MonitoredJob,
+) -> Result<Option<i64>, CronHeartbeatError> {
+    let mut con = pool.get().await?;
+    Ok(con.get::<String, Option<i64>>(last_run_key(job)).await?)
+}
@@ -0,0 +1,154 @@
+use deadpool_redis::{
+    redis::{AsyncCommands, ExistenceCheck, RedisError, SetExpiry, SetOptions},
+    Pool, PoolError,
+};
+use serde::{Deserialize, Serialize};
+
+const IDEMPOTENCY_KEY_PREFIX: &str = "idempotency";
+/// How long a completed response is replayed for.
+pub const IDEMPOTENCY_KEY_TTL_SECONDS: u64 = 24 * 60 * 60;
+/// Releases the key of a request whose process died before completing it.
+const PROCESSING_TTL_SECONDS: u64 = 60;
+
+#[derive(Debug)]
+pub enum IdempotencyError {
+    Pool(PoolError),
+    Redis(RedisError),
+    Serde(serde_json::Error),
+}
+
+impl From<PoolError> for IdempotencyError {
+    fn from(e: PoolError) -> Self {
+        Self::Pool(e)
+    }
+}
+
+impl From<RedisError> for IdempotencyError {
+    fn from(e: RedisError) -> Self {
+        Self::Redis(e)
+    }
+}
+
+impl From<serde_json::Error> for IdempotencyError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Serde(e)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct StoredResponse {
+    pub status: u16,
+    pub content_type: Option<String>,
+    pub body: String,
+}
+
+/// `fingerprint` identifies the request the key was first used with.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum IdempotencyRecord {
+    Processing {
+        fingerprint: String,
+    },
+    Completed {
+        fingerprint: String,
+        response: StoredResponse,
+    },
+}
+
+impl IdempotencyRecord {
+    pub fn fingerprint(&self) -> &str {
+        match self {
+            Self::Processing { fingerprint } => fingerprint,
+            Self::Completed { fingerprint, .. } => fingerprint,
+        }
+    }
+}
+
+/// Keys are per user, so that one user's key never replays another user's response.
+pub fn record_key(user_id: uuid::Uuid, idempotency_key: &str) -> String {
+    format!("{}:{}:{}", IDEMPOTENCY_KEY_PREFIX, user_id, idempotency_key)
+}
+
+/// Marks the key as processing if it is not used yet, and returns None in that case.
+/// Otherwise returns the existing record.
+pub async fn begin(
+    pool: &Pool,
+    key: &str,
+    fingerprint: &str,
+) -> Result<Option<IdempotencyRecord>, IdempotencyError> {
+    let mut con = pool.get().await?;
+    let processing = serde_json::to_string(&IdempotencyRecord::Processing {
+        fingerprint: fingerprint.to_string(),
+    })?;
+    // The record can expire between SET and GET, so try once more in that case.
+    for _ in 0..2 {
+        let is_set = con
+            .set_options::<&str, &str, Option<String>>(
+                key,
+                &processing,
+                SetOptions::default()
+                    .conditional_set(ExistenceCheck::NX)
+                    .with_expiration(SetExpiry::EX(PROCESSING_TTL_SECONDS)),
+            )
+            .await?
+            .is_some();
+        if is_set {
+            return Ok(None);
+        }
+        if let Some(record) = con.get::<&str, Option<String>>(key).await? {
+            return Ok(Some(serde_json::from_str(&record)?));
+        }
+    }
+    Ok(None)
+}
+
+pub async fn complete(
+    pool: &Pool,
+    key: &str,
+    fingerprint: &str,
+    response: StoredResponse,
+) -> Result<(), IdempotencyError> {
+    let mut con = pool.get().await?;
+    let record = serde_json::to_string(&IdempotencyRecord::Completed {
+        fingerprint: fingerprint.to_string(),
+        response,
+    })?;
+    con.set_options::<&str, String, ()>(
+        key,
+        record,
+        SetOptions::default().with_expiration(SetExpiry::EX(IDEMPOTENCY_KEY_TTL_SECONDS)),
+    )
+    .await?;
+    Ok(())
+}
+
+/// Releases the key so that the request can be retried, e.g. after a server error.
+pub async fn abort(pool: &Pool, key: &str) -> Result<(), IdempotencyError> {
+    let mut con = pool.get().await?;
+    con.del::<&str, ()>(key).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_round_trip() {
+        let record = IdempotencyRecord::Completed {
+            fingerprint: "fingerprint".to_string(),
+            response: StoredResponse {
+                status: 201,
+                content_type: Some("application/json".to_string()),
+                body: "{\"id\":1}".to_string(),
+            },
+        };
+        let json = serde_json::to_string(&record).unwrap();
+        assert!(json.contains("\"state\":\"completed\""));
+        assert_eq!(
+            serde_json::from_str::<IdempotencyRecord>(&json).unwrap(),
+            record
+        );
+        assert_eq!(record.fingerprint(), "fingerprint");
+    }
+}
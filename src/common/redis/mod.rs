@@ -2,6 +2,11 @@ use deadpool_redis::{Config, CreatePoolError, Pool, Runtime};
 
 use crate::settings::types::Settings;
 
+pub mod change_events;
+pub mod cron_heartbeat;
+pub mod idempotency;
+pub mod rate_limit;
+
 pub async fn init_redis_pool(settings: &Settings) -> Result<Pool, CreatePoolError> {
     let cfg = Config::from_url(&settings.redis.url);
     let redis_pool = cfg.create_pool(Some(Runtime::Tokio1))?;
@@ -0,0 +1,159 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use deadpool_redis::{
+    redis::{self, RedisError},
+    Pool, PoolError,
+};
+
+use crate::settings::types::RateLimit;
+
+const RATE_LIMIT_KEY_PREFIX: &str = "rate_limit";
+
+/// Refills each bucket continuously and takes one token from every bucket only when all of them
+/// have one, so that a request rejected by one bucket does not use up the others.
+/// Returns 0 when allowed, otherwise milliseconds until a token is available in every bucket.
+///
+/// KEYS: buckets, ARGV: capacity, milliseconds per token, now in milliseconds, ttl in milliseconds
+const TAKE_TOKEN_SCRIPT: &str = r#"
+local capacity = tonumber(ARGV[1])
+local refill_ms = tonumber(ARGV[2])
+local now = tonumber(ARGV[3])
+local ttl = tonumber(ARGV[4])
+local tokens = {}
+local wait = 0
+for i, key in ipairs(KEYS) do
+    local state = redis.call('HMGET', key, 'tokens', 'ts')
+    local available = tonumber(state[1])
+    local ts = tonumber(state[2])
+    if available == nil or ts == nil then
+        available = capacity
+        ts = now
+    end
+    available = math.min(capacity, available + math.max(0, now - ts) / refill_ms)
+    tokens[i] = available
+    if available < 1 then
+        wait = math.max(wait, math.ceil((1 - available) * refill_ms))
+    end
+end
+if wait > 0 then
+    return wait
+end
+for i, key in ipairs(KEYS) do
+    redis.call('HSET', key, 'tokens', tostring(tokens[i] - 1), 'ts', now)
+    redis.call('PEXPIRE', key, ttl)
+end
+return 0
+"#;
+
+#[derive(Debug)]
+pub enum RateLimitError {
+    Pool(PoolError),
+    Redis(RedisError),
+}
+
+impl From<PoolError> for RateLimitError {
+    fn from(e: PoolError) -> Self {
+        Self::Pool(e)
+    }
+}
+
+impl From<RedisError> for RateLimitError {
+    fn from(e: RedisError) -> Self {
+        Self::Redis(e)
+    }
+}
+
+/// Identifies what a bucket counts requests of.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RateLimitSubject {
+    Ip(String),
+    User(uuid::Uuid),
+}
+
+pub fn bucket_key(scope: &str, subject: &RateLimitSubject) -> String {
+    match subject {
+        RateLimitSubject::Ip(ip) => format!("{}:{}:ip:{}", RATE_LIMIT_KEY_PREFIX, scope, ip),
+        RateLimitSubject::User(user_id) => {
+            format!("{}:{}:user:{}", RATE_LIMIT_KEY_PREFIX, scope, user_id)
+        }
+    }
+}
+
+/// Takes a token from the bucket of every subject.
+/// Returns how long to wait before retrying when any of them is empty.
+pub async fn take_token(
+    pool: &Pool,
+    scope: &str,
+    subjects: &[RateLimitSubject],
+    limit: &RateLimit,
+) -> Result<Option<Duration>, RateLimitError> {
+    if subjects.is_empty() || limit.is_unlimited() {
+        return Ok(None);
+    }
+    let keys = subjects
+        .iter()
+        .map(|subject| bucket_key(scope, subject))
+        .collect::<Vec<_>>();
+    let mut con = pool.get().await?;
+    let wait_ms = redis::cmd("EVAL")
+        .arg(TAKE_TOKEN_SCRIPT)
+        .arg(keys.len())
+        .arg(keys)
+        .arg(limit.requests)
+        .arg(refill_interval_ms(limit))
+        .arg(unix_timestamp_ms_now())
+        .arg(limit.period_seconds * 1000)
+        .query_async::<u64>(&mut con)
+        .await?;
+    Ok(match wait_ms {
+        0 => None,
+        wait_ms => Some(Duration::from_millis(wait_ms)),
+    })
+}
+
+/// Milliseconds for one token to be refilled, so that an empty bucket is full after the period.
+fn refill_interval_ms(limit: &RateLimit) -> u64 {
+    (limit.period_seconds * 1000)
+        .div_ceil(limit.requests as u64)
+        .max(1)
+}
+
+fn unix_timestamp_ms_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucket_key_per_subject() {
+        let user_id = uuid::Uuid::nil();
+        assert_eq!(
+            bucket_key("api", &RateLimitSubject::Ip("127.0.0.1".to_string())),
+            "rate_limit:api:ip:127.0.0.1"
+        );
+        assert_eq!(
+            bucket_key("api", &RateLimitSubject::User(user_id)),
+            format!("rate_limit:api:user:{}", user_id)
+        );
+    }
+
+    #[test]
+    fn refill_interval_ms_spreads_period_over_requests() {
+        let limit = RateLimit {
+            requests: 5,
+            period_seconds: 3600,
+        };
+        assert_eq!(refill_interval_ms(&limit), 720_000);
+
+        let limit = RateLimit {
+            requests: 3,
+            period_seconds: 1,
+        };
+        assert_eq!(refill_interval_ms(&limit), 334);
+    }
+}
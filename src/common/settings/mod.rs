@@ -1,8 +1,8 @@
-use std::env;
+use std::{env, net::IpAddr};
 
 use crate::settings::types::{
-    ApplicationSettings, DatabaseSettings, EmailSettings, Environment, RedisSettings,
-    SecretSettings, Settings,
+    ApplicationSettings, DatabaseSettings, EmailSettings, Environment, RateLimit,
+    RateLimitSettings, RedisSettings, SecretSettings, Settings,
 };
 
 pub mod types;
@@ -77,6 +77,22 @@ fn merge_env(s: Settings) -> Result<Settings, String> {
                 .map_err(|e| e.to_string())?,
             vapid_private_key: get_env_var("VAPID_PRIVATE_KEY")?,
             app_owner_email: get_env_var("APP_OWNER_EMAIL")?,
+            run_cron_processes: match env::var("APP_RUN_CRON_PROCESSES") {
+                Ok(run_cron_processes) => &run_cron_processes == "true",
+                Err(_) => s.application.run_cron_processes,
+            },
+            trash_retention_days: match env::var("APP_TRASH_RETENTION_DAYS") {
+                Ok(trash_retention_days) => trash_retention_days
+                    .parse::<i64>()
+                    .map_err(|e| e.to_string())?,
+                Err(_) => s.application.trash_retention_days,
+            },
+            sync_cursor_max_age_days: match env::var("APP_SYNC_CURSOR_MAX_AGE_DAYS") {
+                Ok(sync_cursor_max_age_days) => sync_cursor_max_age_days
+                    .parse::<i64>()
+                    .map_err(|e| e.to_string())?,
+                Err(_) => s.application.sync_cursor_max_age_days,
+            },
             ..s.application
         },
         database: DatabaseSettings {
@@ -108,10 +124,50 @@ fn merge_env(s: Settings) -> Result<Settings, String> {
             sender: get_env_var("APP_EMAIL__SENDER")?,
             ..s.email
         },
+        rate_limit: RateLimitSettings {
+            enabled: match env::var("APP_RATE_LIMIT__ENABLED") {
+                Ok(enabled) => &enabled == "true",
+                Err(_) => s.rate_limit.enabled,
+            },
+            registration: get_rate_limit_env_var(
+                "APP_RATE_LIMIT__REGISTRATION",
+                s.rate_limit.registration,
+            )?,
+            password_change_request: get_rate_limit_env_var(
+                "APP_RATE_LIMIT__PASSWORD_CHANGE_REQUEST",
+                s.rate_limit.password_change_request,
+            )?,
+            resend_email: get_rate_limit_env_var(
+                "APP_RATE_LIMIT__RESEND_EMAIL",
+                s.rate_limit.resend_email,
+            )?,
+            api: get_rate_limit_env_var("APP_RATE_LIMIT__API", s.rate_limit.api)?,
+            trusted_proxies: match env::var("APP_RATE_LIMIT__TRUSTED_PROXIES") {
+                Ok(trusted_proxies) => parse_ip_list(&trusted_proxies)
+                    .map_err(|e| format!("APP_RATE_LIMIT__TRUSTED_PROXIES: {}", e))?,
+                Err(_) => s.rate_limit.trusted_proxies,
+            },
+        },
         ..s
     })
 }
 
+fn get_rate_limit_env_var(key: &str, default: RateLimit) -> Result<RateLimit, String> {
+    match env::var(key) {
+        Ok(value) => RateLimit::try_from(value.as_str()).map_err(|e| format!("{}: {}", key, e)),
+        Err(_) => Ok(default),
+    }
+}
+
+/// Parses comma separated IPs, e.g. `10.0.0.1,10.0.0.2`.
+fn parse_ip_list(s: &str) -> Result<Vec<IpAddr>, String> {
+    s.split(',')
+        .map(str::trim)
+        .filter(|ip| !ip.is_empty())
+        .map(|ip| ip.parse::<IpAddr>().map_err(|e| format!("{}: {}", ip, e)))
+        .collect()
+}
+
 fn get_env_var(key: &str) -> Result<String, String> {
     env::var(key).map_err(|e| e.to_string())
 }
@@ -1,3 +1,5 @@
+use std::net::IpAddr;
+
 use serde::Deserialize;
 
 #[derive(Deserialize, Clone, Default)]
@@ -8,6 +10,7 @@ pub struct Settings {
     pub redis: RedisSettings,
     pub secret: SecretSettings,
     pub email: EmailSettings,
+    pub rate_limit: RateLimitSettings,
 }
 
 impl Settings {
@@ -16,8 +19,31 @@ impl Settings {
             application: ApplicationSettings {
                 port: 5000,
                 max_log_files: 14,
+                run_cron_processes: true,
+                trash_retention_days: 30,
+                sync_cursor_max_age_days: 90,
                 ..Default::default()
             },
+            rate_limit: RateLimitSettings {
+                enabled: true,
+                registration: RateLimit {
+                    requests: 5,
+                    period_seconds: 60 * 60,
+                },
+                password_change_request: RateLimit {
+                    requests: 5,
+                    period_seconds: 60 * 60,
+                },
+                resend_email: RateLimit {
+                    requests: 3,
+                    period_seconds: 60 * 60,
+                },
+                api: RateLimit {
+                    requests: 300,
+                    period_seconds: 60,
+                },
+                trusted_proxies: vec![],
+            },
             ..Default::default()
         }
     }
@@ -36,6 +62,12 @@ pub struct ApplicationSettings {
     pub vapid_private_key: String,
     pub app_owner_email: String,
     pub session_lifetime_days: i64,
+    /// Whether the web process also runs cron processes. Turn off when a cron_worker runs them.
+    pub run_cron_processes: bool,
+    /// Deleted items stay in the trash, restorable, for this many days before they are purged.
+    pub trash_retention_days: i64,
+    /// Tombstones are kept this long, so clients that have not synced for longer must resync fully.
+    pub sync_cursor_max_age_days: i64,
 }
 
 #[derive(Deserialize, Clone, Default, Debug)]
@@ -66,6 +98,51 @@ pub struct EmailSettings {
     pub sender: String,
 }
 
+/// Token buckets per route scope. Each applies to the client IP and to the logged in user separately.
+#[derive(Deserialize, Clone, Default, Debug)]
+pub struct RateLimitSettings {
+    pub enabled: bool,
+    pub registration: RateLimit,
+    pub password_change_request: RateLimit,
+    pub resend_email: RateLimit,
+    /// Applies to every route under /api.
+    pub api: RateLimit,
+    /// Peers whose Forwarded/X-Forwarded-For headers are believed. Others are limited by their own IP.
+    pub trusted_proxies: Vec<IpAddr>,
+}
+
+/// Allows bursts of `requests`, refilled evenly over `period_seconds`. 0 requests means no limit.
+#[derive(Deserialize, Clone, Copy, Default, Debug, PartialEq)]
+pub struct RateLimit {
+    pub requests: u32,
+    pub period_seconds: u64,
+}
+
+impl RateLimit {
+    pub fn is_unlimited(&self) -> bool {
+        self.requests == 0 || self.period_seconds == 0
+    }
+}
+
+impl TryFrom<&str> for RateLimit {
+    type Error = String;
+
+    /// Parses `<requests>/<period_seconds>`, e.g. `5/3600`.
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let (requests, period_seconds) = s.split_once('/').ok_or(format!(
+            "{} is not in the form of <requests>/<period_seconds>.",
+            s
+        ))?;
+        Ok(Self {
+            requests: requests.trim().parse::<u32>().map_err(|e| e.to_string())?,
+            period_seconds: period_seconds
+                .trim()
+                .parse::<u64>()
+                .map_err(|e| e.to_string())?,
+        })
+    }
+}
+
 pub enum Environment {
     Testing,
     Development,
@@ -84,3 +161,22 @@ impl TryFrom<String> for Environment {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_limit_try_from() {
+        assert_eq!(
+            RateLimit::try_from("5/3600"),
+            Ok(RateLimit {
+                requests: 5,
+                period_seconds: 3600
+            })
+        );
+        assert!(RateLimit::try_from("0/60").unwrap().is_unlimited());
+        assert!(RateLimit::try_from("5").is_err());
+        assert!(RateLimit::try_from("five/60").is_err());
+    }
+}
@@ -0,0 +1,89 @@
+use chrono::{DateTime, Duration, Timelike, Utc};
+use deadpool_redis::{
+    redis::{AsyncCommands, ExistenceCheck, SetExpiry, SetOptions},
+    Pool,
+};
+use tracing::{event, Level};
+
+const JOB_LOCK_KEY_PREFIX: &str = "cron_lock_";
+const JOB_LOCK_TTL_SECONDS: u64 = 60 * 60;
+
+/// Makes sure only one instance processes a given run of a job,
+/// no matter how many web processes or cron workers are running.
+///
+/// The lock is never released, it expires after an hour instead,
+/// so that an instance starting late in the same slot does not run it again.
+pub async fn try_acquire_job_lock(redis_pool: &Pool, job_name: &str, slot: &str) -> bool {
+    let key = format!("{}{}_{}", JOB_LOCK_KEY_PREFIX, job_name, slot);
+    let mut redis_con = match redis_pool.get().await {
+        Ok(redis_con) => redis_con,
+        Err(e) => {
+            event!(
+                Level::ERROR,
+                "Error getting Redis connection for job lock: {:?}",
+                e
+            );
+            return false;
+        }
+    };
+    match redis_con
+        .set_options::<&str, &str, Option<String>>(
+            &key,
+            "locked",
+            SetOptions::default()
+                .conditional_set(ExistenceCheck::NX)
+                .with_expiration(SetExpiry::EX(JOB_LOCK_TTL_SECONDS)),
+        )
+        .await
+    {
+        Ok(Some(_)) => true,
+        Ok(None) => {
+            event!(Level::INFO, "{} is taken by another instance.", key);
+            false
+        }
+        Err(e) => {
+            event!(Level::ERROR, "Error acquiring job lock {}: {:?}", key, e);
+            false
+        }
+    }
+}
+
+/// Identifies the 10 minutes slot which a run at `time` processes,
+/// using the same 5 minutes look-ahead as the reminder itself.
+pub fn get_10_minutes_slot(time: DateTime<Utc>) -> String {
+    let five_minutes_ahead = time + Duration::minutes(5);
+    format!(
+        "{}{:02}",
+        five_minutes_ahead.format("%Y%m%d%H"),
+        five_minutes_ahead.minute() / 10 * 10
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_10_minutes_slot() {
+        let time = DateTime::parse_from_rfc3339("2025-12-28T00:00:00Z")
+            .unwrap()
+            .to_utc();
+        assert_eq!(get_10_minutes_slot(time), "202512280000".to_string());
+    }
+
+    #[test]
+    fn test_get_10_minutes_slot_55_minutes_to_next_day() {
+        let time = DateTime::parse_from_rfc3339("2025-12-28T23:55:00Z")
+            .unwrap()
+            .to_utc();
+        assert_eq!(get_10_minutes_slot(time), "202512290000".to_string());
+    }
+
+    #[test]
+    fn test_get_10_minutes_slot_late_start() {
+        let time = DateTime::parse_from_rfc3339("2025-12-28T10:14:59Z")
+            .unwrap()
+            .to_utc();
+        assert_eq!(get_10_minutes_slot(time), "202512281010".to_string());
+    }
+}
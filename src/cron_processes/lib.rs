@@ -1 +1,3 @@
+mod job_lock;
 pub mod notification;
+pub mod trash_purge;
@@ -1,14 +1,39 @@
 use chrono::{DateTime, Datelike, Duration, NaiveTime, Timelike, Utc, Weekday};
-use common::{db::init_db, settings::types::Settings};
+use common::{
+    db::init_db_bypassing_rls,
+    redis::{
+        cron_heartbeat::{
+            self, MonitoredJob, MY_WAY_REMINDER_JOB, SCHEDULER_HEARTBEAT_JOB, TRASH_PURGE_JOB,
+        },
+        init_redis_pool,
+    },
+    settings::types::Settings,
+};
+use deadpool_redis::Pool;
+use sea_orm::DbConn;
 use tokio_cron_scheduler::{Job, JobScheduler};
 use tracing::{event, instrument, Level};
+use uuid::Uuid;
+
+use crate::{
+    job_lock::{get_10_minutes_slot, try_acquire_job_lock},
+    trash_purge::purge_trash,
+};
+use utils::{send_messages, MessageWithUserId};
 
 mod my_way_reminder;
 mod utils;
 
 #[instrument(skip_all)]
 pub async fn run_cron_processes(settings: Settings) -> Result<(), ()> {
-    let db = init_db(&settings).await;
+    let db = init_db_bypassing_rls(&settings).await;
+    let redis_pool = match init_redis_pool(&settings).await {
+        Ok(redis_pool) => redis_pool,
+        Err(e) => {
+            event!(Level::ERROR, "{:?}", e);
+            return Err(());
+        }
+    };
 
     let scheduler = match JobScheduler::new().await {
         Ok(scheduler) => scheduler,
@@ -18,10 +43,71 @@ pub async fn run_cron_processes(settings: Settings) -> Result<(), ()> {
         }
     };
 
+    let heartbeat_redis_pool = redis_pool.clone();
+    let heartbeat_job = match Job::new_async("0 * * * * *", move |_, _| {
+        let redis_pool = heartbeat_redis_pool.clone();
+        Box::pin(async move { record_run(&redis_pool, &SCHEDULER_HEARTBEAT_JOB).await })
+    }) {
+        Ok(job) => job,
+        Err(e) => {
+            event!(Level::ERROR, "{:?}", e);
+            return Err(());
+        }
+    };
+    if let Err(e) = scheduler.add(heartbeat_job).await {
+        event!(Level::ERROR, "{:?}", e);
+        return Err(());
+    };
+
+    let trash_purge_redis_pool = redis_pool.clone();
+    let trash_purge_settings = settings.clone();
+    let trash_purge_db = db.clone();
+    let trash_purge_job = match Job::new_async("0 0 3 * * *", move |_, _| {
+        let params = (
+            trash_purge_settings.clone(),
+            trash_purge_db.clone(),
+            trash_purge_redis_pool.clone(),
+        );
+        Box::pin(async move {
+            let now = Utc::now();
+            let slot = now.format("%Y%m%d").to_string();
+            if !try_acquire_job_lock(&params.2, TRASH_PURGE_JOB.name, &slot).await {
+                return;
+            }
+            purge_trash(&params.0, &params.1, now).await;
+            record_run(&params.2, &TRASH_PURGE_JOB).await
+        })
+    }) {
+        Ok(job) => job,
+        Err(e) => {
+            event!(Level::ERROR, "{:?}", e);
+            return Err(());
+        }
+    };
+    if let Err(e) = scheduler.add(trash_purge_job).await {
+        event!(Level::ERROR, "{:?}", e);
+        return Err(());
+    };
+
+    let my_way_reminder_redis_pool = redis_pool.clone();
     let my_way_reminder_job = match Job::new_async("0 0,10,20,30,40,50, * * * *", move |_, _| {
-        let params = (settings.clone(), db.clone());
+        let params = (
+            settings.clone(),
+            db.clone(),
+            my_way_reminder_redis_pool.clone(),
+        );
         Box::pin(async move {
-            let (weekday, utc_time_rounded_by_10_minutes) = match get_parsed_time(Utc::now()) {
+            let now = Utc::now();
+            if !try_acquire_job_lock(
+                &params.2,
+                MY_WAY_REMINDER_JOB.name,
+                &get_10_minutes_slot(now),
+            )
+            .await
+            {
+                return;
+            }
+            let (weekday, utc_time_rounded_by_10_minutes) = match get_parsed_time(now) {
                 Some(parsed_now) => parsed_now,
                 None => {
                     event!(Level::ERROR, "Error on parsing utc_time.");
@@ -34,7 +120,8 @@ pub async fn run_cron_processes(settings: Settings) -> Result<(), ()> {
                 weekday,
                 utc_time_rounded_by_10_minutes,
             )
-            .await
+            .await;
+            record_run(&params.2, &MY_WAY_REMINDER_JOB).await
         })
     }) {
         Ok(job) => job,
@@ -53,9 +140,32 @@ pub async fn run_cron_processes(settings: Settings) -> Result<(), ()> {
         return Err(());
     }
 
+    if let Err(e) = cron_heartbeat::record_started(&redis_pool).await {
+        event!(Level::WARN, "Error recording cron start in Redis: {:?}", e);
+    }
+
     Ok(())
 }
 
+async fn record_run(redis_pool: &Pool, job: &MonitoredJob) {
+    if let Err(e) = cron_heartbeat::record_run(redis_pool, job).await {
+        event!(
+            Level::WARN,
+            "Error recording cron heartbeat of {} in Redis: {:?}",
+            job.name,
+            e
+        );
+    }
+}
+
+/// Sends a fixed message to the user's web push subscription, to check the setup.
+#[instrument(skip(settings, db))]
+pub async fn send_test_message(settings: &Settings, db: &DbConn, user_id: Uuid) {
+    let message = MessageWithUserId::new("This is a test notification.".to_string(), user_id)
+        .title(Some("Test".to_string()));
+    send_messages(vec![message], settings, db).await;
+}
+
 fn get_parsed_time(time: DateTime<Utc>) -> Option<(Weekday, NaiveTime)> {
     let five_minutes_ahead = time + Duration::minutes(5);
     let weekday = five_minutes_ahead.weekday();
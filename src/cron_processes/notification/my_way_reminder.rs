@@ -8,7 +8,7 @@ use crate::notification::utils::{send_messages, MessageWithUserId};
 use common::settings::types::Settings;
 use db_adapters::{
     ambition_adapter::{AmbitionAdapter, AmbitionFilter, AmbitionQuery},
-    direction_adapter::{DirectionAdapter, DirectionFilter, DirectionJoin, DirectionQuery},
+    direction_adapter::{DirectionAdapter, DirectionFilter, DirectionQuery},
     notification_rule_adapter::{
         NotificationRuleAdapter, NotificationRuleFilter, NotificationRuleOrder,
         NotificationRuleQuery,
@@ -154,8 +154,7 @@ async fn get_random_message(
             (title, body)
         }
         NotificationChoice::Direction => {
-            let (direction, category) = match DirectionAdapter::init(db)
-                .join_category()
+            let (direction, category_path) = match DirectionAdapter::init(db)
                 .filter_eq_user_id(user_id)
                 .filter_eq_archived(false)
                 .get_random_with_category()
@@ -170,9 +169,16 @@ async fn get_random_message(
                     return None;
                 }
             };
-            let title = match category {
-                Some(category) => Some(format!("大事にすること: {}", category.name)),
-                None => Some("大事にすること".to_string()),
+            let title = match category_path.is_empty() {
+                true => Some("大事にすること".to_string()),
+                false => Some(format!(
+                    "大事にすること: {}",
+                    category_path
+                        .iter()
+                        .map(|category| category.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(" > ")
+                )),
             };
             let body = match direction.description {
                 Some(description) => format!(
@@ -192,7 +198,7 @@ async fn get_random_message(
 #[cfg(test)]
 mod tests {
     use common::{
-        db::init_db,
+        db::test_db::init_test_db,
         factory::{self, *},
         settings::get_test_settings,
     };
@@ -214,12 +220,10 @@ mod tests {
     #[actix_web::test]
     async fn test_get_notification_rules() -> Result<(), DbErr> {
         let settings = get_test_settings();
-        let db = init_db(&settings).await;
+        let db = init_test_db(&settings).await;
         let user = factory::user().insert(&db).await?;
         let weekday = Weekday::Mon;
         let time = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
-        // FIXME: This has to be done in a better way.
-        notification_rule::Entity::delete_many().exec(&db).await?;
         let notification_rule_0 = default_notification_rule(user.id, weekday.clone(), time.clone())
             .insert(&db)
             .await?;
@@ -271,7 +275,7 @@ mod tests {
     #[actix_web::test]
     async fn test_get_random_message_case_ambition_no_description() -> Result<(), DbErr> {
         let settings = get_test_settings();
-        let db = init_db(&settings).await;
+        let db = init_test_db(&settings).await;
         let user = factory::user().insert(&db).await?;
         let ambition = factory::ambition(user.id).insert(&db).await?;
 
@@ -290,7 +294,7 @@ mod tests {
     #[actix_web::test]
     async fn test_get_random_message_case_ambition_with_description() -> Result<(), DbErr> {
         let settings = get_test_settings();
-        let db = init_db(&settings).await;
+        let db = init_test_db(&settings).await;
         let user = factory::user().insert(&db).await?;
         let ambition = factory::ambition(user.id)
             .description(Some("Description".to_string()))
@@ -323,7 +327,7 @@ mod tests {
     #[actix_web::test]
     async fn test_get_random_message_case_direction_no_description() -> Result<(), DbErr> {
         let settings = get_test_settings();
-        let db = init_db(&settings).await;
+        let db = init_test_db(&settings).await;
         let user = factory::user().insert(&db).await?;
         let direction = factory::direction(user.id).insert(&db).await?;
 
@@ -342,7 +346,7 @@ mod tests {
     #[actix_web::test]
     async fn test_get_random_message_case_direction_with_description() -> Result<(), DbErr> {
         let settings = get_test_settings();
-        let db = init_db(&settings).await;
+        let db = init_test_db(&settings).await;
         let user = factory::user().insert(&db).await?;
         let direction = factory::direction(user.id)
             .description(Some("Description".to_string()))
@@ -375,7 +379,7 @@ mod tests {
     #[actix_web::test]
     async fn test_get_random_message_case_direction_with_category() -> Result<(), DbErr> {
         let settings = get_test_settings();
-        let db = init_db(&settings).await;
+        let db = init_test_db(&settings).await;
         let user = factory::user().insert(&db).await?;
         let category = factory::direction_category(user.id).insert(&db).await?;
         let direction = factory::direction(user.id)
@@ -397,4 +401,34 @@ mod tests {
 
         Ok(())
     }
+
+    #[actix_web::test]
+    async fn test_get_random_message_case_direction_with_nested_category() -> Result<(), DbErr> {
+        let settings = get_test_settings();
+        let db = init_test_db(&settings).await;
+        let user = factory::user().insert(&db).await?;
+        let parent = factory::direction_category(user.id)
+            .name("Health".to_string())
+            .insert(&db)
+            .await?;
+        let category = factory::direction_category(user.id)
+            .name("Sleep".to_string())
+            .parent_id(Some(parent.id))
+            .insert(&db)
+            .await?;
+        factory::direction(user.id)
+            .category_id(Some(category.id))
+            .insert(&db)
+            .await?;
+
+        let res = get_random_message(&NotificationChoice::Direction, user.id, &db).await;
+        assert!(res.is_some());
+
+        assert_eq!(
+            res.unwrap().content.title,
+            Some("大事にすること: Health > Sleep".to_string())
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,113 @@
+use chrono::{DateTime, Duration, Utc};
+use sea_orm::DbConn;
+use tracing::{event, instrument, Level};
+
+use common::settings::types::Settings;
+use db_adapters::{
+    tombstone_adapter::{TombstoneAdapter, TombstoneMutation},
+    trash_adapter::{TrashAdapter, TrashMutation},
+};
+
+/// Deletes for good what has been in the trash for longer than the retention period, and
+/// tombstones older than the maximum age of sync cursors.
+#[instrument(skip_all)]
+pub async fn purge_trash(settings: &Settings, db: &DbConn, now: DateTime<Utc>) {
+    let deleted_before = now - Duration::days(settings.application.trash_retention_days);
+    match TrashAdapter::init(db).purge(deleted_before.into()).await {
+        Ok(purged) => event!(Level::INFO, "Purged {} records from the trash", purged),
+        Err(e) => event!(Level::ERROR, "Error purging the trash: {:?}", e),
+    }
+
+    let deleted_before = now - Duration::days(settings.application.sync_cursor_max_age_days);
+    match TombstoneAdapter::init(db)
+        .prune(deleted_before.into())
+        .await
+    {
+        Ok(pruned) => event!(Level::INFO, "Pruned {} tombstones", pruned),
+        Err(e) => event!(Level::ERROR, "Error pruning tombstones: {:?}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        db::test_db::init_test_db,
+        factory::{self, *},
+        settings::get_test_settings,
+    };
+    use entities::{ambition, diary, tombstone};
+    use sea_orm::{ActiveModelTrait, DbErr, EntityTrait, Set};
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[actix_web::test]
+    async fn test_purge_trash() -> Result<(), DbErr> {
+        let settings = get_test_settings();
+        let db = init_test_db(&settings).await;
+        let user = factory::user().insert(&db).await?;
+        let now = Utc::now();
+        let retention = Duration::days(settings.application.trash_retention_days);
+        let expired_ambition = factory::ambition(user.id)
+            .deleted_at(Some((now - retention - Duration::minutes(1)).into()))
+            .insert(&db)
+            .await?;
+        let recently_trashed_diary = factory::diary(user.id)
+            .deleted_at(Some((now - retention + Duration::minutes(1)).into()))
+            .insert(&db)
+            .await?;
+        let live_ambition = factory::ambition(user.id).insert(&db).await?;
+
+        purge_trash(&settings, &db, now).await;
+
+        assert!(ambition::Entity::find_by_id(expired_ambition.id)
+            .one(&db)
+            .await?
+            .is_none());
+        assert!(diary::Entity::find_by_id(recently_trashed_diary.id)
+            .one(&db)
+            .await?
+            .is_some());
+        assert!(ambition::Entity::find_by_id(live_ambition.id)
+            .one(&db)
+            .await?
+            .is_some());
+
+        Ok(())
+    }
+
+    #[actix_web::test]
+    async fn test_purge_trash_prunes_old_tombstones() -> Result<(), DbErr> {
+        let settings = get_test_settings();
+        let db = init_test_db(&settings).await;
+        let user = factory::user().insert(&db).await?;
+        let now = Utc::now();
+        let max_age = Duration::days(settings.application.sync_cursor_max_age_days);
+        let insert_tombstone = |deleted_at: DateTime<Utc>| tombstone::ActiveModel {
+            id: Set(Uuid::now_v7()),
+            user_id: Set(user.id),
+            table_name: Set("ambition".to_string()),
+            record_id: Set(Uuid::now_v7()),
+            deleted_at: Set(deleted_at.into()),
+        };
+        let expired_tombstone = insert_tombstone(now - max_age - Duration::minutes(1))
+            .insert(&db)
+            .await?;
+        let recent_tombstone = insert_tombstone(now - max_age + Duration::minutes(1))
+            .insert(&db)
+            .await?;
+
+        purge_trash(&settings, &db, now).await;
+
+        assert!(tombstone::Entity::find_by_id(expired_tombstone.id)
+            .one(&db)
+            .await?
+            .is_none());
+        assert!(tombstone::Entity::find_by_id(recent_tombstone.id)
+            .one(&db)
+            .await?
+            .is_some());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+use std::{future::Future, pin::Pin};
+
+use sea_orm::{
+    AccessMode, ConnectionTrait, DatabaseTransaction, DbBackend, DbConn, DbErr, ExecResult,
+    IsolationLevel, QueryResult, Statement, TransactionError, TransactionTrait,
+};
+
+/// What adapters run their queries on. Adapters made with `init_in_transaction` take part in
+/// the caller's transaction, so several use cases can be committed or rolled back together.
+/// Transactions the adapters begin themselves become savepoints in that case.
+#[derive(Clone, Copy, Debug)]
+pub enum DbHandle<'a> {
+    Connection(&'a DbConn),
+    Transaction(&'a DatabaseTransaction),
+}
+
+#[async_trait::async_trait]
+impl ConnectionTrait for DbHandle<'_> {
+    fn get_database_backend(&self) -> DbBackend {
+        match self {
+            Self::Connection(db) => db.get_database_backend(),
+            Self::Transaction(txn) => txn.get_database_backend(),
+        }
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        match self {
+            Self::Connection(db) => db.execute(stmt).await,
+            Self::Transaction(txn) => txn.execute(stmt).await,
+        }
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        match self {
+            Self::Connection(db) => db.execute_unprepared(sql).await,
+            Self::Transaction(txn) => txn.execute_unprepared(sql).await,
+        }
+    }
+
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        match self {
+            Self::Connection(db) => db.query_one(stmt).await,
+            Self::Transaction(txn) => txn.query_one(stmt).await,
+        }
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        match self {
+            Self::Connection(db) => db.query_all(stmt).await,
+            Self::Transaction(txn) => txn.query_all(stmt).await,
+        }
+    }
+
+    fn support_returning(&self) -> bool {
+        match self {
+            Self::Connection(db) => db.support_returning(),
+            Self::Transaction(txn) => txn.support_returning(),
+        }
+    }
+
+    fn is_mock_connection(&self) -> bool {
+        match self {
+            Self::Connection(db) => db.is_mock_connection(),
+            Self::Transaction(txn) => txn.is_mock_connection(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TransactionTrait for DbHandle<'_> {
+    async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+        match self {
+            Self::Connection(db) => db.begin().await,
+            Self::Transaction(txn) => txn.begin().await,
+        }
+    }
+
+    async fn begin_with_config(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        match self {
+            Self::Connection(db) => db.begin_with_config(isolation_level, access_mode).await,
+            Self::Transaction(txn) => txn.begin_with_config(isolation_level, access_mode).await,
+        }
+    }
+
+    async fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::fmt::Display + std::fmt::Debug + Send,
+    {
+        match self {
+            Self::Connection(db) => db.transaction(callback).await,
+            Self::Transaction(txn) => txn.transaction(callback).await,
+        }
+    }
+
+    async fn transaction_with_config<F, T, E>(
+        &self,
+        callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::fmt::Display + std::fmt::Debug + Send,
+    {
+        match self {
+            Self::Connection(db) => {
+                db.transaction_with_config(callback, isolation_level, access_mode)
+                    .await
+            }
+            Self::Transaction(txn) => {
+                txn.transaction_with_config(callback, isolation_level, access_mode)
+                    .await
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::{sea_query::NullOrdering, DbErr, Order};
+use uuid::Uuid;
+
+use entities::{
+    action::{Entity, Model},
+    action_goal, user,
+};
+
+use super::{InMemoryAdapter, InMemoryDb};
+use crate::action_adapter::{ActionFilter, ActionOrder, ActionQuery};
+
+impl InMemoryAdapter<Entity> {
+    pub fn init(db: &InMemoryDb) -> Self {
+        Self::with_filter(db, |_, action: &Model| action.deleted_at.is_none())
+    }
+}
+
+impl ActionFilter for InMemoryAdapter<Entity> {
+    fn filter_eq_user(self, user: &user::Model) -> Self {
+        let user_id = user.id;
+        self.filter(move |_, action| action.user_id == user_id)
+    }
+
+    fn filter_eq_archived(self, archived: bool) -> Self {
+        self.filter(move |_, action| action.archived == archived)
+    }
+
+    fn filter_in_ids(self, ids: Vec<Uuid>) -> Self {
+        self.filter(move |_, action| ids.contains(&action.id))
+    }
+
+    fn filter_updated_at_gte(self, updated_at: DateTime<FixedOffset>) -> Self {
+        self.filter(move |_, action| action.updated_at >= updated_at)
+    }
+}
+
+impl ActionOrder for InMemoryAdapter<Entity> {
+    fn order_by_ordering_nulls_last(self, order: Order) -> Self {
+        self.order_by_with_nulls(|row| row.model.ordering, order, NullOrdering::Last)
+    }
+
+    fn order_by_created_at(self, order: Order) -> Self {
+        self.order_by(|row| row.model.created_at, order)
+    }
+}
+
+impl ActionQuery for InMemoryAdapter<Entity> {
+    async fn get_all(self) -> Result<Vec<Model>, DbErr> {
+        Ok(self.models())
+    }
+
+    /// With the active goal of each action, as if `join_active_goal` were called.
+    async fn get_all_with_goal(self) -> Result<Vec<(Model, Option<action_goal::Model>)>, DbErr> {
+        let actions = self.models();
+        let tables = self.db.tables();
+        Ok(actions
+            .into_iter()
+            .map(|action| {
+                let active_goal = tables
+                    .action_goals
+                    .iter()
+                    .find(|goal| goal.action_id == action.id && goal.to_date.is_none())
+                    .cloned();
+                (action, active_goal)
+            })
+            .collect())
+    }
+
+    async fn get_by_id(self, id: Uuid) -> Result<Option<Model>, DbErr> {
+        Ok(self.filter(move |_, action| action.id == id).one())
+    }
+}
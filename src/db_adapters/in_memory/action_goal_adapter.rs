@@ -0,0 +1,101 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+use sea_orm::DbErr;
+
+use entities::{
+    action,
+    action_goal::{Entity, Model},
+    user,
+};
+
+use super::{InMemoryAdapter, InMemoryDb};
+use crate::action_goal_adapter::{
+    ActionGoalFilter, ActionGoalMutation, ActionGoalQuery, CreateActionGoalParams,
+    UpdateActionGoalParams,
+};
+
+impl InMemoryAdapter<Entity> {
+    pub fn init(db: &InMemoryDb) -> Self {
+        Self::with_filter(db, |tables, action_goal: &Model| {
+            tables.action_is_untrashed(action_goal.action_id)
+        })
+    }
+}
+
+impl ActionGoalFilter for InMemoryAdapter<Entity> {
+    fn filter_eq_user(self, user: &user::Model) -> Self {
+        let user_id = user.id;
+        self.filter(move |_, action_goal| action_goal.user_id == user_id)
+    }
+
+    fn filter_eq_action(self, action: &action::Model) -> Self {
+        let action_id = action.id;
+        self.filter(move |_, action_goal| action_goal.action_id == action_id)
+    }
+
+    fn filter_to_date_null(self) -> Self {
+        self.filter(|_, action_goal| action_goal.to_date.is_none())
+    }
+
+    fn filter_overlaps_dates(self, from: NaiveDate, to: NaiveDate) -> Self {
+        self.filter(move |_, action_goal| {
+            action_goal.from_date <= to && action_goal.to_date.is_none_or(|to_date| to_date >= from)
+        })
+    }
+
+    fn filter_updated_at_gte(self, updated_at: DateTime<FixedOffset>) -> Self {
+        self.filter(move |_, action_goal| action_goal.updated_at >= updated_at)
+    }
+}
+
+impl ActionGoalQuery for InMemoryAdapter<Entity> {
+    async fn get_all(self) -> Result<Vec<Model>, DbErr> {
+        Ok(self.models())
+    }
+
+    async fn get_one(self) -> Result<Option<Model>, DbErr> {
+        Ok(self.one())
+    }
+}
+
+impl ActionGoalMutation for InMemoryAdapter<Entity> {
+    async fn create(self, params: CreateActionGoalParams) -> Result<Model, DbErr> {
+        let action_goal = Model {
+            id: uuid::Uuid::now_v7(),
+            user_id: params.user_id,
+            action_id: params.action_id,
+            from_date: params.from_date,
+            to_date: None,
+            duration_seconds: params.duration_seconds,
+            count: params.count,
+            updated_at: Utc::now().into(),
+        };
+        self.db.tables().action_goals.push(action_goal.clone());
+        Ok(action_goal)
+    }
+
+    async fn update(
+        self,
+        params: UpdateActionGoalParams,
+        action_goal: Model,
+    ) -> Result<Model, DbErr> {
+        let mut tables = self.db.tables();
+        let stored = tables
+            .action_goals
+            .iter_mut()
+            .find(|stored| stored.id == action_goal.id)
+            .ok_or(DbErr::RecordNotUpdated)?;
+        stored.to_date = params.to_date;
+        stored.duration_seconds = params.duration_seconds;
+        stored.count = params.count;
+        stored.updated_at = Utc::now().into();
+        Ok(stored.clone())
+    }
+
+    async fn delete(self, action_goal: Model) -> Result<(), DbErr> {
+        self.db
+            .tables()
+            .action_goals
+            .retain(|stored| stored.id != action_goal.id);
+        Ok(())
+    }
+}
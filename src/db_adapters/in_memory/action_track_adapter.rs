@@ -0,0 +1,123 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use sea_orm::{DbErr, Order};
+use uuid::Uuid;
+
+use entities::{
+    action_track::{Entity, Model},
+    sea_orm_active_enums::TimezoneEnum,
+    user,
+};
+
+use super::{InMemoryAdapter, InMemoryDb};
+use crate::action_track_adapter::{
+    get_date_start_end_in_utc, ActionTrackCursor, ActionTrackFilter, ActionTrackLimit,
+    ActionTrackOrder, ActionTrackQuery,
+};
+
+impl InMemoryAdapter<Entity> {
+    pub fn init(db: &InMemoryDb) -> Self {
+        Self::with_filter(db, |tables, action_track: &Model| {
+            tables.action_is_untrashed(action_track.action_id)
+        })
+    }
+}
+
+impl ActionTrackFilter for InMemoryAdapter<Entity> {
+    fn filter_eq_user(self, user: &user::Model) -> Self {
+        let user_id = user.id;
+        self.filter(move |_, action_track| action_track.user_id == user_id)
+    }
+
+    fn filter_started_at_gte(self, started_at: DateTime<FixedOffset>) -> Self {
+        self.filter(move |_, action_track| action_track.started_at >= started_at)
+    }
+
+    fn filter_started_at_lte(self, started_at: DateTime<FixedOffset>) -> Self {
+        self.filter(move |_, action_track| action_track.started_at <= started_at)
+    }
+
+    fn filter_started_at_in_dates(
+        self,
+        dates: Vec<NaiveDate>,
+        user_timezone: TimezoneEnum,
+    ) -> Self {
+        let ranges = dates
+            .into_iter()
+            .map(|date| get_date_start_end_in_utc(date, &user_timezone))
+            .collect::<Vec<_>>();
+        self.filter(move |_, action_track| {
+            ranges
+                .iter()
+                .any(|(start, end)| (*start..=*end).contains(&action_track.started_at.to_utc()))
+        })
+    }
+
+    fn filter_started_at_in_date_range(
+        self,
+        from: NaiveDate,
+        to: NaiveDate,
+        user_timezone: TimezoneEnum,
+    ) -> Self {
+        let (start, _) = get_date_start_end_in_utc(from, &user_timezone);
+        let (_, end) = get_date_start_end_in_utc(to, &user_timezone);
+        self.filter(move |_, action_track| {
+            (start..=end).contains(&action_track.started_at.to_utc())
+        })
+    }
+
+    fn filter_ended_at_is_null(self, is_null: bool) -> Self {
+        self.filter(move |_, action_track| action_track.ended_at.is_none() == is_null)
+    }
+
+    fn filter_eq_archived_action(self, archived: bool) -> Self {
+        self.filter(move |tables, action_track| {
+            tables
+                .actions
+                .iter()
+                .any(|action| action.id == action_track.action_id && action.archived == archived)
+        })
+    }
+
+    fn filter_updated_at_gte(self, updated_at: DateTime<FixedOffset>) -> Self {
+        self.filter(move |_, action_track| action_track.updated_at >= updated_at)
+    }
+
+    fn filter_after_cursor(self, cursor: &ActionTrackCursor) -> Self {
+        let cursor = cursor.clone();
+        self.filter(move |_, action_track| {
+            (action_track.started_at, action_track.id) < (cursor.started_at, cursor.id)
+        })
+    }
+}
+
+impl ActionTrackOrder for InMemoryAdapter<Entity> {
+    fn order_by_action_id(self, order: Order) -> Self {
+        self.order_by(|row| row.model.action_id, order)
+    }
+
+    fn order_by_started_at(self, order: Order) -> Self {
+        self.order_by(|row| row.model.started_at, order)
+    }
+
+    fn order_by_id(self, order: Order) -> Self {
+        self.order_by(|row| row.model.id, order)
+    }
+}
+
+impl ActionTrackLimit for InMemoryAdapter<Entity> {
+    fn limit(self, num: u64) -> Self {
+        self.limit_to(num)
+    }
+}
+
+impl ActionTrackQuery for InMemoryAdapter<Entity> {
+    async fn get_all(self) -> Result<Vec<Model>, DbErr> {
+        Ok(self.models())
+    }
+
+    async fn get_by_id(self, id: Uuid) -> Result<Option<Model>, DbErr> {
+        Ok(self
+            .filter(move |_, action_track| action_track.id == id)
+            .one())
+    }
+}
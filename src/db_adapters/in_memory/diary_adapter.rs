@@ -0,0 +1,139 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::{DbErr, Order};
+use uuid::Uuid;
+
+use entities::{
+    diary::{Entity, Model},
+    tag, user,
+};
+
+use super::{InMemoryAdapter, InMemoryDb, Tables, Tagged};
+use crate::diary_adapter::{
+    DiaryCursor, DiaryFilter, DiaryJoin, DiaryLimit, DiaryOrder, DiaryQuery, DiaryWithTag,
+};
+
+impl InMemoryAdapter<Entity> {
+    pub fn init(db: &InMemoryDb) -> Self {
+        Self::with_filter(db, |_, diary: &Model| diary.deleted_at.is_none())
+    }
+}
+
+impl Tagged for Entity {
+    fn tag_ids(tables: &Tables, diary: &Model) -> Vec<Uuid> {
+        tables
+            .diaries_tags
+            .iter()
+            .filter(|link| link.diary_id == diary.id)
+            .map(|link| link.tag_id)
+            .collect()
+    }
+}
+
+impl DiaryJoin for InMemoryAdapter<Entity> {
+    fn join_tags(self) -> Self {
+        InMemoryAdapter::join_tags(self)
+    }
+
+    /// Tags come with their ambitions, directions and actions already.
+    fn join_my_way_via_tags(self) -> Self {
+        self
+    }
+}
+
+impl DiaryFilter for InMemoryAdapter<Entity> {
+    fn filter_eq_id(self, id: Uuid) -> Self {
+        self.filter(move |_, diary| diary.id == id)
+    }
+
+    fn filter_id_in(self, ids: Vec<Uuid>) -> Self {
+        self.filter(move |_, diary| ids.contains(&diary.id))
+    }
+
+    fn filter_eq_user(self, user: &user::Model) -> Self {
+        let user_id = user.id;
+        self.filter(move |_, diary| diary.user_id == user_id)
+    }
+
+    fn filter_updated_at_gte(self, updated_at: DateTime<FixedOffset>) -> Self {
+        self.filter(move |_, diary| diary.updated_at >= updated_at)
+    }
+
+    fn filter_tagged_with_any(self, tag_ids: Vec<Uuid>) -> Self {
+        self.filter(move |tables, diary| {
+            Entity::tag_ids(tables, diary)
+                .iter()
+                .any(|tag_id| tag_ids.contains(tag_id))
+        })
+    }
+
+    fn filter_after_cursor(self, cursor: &DiaryCursor) -> Self {
+        let cursor = cursor.clone();
+        self.filter(move |_, diary| (diary.date, diary.id) < (cursor.date, cursor.id))
+    }
+}
+
+impl DiaryOrder for InMemoryAdapter<Entity> {
+    fn order_by_date(self, order: Order) -> Self {
+        self.order_by(|row| row.model.date, order)
+    }
+
+    fn order_by_id(self, order: Order) -> Self {
+        self.order_by(|row| row.model.id, order)
+    }
+
+    fn order_by_ambition_created_at_nulls_last(self, order: Order) -> Self {
+        self.order_by_ambition_created_at(order)
+    }
+
+    fn order_by_direction_created_at_nulls_last(self, order: Order) -> Self {
+        self.order_by_direction_created_at(order)
+    }
+
+    fn order_by_action_created_at_nulls_last(self, order: Order) -> Self {
+        self.order_by_action_created_at(order)
+    }
+
+    fn order_by_tag_created_at_nulls_last(self, order: Order) -> Self {
+        self.order_by_tag_created_at(order)
+    }
+}
+
+impl DiaryLimit for InMemoryAdapter<Entity> {
+    fn limit(self, num: u64) -> Self {
+        self.limit_to(num)
+    }
+}
+
+impl DiaryQuery for InMemoryAdapter<Entity> {
+    async fn get_all_with_tags(self) -> Result<Vec<DiaryWithTag>, DbErr> {
+        Ok(self
+            .rows()
+            .into_iter()
+            .map(|row| DiaryWithTag {
+                id: row.model.id,
+                text: row.model.text,
+                date: row.model.date,
+                tag_id: row.tag.as_ref().map(|tag| tag.tag.id),
+                tag_name: row.tag.as_ref().map(|tag| tag.name.clone()),
+                tag_type: row.tag.as_ref().map(|tag| tag.tag.r#type.clone()),
+                tag_created_at: row.tag.as_ref().map(|tag| tag.tag.created_at),
+            })
+            .collect())
+    }
+
+    async fn get_all_with_linked_tags(self) -> Result<Vec<(Model, Vec<tag::Model>)>, DbErr> {
+        Ok(self.models_with_tags())
+    }
+
+    async fn get_all_ids(self) -> Result<Vec<Uuid>, DbErr> {
+        Ok(self.models().into_iter().map(|diary| diary.id).collect())
+    }
+
+    async fn get_by_id(self, id: Uuid) -> Result<Option<Model>, DbErr> {
+        Ok(self.filter(move |_, diary| diary.id == id).one())
+    }
+
+    async fn get_with_tags(self) -> Result<Option<(Model, Vec<tag::Model>)>, DbErr> {
+        Ok(self.models_with_tags().into_iter().next())
+    }
+}
@@ -0,0 +1,410 @@
+//! Adapters keeping records in memory instead of a database, for unit tests of use cases.
+//! They implement the same Filter/Order/Query/Mutation traits as the database adapters, so a
+//! use case generic over those traits runs on either.
+
+mod action_adapter;
+mod action_goal_adapter;
+mod action_track_adapter;
+mod diary_adapter;
+mod reading_note_adapter;
+mod thinking_note_adapter;
+
+use std::{
+    cmp::Ordering,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use sea_orm::{
+    sea_query::NullOrdering, ActiveModelTrait, EntityTrait, Iterable, ModelTrait, Order,
+    TryIntoModel,
+};
+use uuid::Uuid;
+
+use entities::{
+    action, action_goal, action_track, ambition, diaries_tags, diary, direction, reading_note,
+    reading_notes_tags, sea_orm_active_enums::TagType, tag, thinking_note, thinking_note_tags,
+};
+
+pub type InMemoryActionAdapter = InMemoryAdapter<action::Entity>;
+pub type InMemoryActionGoalAdapter = InMemoryAdapter<action_goal::Entity>;
+pub type InMemoryActionTrackAdapter = InMemoryAdapter<action_track::Entity>;
+pub type InMemoryDiaryAdapter = InMemoryAdapter<diary::Entity>;
+pub type InMemoryReadingNoteAdapter = InMemoryAdapter<reading_note::Entity>;
+pub type InMemoryThinkingNoteAdapter = InMemoryAdapter<thinking_note::Entity>;
+
+/// Records shared by all the adapters made from it, like a database.
+#[derive(Clone, Default)]
+pub struct InMemoryDb {
+    tables: Arc<Mutex<Tables>>,
+}
+
+#[derive(Default, Debug)]
+pub struct Tables {
+    pub actions: Vec<action::Model>,
+    pub action_goals: Vec<action_goal::Model>,
+    pub action_tracks: Vec<action_track::Model>,
+    pub ambitions: Vec<ambition::Model>,
+    pub directions: Vec<direction::Model>,
+    pub tags: Vec<tag::Model>,
+    pub diaries: Vec<diary::Model>,
+    pub diaries_tags: Vec<diaries_tags::Model>,
+    pub reading_notes: Vec<reading_note::Model>,
+    pub reading_notes_tags: Vec<reading_notes_tags::Model>,
+    pub thinking_notes: Vec<thinking_note::Model>,
+    pub thinking_note_tags: Vec<thinking_note_tags::Model>,
+}
+
+impl InMemoryDb {
+    pub fn tables(&self) -> MutexGuard<'_, Tables> {
+        self.tables.lock().unwrap()
+    }
+
+    /// Inserts the record as given, where columns not set take their Rust defaults, e.g. None
+    /// for nullable ones. Unlike the database, column defaults such as `now()` are not applied.
+    /// e.g. `db.insert(factory::action(user.id))`.
+    pub fn insert<A, M>(&self, mut active_model: A) -> M
+    where
+        A: ActiveModelTrait + TryIntoModel<M>,
+        A::Entity: InMemoryTable<Model = M>,
+        M: ModelTrait<Entity = A::Entity> + Clone,
+    {
+        let nulls = A::default_values();
+        for column in <A::Entity as EntityTrait>::Column::iter() {
+            if active_model.is_not_set(column) {
+                if let Some(null) = nulls.get(column).into_value() {
+                    active_model.set(column, null);
+                }
+            }
+        }
+        let model = active_model
+            .try_into_model()
+            .expect("Columns without Rust defaults must be set.");
+        A::Entity::rows_mut(&mut self.tables()).push(model.clone());
+        model
+    }
+}
+
+/// Entities with a table in `Tables`.
+pub trait InMemoryTable: EntityTrait {
+    fn rows(tables: &Tables) -> &Vec<Self::Model>;
+    fn rows_mut(tables: &mut Tables) -> &mut Vec<Self::Model>;
+}
+
+macro_rules! impl_in_memory_table {
+    ($($entity:ident => $rows:ident),*) => {
+        $(impl InMemoryTable for $entity::Entity {
+            fn rows(tables: &Tables) -> &Vec<Self::Model> {
+                &tables.$rows
+            }
+
+            fn rows_mut(tables: &mut Tables) -> &mut Vec<Self::Model> {
+                &mut tables.$rows
+            }
+        })*
+    };
+}
+
+impl_in_memory_table!(
+    action => actions,
+    action_goal => action_goals,
+    action_track => action_tracks,
+    ambition => ambitions,
+    direction => directions,
+    tag => tags,
+    diary => diaries,
+    diaries_tags => diaries_tags,
+    reading_note => reading_notes,
+    reading_notes_tags => reading_notes_tags,
+    thinking_note => thinking_notes,
+    thinking_note_tags => thinking_note_tags
+);
+
+/// Entities linked to tags, whose rows can be joined with them.
+pub trait Tagged: InMemoryTable {
+    fn tag_ids(tables: &Tables, model: &Self::Model) -> Vec<Uuid>;
+}
+
+/// A record with one of its tags, like a row of a query joining tags. `tag` is None for
+/// records without tags, and for any record when tags are not joined.
+#[derive(Clone)]
+pub(crate) struct Row<M> {
+    pub model: M,
+    pub tag: Option<JoinedTag>,
+}
+
+#[derive(Clone)]
+pub(crate) struct JoinedTag {
+    pub tag: tag::Model,
+    pub name: String,
+    pub ambition: Option<ambition::Model>,
+    pub direction: Option<direction::Model>,
+    pub action: Option<action::Model>,
+}
+
+impl Tables {
+    /// None if the tag does not exist, or is hidden along with its trashed ambition, direction
+    /// or action.
+    pub(crate) fn joined_tag(&self, tag_id: Uuid) -> Option<JoinedTag> {
+        let tag = self.tags.iter().find(|tag| tag.id == tag_id)?.clone();
+        let ambition = tag
+            .ambition_id
+            .and_then(|id| self.ambitions.iter().find(|a| a.id == id).cloned());
+        let direction = tag
+            .direction_id
+            .and_then(|id| self.directions.iter().find(|d| d.id == id).cloned());
+        let action = tag
+            .action_id
+            .and_then(|id| self.actions.iter().find(|a| a.id == id).cloned());
+        if ambition.as_ref().is_some_and(|a| a.deleted_at.is_some())
+            || direction.as_ref().is_some_and(|d| d.deleted_at.is_some())
+            || action.as_ref().is_some_and(|a| a.deleted_at.is_some())
+        {
+            return None;
+        }
+        let name = match tag.r#type {
+            TagType::Ambition => ambition.as_ref().map(|a| a.name.clone()),
+            TagType::Direction => direction.as_ref().map(|d| d.name.clone()),
+            TagType::Action => action.as_ref().map(|a| a.name.clone()),
+            TagType::Plain => tag.name.clone(),
+        }
+        .unwrap_or("no_name".to_string());
+        Some(JoinedTag {
+            tag,
+            name,
+            ambition,
+            direction,
+            action,
+        })
+    }
+
+    pub(crate) fn action_is_untrashed(&self, action_id: Uuid) -> bool {
+        self.actions
+            .iter()
+            .any(|action| action.id == action_id && action.deleted_at.is_none())
+    }
+}
+
+type Predicate<M> = Arc<dyn Fn(&Tables, &M) -> bool + Send + Sync>;
+type Comparator<M> = Arc<dyn Fn(&Row<M>, &Row<M>) -> Ordering + Send + Sync>;
+
+/// Filters, orders and limits are kept until a query method runs them over the table of `E`.
+pub struct InMemoryAdapter<E: InMemoryTable> {
+    pub db: InMemoryDb,
+    filters: Vec<Predicate<E::Model>>,
+    orders: Vec<Comparator<E::Model>>,
+    limit: Option<u64>,
+    joins_tags: bool,
+}
+
+impl<E: InMemoryTable> Clone for InMemoryAdapter<E> {
+    fn clone(&self) -> Self {
+        Self {
+            db: self.db.clone(),
+            filters: self.filters.clone(),
+            orders: self.orders.clone(),
+            limit: self.limit,
+            joins_tags: self.joins_tags,
+        }
+    }
+}
+
+impl<E: InMemoryTable> InMemoryAdapter<E> {
+    fn with_filter(
+        db: &InMemoryDb,
+        filter: impl Fn(&Tables, &E::Model) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            db: db.clone(),
+            filters: vec![],
+            orders: vec![],
+            limit: None,
+            joins_tags: false,
+        }
+        .filter(filter)
+    }
+
+    fn filter(
+        mut self,
+        filter: impl Fn(&Tables, &E::Model) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.filters.push(Arc::new(filter));
+        self
+    }
+
+    fn order_by<K: Ord>(
+        mut self,
+        key: impl Fn(&Row<E::Model>) -> K + Send + Sync + 'static,
+        order: Order,
+    ) -> Self {
+        self.orders.push(Arc::new(move |a, b| match order {
+            Order::Desc => key(b).cmp(&key(a)),
+            _ => key(a).cmp(&key(b)),
+        }));
+        self
+    }
+
+    fn order_by_with_nulls<K: Ord>(
+        mut self,
+        key: impl Fn(&Row<E::Model>) -> Option<K> + Send + Sync + 'static,
+        order: Order,
+        nulls: NullOrdering,
+    ) -> Self {
+        self.orders
+            .push(Arc::new(move |a, b| match (key(a), key(b), &nulls) {
+                (None, None, _) => Ordering::Equal,
+                (None, Some(_), NullOrdering::First) | (Some(_), None, NullOrdering::Last) => {
+                    Ordering::Less
+                }
+                (None, Some(_), NullOrdering::Last) | (Some(_), None, NullOrdering::First) => {
+                    Ordering::Greater
+                }
+                (Some(a), Some(b), _) => match order {
+                    Order::Desc => b.cmp(&a),
+                    _ => a.cmp(&b),
+                },
+            }));
+        self
+    }
+
+    fn limit_to(mut self, num: u64) -> Self {
+        self.limit = Some(num);
+        self
+    }
+
+    fn join_tags(mut self) -> Self {
+        self.joins_tags = true;
+        self
+    }
+
+    fn matches(&self, tables: &Tables, model: &E::Model) -> bool {
+        self.filters.iter().all(|filter| filter(tables, model))
+    }
+
+    fn sort_and_limit(&self, mut rows: Vec<Row<E::Model>>) -> Vec<Row<E::Model>> {
+        rows.sort_by(|a, b| {
+            self.orders
+                .iter()
+                .map(|order| order(a, b))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(Ordering::Equal)
+        });
+        if let Some(limit) = self.limit {
+            rows.truncate(limit as usize);
+        }
+        rows
+    }
+
+    /// Records which pass the filters, in order.
+    fn models(&self) -> Vec<E::Model> {
+        let tables = self.db.tables();
+        let rows = E::rows(&tables)
+            .iter()
+            .filter(|model| self.matches(&tables, model))
+            .map(|model| Row {
+                model: model.clone(),
+                tag: None,
+            })
+            .collect();
+        self.sort_and_limit(rows)
+            .into_iter()
+            .map(|row| row.model)
+            .collect()
+    }
+
+    fn one(&self) -> Option<E::Model> {
+        self.models().into_iter().next()
+    }
+}
+
+impl<E: Tagged> InMemoryAdapter<E> {
+    /// Rows of the records which pass the filters, one per tag when tags are joined.
+    fn rows(&self) -> Vec<Row<E::Model>> {
+        let tables = self.db.tables();
+        let mut rows = vec![];
+        for model in E::rows(&tables)
+            .iter()
+            .filter(|model| self.matches(&tables, model))
+        {
+            let tags = match self.joins_tags {
+                true => E::tag_ids(&tables, model)
+                    .into_iter()
+                    .filter_map(|tag_id| tables.joined_tag(tag_id))
+                    .collect(),
+                false => vec![],
+            };
+            match tags.is_empty() {
+                true => rows.push(Row {
+                    model: model.clone(),
+                    tag: None,
+                }),
+                false => rows.extend(tags.into_iter().map(|tag| Row {
+                    model: model.clone(),
+                    tag: Some(tag),
+                })),
+            }
+        }
+        drop(tables);
+        self.sort_and_limit(rows)
+    }
+
+    /// Records which pass the filters with their tags, in order.
+    fn models_with_tags(&self) -> Vec<(E::Model, Vec<tag::Model>)> {
+        let models = self.models();
+        let tables = self.db.tables();
+        models
+            .into_iter()
+            .map(|model| {
+                let tags = E::tag_ids(&tables, &model)
+                    .into_iter()
+                    .filter_map(|tag_id| tables.tags.iter().find(|tag| tag.id == tag_id))
+                    .cloned()
+                    .collect();
+                (model, tags)
+            })
+            .collect()
+    }
+
+    fn order_by_ambition_created_at(self, order: Order) -> Self {
+        self.order_by_with_nulls(
+            |row| {
+                row.tag
+                    .as_ref()
+                    .and_then(|tag| tag.ambition.as_ref().map(|a| a.created_at))
+            },
+            order,
+            NullOrdering::Last,
+        )
+    }
+
+    fn order_by_direction_created_at(self, order: Order) -> Self {
+        self.order_by_with_nulls(
+            |row| {
+                row.tag
+                    .as_ref()
+                    .and_then(|tag| tag.direction.as_ref().map(|d| d.created_at))
+            },
+            order,
+            NullOrdering::Last,
+        )
+    }
+
+    fn order_by_action_created_at(self, order: Order) -> Self {
+        self.order_by_with_nulls(
+            |row| {
+                row.tag
+                    .as_ref()
+                    .and_then(|tag| tag.action.as_ref().map(|a| a.created_at))
+            },
+            order,
+            NullOrdering::Last,
+        )
+    }
+
+    fn order_by_tag_created_at(self, order: Order) -> Self {
+        self.order_by_with_nulls(
+            |row| row.tag.as_ref().map(|tag| tag.tag.created_at),
+            order,
+            NullOrdering::Last,
+        )
+    }
+}
@@ -0,0 +1,174 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::{DbErr, Order};
+use uuid::Uuid;
+
+use entities::{
+    reading_note::{Entity, Model},
+    tag, user,
+};
+
+use super::{InMemoryAdapter, InMemoryDb, Tables, Tagged};
+use crate::reading_note_adapter::{
+    ReadingNoteCursor, ReadingNoteFilter, ReadingNoteJoin, ReadingNoteLimit, ReadingNoteOrder,
+    ReadingNoteQuery, ReadingNoteWithTag,
+};
+
+impl InMemoryAdapter<Entity> {
+    pub fn init(db: &InMemoryDb) -> Self {
+        Self::with_filter(db, |_, reading_note: &Model| {
+            reading_note.deleted_at.is_none()
+        })
+    }
+}
+
+impl Tagged for Entity {
+    fn tag_ids(tables: &Tables, reading_note: &Model) -> Vec<Uuid> {
+        tables
+            .reading_notes_tags
+            .iter()
+            .filter(|link| link.reading_note_id == reading_note.id)
+            .map(|link| link.tag_id)
+            .collect()
+    }
+}
+
+impl ReadingNoteJoin for InMemoryAdapter<Entity> {
+    fn join_tags(self) -> Self {
+        InMemoryAdapter::join_tags(self)
+    }
+
+    /// Tags come with their ambitions, directions and actions already.
+    fn join_my_way_via_tags(self) -> Self {
+        self
+    }
+}
+
+impl ReadingNoteFilter for InMemoryAdapter<Entity> {
+    fn filter_eq_id(self, id: Uuid) -> Self {
+        self.filter(move |_, reading_note| reading_note.id == id)
+    }
+
+    fn filter_id_in(self, ids: Vec<Uuid>) -> Self {
+        self.filter(move |_, reading_note| ids.contains(&reading_note.id))
+    }
+
+    fn filter_eq_user(self, user: &user::Model) -> Self {
+        let user_id = user.id;
+        self.filter(move |_, reading_note| reading_note.user_id == user_id)
+    }
+
+    fn filter_updated_at_gte(self, updated_at: DateTime<FixedOffset>) -> Self {
+        self.filter(move |_, reading_note| reading_note.updated_at >= updated_at)
+    }
+
+    fn filter_tagged_with_any(self, tag_ids: Vec<Uuid>) -> Self {
+        self.filter(move |tables, reading_note| {
+            Entity::tag_ids(tables, reading_note)
+                .iter()
+                .any(|tag_id| tag_ids.contains(tag_id))
+        })
+    }
+
+    fn filter_after_cursor(self, cursor: &ReadingNoteCursor) -> Self {
+        let cursor = cursor.clone();
+        self.filter(move |_, reading_note| {
+            (reading_note.date, reading_note.created_at, reading_note.id)
+                < (cursor.date, cursor.created_at, cursor.id)
+        })
+    }
+}
+
+impl ReadingNoteOrder for InMemoryAdapter<Entity> {
+    fn order_by_title(self, order: Order) -> Self {
+        self.order_by(|row| row.model.title.clone(), order)
+    }
+
+    fn order_by_date(self, order: Order) -> Self {
+        self.order_by(|row| row.model.date, order)
+    }
+
+    fn order_by_created_at(self, order: Order) -> Self {
+        self.order_by(|row| row.model.created_at, order)
+    }
+
+    fn order_by_id(self, order: Order) -> Self {
+        self.order_by(|row| row.model.id, order)
+    }
+
+    fn order_by_ambition_created_at_nulls_last(self, order: Order) -> Self {
+        self.order_by_ambition_created_at(order)
+    }
+
+    fn order_by_direction_created_at_nulls_last(self, order: Order) -> Self {
+        self.order_by_direction_created_at(order)
+    }
+
+    fn order_by_action_created_at_nulls_last(self, order: Order) -> Self {
+        self.order_by_action_created_at(order)
+    }
+
+    fn order_by_tag_created_at_nulls_last(self, order: Order) -> Self {
+        self.order_by_tag_created_at(order)
+    }
+}
+
+impl ReadingNoteLimit for InMemoryAdapter<Entity> {
+    fn limit(self, num: u64) -> Self {
+        self.limit_to(num)
+    }
+}
+
+impl ReadingNoteQuery for InMemoryAdapter<Entity> {
+    async fn get_all_with_tags(self) -> Result<Vec<ReadingNoteWithTag>, DbErr> {
+        Ok(self
+            .rows()
+            .into_iter()
+            .map(|row| ReadingNoteWithTag {
+                id: row.model.id,
+                title: row.model.title,
+                page_number: row.model.page_number,
+                text: row.model.text,
+                date: row.model.date,
+                created_at: row.model.created_at,
+                updated_at: row.model.updated_at,
+                tag_id: row.tag.as_ref().map(|tag| tag.tag.id),
+                tag_name: row.tag.as_ref().map(|tag| tag.name.clone()),
+                tag_type: row.tag.as_ref().map(|tag| tag.tag.r#type.clone()),
+                tag_created_at: row.tag.as_ref().map(|tag| tag.tag.created_at),
+            })
+            .collect())
+    }
+
+    async fn get_all_with_linked_tags(self) -> Result<Vec<(Model, Vec<tag::Model>)>, DbErr> {
+        Ok(self.models_with_tags())
+    }
+
+    async fn get_all_ids(self) -> Result<Vec<Uuid>, DbErr> {
+        Ok(self
+            .models()
+            .into_iter()
+            .map(|reading_note| reading_note.id)
+            .collect())
+    }
+
+    async fn get_by_id(self, id: Uuid) -> Result<Option<Model>, DbErr> {
+        Ok(self
+            .filter(move |_, reading_note| reading_note.id == id)
+            .one())
+    }
+
+    async fn get_with_tags(self) -> Result<Option<(Model, Vec<tag::Model>)>, DbErr> {
+        Ok(self.models_with_tags().into_iter().next())
+    }
+
+    async fn get_all_only_titles(self) -> Result<Vec<String>, DbErr> {
+        let mut titles = self
+            .models()
+            .into_iter()
+            .map(|reading_note| reading_note.title)
+            .collect::<Vec<_>>();
+        titles.sort();
+        titles.dedup();
+        Ok(titles)
+    }
+}
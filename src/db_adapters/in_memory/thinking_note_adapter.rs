@@ -0,0 +1,173 @@
+use chrono::{DateTime, FixedOffset};
+use sea_orm::{sea_query::NullOrdering, DbErr, Order};
+use uuid::Uuid;
+
+use entities::{
+    tag,
+    thinking_note::{Entity, Model},
+    user,
+};
+
+use super::{InMemoryAdapter, InMemoryDb, Tables, Tagged};
+use crate::thinking_note_adapter::{
+    ThinkingNoteCursor, ThinkingNoteFilter, ThinkingNoteJoin, ThinkingNoteLimit, ThinkingNoteOrder,
+    ThinkingNoteQuery, ThinkingNoteWithTag,
+};
+
+impl InMemoryAdapter<Entity> {
+    pub fn init(db: &InMemoryDb) -> Self {
+        Self::with_filter(db, |_, thinking_note: &Model| {
+            thinking_note.deleted_at.is_none()
+        })
+    }
+}
+
+impl Tagged for Entity {
+    fn tag_ids(tables: &Tables, thinking_note: &Model) -> Vec<Uuid> {
+        tables
+            .thinking_note_tags
+            .iter()
+            .filter(|link| link.thinking_note_id == thinking_note.id)
+            .map(|link| link.tag_id)
+            .collect()
+    }
+}
+
+impl ThinkingNoteJoin for InMemoryAdapter<Entity> {
+    fn join_tags(self) -> Self {
+        InMemoryAdapter::join_tags(self)
+    }
+
+    /// Tags come with their ambitions, directions and actions already.
+    fn join_my_way_via_tags(self) -> Self {
+        self
+    }
+}
+
+impl ThinkingNoteFilter for InMemoryAdapter<Entity> {
+    fn filter_eq_id(self, id: Uuid) -> Self {
+        self.filter(move |_, thinking_note| thinking_note.id == id)
+    }
+
+    fn filter_id_in(self, ids: Vec<Uuid>) -> Self {
+        self.filter(move |_, thinking_note| ids.contains(&thinking_note.id))
+    }
+
+    fn filter_eq_user(self, user: &user::Model) -> Self {
+        let user_id = user.id;
+        self.filter(move |_, thinking_note| thinking_note.user_id == user_id)
+    }
+
+    fn filter_null_resolved_at(self, is_null: bool) -> Self {
+        self.filter(move |_, thinking_note| thinking_note.resolved_at.is_none() == is_null)
+    }
+
+    fn filter_updated_at_gte(self, updated_at: DateTime<FixedOffset>) -> Self {
+        self.filter(move |_, thinking_note| thinking_note.updated_at >= updated_at)
+    }
+
+    fn filter_tagged_with_any(self, tag_ids: Vec<Uuid>) -> Self {
+        self.filter(move |tables, thinking_note| {
+            Entity::tag_ids(tables, thinking_note)
+                .iter()
+                .any(|tag_id| tag_ids.contains(tag_id))
+        })
+    }
+
+    fn filter_after_cursor(self, cursor: &ThinkingNoteCursor) -> Self {
+        let cursor = cursor.clone();
+        self.filter(move |_, thinking_note| {
+            let updated_at_and_id_after_cursor =
+                (thinking_note.updated_at, thinking_note.id) < (cursor.updated_at, cursor.id);
+            match (cursor.resolved_at, thinking_note.resolved_at) {
+                (None, None) => updated_at_and_id_after_cursor,
+                (None, Some(_)) => true,
+                (Some(_), None) => false,
+                (Some(cursor_resolved_at), Some(resolved_at)) => {
+                    resolved_at < cursor_resolved_at
+                        || (resolved_at == cursor_resolved_at && updated_at_and_id_after_cursor)
+                }
+            }
+        })
+    }
+}
+
+impl ThinkingNoteOrder for InMemoryAdapter<Entity> {
+    fn order_by_resolved_at_nulls_first(self, order: Order) -> Self {
+        self.order_by_with_nulls(|row| row.model.resolved_at, order, NullOrdering::First)
+    }
+
+    fn order_by_updated_at(self, order: Order) -> Self {
+        self.order_by(|row| row.model.updated_at, order)
+    }
+
+    fn order_by_id(self, order: Order) -> Self {
+        self.order_by(|row| row.model.id, order)
+    }
+
+    fn order_by_ambition_created_at_nulls_last(self, order: Order) -> Self {
+        self.order_by_ambition_created_at(order)
+    }
+
+    fn order_by_direction_created_at_nulls_last(self, order: Order) -> Self {
+        self.order_by_direction_created_at(order)
+    }
+
+    fn order_by_action_created_at_nulls_last(self, order: Order) -> Self {
+        self.order_by_action_created_at(order)
+    }
+
+    fn order_by_tag_created_at_nulls_last(self, order: Order) -> Self {
+        self.order_by_tag_created_at(order)
+    }
+}
+
+impl ThinkingNoteLimit for InMemoryAdapter<Entity> {
+    fn limit(self, num: u64) -> Self {
+        self.limit_to(num)
+    }
+}
+
+impl ThinkingNoteQuery for InMemoryAdapter<Entity> {
+    async fn get_all_with_tags(self) -> Result<Vec<ThinkingNoteWithTag>, DbErr> {
+        Ok(self
+            .rows()
+            .into_iter()
+            .map(|row| ThinkingNoteWithTag {
+                id: row.model.id,
+                question: row.model.question,
+                thought: row.model.thought,
+                answer: row.model.answer,
+                resolved_at: row.model.resolved_at,
+                created_at: row.model.created_at,
+                updated_at: row.model.updated_at,
+                tag_id: row.tag.as_ref().map(|tag| tag.tag.id),
+                tag_name: row.tag.as_ref().map(|tag| tag.name.clone()),
+                tag_type: row.tag.as_ref().map(|tag| tag.tag.r#type.clone()),
+                tag_created_at: row.tag.as_ref().map(|tag| tag.tag.created_at),
+            })
+            .collect())
+    }
+
+    async fn get_all_with_linked_tags(self) -> Result<Vec<(Model, Vec<tag::Model>)>, DbErr> {
+        Ok(self.models_with_tags())
+    }
+
+    async fn get_all_ids(self) -> Result<Vec<Uuid>, DbErr> {
+        Ok(self
+            .models()
+            .into_iter()
+            .map(|thinking_note| thinking_note.id)
+            .collect())
+    }
+
+    async fn get_by_id(self, id: Uuid) -> Result<Option<Model>, DbErr> {
+        Ok(self
+            .filter(move |_, thinking_note| thinking_note.id == id)
+            .one())
+    }
+
+    async fn get_with_tags(self) -> Result<Option<(Model, Vec<tag::Model>)>, DbErr> {
+        Ok(self.models_with_tags().into_iter().next())
+    }
+}
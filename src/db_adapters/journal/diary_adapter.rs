@@ -1,15 +1,19 @@
 use std::future::Future;
 
-use chrono::{DateTime, FixedOffset, NaiveDate};
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
 use sea_orm::{
-    prelude::Expr, sea_query::NullOrdering::Last, sqlx::error::Error::Database, ActiveModelTrait,
-    ColumnAsExpr, ColumnTrait, DbConn, DbErr, EntityTrait, FromQueryResult, IntoActiveModel,
-    JoinType::LeftJoin, ModelTrait, Order, QueryFilter, QueryOrder, QuerySelect, RelationTrait,
-    RuntimeErr::SqlxError, Select, Set,
+    prelude::Expr,
+    sea_query::{NullOrdering::Last, Query},
+    ActiveModelTrait, ColumnAsExpr, ColumnTrait, DatabaseTransaction, DbConn, DbErr, EntityTrait,
+    FromQueryResult, IntoActiveModel,
+    JoinType::LeftJoin,
+    Order, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Select, Set, TransactionTrait,
 };
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
+use common::db::in_utc;
 use entities::{
     action, ambition, diaries_tags,
     diary::{ActiveModel, Column, Entity, Model},
@@ -18,19 +22,29 @@ use entities::{
     tag, user,
 };
 
-use crate::{tag_adapter::TagWithName, CustomDbErr};
+use crate::{
+    db_handle::DbHandle, duplicate_on_unique_violation, not_found_on_foreign_key_violation,
+    tag_adapter::TagWithName, tombstone_adapter::record_deletions, trash_adapter::tag_is_untrashed,
+};
 
 #[derive(Clone)]
 pub struct DiaryAdapter<'a> {
-    pub db: &'a DbConn,
+    pub db: DbHandle<'a>,
     pub query: Select<Entity>,
 }
 
 impl<'a> DiaryAdapter<'a> {
     pub fn init(db: &'a DbConn) -> Self {
         Self {
-            db,
-            query: Entity::find(),
+            db: DbHandle::Connection(db),
+            query: Entity::find().filter(Column::DeletedAt.is_null()),
+        }
+    }
+
+    pub fn init_in_transaction(txn: &'a DatabaseTransaction) -> Self {
+        Self {
+            db: DbHandle::Transaction(txn),
+            query: Entity::find().filter(Column::DeletedAt.is_null()),
         }
     }
 }
@@ -45,7 +59,12 @@ impl DiaryJoin for DiaryAdapter<'_> {
         self.query = self
             .query
             .join_rev(LeftJoin, diaries_tags::Relation::Diary.def())
-            .join(LeftJoin, diaries_tags::Relation::Tag.def());
+            .join(
+                LeftJoin,
+                diaries_tags::Relation::Tag
+                    .def()
+                    .on_condition(|_, _| tag_is_untrashed()),
+            );
         self
     }
     fn join_my_way_via_tags(mut self) -> Self {
@@ -58,9 +77,20 @@ impl DiaryJoin for DiaryAdapter<'_> {
     }
 }
 
+/// Position of a diary in the order of date desc, id desc.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DiaryCursor {
+    pub date: NaiveDate,
+    pub id: Uuid,
+}
+
 pub trait DiaryFilter {
     fn filter_eq_id(self, id: Uuid) -> Self;
+    fn filter_id_in(self, ids: Vec<Uuid>) -> Self;
     fn filter_eq_user(self, user: &user::Model) -> Self;
+    fn filter_updated_at_gte(self, updated_at: DateTime<FixedOffset>) -> Self;
+    fn filter_tagged_with_any(self, tag_ids: Vec<Uuid>) -> Self;
+    fn filter_after_cursor(self, cursor: &DiaryCursor) -> Self;
 }
 
 impl DiaryFilter for DiaryAdapter<'_> {
@@ -69,10 +99,48 @@ impl DiaryFilter for DiaryAdapter<'_> {
         self
     }
 
+    fn filter_id_in(mut self, ids: Vec<Uuid>) -> Self {
+        self.query = self.query.filter(Column::Id.is_in(ids));
+        self
+    }
+
     fn filter_eq_user(mut self, user: &user::Model) -> Self {
         self.query = self.query.filter(Column::UserId.eq(user.id));
         self
     }
+
+    fn filter_updated_at_gte(mut self, updated_at: DateTime<FixedOffset>) -> Self {
+        self.query = self.query.filter(Column::UpdatedAt.gte(in_utc(updated_at)));
+        self
+    }
+
+    fn filter_tagged_with_any(mut self, tag_ids: Vec<Uuid>) -> Self {
+        // NOTE: Filtering with a subquery so that joined tags are not narrowed down to tag_ids.
+        self.query = self.query.filter(
+            Column::Id.in_subquery(
+                Query::select()
+                    .column(diaries_tags::Column::DiaryId)
+                    .from(diaries_tags::Entity)
+                    .and_where(diaries_tags::Column::TagId.is_in(tag_ids))
+                    .to_owned(),
+            ),
+        );
+        self
+    }
+
+    fn filter_after_cursor(mut self, cursor: &DiaryCursor) -> Self {
+        self.query = self.query.filter(
+            Expr::tuple([
+                Expr::col((Entity, Column::Date)).into(),
+                Expr::col((Entity, Column::Id)).into(),
+            ])
+            .lt(Expr::tuple([
+                Expr::value(cursor.date),
+                Expr::value(cursor.id),
+            ])),
+        );
+        self
+    }
 }
 
 pub trait DiaryOrder {
@@ -125,6 +193,17 @@ impl DiaryOrder for DiaryAdapter<'_> {
     }
 }
 
+pub trait DiaryLimit {
+    fn limit(self, num: u64) -> Self;
+}
+
+impl DiaryLimit for DiaryAdapter<'_> {
+    fn limit(mut self, num: u64) -> Self {
+        self.query = self.query.limit(Some(num));
+        self
+    }
+}
+
 #[derive(FromQueryResult, Debug, Serialize, Deserialize, PartialEq)]
 pub struct DiaryWithTag {
     pub id: Uuid,
@@ -150,6 +229,10 @@ impl Into<TagWithName> for &DiaryWithTag {
 
 pub trait DiaryQuery {
     fn get_all_with_tags(self) -> impl Future<Output = Result<Vec<DiaryWithTag>, DbErr>>;
+    fn get_all_with_linked_tags(
+        self,
+    ) -> impl Future<Output = Result<Vec<(Model, Vec<tag::Model>)>, DbErr>>;
+    fn get_all_ids(self) -> impl Future<Output = Result<Vec<Uuid>, DbErr>>;
     fn get_by_id(self, id: Uuid) -> impl Future<Output = Result<Option<Model>, DbErr>>;
     fn get_with_tags(self)
         -> impl Future<Output = Result<Option<(Model, Vec<tag::Model>)>, DbErr>>;
@@ -190,16 +273,29 @@ impl DiaryQuery for DiaryAdapter<'_> {
             .column_as(tag::Column::Type, "tag_type")
             .column_as(tag::Column::CreatedAt, "tag_created_at")
             .into_model::<DiaryWithTag>()
-            .all(self.db)
+            .all(&self.db)
+            .await
+    }
+
+    async fn get_all_with_linked_tags(self) -> Result<Vec<(Model, Vec<tag::Model>)>, DbErr> {
+        self.query.select_with(tag::Entity).all(&self.db).await
+    }
+
+    async fn get_all_ids(self) -> Result<Vec<Uuid>, DbErr> {
+        self.query
+            .select_only()
+            .column(Column::Id)
+            .into_tuple()
+            .all(&self.db)
             .await
     }
 
     async fn get_by_id(self, id: Uuid) -> Result<Option<Model>, DbErr> {
-        self.query.filter(Column::Id.eq(id)).one(self.db).await
+        self.query.filter(Column::Id.eq(id)).one(&self.db).await
     }
 
     async fn get_with_tags(self) -> Result<Option<(Model, Vec<tag::Model>)>, DbErr> {
-        match self.query.select_with(tag::Entity).all(self.db).await {
+        match self.query.select_with(tag::Entity).all(&self.db).await {
             Ok(diaries) => match diaries.len() > 0 {
                 true => Ok(diaries.into_iter().nth(0)),
                 false => Ok(None),
@@ -216,7 +312,7 @@ pub struct CreateDiaryParams {
     pub user_id: Uuid,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, ToSchema)]
 pub enum DiaryUpdateKey {
     Text,
     Date,
@@ -237,7 +333,7 @@ pub trait DiaryMutation {
         diary: Model,
         params: UpdateDiaryParams,
     ) -> impl Future<Output = Result<Model, DbErr>>;
-    fn delete(self, diary: Model) -> impl Future<Output = Result<(), DbErr>>;
+    fn trash(self, diary: Model) -> impl Future<Output = Result<(), DbErr>>;
     fn link_tags(
         &self,
         diary: &Model,
@@ -257,18 +353,12 @@ impl DiaryMutation for DiaryAdapter<'_> {
             user_id: Set(params.user_id),
             text: Set(params.text),
             date: Set(params.date),
+            updated_at: Set(Utc::now().into()),
+            deleted_at: Set(None),
         }
-        .insert(self.db)
+        .insert(&self.db)
         .await
-        .map_err(|e| match &e {
-            DbErr::Query(SqlxError(Database(err))) => match err.constraint() {
-                Some("diaries_user_id_date_unique_index") => {
-                    DbErr::Custom(CustomDbErr::Duplicate.to_string())
-                }
-                _ => e,
-            },
-            _ => e,
-        })
+        .map_err(duplicate_on_unique_violation)
     }
 
     async fn partial_update(self, diary: Model, params: UpdateDiaryParams) -> Result<Model, DbErr> {
@@ -279,19 +369,20 @@ impl DiaryMutation for DiaryAdapter<'_> {
         if params.update_keys.contains(&DiaryUpdateKey::Date) {
             diary.date = Set(params.date);
         }
-        diary.update(self.db).await.map_err(|e| match &e {
-            DbErr::Query(SqlxError(Database(err))) => match err.constraint() {
-                Some("diaries_user_id_date_unique_index") => {
-                    DbErr::Custom(CustomDbErr::Duplicate.to_string())
-                }
-                _ => e,
-            },
-            _ => e,
-        })
+        diary.updated_at = Set(Utc::now().into());
+        diary
+            .update(&self.db)
+            .await
+            .map_err(duplicate_on_unique_violation)
     }
 
-    async fn delete(self, diary: Model) -> Result<(), DbErr> {
-        diary.delete(self.db).await.map(|_| ())
+    async fn trash(self, diary: Model) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+        record_deletions::<_, Entity>(&txn, diary.user_id, [diary.id]).await?;
+        let mut diary = diary.into_active_model();
+        diary.deleted_at = Set(Some(Utc::now().into()));
+        diary.update(&txn).await?;
+        txn.commit().await
     }
 
     async fn link_tags(
@@ -305,18 +396,10 @@ impl DiaryMutation for DiaryAdapter<'_> {
         });
         diaries_tags::Entity::insert_many(tag_links)
             .on_empty_do_nothing()
-            .exec(self.db)
+            .exec(&self.db)
             .await
             .map(|_| ())
-            .map_err(|e| match &e {
-                DbErr::Exec(SqlxError(Database(err))) => match err.constraint() {
-                    Some("fk-diaries_tags-tag_id") => {
-                        DbErr::Custom(CustomDbErr::NotFound.to_string())
-                    }
-                    _ => e,
-                },
-                _ => e,
-            })
+            .map_err(not_found_on_foreign_key_violation)
     }
 
     async fn unlink_tags(
@@ -327,7 +410,7 @@ impl DiaryMutation for DiaryAdapter<'_> {
         diaries_tags::Entity::delete_many()
             .filter(diaries_tags::Column::DiaryId.eq(diary.id))
             .filter(diaries_tags::Column::TagId.is_in(tag_ids))
-            .exec(self.db)
+            .exec(&self.db)
             .await
             .map(|_| ())
     }
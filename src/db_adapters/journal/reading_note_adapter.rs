@@ -2,14 +2,17 @@ use std::future::Future;
 
 use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
 use sea_orm::{
-    prelude::Expr, sea_query::NullOrdering::Last, sqlx::error::Error::Database, ActiveModelTrait,
-    ColumnAsExpr, ColumnTrait, DbConn, DbErr, DeriveColumn, EntityTrait, EnumIter, FromQueryResult,
-    IntoActiveModel, JoinType::LeftJoin, ModelTrait, Order, QueryFilter, QueryOrder, QuerySelect,
-    RelationTrait, RuntimeErr::SqlxError, Select, Set,
+    prelude::Expr,
+    sea_query::{NullOrdering::Last, Query},
+    ActiveModelTrait, ColumnAsExpr, ColumnTrait, DatabaseTransaction, DbConn, DbErr, DeriveColumn,
+    EntityTrait, EnumIter, FromQueryResult, IntoActiveModel,
+    JoinType::LeftJoin,
+    Order, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Select, Set, TransactionTrait,
 };
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use common::db::in_utc;
 use entities::{
     action, ambition, direction,
     reading_note::{ActiveModel, Column, Entity, Model},
@@ -18,19 +21,29 @@ use entities::{
     tag, user,
 };
 
-use crate::{tag_adapter::TagWithName, CustomDbErr};
+use crate::{
+    db_handle::DbHandle, not_found_on_foreign_key_violation, tag_adapter::TagWithName,
+    tombstone_adapter::record_deletions, trash_adapter::tag_is_untrashed,
+};
 
 #[derive(Clone)]
 pub struct ReadingNoteAdapter<'a> {
-    pub db: &'a DbConn,
+    pub db: DbHandle<'a>,
     pub query: Select<Entity>,
 }
 
 impl<'a> ReadingNoteAdapter<'a> {
     pub fn init(db: &'a DbConn) -> Self {
         Self {
-            db,
-            query: Entity::find(),
+            db: DbHandle::Connection(db),
+            query: Entity::find().filter(Column::DeletedAt.is_null()),
+        }
+    }
+
+    pub fn init_in_transaction(txn: &'a DatabaseTransaction) -> Self {
+        Self {
+            db: DbHandle::Transaction(txn),
+            query: Entity::find().filter(Column::DeletedAt.is_null()),
         }
     }
 }
@@ -45,7 +58,12 @@ impl ReadingNoteJoin for ReadingNoteAdapter<'_> {
         self.query = self
             .query
             .join_rev(LeftJoin, reading_notes_tags::Relation::ReadingNote.def())
-            .join(LeftJoin, reading_notes_tags::Relation::Tag.def());
+            .join(
+                LeftJoin,
+                reading_notes_tags::Relation::Tag
+                    .def()
+                    .on_condition(|_, _| tag_is_untrashed()),
+            );
         self
     }
 
@@ -59,9 +77,21 @@ impl ReadingNoteJoin for ReadingNoteAdapter<'_> {
     }
 }
 
+/// Position of a reading note in the order of date desc, created_at desc, id desc.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReadingNoteCursor {
+    pub date: NaiveDate,
+    pub created_at: DateTime<FixedOffset>,
+    pub id: Uuid,
+}
+
 pub trait ReadingNoteFilter {
     fn filter_eq_id(self, id: Uuid) -> Self;
+    fn filter_id_in(self, ids: Vec<Uuid>) -> Self;
     fn filter_eq_user(self, user: &user::Model) -> Self;
+    fn filter_updated_at_gte(self, updated_at: DateTime<FixedOffset>) -> Self;
+    fn filter_tagged_with_any(self, tag_ids: Vec<Uuid>) -> Self;
+    fn filter_after_cursor(self, cursor: &ReadingNoteCursor) -> Self;
 }
 
 impl ReadingNoteFilter for ReadingNoteAdapter<'_> {
@@ -70,16 +100,57 @@ impl ReadingNoteFilter for ReadingNoteAdapter<'_> {
         self
     }
 
+    fn filter_id_in(mut self, ids: Vec<Uuid>) -> Self {
+        self.query = self.query.filter(Column::Id.is_in(ids));
+        self
+    }
+
     fn filter_eq_user(mut self, user: &user::Model) -> Self {
         self.query = self.query.filter(Column::UserId.eq(user.id));
         self
     }
+
+    fn filter_updated_at_gte(mut self, updated_at: DateTime<FixedOffset>) -> Self {
+        self.query = self.query.filter(Column::UpdatedAt.gte(in_utc(updated_at)));
+        self
+    }
+
+    fn filter_tagged_with_any(mut self, tag_ids: Vec<Uuid>) -> Self {
+        // NOTE: Filtering with a subquery so that joined tags are not narrowed down to tag_ids.
+        self.query = self.query.filter(
+            Column::Id.in_subquery(
+                Query::select()
+                    .column(reading_notes_tags::Column::ReadingNoteId)
+                    .from(reading_notes_tags::Entity)
+                    .and_where(reading_notes_tags::Column::TagId.is_in(tag_ids))
+                    .to_owned(),
+            ),
+        );
+        self
+    }
+
+    fn filter_after_cursor(mut self, cursor: &ReadingNoteCursor) -> Self {
+        self.query = self.query.filter(
+            Expr::tuple([
+                Expr::col((Entity, Column::Date)).into(),
+                Expr::col((Entity, Column::CreatedAt)).into(),
+                Expr::col((Entity, Column::Id)).into(),
+            ])
+            .lt(Expr::tuple([
+                Expr::value(cursor.date),
+                Expr::value(in_utc(cursor.created_at)),
+                Expr::value(cursor.id),
+            ])),
+        );
+        self
+    }
 }
 
 pub trait ReadingNoteOrder {
     fn order_by_title(self, order: Order) -> Self;
     fn order_by_date(self, order: Order) -> Self;
     fn order_by_created_at(self, order: Order) -> Self;
+    fn order_by_id(self, order: Order) -> Self;
     fn order_by_ambition_created_at_nulls_last(self, order: Order) -> Self;
     fn order_by_direction_created_at_nulls_last(self, order: Order) -> Self;
     fn order_by_action_created_at_nulls_last(self, order: Order) -> Self;
@@ -102,6 +173,11 @@ impl ReadingNoteOrder for ReadingNoteAdapter<'_> {
         self
     }
 
+    fn order_by_id(mut self, order: Order) -> Self {
+        self.query = self.query.order_by(Column::Id, order);
+        self
+    }
+
     fn order_by_ambition_created_at_nulls_last(mut self, order: Order) -> Self {
         self.query = self
             .query
@@ -131,6 +207,17 @@ impl ReadingNoteOrder for ReadingNoteAdapter<'_> {
     }
 }
 
+pub trait ReadingNoteLimit {
+    fn limit(self, num: u64) -> Self;
+}
+
+impl ReadingNoteLimit for ReadingNoteAdapter<'_> {
+    fn limit(mut self, num: u64) -> Self {
+        self.query = self.query.limit(Some(num));
+        self
+    }
+}
+
 #[derive(FromQueryResult, Debug, Serialize, Deserialize, PartialEq)]
 pub struct ReadingNoteWithTag {
     pub id: Uuid,
@@ -165,6 +252,10 @@ enum TitleQuery {
 
 pub trait ReadingNoteQuery {
     fn get_all_with_tags(self) -> impl Future<Output = Result<Vec<ReadingNoteWithTag>, DbErr>>;
+    fn get_all_with_linked_tags(
+        self,
+    ) -> impl Future<Output = Result<Vec<(Model, Vec<tag::Model>)>, DbErr>>;
+    fn get_all_ids(self) -> impl Future<Output = Result<Vec<Uuid>, DbErr>>;
     fn get_by_id(self, id: Uuid) -> impl Future<Output = Result<Option<Model>, DbErr>>;
     fn get_with_tags(self)
         -> impl Future<Output = Result<Option<(Model, Vec<tag::Model>)>, DbErr>>;
@@ -206,16 +297,29 @@ impl ReadingNoteQuery for ReadingNoteAdapter<'_> {
             .column_as(tag::Column::Type, "tag_type")
             .column_as(tag::Column::CreatedAt, "tag_created_at")
             .into_model::<ReadingNoteWithTag>()
-            .all(self.db)
+            .all(&self.db)
+            .await
+    }
+
+    async fn get_all_with_linked_tags(self) -> Result<Vec<(Model, Vec<tag::Model>)>, DbErr> {
+        self.query.select_with(tag::Entity).all(&self.db).await
+    }
+
+    async fn get_all_ids(self) -> Result<Vec<Uuid>, DbErr> {
+        self.query
+            .select_only()
+            .column(Column::Id)
+            .into_tuple()
+            .all(&self.db)
             .await
     }
 
     async fn get_by_id(self, id: Uuid) -> Result<Option<Model>, DbErr> {
-        self.query.filter(Column::Id.eq(id)).one(self.db).await
+        self.query.filter(Column::Id.eq(id)).one(&self.db).await
     }
 
     async fn get_with_tags(self) -> Result<Option<(Model, Vec<tag::Model>)>, DbErr> {
-        match self.query.select_with(tag::Entity).all(self.db).await {
+        match self.query.select_with(tag::Entity).all(&self.db).await {
             Ok(reading_notes) => match reading_notes.len() > 0 {
                 true => Ok(reading_notes.into_iter().nth(0)),
                 false => Ok(None),
@@ -230,7 +334,7 @@ impl ReadingNoteQuery for ReadingNoteAdapter<'_> {
             .column(Column::Title)
             .distinct()
             .into_values::<String, TitleQuery>()
-            .all(self.db)
+            .all(&self.db)
             .await
     }
 }
@@ -259,7 +363,7 @@ pub trait ReadingNoteMutation {
         reading_note: Model,
         params: UpdateReadingNoteParams,
     ) -> impl Future<Output = Result<Model, DbErr>>;
-    fn delete(self, reading_note: Model) -> impl Future<Output = Result<(), DbErr>>;
+    fn trash(self, reading_note: Model) -> impl Future<Output = Result<(), DbErr>>;
     fn link_tags(
         &self,
         reading_note: &Model,
@@ -284,8 +388,9 @@ impl ReadingNoteMutation for ReadingNoteAdapter<'_> {
             date: Set(params.date),
             created_at: Set(now.into()),
             updated_at: Set(now.into()),
+            deleted_at: Set(None),
         }
-        .insert(self.db)
+        .insert(&self.db)
         .await
     }
 
@@ -308,11 +413,16 @@ impl ReadingNoteMutation for ReadingNoteAdapter<'_> {
             reading_note.date = Set(date);
         }
         reading_note.updated_at = Set(Utc::now().into());
-        reading_note.update(self.db).await
+        reading_note.update(&self.db).await
     }
 
-    async fn delete(self, reading_note: Model) -> Result<(), DbErr> {
-        reading_note.delete(self.db).await.map(|_| ())
+    async fn trash(self, reading_note: Model) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+        record_deletions::<_, Entity>(&txn, reading_note.user_id, [reading_note.id]).await?;
+        let mut reading_note = reading_note.into_active_model();
+        reading_note.deleted_at = Set(Some(Utc::now().into()));
+        reading_note.update(&txn).await?;
+        txn.commit().await
     }
 
     async fn link_tags(
@@ -328,18 +438,10 @@ impl ReadingNoteMutation for ReadingNoteAdapter<'_> {
             });
         reading_notes_tags::Entity::insert_many(tag_links)
             .on_empty_do_nothing()
-            .exec(self.db)
+            .exec(&self.db)
             .await
             .map(|_| ())
-            .map_err(|e| match &e {
-                DbErr::Exec(SqlxError(Database(err))) => match err.constraint() {
-                    Some("fk-book_excerpts_tags-tag_id") => {
-                        DbErr::Custom(CustomDbErr::NotFound.to_string())
-                    }
-                    _ => e,
-                },
-                _ => e,
-            })
+            .map_err(not_found_on_foreign_key_violation)
     }
 
     async fn unlink_tags(
@@ -350,7 +452,7 @@ impl ReadingNoteMutation for ReadingNoteAdapter<'_> {
         reading_notes_tags::Entity::delete_many()
             .filter(reading_notes_tags::Column::ReadingNoteId.eq(reading_note.id))
             .filter(reading_notes_tags::Column::TagId.is_in(tag_ids))
-            .exec(self.db)
+            .exec(&self.db)
             .await
             .map(|_| ())
     }
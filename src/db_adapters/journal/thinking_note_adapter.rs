@@ -3,18 +3,19 @@ use std::future::Future;
 use chrono::{DateTime, FixedOffset, Utc};
 use sea_orm::{
     prelude::Expr,
-    sea_query::NullOrdering::{First, Last},
-    sqlx::error::Error::Database,
-    ActiveModelTrait, ColumnAsExpr, ColumnTrait, DbConn, DbErr, EntityTrait, FromQueryResult,
-    IntoActiveModel,
+    sea_query::{
+        NullOrdering::{First, Last},
+        Query,
+    },
+    ActiveModelTrait, ColumnAsExpr, ColumnTrait, Condition, DatabaseTransaction, DbConn, DbErr,
+    EntityTrait, FromQueryResult, IntoActiveModel,
     JoinType::LeftJoin,
-    ModelTrait, Order, QueryFilter, QueryOrder, QuerySelect, RelationTrait,
-    RuntimeErr::SqlxError,
-    Select, Set,
+    Order, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Select, Set, TransactionTrait,
 };
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use common::db::in_utc;
 use entities::{
     action, ambition, direction,
     sea_orm_active_enums::TagType,
@@ -23,19 +24,29 @@ use entities::{
     thinking_note_tags, user,
 };
 
-use crate::{tag_adapter::TagWithName, CustomDbErr};
+use crate::{
+    db_handle::DbHandle, not_found_on_foreign_key_violation, tag_adapter::TagWithName,
+    tombstone_adapter::record_deletions, trash_adapter::tag_is_untrashed,
+};
 
 #[derive(Clone)]
 pub struct ThinkingNoteAdapter<'a> {
-    pub db: &'a DbConn,
+    pub db: DbHandle<'a>,
     pub query: Select<Entity>,
 }
 
 impl<'a> ThinkingNoteAdapter<'a> {
     pub fn init(db: &'a DbConn) -> Self {
         Self {
-            db,
-            query: Entity::find(),
+            db: DbHandle::Connection(db),
+            query: Entity::find().filter(Column::DeletedAt.is_null()),
+        }
+    }
+
+    pub fn init_in_transaction(txn: &'a DatabaseTransaction) -> Self {
+        Self {
+            db: DbHandle::Transaction(txn),
+            query: Entity::find().filter(Column::DeletedAt.is_null()),
         }
     }
 }
@@ -50,7 +61,12 @@ impl ThinkingNoteJoin for ThinkingNoteAdapter<'_> {
         self.query = self
             .query
             .join_rev(LeftJoin, thinking_note_tags::Relation::ThinkingNote.def())
-            .join(LeftJoin, thinking_note_tags::Relation::Tag.def());
+            .join(
+                LeftJoin,
+                thinking_note_tags::Relation::Tag
+                    .def()
+                    .on_condition(|_, _| tag_is_untrashed()),
+            );
         self
     }
     fn join_my_way_via_tags(mut self) -> Self {
@@ -63,10 +79,23 @@ impl ThinkingNoteJoin for ThinkingNoteAdapter<'_> {
     }
 }
 
+/// Position of a thinking note in the order of resolved_at desc nulls first, updated_at desc,
+/// id desc.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ThinkingNoteCursor {
+    pub resolved_at: Option<DateTime<FixedOffset>>,
+    pub updated_at: DateTime<FixedOffset>,
+    pub id: Uuid,
+}
+
 pub trait ThinkingNoteFilter {
     fn filter_eq_id(self, id: Uuid) -> Self;
+    fn filter_id_in(self, ids: Vec<Uuid>) -> Self;
     fn filter_eq_user(self, user: &user::Model) -> Self;
     fn filter_null_resolved_at(self, is_null: bool) -> Self;
+    fn filter_updated_at_gte(self, updated_at: DateTime<FixedOffset>) -> Self;
+    fn filter_tagged_with_any(self, tag_ids: Vec<Uuid>) -> Self;
+    fn filter_after_cursor(self, cursor: &ThinkingNoteCursor) -> Self;
 }
 
 impl ThinkingNoteFilter for ThinkingNoteAdapter<'_> {
@@ -75,6 +104,11 @@ impl ThinkingNoteFilter for ThinkingNoteAdapter<'_> {
         self
     }
 
+    fn filter_id_in(mut self, ids: Vec<Uuid>) -> Self {
+        self.query = self.query.filter(Column::Id.is_in(ids));
+        self
+    }
+
     fn filter_eq_user(mut self, user: &user::Model) -> Self {
         self.query = self.query.filter(Column::UserId.eq(user.id));
         self
@@ -87,11 +121,61 @@ impl ThinkingNoteFilter for ThinkingNoteAdapter<'_> {
         };
         self
     }
+
+    fn filter_updated_at_gte(mut self, updated_at: DateTime<FixedOffset>) -> Self {
+        self.query = self.query.filter(Column::UpdatedAt.gte(in_utc(updated_at)));
+        self
+    }
+
+    fn filter_tagged_with_any(mut self, tag_ids: Vec<Uuid>) -> Self {
+        // NOTE: Filtering with a subquery so that joined tags are not narrowed down to tag_ids.
+        self.query = self.query.filter(
+            Column::Id.in_subquery(
+                Query::select()
+                    .column(thinking_note_tags::Column::ThinkingNoteId)
+                    .from(thinking_note_tags::Entity)
+                    .and_where(thinking_note_tags::Column::TagId.is_in(tag_ids))
+                    .to_owned(),
+            ),
+        );
+        self
+    }
+
+    fn filter_after_cursor(mut self, cursor: &ThinkingNoteCursor) -> Self {
+        let updated_at_and_id_after_cursor = Expr::tuple([
+            Expr::col((Entity, Column::UpdatedAt)).into(),
+            Expr::col((Entity, Column::Id)).into(),
+        ])
+        .lt(Expr::tuple([
+            Expr::value(in_utc(cursor.updated_at)),
+            Expr::value(cursor.id),
+        ]));
+        // NOTE: Unresolved ones come first, so every resolved one is after an unresolved cursor.
+        let cond = match cursor.resolved_at.map(in_utc) {
+            None => Condition::any()
+                .add(
+                    Condition::all()
+                        .add(Column::ResolvedAt.is_null())
+                        .add(updated_at_and_id_after_cursor),
+                )
+                .add(Column::ResolvedAt.is_not_null()),
+            Some(resolved_at) => Condition::any()
+                .add(Column::ResolvedAt.lt(resolved_at))
+                .add(
+                    Condition::all()
+                        .add(Column::ResolvedAt.eq(resolved_at))
+                        .add(updated_at_and_id_after_cursor),
+                ),
+        };
+        self.query = self.query.filter(cond);
+        self
+    }
 }
 
 pub trait ThinkingNoteOrder {
     fn order_by_resolved_at_nulls_first(self, order: Order) -> Self;
     fn order_by_updated_at(self, order: Order) -> Self;
+    fn order_by_id(self, order: Order) -> Self;
     fn order_by_ambition_created_at_nulls_last(self, order: Order) -> Self;
     fn order_by_direction_created_at_nulls_last(self, order: Order) -> Self;
     fn order_by_action_created_at_nulls_last(self, order: Order) -> Self;
@@ -111,6 +195,11 @@ impl ThinkingNoteOrder for ThinkingNoteAdapter<'_> {
         self
     }
 
+    fn order_by_id(mut self, order: Order) -> Self {
+        self.query = self.query.order_by(Column::Id, order);
+        self
+    }
+
     fn order_by_ambition_created_at_nulls_last(mut self, order: Order) -> Self {
         self.query = self
             .query
@@ -140,6 +229,17 @@ impl ThinkingNoteOrder for ThinkingNoteAdapter<'_> {
     }
 }
 
+pub trait ThinkingNoteLimit {
+    fn limit(self, num: u64) -> Self;
+}
+
+impl ThinkingNoteLimit for ThinkingNoteAdapter<'_> {
+    fn limit(mut self, num: u64) -> Self {
+        self.query = self.query.limit(Some(num));
+        self
+    }
+}
+
 #[derive(FromQueryResult, Debug, Serialize, Deserialize, PartialEq)]
 pub struct ThinkingNoteWithTag {
     pub id: Uuid,
@@ -169,6 +269,10 @@ impl Into<TagWithName> for &ThinkingNoteWithTag {
 
 pub trait ThinkingNoteQuery {
     fn get_all_with_tags(self) -> impl Future<Output = Result<Vec<ThinkingNoteWithTag>, DbErr>>;
+    fn get_all_with_linked_tags(
+        self,
+    ) -> impl Future<Output = Result<Vec<(Model, Vec<tag::Model>)>, DbErr>>;
+    fn get_all_ids(self) -> impl Future<Output = Result<Vec<Uuid>, DbErr>>;
     fn get_by_id(self, id: Uuid) -> impl Future<Output = Result<Option<Model>, DbErr>>;
     fn get_with_tags(self)
         -> impl Future<Output = Result<Option<(Model, Vec<tag::Model>)>, DbErr>>;
@@ -209,16 +313,29 @@ impl ThinkingNoteQuery for ThinkingNoteAdapter<'_> {
             .column_as(tag::Column::Type, "tag_type")
             .column_as(tag::Column::CreatedAt, "tag_created_at")
             .into_model::<ThinkingNoteWithTag>()
-            .all(self.db)
+            .all(&self.db)
+            .await
+    }
+
+    async fn get_all_with_linked_tags(self) -> Result<Vec<(Model, Vec<tag::Model>)>, DbErr> {
+        self.query.select_with(tag::Entity).all(&self.db).await
+    }
+
+    async fn get_all_ids(self) -> Result<Vec<Uuid>, DbErr> {
+        self.query
+            .select_only()
+            .column(Column::Id)
+            .into_tuple()
+            .all(&self.db)
             .await
     }
 
     async fn get_by_id(self, id: Uuid) -> Result<Option<Model>, DbErr> {
-        self.query.filter(Column::Id.eq(id)).one(self.db).await
+        self.query.filter(Column::Id.eq(id)).one(&self.db).await
     }
 
     async fn get_with_tags(self) -> Result<Option<(Model, Vec<tag::Model>)>, DbErr> {
-        match self.query.select_with(tag::Entity).all(self.db).await {
+        match self.query.select_with(tag::Entity).all(&self.db).await {
             Ok(thinking_notes) => match thinking_notes.len() > 0 {
                 true => Ok(thinking_notes.into_iter().nth(0)),
                 false => Ok(None),
@@ -252,7 +369,7 @@ pub trait ThinkingNoteMutation {
         params: UpdateThinkingNoteParams,
         thinking_note: Model,
     ) -> impl Future<Output = Result<Model, DbErr>>;
-    fn delete(self, thinking_note: Model) -> impl Future<Output = Result<(), DbErr>>;
+    fn trash(self, thinking_note: Model) -> impl Future<Output = Result<(), DbErr>>;
     fn link_tags(
         &self,
         thinking_note: &Model,
@@ -277,8 +394,9 @@ impl ThinkingNoteMutation for ThinkingNoteAdapter<'_> {
             resolved_at: Set(None),
             created_at: Set(now.into()),
             updated_at: Set(now.into()),
+            deleted_at: Set(None),
         }
-        .insert(self.db)
+        .insert(&self.db)
         .await
     }
 
@@ -291,13 +409,18 @@ impl ThinkingNoteMutation for ThinkingNoteAdapter<'_> {
         thinking_note.question = Set(params.question);
         thinking_note.thought = Set(params.thought);
         thinking_note.answer = Set(params.answer);
-        thinking_note.resolved_at = Set(params.resolved_at);
+        thinking_note.resolved_at = Set(params.resolved_at.map(in_utc));
         thinking_note.updated_at = Set(Utc::now().into());
-        thinking_note.update(self.db).await
+        thinking_note.update(&self.db).await
     }
 
-    async fn delete(self, thinking_note: Model) -> Result<(), DbErr> {
-        thinking_note.delete(self.db).await.map(|_| ())
+    async fn trash(self, thinking_note: Model) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+        record_deletions::<_, Entity>(&txn, thinking_note.user_id, [thinking_note.id]).await?;
+        let mut thinking_note = thinking_note.into_active_model();
+        thinking_note.deleted_at = Set(Some(Utc::now().into()));
+        thinking_note.update(&txn).await?;
+        txn.commit().await
     }
 
     async fn link_tags(
@@ -313,18 +436,10 @@ impl ThinkingNoteMutation for ThinkingNoteAdapter<'_> {
             });
         thinking_note_tags::Entity::insert_many(tag_links)
             .on_empty_do_nothing()
-            .exec(self.db)
+            .exec(&self.db)
             .await
             .map(|_| ())
-            .map_err(|e| match &e {
-                DbErr::Exec(SqlxError(Database(err))) => match err.constraint() {
-                    Some("fk-thinking_note_tags-tag_id") => {
-                        DbErr::Custom(CustomDbErr::NotFound.to_string())
-                    }
-                    _ => e,
-                },
-                _ => e,
-            })
+            .map_err(not_found_on_foreign_key_violation)
     }
 
     async fn unlink_tags(
@@ -335,7 +450,7 @@ impl ThinkingNoteMutation for ThinkingNoteAdapter<'_> {
         thinking_note_tags::Entity::delete_many()
             .filter(thinking_note_tags::Column::ThinkingNoteId.eq(thinking_note.id))
             .filter(thinking_note_tags::Column::TagId.is_in(tag_ids))
-            .exec(self.db)
+            .exec(&self.db)
             .await
             .map(|_| ())
     }
@@ -1,7 +1,14 @@
+pub mod db_handle;
+#[cfg(feature = "in_memory")]
+pub mod in_memory;
 mod journal;
 mod my_way;
 mod notification;
+pub mod revision_adapter;
+pub mod search_adapter;
 pub mod tag_adapter;
+pub mod tombstone_adapter;
+pub mod trash_adapter;
 pub mod user_adapter;
 
 pub use journal::*;
@@ -10,6 +17,7 @@ pub use notification::*;
 
 use core::fmt;
 pub use sea_orm::Order;
+use sea_orm::{DbErr, SqlErr};
 
 pub enum CustomDbErr {
     Duplicate,
@@ -44,3 +52,24 @@ impl From<&String> for CustomDbErr {
         value.parse().unwrap()
     }
 }
+
+/// Unique constraint violations as `CustomDbErr::Duplicate`. Matched by kind rather than by
+/// constraint name, since SQLite does not report the name.
+pub(crate) fn duplicate_on_unique_violation(e: DbErr) -> DbErr {
+    match e.sql_err() {
+        Some(SqlErr::UniqueConstraintViolation(_)) => {
+            DbErr::Custom(CustomDbErr::Duplicate.to_string())
+        }
+        _ => e,
+    }
+}
+
+/// Foreign key violations as `CustomDbErr::NotFound`, e.g. on linking a tag which does not exist.
+pub(crate) fn not_found_on_foreign_key_violation(e: DbErr) -> DbErr {
+    match e.sql_err() {
+        Some(SqlErr::ForeignKeyConstraintViolation(_)) => {
+            DbErr::Custom(CustomDbErr::NotFound.to_string())
+        }
+        _ => e,
+    }
+}
@@ -1,33 +1,47 @@
 use std::future::Future;
 
-use chrono::Utc;
+use chrono::{DateTime, FixedOffset, Utc};
 use sea_orm::{
     sea_query::{IntoCondition, NullOrdering::Last},
-    ActiveModelTrait, ColumnTrait, DbConn, DbErr, EntityTrait, IntoActiveModel,
+    ActiveModelTrait, ColumnTrait, DatabaseTransaction, DbConn, DbErr, EntityTrait,
+    IntoActiveModel,
     JoinType::LeftJoin,
-    ModelTrait, Order, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Select, Set,
-    TransactionError, TransactionTrait,
+    Order, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Select, Set, TransactionError,
+    TransactionTrait,
 };
 use uuid::Uuid;
 
+use common::db::in_utc;
 use entities::{
     action::{ActiveModel, Column, Entity, Model, Relation},
-    action_goal,
+    action_goal, action_track,
     sea_orm_active_enums::{ActionTrackType, TagType},
     tag, user,
 };
 
+use crate::{
+    db_handle::DbHandle, revision_adapter::record_revision, tag_adapter::record_tag_deletions,
+    tombstone_adapter::record_deletions,
+};
+
 #[derive(Clone)]
 pub struct ActionAdapter<'a> {
-    pub db: &'a DbConn,
+    pub db: DbHandle<'a>,
     pub query: Select<Entity>,
 }
 
 impl<'a> ActionAdapter<'a> {
     pub fn init(db: &'a DbConn) -> Self {
         Self {
-            db,
-            query: Entity::find(),
+            db: DbHandle::Connection(db),
+            query: Entity::find().filter(Column::DeletedAt.is_null()),
+        }
+    }
+
+    pub fn init_in_transaction(txn: &'a DatabaseTransaction) -> Self {
+        Self {
+            db: DbHandle::Transaction(txn),
+            query: Entity::find().filter(Column::DeletedAt.is_null()),
         }
     }
 }
@@ -50,7 +64,9 @@ impl ActionJoin for ActionAdapter<'_> {
 
 pub trait ActionFilter {
     fn filter_eq_user(self, user: &user::Model) -> Self;
+    fn filter_eq_archived(self, archived: bool) -> Self;
     fn filter_in_ids(self, ids: Vec<Uuid>) -> Self;
+    fn filter_updated_at_gte(self, updated_at: DateTime<FixedOffset>) -> Self;
 }
 
 impl ActionFilter for ActionAdapter<'_> {
@@ -59,10 +75,20 @@ impl ActionFilter for ActionAdapter<'_> {
         self
     }
 
+    fn filter_eq_archived(mut self, archived: bool) -> Self {
+        self.query = self.query.filter(Column::Archived.eq(archived));
+        self
+    }
+
     fn filter_in_ids(mut self, ids: Vec<Uuid>) -> Self {
         self.query = self.query.filter(Column::Id.is_in(ids));
         self
     }
+
+    fn filter_updated_at_gte(mut self, updated_at: DateTime<FixedOffset>) -> Self {
+        self.query = self.query.filter(Column::UpdatedAt.gte(in_utc(updated_at)));
+        self
+    }
 }
 
 pub trait ActionOrder {
@@ -94,18 +120,18 @@ pub trait ActionQuery {
 
 impl ActionQuery for ActionAdapter<'_> {
     async fn get_all(self) -> Result<Vec<Model>, DbErr> {
-        self.query.all(self.db).await
+        self.query.all(&self.db).await
     }
 
     async fn get_all_with_goal(self) -> Result<Vec<(Model, Option<action_goal::Model>)>, DbErr> {
         self.query
             .select_also(action_goal::Entity)
-            .all(self.db)
+            .all(&self.db)
             .await
     }
 
     async fn get_by_id(self, id: Uuid) -> Result<Option<Model>, DbErr> {
-        self.query.filter(Column::Id.eq(id)).one(self.db).await
+        self.query.filter(Column::Id.eq(id)).one(&self.db).await
     }
 }
 
@@ -148,7 +174,7 @@ pub trait ActionMutation {
         actions: Vec<Model>,
         ordering: Vec<Uuid>,
     ) -> impl Future<Output = Result<(), DbErr>>;
-    fn delete(self, action: Model) -> impl Future<Output = Result<(), DbErr>>;
+    fn trash(self, action: Model) -> impl Future<Output = Result<(), DbErr>>;
 }
 
 impl ActionMutation for ActionAdapter<'_> {
@@ -160,6 +186,7 @@ impl ActionMutation for ActionAdapter<'_> {
             .transaction::<_, Model, DbErr>(|txn| {
                 Box::pin(async move {
                     let action_id = uuid::Uuid::now_v7();
+                    let now = Utc::now();
                     let created_action = ActiveModel {
                         id: Set(action_id),
                         user_id: Set(params.user_id),
@@ -167,6 +194,8 @@ impl ActionMutation for ActionAdapter<'_> {
                         discipline: Set(params.discipline.to_owned()),
                         memo: Set(params.memo.to_owned()),
                         track_type: Set(params.track_type),
+                        created_at: Set(now.into()),
+                        updated_at: Set(now.into()),
                         ..Default::default()
                     }
                     .insert(txn)
@@ -176,6 +205,8 @@ impl ActionMutation for ActionAdapter<'_> {
                         user_id: Set(params.user_id),
                         action_id: Set(Some(action_id)),
                         r#type: Set(TagType::Action),
+                        created_at: Set(now.into()),
+                        updated_at: Set(now.into()),
                         ..Default::default()
                     }
                     .insert(txn)
@@ -188,6 +219,8 @@ impl ActionMutation for ActionAdapter<'_> {
     }
 
     async fn update(self, action: Model, params: UpdateActionParams) -> Result<Model, DbErr> {
+        let txn = self.db.begin().await?;
+        record_revision::<_, Entity>(&txn, action.user_id, action.id, &action).await?;
         let mut action = action.into_active_model();
         action.name = Set(params.name);
         action.discipline = Set(params.discipline);
@@ -196,7 +229,9 @@ impl ActionMutation for ActionAdapter<'_> {
             action.color = Set(color);
         }
         action.updated_at = Set(Utc::now().into());
-        action.update(self.db).await
+        let action = action.update(&txn).await?;
+        txn.commit().await?;
+        Ok(action)
     }
 
     async fn convert_track_type(
@@ -207,21 +242,21 @@ impl ActionMutation for ActionAdapter<'_> {
         let mut action = action.into_active_model();
         action.track_type = Set(track_type);
         action.updated_at = Set(Utc::now().into());
-        action.update(self.db).await
+        action.update(&self.db).await
     }
 
     async fn archive(self, action: Model) -> Result<Model, DbErr> {
         let mut action = action.into_active_model();
         action.archived = Set(true);
         action.updated_at = Set(Utc::now().into());
-        action.update(self.db).await
+        action.update(&self.db).await
     }
 
     async fn unarchive(self, action: Model) -> Result<Model, DbErr> {
         let mut action = action.into_active_model();
         action.archived = Set(false);
         action.updated_at = Set(Utc::now().into());
-        action.update(self.db).await
+        action.update(&self.db).await
     }
 
     async fn bulk_update_ordering(
@@ -234,13 +269,36 @@ impl ActionMutation for ActionAdapter<'_> {
             if let Some(order) = order {
                 let mut action = action.into_active_model();
                 action.ordering = Set(Some((order + 1) as i32));
-                action.update(self.db).await?;
+                action.updated_at = Set(Utc::now().into());
+                action.update(&self.db).await?;
             }
         }
         Ok(())
     }
 
-    async fn delete(self, action: Model) -> Result<(), DbErr> {
-        action.delete(self.db).await.map(|_| ())
+    async fn trash(self, action: Model) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+        let action_goal_ids = action_goal::Entity::find()
+            .select_only()
+            .column(action_goal::Column::Id)
+            .filter(action_goal::Column::ActionId.eq(action.id))
+            .into_tuple::<Uuid>()
+            .all(&txn)
+            .await?;
+        let action_track_ids = action_track::Entity::find()
+            .select_only()
+            .column(action_track::Column::Id)
+            .filter(action_track::Column::ActionId.eq(action.id))
+            .into_tuple::<Uuid>()
+            .all(&txn)
+            .await?;
+        record_deletions::<_, action_goal::Entity>(&txn, action.user_id, action_goal_ids).await?;
+        record_deletions::<_, action_track::Entity>(&txn, action.user_id, action_track_ids).await?;
+        record_tag_deletions(&txn, action.user_id, tag::Column::ActionId.eq(action.id)).await?;
+        record_deletions::<_, Entity>(&txn, action.user_id, [action.id]).await?;
+        let mut action = action.into_active_model();
+        action.deleted_at = Set(Some(Utc::now().into()));
+        action.update(&txn).await?;
+        txn.commit().await
     }
 }
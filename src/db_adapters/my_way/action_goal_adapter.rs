@@ -1,29 +1,41 @@
 use std::future::Future;
 
-use chrono::NaiveDate;
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DbConn, DbErr, EntityTrait, IntoActiveModel, ModelTrait,
-    QueryFilter, Select, Set,
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseTransaction, DbConn, DbErr, EntityTrait,
+    IntoActiveModel, ModelTrait, QueryFilter, Select, Set, TransactionTrait,
 };
 use uuid::Uuid;
 
+use common::db::in_utc;
 use entities::{
     action,
     action_goal::{ActiveModel, Column, Entity, Model},
     user,
 };
 
+use crate::{
+    db_handle::DbHandle, tombstone_adapter::record_deletions, trash_adapter::untrashed_action_ids,
+};
+
 #[derive(Clone)]
 pub struct ActionGoalAdapter<'a> {
-    pub db: &'a DbConn,
+    pub db: DbHandle<'a>,
     pub query: Select<Entity>,
 }
 
 impl<'a> ActionGoalAdapter<'a> {
     pub fn init(db: &'a DbConn) -> Self {
         Self {
-            db,
-            query: Entity::find(),
+            db: DbHandle::Connection(db),
+            query: Entity::find().filter(Column::ActionId.in_subquery(untrashed_action_ids())),
+        }
+    }
+
+    pub fn init_in_transaction(txn: &'a DatabaseTransaction) -> Self {
+        Self {
+            db: DbHandle::Transaction(txn),
+            query: Entity::find().filter(Column::ActionId.in_subquery(untrashed_action_ids())),
         }
     }
 }
@@ -32,6 +44,9 @@ pub trait ActionGoalFilter {
     fn filter_eq_user(self, user: &user::Model) -> Self;
     fn filter_eq_action(self, action: &action::Model) -> Self;
     fn filter_to_date_null(self) -> Self;
+    /// Goals in effect on any of the dates from `from` to `to`, both inclusive.
+    fn filter_overlaps_dates(self, from: NaiveDate, to: NaiveDate) -> Self;
+    fn filter_updated_at_gte(self, updated_at: DateTime<FixedOffset>) -> Self;
 }
 
 impl ActionGoalFilter for ActionGoalAdapter<'_> {
@@ -49,15 +64,34 @@ impl ActionGoalFilter for ActionGoalAdapter<'_> {
         self.query = self.query.filter(Column::ToDate.is_null());
         self
     }
+
+    fn filter_overlaps_dates(mut self, from: NaiveDate, to: NaiveDate) -> Self {
+        self.query = self.query.filter(Column::FromDate.lte(to)).filter(
+            Condition::any()
+                .add(Column::ToDate.is_null())
+                .add(Column::ToDate.gte(from)),
+        );
+        self
+    }
+
+    fn filter_updated_at_gte(mut self, updated_at: DateTime<FixedOffset>) -> Self {
+        self.query = self.query.filter(Column::UpdatedAt.gte(in_utc(updated_at)));
+        self
+    }
 }
 
 pub trait ActionGoalQuery {
+    fn get_all(self) -> impl Future<Output = Result<Vec<Model>, DbErr>>;
     fn get_one(self) -> impl Future<Output = Result<Option<Model>, DbErr>>;
 }
 
 impl ActionGoalQuery for ActionGoalAdapter<'_> {
+    async fn get_all(self) -> Result<Vec<Model>, DbErr> {
+        self.query.all(&self.db).await
+    }
+
     async fn get_one(self) -> Result<Option<Model>, DbErr> {
-        self.query.one(self.db).await
+        self.query.one(&self.db).await
     }
 }
 
@@ -97,9 +131,10 @@ impl ActionGoalMutation for ActionGoalAdapter<'_> {
             from_date: Set(params.from_date),
             duration_seconds: Set(params.duration_seconds),
             count: Set(params.count),
+            updated_at: Set(Utc::now().into()),
             ..Default::default()
         }
-        .insert(self.db)
+        .insert(&self.db)
         .await
     }
 
@@ -112,10 +147,14 @@ impl ActionGoalMutation for ActionGoalAdapter<'_> {
         action_goal.to_date = Set(params.to_date);
         action_goal.duration_seconds = Set(params.duration_seconds);
         action_goal.count = Set(params.count);
-        action_goal.update(self.db).await
+        action_goal.updated_at = Set(Utc::now().into());
+        action_goal.update(&self.db).await
     }
 
     async fn delete(self, action_goal: Model) -> Result<(), DbErr> {
-        action_goal.delete(self.db).await.map(|_| ())
+        let txn = self.db.begin().await?;
+        record_deletions::<_, Entity>(&txn, action_goal.user_id, [action_goal.id]).await?;
+        action_goal.delete(&txn).await?;
+        txn.commit().await
     }
 }
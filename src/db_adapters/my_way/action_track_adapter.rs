@@ -2,13 +2,18 @@ use std::future::Future;
 
 use chrono::{DateTime, Duration, FixedOffset, NaiveDate, Utc};
 use sea_orm::{
-    sqlx::error::Error::Database, ActiveModelTrait, ColumnTrait, Condition, DbConn, DbErr,
+    prelude::Expr, ActiveModelTrait, ColumnTrait, Condition, DatabaseTransaction, DbConn, DbErr,
     EntityTrait, IntoActiveModel, JoinType::LeftJoin, ModelTrait, Order, QueryFilter, QueryOrder,
-    QuerySelect, RelationTrait, RuntimeErr::SqlxError, Select, Set,
+    QuerySelect, RelationTrait, Select, Set, TransactionTrait,
 };
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::CustomDbErr;
+use crate::{
+    db_handle::DbHandle, duplicate_on_unique_violation, tombstone_adapter::record_deletions,
+    trash_adapter::untrashed_action_ids,
+};
+use common::db::in_utc;
 use entities::{
     action,
     action_track::{ActiveModel, Column, Entity, Model, Relation},
@@ -18,20 +23,27 @@ use entities::{
 
 #[derive(Clone)]
 pub struct ActionTrackAdapter<'a> {
-    pub db: &'a DbConn,
+    pub db: DbHandle<'a>,
     pub query: Select<Entity>,
 }
 
 impl<'a> ActionTrackAdapter<'a> {
     pub fn init(db: &'a DbConn) -> Self {
         Self {
-            db,
-            query: Entity::find(),
+            db: DbHandle::Connection(db),
+            query: Entity::find().filter(Column::ActionId.in_subquery(untrashed_action_ids())),
+        }
+    }
+
+    pub fn init_in_transaction(txn: &'a DatabaseTransaction) -> Self {
+        Self {
+            db: DbHandle::Transaction(txn),
+            query: Entity::find().filter(Column::ActionId.in_subquery(untrashed_action_ids())),
         }
     }
 }
 
-fn get_date_start_end_in_utc(
+pub(crate) fn get_date_start_end_in_utc(
     date: NaiveDate,
     user_timezone: &TimezoneEnum,
 ) -> (DateTime<Utc>, DateTime<Utc>) {
@@ -46,14 +58,30 @@ fn get_date_start_end_in_utc(
     }
 }
 
+/// Position of an action track in the order of started_at desc, id desc.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ActionTrackCursor {
+    pub started_at: DateTime<FixedOffset>,
+    pub id: Uuid,
+}
+
 pub trait ActionTrackFilter {
     fn filter_eq_user(self, user: &user::Model) -> Self;
     fn filter_started_at_gte(self, started_at: DateTime<FixedOffset>) -> Self;
     fn filter_started_at_lte(self, started_at: DateTime<FixedOffset>) -> Self;
     fn filter_started_at_in_dates(self, dates: Vec<NaiveDate>, user_timezone: TimezoneEnum)
         -> Self;
+    /// Tracks started from the beginning of `from` to the end of `to` in the user's timezone.
+    fn filter_started_at_in_date_range(
+        self,
+        from: NaiveDate,
+        to: NaiveDate,
+        user_timezone: TimezoneEnum,
+    ) -> Self;
     fn filter_ended_at_is_null(self, is_null: bool) -> Self;
     fn filter_eq_archived_action(self, archived: bool) -> Self;
+    fn filter_updated_at_gte(self, updated_at: DateTime<FixedOffset>) -> Self;
+    fn filter_after_cursor(self, cursor: &ActionTrackCursor) -> Self;
 }
 
 impl ActionTrackFilter for ActionTrackAdapter<'_> {
@@ -63,12 +91,12 @@ impl ActionTrackFilter for ActionTrackAdapter<'_> {
     }
 
     fn filter_started_at_gte(mut self, started_at: DateTime<FixedOffset>) -> Self {
-        self.query = self.query.filter(Column::StartedAt.gte(started_at));
+        self.query = self.query.filter(Column::StartedAt.gte(in_utc(started_at)));
         self
     }
 
     fn filter_started_at_lte(mut self, started_at: DateTime<FixedOffset>) -> Self {
-        self.query = self.query.filter(Column::StartedAt.lte(started_at));
+        self.query = self.query.filter(Column::StartedAt.lte(in_utc(started_at)));
         self
     }
 
@@ -86,6 +114,18 @@ impl ActionTrackFilter for ActionTrackAdapter<'_> {
         self
     }
 
+    fn filter_started_at_in_date_range(
+        mut self,
+        from: NaiveDate,
+        to: NaiveDate,
+        user_timezone: TimezoneEnum,
+    ) -> Self {
+        let (start, _) = get_date_start_end_in_utc(from, &user_timezone);
+        let (_, end) = get_date_start_end_in_utc(to, &user_timezone);
+        self.query = self.query.filter(Column::StartedAt.between(start, end));
+        self
+    }
+
     fn filter_ended_at_is_null(mut self, is_null: bool) -> Self {
         self.query = match is_null {
             true => self.query.filter(Column::EndedAt.is_null()),
@@ -101,11 +141,31 @@ impl ActionTrackFilter for ActionTrackAdapter<'_> {
             .filter(action::Column::Archived.eq(archived));
         self
     }
+
+    fn filter_updated_at_gte(mut self, updated_at: DateTime<FixedOffset>) -> Self {
+        self.query = self.query.filter(Column::UpdatedAt.gte(in_utc(updated_at)));
+        self
+    }
+
+    fn filter_after_cursor(mut self, cursor: &ActionTrackCursor) -> Self {
+        self.query = self.query.filter(
+            Expr::tuple([
+                Expr::col((Entity, Column::StartedAt)).into(),
+                Expr::col((Entity, Column::Id)).into(),
+            ])
+            .lt(Expr::tuple([
+                Expr::value(in_utc(cursor.started_at)),
+                Expr::value(cursor.id),
+            ])),
+        );
+        self
+    }
 }
 
 pub trait ActionTrackOrder {
     fn order_by_action_id(self, order: Order) -> Self;
     fn order_by_started_at(self, order: Order) -> Self;
+    fn order_by_id(self, order: Order) -> Self;
 }
 
 impl ActionTrackOrder for ActionTrackAdapter<'_> {
@@ -118,6 +178,11 @@ impl ActionTrackOrder for ActionTrackAdapter<'_> {
         self.query = self.query.order_by(Column::StartedAt, order);
         self
     }
+
+    fn order_by_id(mut self, order: Order) -> Self {
+        self.query = self.query.order_by(Column::Id, order);
+        self
+    }
 }
 
 pub trait ActionTrackLimit {
@@ -138,11 +203,11 @@ pub trait ActionTrackQuery {
 
 impl ActionTrackQuery for ActionTrackAdapter<'_> {
     async fn get_all(self) -> Result<Vec<Model>, DbErr> {
-        self.query.all(self.db).await
+        self.query.all(&self.db).await
     }
 
     async fn get_by_id(self, id: Uuid) -> Result<Option<Model>, DbErr> {
-        self.query.filter(Column::Id.eq(id)).one(self.db).await
+        self.query.filter(Column::Id.eq(id)).one(&self.db).await
     }
 }
 
@@ -179,21 +244,14 @@ impl ActionTrackMutation for ActionTrackAdapter<'_> {
             id: Set(uuid::Uuid::now_v7()),
             user_id: Set(params.user_id),
             action_id: Set(params.action_id),
-            started_at: Set(params.started_at),
-            ended_at: Set(params.ended_at),
+            started_at: Set(in_utc(params.started_at)),
+            ended_at: Set(params.ended_at.map(in_utc)),
             duration: Set(params.duration),
+            updated_at: Set(Utc::now().into()),
         }
-        .insert(self.db)
+        .insert(&self.db)
         .await
-        .map_err(|e| match &e {
-            DbErr::Query(SqlxError(Database(error))) => match error.constraint() {
-                Some("action_tracks_user_id_action_id_started_at_unique_index") => {
-                    DbErr::Custom(CustomDbErr::Duplicate.to_string())
-                }
-                _ => e,
-            },
-            _ => e,
-        })
+        .map_err(duplicate_on_unique_violation)
     }
 
     async fn update(
@@ -202,22 +260,21 @@ impl ActionTrackMutation for ActionTrackAdapter<'_> {
         params: UpdateActionTrackParams,
     ) -> Result<Model, DbErr> {
         let mut action_track = action_track.into_active_model();
-        action_track.started_at = Set(params.started_at);
-        action_track.ended_at = Set(params.ended_at);
+        action_track.started_at = Set(in_utc(params.started_at));
+        action_track.ended_at = Set(params.ended_at.map(in_utc));
         action_track.duration = Set(params.duration);
         action_track.action_id = Set(params.action_id);
-        action_track.update(self.db).await.map_err(|e| match &e {
-            DbErr::Query(SqlxError(Database(error))) => match error.constraint() {
-                Some("action_tracks_user_id_action_id_started_at_unique_index") => {
-                    DbErr::Custom(CustomDbErr::Duplicate.to_string())
-                }
-                _ => e,
-            },
-            _ => e,
-        })
+        action_track.updated_at = Set(Utc::now().into());
+        action_track
+            .update(&self.db)
+            .await
+            .map_err(duplicate_on_unique_violation)
     }
 
     async fn delete(self, action_track: Model) -> Result<(), DbErr> {
-        action_track.delete(self.db).await.map(|_| ())
+        let txn = self.db.begin().await?;
+        record_deletions::<_, Entity>(&txn, action_track.user_id, [action_track.id]).await?;
+        action_track.delete(&txn).await?;
+        txn.commit().await
     }
 }
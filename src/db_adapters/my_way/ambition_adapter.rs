@@ -1,30 +1,45 @@
 use std::future::Future;
 
-use chrono::Utc;
+use chrono::{DateTime, FixedOffset, Utc};
 use sea_orm::{
-    sea_query::{Func, NullOrdering::Last, SimpleExpr},
-    ActiveModelTrait, ColumnTrait, DbConn, DbErr, EntityTrait, IntoActiveModel, ModelTrait, Order,
-    QueryFilter, QueryOrder, QuerySelect, Select, Set, TransactionError, TransactionTrait,
+    sea_query::{Func, NullOrdering::Last, OnConflict, SimpleExpr},
+    ActiveModelTrait, ColumnTrait, DatabaseTransaction, DbConn, DbErr, EntityTrait,
+    IntoActiveModel, Order, QueryFilter, QueryOrder, QuerySelect, QueryTrait, Select, Set,
+    TransactionError, TransactionTrait,
 };
 use uuid::Uuid;
 
+use common::db::in_utc;
 use entities::{
     ambition::{ActiveModel, Column, Entity, Model},
+    ambitions_directions,
     sea_orm_active_enums::TagType,
     tag, user,
 };
 
+use crate::{
+    db_handle::DbHandle, revision_adapter::record_revision, tag_adapter::record_tag_deletions,
+    tombstone_adapter::record_deletions,
+};
+
 #[derive(Clone)]
 pub struct AmbitionAdapter<'a> {
-    pub db: &'a DbConn,
+    pub db: DbHandle<'a>,
     pub query: Select<Entity>,
 }
 
 impl<'a> AmbitionAdapter<'a> {
     pub fn init(db: &'a DbConn) -> Self {
         Self {
-            db,
-            query: Entity::find(),
+            db: DbHandle::Connection(db),
+            query: Entity::find().filter(Column::DeletedAt.is_null()),
+        }
+    }
+
+    pub fn init_in_transaction(txn: &'a DatabaseTransaction) -> Self {
+        Self {
+            db: DbHandle::Transaction(txn),
+            query: Entity::find().filter(Column::DeletedAt.is_null()),
         }
     }
 }
@@ -34,6 +49,7 @@ pub trait AmbitionFilter {
     fn filter_eq_user(self, user: &user::Model) -> Self;
     fn filter_eq_archived(self, archived: bool) -> Self;
     fn filter_in_ids(self, ids: Vec<Uuid>) -> Self;
+    fn filter_updated_at_gte(self, updated_at: DateTime<FixedOffset>) -> Self;
 }
 
 impl AmbitionFilter for AmbitionAdapter<'_> {
@@ -56,6 +72,11 @@ impl AmbitionFilter for AmbitionAdapter<'_> {
         self.query = self.query.filter(Column::Id.is_in(ids));
         self
     }
+
+    fn filter_updated_at_gte(mut self, updated_at: DateTime<FixedOffset>) -> Self {
+        self.query = self.query.filter(Column::UpdatedAt.gte(in_utc(updated_at)));
+        self
+    }
 }
 
 pub trait AmbitionOrder {
@@ -81,22 +102,36 @@ pub trait AmbitionQuery {
     fn get_all(self) -> impl Future<Output = Result<Vec<Model>, DbErr>>;
     fn get_by_id(self, id: Uuid) -> impl Future<Output = Result<Option<Model>, DbErr>>;
     fn get_random(self) -> impl Future<Output = Result<Option<Model>, DbErr>>;
+    /// Links from the ambitions of the query to their directions.
+    fn get_direction_links(
+        self,
+    ) -> impl Future<Output = Result<Vec<ambitions_directions::Model>, DbErr>>;
 }
 
 impl AmbitionQuery for AmbitionAdapter<'_> {
     async fn get_all(self) -> Result<Vec<Model>, DbErr> {
-        self.query.all(self.db).await
+        self.query.all(&self.db).await
     }
 
     async fn get_by_id(self, id: Uuid) -> Result<Option<Model>, DbErr> {
-        self.query.filter(Column::Id.eq(id)).one(self.db).await
+        self.query.filter(Column::Id.eq(id)).one(&self.db).await
     }
 
     async fn get_random(self) -> Result<Option<Model>, DbErr> {
         self.query
             .order_by(SimpleExpr::FunctionCall(Func::random()), Order::Asc)
             .limit(1)
-            .one(self.db)
+            .one(&self.db)
+            .await
+    }
+
+    async fn get_direction_links(self) -> Result<Vec<ambitions_directions::Model>, DbErr> {
+        ambitions_directions::Entity::find()
+            .filter(
+                ambitions_directions::Column::AmbitionId
+                    .in_subquery(self.query.select_only().column(Column::Id).into_query()),
+            )
+            .all(&self.db)
             .await
     }
 }
@@ -131,7 +166,18 @@ pub trait AmbitionMutation {
         ambitions: Vec<Model>,
         ordering: Vec<Uuid>,
     ) -> impl Future<Output = Result<(), DbErr>>;
-    fn delete(self, ambition: Model) -> impl Future<Output = Result<(), DbErr>>;
+    fn trash(self, ambition: Model) -> impl Future<Output = Result<(), DbErr>>;
+    /// Does nothing if linked already.
+    fn link_direction(
+        self,
+        ambition: &Model,
+        direction_id: Uuid,
+    ) -> impl Future<Output = Result<(), DbErr>>;
+    fn unlink_direction(
+        self,
+        ambition: &Model,
+        direction_id: Uuid,
+    ) -> impl Future<Output = Result<(), DbErr>>;
 }
 
 impl AmbitionMutation for AmbitionAdapter<'_> {
@@ -143,11 +189,14 @@ impl AmbitionMutation for AmbitionAdapter<'_> {
             .transaction::<_, Model, DbErr>(|txn| {
                 Box::pin(async move {
                     let ambition_id = uuid::Uuid::now_v7();
+                    let now = Utc::now();
                     let created_ambition = ActiveModel {
                         id: Set(ambition_id),
                         user_id: Set(params.user_id),
                         name: Set(params.name.to_owned()),
                         description: Set(params.description),
+                        created_at: Set(now.into()),
+                        updated_at: Set(now.into()),
                         ..Default::default()
                     }
                     .insert(txn)
@@ -157,6 +206,8 @@ impl AmbitionMutation for AmbitionAdapter<'_> {
                         user_id: Set(params.user_id),
                         ambition_id: Set(Some(ambition_id)),
                         r#type: Set(TagType::Ambition),
+                        created_at: Set(now.into()),
+                        updated_at: Set(now.into()),
                         ..Default::default()
                     }
                     .insert(txn)
@@ -169,25 +220,29 @@ impl AmbitionMutation for AmbitionAdapter<'_> {
     }
 
     async fn update(self, ambition: Model, params: UpdateAmbitionParams) -> Result<Model, DbErr> {
+        let txn = self.db.begin().await?;
+        record_revision::<_, Entity>(&txn, ambition.user_id, ambition.id, &ambition).await?;
         let mut ambition = ambition.into_active_model();
         ambition.name = Set(params.name);
         ambition.description = Set(params.description);
         ambition.updated_at = Set(Utc::now().into());
-        ambition.update(self.db).await
+        let ambition = ambition.update(&txn).await?;
+        txn.commit().await?;
+        Ok(ambition)
     }
 
     async fn archive(self, ambition: Model) -> Result<Model, DbErr> {
         let mut ambition = ambition.into_active_model();
         ambition.archived = Set(true);
         ambition.updated_at = Set(Utc::now().into());
-        ambition.update(self.db).await
+        ambition.update(&self.db).await
     }
 
     async fn unarchive(self, ambition: Model) -> Result<Model, DbErr> {
         let mut ambition = ambition.into_active_model();
         ambition.archived = Set(false);
         ambition.updated_at = Set(Utc::now().into());
-        ambition.update(self.db).await
+        ambition.update(&self.db).await
     }
 
     async fn bulk_update_ordering(
@@ -200,13 +255,51 @@ impl AmbitionMutation for AmbitionAdapter<'_> {
             if let Some(order) = order {
                 let mut ambition = ambition.into_active_model();
                 ambition.ordering = Set(Some((order + 1) as i32));
-                ambition.update(self.db).await?;
+                ambition.updated_at = Set(Utc::now().into());
+                ambition.update(&self.db).await?;
             }
         }
         Ok(())
     }
 
-    async fn delete(self, ambition: Model) -> Result<(), DbErr> {
-        ambition.delete(self.db).await.map(|_| ())
+    async fn trash(self, ambition: Model) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+        record_tag_deletions(
+            &txn,
+            ambition.user_id,
+            tag::Column::AmbitionId.eq(ambition.id),
+        )
+        .await?;
+        record_deletions::<_, Entity>(&txn, ambition.user_id, [ambition.id]).await?;
+        let mut ambition = ambition.into_active_model();
+        ambition.deleted_at = Set(Some(Utc::now().into()));
+        ambition.update(&txn).await?;
+        txn.commit().await
+    }
+
+    async fn link_direction(self, ambition: &Model, direction_id: Uuid) -> Result<(), DbErr> {
+        ambitions_directions::Entity::insert(ambitions_directions::ActiveModel {
+            ambition_id: Set(ambition.id),
+            direction_id: Set(direction_id),
+        })
+        .on_conflict(
+            OnConflict::columns([
+                ambitions_directions::Column::AmbitionId,
+                ambitions_directions::Column::DirectionId,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .do_nothing()
+        .exec(&self.db)
+        .await
+        .map(|_| ())
+    }
+
+    async fn unlink_direction(self, ambition: &Model, direction_id: Uuid) -> Result<(), DbErr> {
+        ambitions_directions::Entity::delete_by_id((ambition.id, direction_id))
+            .exec(&self.db)
+            .await
+            .map(|_| ())
     }
 }
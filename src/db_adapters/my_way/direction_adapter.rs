@@ -1,37 +1,51 @@
 use std::future::Future;
 
-use chrono::Utc;
+use chrono::{DateTime, FixedOffset, Utc};
 use sea_orm::{
     sea_query::{
         Func,
         NullOrdering::{First, Last},
-        SimpleExpr,
+        OnConflict, SimpleExpr,
     },
-    ActiveModelTrait, ColumnTrait, DbConn, DbErr, EntityTrait, IntoActiveModel,
+    ActiveModelTrait, ColumnTrait, DatabaseTransaction, DbConn, DbErr, EntityTrait,
+    IntoActiveModel,
     JoinType::LeftJoin,
-    ModelTrait, Order, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Select, Set,
+    Order, QueryFilter, QueryOrder, QuerySelect, QueryTrait, RelationTrait, Select, Set,
     TransactionError, TransactionTrait,
 };
 use uuid::Uuid;
 
+use common::db::in_utc;
 use entities::{
     direction::{ActiveModel, Column, Entity, Model, Relation},
-    direction_category,
+    direction_category, directions_actions,
     sea_orm_active_enums::TagType,
     tag, user,
 };
 
+use crate::{
+    db_handle::DbHandle, revision_adapter::record_revision, tag_adapter::record_tag_deletions,
+    tombstone_adapter::record_deletions,
+};
+
 #[derive(Clone)]
 pub struct DirectionAdapter<'a> {
-    pub db: &'a DbConn,
+    pub db: DbHandle<'a>,
     pub query: Select<Entity>,
 }
 
 impl<'a> DirectionAdapter<'a> {
     pub fn init(db: &'a DbConn) -> Self {
         Self {
-            db,
-            query: Entity::find(),
+            db: DbHandle::Connection(db),
+            query: Entity::find().filter(Column::DeletedAt.is_null()),
+        }
+    }
+
+    pub fn init_in_transaction(txn: &'a DatabaseTransaction) -> Self {
+        Self {
+            db: DbHandle::Transaction(txn),
+            query: Entity::find().filter(Column::DeletedAt.is_null()),
         }
     }
 }
@@ -42,9 +56,7 @@ pub trait DirectionJoin {
 
 impl DirectionJoin for DirectionAdapter<'_> {
     fn join_category(mut self) -> Self {
-        self.query = self
-            .query
-            .join(LeftJoin, Relation::DirectionCategory.def());
+        self.query = self.query.join(LeftJoin, Relation::DirectionCategory.def());
         self
     }
 }
@@ -54,6 +66,7 @@ pub trait DirectionFilter {
     fn filter_eq_user(self, user: &user::Model) -> Self;
     fn filter_eq_archived(self, archived: bool) -> Self;
     fn filter_in_ids(self, ids: Vec<Uuid>) -> Self;
+    fn filter_updated_at_gte(self, updated_at: DateTime<FixedOffset>) -> Self;
 }
 
 impl DirectionFilter for DirectionAdapter<'_> {
@@ -76,6 +89,11 @@ impl DirectionFilter for DirectionAdapter<'_> {
         self.query = self.query.filter(Column::Id.is_in(ids));
         self
     }
+
+    fn filter_updated_at_gte(mut self, updated_at: DateTime<FixedOffset>) -> Self {
+        self.query = self.query.filter(Column::UpdatedAt.gte(in_utc(updated_at)));
+        self
+    }
 }
 
 pub trait DirectionOrder {
@@ -117,36 +135,81 @@ pub trait DirectionQuery {
     fn get_all(self) -> impl Future<Output = Result<Vec<Model>, DbErr>>;
     fn get_by_id(self, id: Uuid) -> impl Future<Output = Result<Option<Model>, DbErr>>;
     fn get_random(self) -> impl Future<Output = Result<Option<Model>, DbErr>>;
+    /// With the path to its category from the top-level one, which is empty without a category.
     fn get_random_with_category(
         self,
-    ) -> impl Future<Output = Result<Option<(Model, Option<direction_category::Model>)>, DbErr>>;
+    ) -> impl Future<Output = Result<Option<(Model, Vec<direction_category::Model>)>, DbErr>>;
+    /// Links from the directions of the query to their actions.
+    fn get_action_links(
+        self,
+    ) -> impl Future<Output = Result<Vec<directions_actions::Model>, DbErr>>;
 }
 
 impl DirectionQuery for DirectionAdapter<'_> {
     async fn get_all(self) -> Result<Vec<Model>, DbErr> {
-        self.query.all(self.db).await
+        self.query.all(&self.db).await
     }
 
     async fn get_by_id(self, id: Uuid) -> Result<Option<Model>, DbErr> {
-        self.query.filter(Column::Id.eq(id)).one(self.db).await
+        self.query.filter(Column::Id.eq(id)).one(&self.db).await
     }
 
     async fn get_random(self) -> Result<Option<Model>, DbErr> {
         self.query
             .order_by(SimpleExpr::FunctionCall(Func::random()), Order::Asc)
             .limit(1)
-            .one(self.db)
+            .one(&self.db)
             .await
     }
 
     async fn get_random_with_category(
         self,
-    ) -> Result<Option<(Model, Option<direction_category::Model>)>, DbErr> {
-        self.query
-            .select_also(direction_category::Entity)
+    ) -> Result<Option<(Model, Vec<direction_category::Model>)>, DbErr> {
+        let direction = match self
+            .query
             .order_by(SimpleExpr::FunctionCall(Func::random()), Order::Asc)
             .limit(1)
-            .one(self.db)
+            .one(&self.db)
+            .await?
+        {
+            Some(direction) => direction,
+            None => return Ok(None),
+        };
+        let categories = match direction.category_id {
+            Some(_) => {
+                direction_category::Entity::find()
+                    .filter(direction_category::Column::UserId.eq(direction.user_id))
+                    .all(&self.db)
+                    .await?
+            }
+            None => vec![],
+        };
+        let mut path = vec![];
+        let mut category_id = direction.category_id;
+        while let Some(category) =
+            category_id.and_then(|id| categories.iter().find(|category| category.id == id))
+        {
+            // NOTE: Updates prevent cycles, so this only guards against broken data.
+            if path
+                .iter()
+                .any(|c: &direction_category::Model| c.id == category.id)
+            {
+                break;
+            }
+            path.push(category.clone());
+            category_id = category.parent_id;
+        }
+        path.reverse();
+        Ok(Some((direction, path)))
+    }
+
+    async fn get_action_links(self) -> Result<Vec<directions_actions::Model>, DbErr> {
+        directions_actions::Entity::find()
+            .filter(
+                directions_actions::Column::DirectionId
+                    .in_subquery(self.query.select_only().column(Column::Id).into_query()),
+            )
+            .all(&self.db)
             .await
     }
 }
@@ -183,7 +246,18 @@ pub trait DirectionMutation {
         directions: Vec<Model>,
         ordering: Vec<Uuid>,
     ) -> impl Future<Output = Result<(), DbErr>>;
-    fn delete(self, direction: Model) -> impl Future<Output = Result<(), DbErr>>;
+    fn trash(self, direction: Model) -> impl Future<Output = Result<(), DbErr>>;
+    /// Does nothing if linked already.
+    fn link_action(
+        self,
+        direction: &Model,
+        action_id: Uuid,
+    ) -> impl Future<Output = Result<(), DbErr>>;
+    fn unlink_action(
+        self,
+        direction: &Model,
+        action_id: Uuid,
+    ) -> impl Future<Output = Result<(), DbErr>>;
 }
 
 impl DirectionMutation for DirectionAdapter<'_> {
@@ -195,12 +269,15 @@ impl DirectionMutation for DirectionAdapter<'_> {
             .transaction::<_, Model, DbErr>(|txn| {
                 Box::pin(async move {
                     let direction_id = uuid::Uuid::now_v7();
+                    let now = Utc::now();
                     let created_direction = ActiveModel {
                         id: Set(direction_id),
                         user_id: Set(params.user_id),
                         name: Set(params.name.to_owned()),
                         description: Set(params.description.to_owned()),
                         category_id: Set(params.category_id),
+                        created_at: Set(now.into()),
+                        updated_at: Set(now.into()),
                         ..Default::default()
                     }
                     .insert(txn)
@@ -210,6 +287,8 @@ impl DirectionMutation for DirectionAdapter<'_> {
                         user_id: Set(params.user_id),
                         direction_id: Set(Some(direction_id)),
                         r#type: Set(TagType::Direction),
+                        created_at: Set(now.into()),
+                        updated_at: Set(now.into()),
                         ..Default::default()
                     }
                     .insert(txn)
@@ -221,31 +300,31 @@ impl DirectionMutation for DirectionAdapter<'_> {
             .await
     }
 
-    async fn update(
-        self,
-        direction: Model,
-        params: UpdateDirectionParams,
-    ) -> Result<Model, DbErr> {
+    async fn update(self, direction: Model, params: UpdateDirectionParams) -> Result<Model, DbErr> {
+        let txn = self.db.begin().await?;
+        record_revision::<_, Entity>(&txn, direction.user_id, direction.id, &direction).await?;
         let mut direction = direction.into_active_model();
         direction.name = Set(params.name);
         direction.description = Set(params.description);
         direction.category_id = Set(params.category_id);
         direction.updated_at = Set(Utc::now().into());
-        direction.update(self.db).await
+        let direction = direction.update(&txn).await?;
+        txn.commit().await?;
+        Ok(direction)
     }
 
     async fn archive(self, direction: Model) -> Result<Model, DbErr> {
         let mut direction = direction.into_active_model();
         direction.archived = Set(true);
         direction.updated_at = Set(Utc::now().into());
-        direction.update(self.db).await
+        direction.update(&self.db).await
     }
 
     async fn unarchive(self, direction: Model) -> Result<Model, DbErr> {
         let mut direction = direction.into_active_model();
         direction.archived = Set(false);
         direction.updated_at = Set(Utc::now().into());
-        direction.update(self.db).await
+        direction.update(&self.db).await
     }
 
     async fn bulk_update_ordering(
@@ -258,13 +337,51 @@ impl DirectionMutation for DirectionAdapter<'_> {
             if let Some(order) = order {
                 let mut direction = direction.into_active_model();
                 direction.ordering = Set(Some((order + 1) as i32));
-                direction.update(self.db).await?;
+                direction.updated_at = Set(Utc::now().into());
+                direction.update(&self.db).await?;
             }
         }
         Ok(())
     }
 
-    async fn delete(self, direction: Model) -> Result<(), DbErr> {
-        direction.delete(self.db).await.map(|_| ())
+    async fn trash(self, direction: Model) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+        record_tag_deletions(
+            &txn,
+            direction.user_id,
+            tag::Column::DirectionId.eq(direction.id),
+        )
+        .await?;
+        record_deletions::<_, Entity>(&txn, direction.user_id, [direction.id]).await?;
+        let mut direction = direction.into_active_model();
+        direction.deleted_at = Set(Some(Utc::now().into()));
+        direction.update(&txn).await?;
+        txn.commit().await
+    }
+
+    async fn link_action(self, direction: &Model, action_id: Uuid) -> Result<(), DbErr> {
+        directions_actions::Entity::insert(directions_actions::ActiveModel {
+            direction_id: Set(direction.id),
+            action_id: Set(action_id),
+        })
+        .on_conflict(
+            OnConflict::columns([
+                directions_actions::Column::DirectionId,
+                directions_actions::Column::ActionId,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .do_nothing()
+        .exec(&self.db)
+        .await
+        .map(|_| ())
+    }
+
+    async fn unlink_action(self, direction: &Model, action_id: Uuid) -> Result<(), DbErr> {
+        directions_actions::Entity::delete_by_id((direction.id, action_id))
+            .exec(&self.db)
+            .await
+            .map(|_| ())
     }
 }
@@ -1,26 +1,39 @@
 use std::future::Future;
 
+use chrono::{DateTime, FixedOffset, Utc};
 use sea_orm::{
-    sea_query::NullOrdering::Last, ActiveModelTrait, ColumnTrait, DbConn, DbErr, EntityTrait,
-    IntoActiveModel, ModelTrait, Order, PaginatorTrait, QueryFilter, QueryOrder, Select, Set,
+    prelude::Expr, sea_query::NullOrdering::Last, ActiveModelTrait, ColumnTrait,
+    DatabaseTransaction, DbConn, DbErr, EntityTrait, IntoActiveModel, ModelTrait, Order,
+    PaginatorTrait, QueryFilter, QueryOrder, Select, Set, TransactionTrait,
 };
 use uuid::Uuid;
 
+use common::db::in_utc;
 use entities::{
+    direction,
     direction_category::{ActiveModel, Column, Entity, Model},
     user,
 };
 
+use crate::{db_handle::DbHandle, tombstone_adapter::record_deletions};
+
 #[derive(Clone)]
 pub struct DirectionCategoryAdapter<'a> {
-    pub db: &'a DbConn,
+    pub db: DbHandle<'a>,
     pub query: Select<Entity>,
 }
 
 impl<'a> DirectionCategoryAdapter<'a> {
     pub fn init(db: &'a DbConn) -> Self {
         Self {
-            db,
+            db: DbHandle::Connection(db),
+            query: Entity::find(),
+        }
+    }
+
+    pub fn init_in_transaction(txn: &'a DatabaseTransaction) -> Self {
+        Self {
+            db: DbHandle::Transaction(txn),
             query: Entity::find(),
         }
     }
@@ -29,6 +42,9 @@ impl<'a> DirectionCategoryAdapter<'a> {
 pub trait DirectionCategoryFilter {
     fn filter_eq_user(self, user: &user::Model) -> Self;
     fn filter_in_ids(self, ids: Vec<Uuid>) -> Self;
+    /// Top-level categories if None.
+    fn filter_eq_parent_id(self, parent_id: Option<Uuid>) -> Self;
+    fn filter_updated_at_gte(self, updated_at: DateTime<FixedOffset>) -> Self;
 }
 
 impl DirectionCategoryFilter for DirectionCategoryAdapter<'_> {
@@ -41,6 +57,19 @@ impl DirectionCategoryFilter for DirectionCategoryAdapter<'_> {
         self.query = self.query.filter(Column::Id.is_in(ids));
         self
     }
+
+    fn filter_eq_parent_id(mut self, parent_id: Option<Uuid>) -> Self {
+        self.query = match parent_id {
+            Some(parent_id) => self.query.filter(Column::ParentId.eq(parent_id)),
+            None => self.query.filter(Column::ParentId.is_null()),
+        };
+        self
+    }
+
+    fn filter_updated_at_gte(mut self, updated_at: DateTime<FixedOffset>) -> Self {
+        self.query = self.query.filter(Column::UpdatedAt.gte(in_utc(updated_at)));
+        self
+    }
 }
 
 pub trait DirectionCategoryOrder {
@@ -70,15 +99,15 @@ pub trait DirectionCategoryQuery {
 
 impl DirectionCategoryQuery for DirectionCategoryAdapter<'_> {
     async fn get_all(self) -> Result<Vec<Model>, DbErr> {
-        self.query.all(self.db).await
+        self.query.all(&self.db).await
     }
 
     async fn get_by_id(self, id: Uuid) -> Result<Option<Model>, DbErr> {
-        self.query.filter(Column::Id.eq(id)).one(self.db).await
+        self.query.filter(Column::Id.eq(id)).one(&self.db).await
     }
 
     async fn get_count(self) -> Result<u64, DbErr> {
-        self.query.count(self.db).await
+        self.query.count(&self.db).await
     }
 }
 
@@ -86,12 +115,15 @@ impl DirectionCategoryQuery for DirectionCategoryAdapter<'_> {
 pub struct CreateDirectionCategoryParams {
     pub name: String,
     pub ordering: Option<i32>,
+    pub parent_id: Option<Uuid>,
     pub user_id: Uuid,
 }
 
 #[derive(Debug, Clone)]
 pub struct UpdateDirectionCategoryParams {
     pub name: String,
+    pub ordering: Option<i32>,
+    pub parent_id: Option<Uuid>,
 }
 
 pub trait DirectionCategoryMutation {
@@ -118,8 +150,10 @@ impl DirectionCategoryMutation for DirectionCategoryAdapter<'_> {
             user_id: Set(params.user_id),
             name: Set(params.name),
             ordering: Set(params.ordering),
+            updated_at: Set(Utc::now().into()),
+            parent_id: Set(params.parent_id),
         }
-        .insert(self.db)
+        .insert(&self.db)
         .await
     }
 
@@ -130,19 +164,41 @@ impl DirectionCategoryMutation for DirectionCategoryAdapter<'_> {
     ) -> Result<Model, DbErr> {
         let mut category = category.into_active_model();
         category.name = Set(params.name);
-        category.update(self.db).await
+        category.ordering = Set(params.ordering);
+        category.parent_id = Set(params.parent_id);
+        category.updated_at = Set(Utc::now().into());
+        category.update(&self.db).await
     }
 
     async fn bulk_update_ordering(self, params: Vec<(Model, Option<i32>)>) -> Result<(), DbErr> {
         for (category, ordering) in params {
             let mut category = category.into_active_model();
             category.ordering = Set(ordering);
-            category.update(self.db).await?;
+            category.updated_at = Set(Utc::now().into());
+            category.update(&self.db).await?;
         }
         Ok(())
     }
 
     async fn delete(self, category: Model) -> Result<(), DbErr> {
-        category.delete(self.db).await.map(|_| ())
+        let txn = self.db.begin().await?;
+        let now: DateTime<FixedOffset> = Utc::now().into();
+        // Children move up to the parent, so Health > Sleep > Naps becomes Health > Naps.
+        Entity::update_many()
+            .col_expr(Column::ParentId, Expr::value(category.parent_id))
+            .col_expr(Column::UpdatedAt, Expr::value(now))
+            .filter(Column::ParentId.eq(category.id))
+            .exec(&txn)
+            .await?;
+        // Directions lose the category by ON DELETE SET NULL, which needs to be synced too.
+        direction::Entity::update_many()
+            .col_expr(direction::Column::CategoryId, Expr::value(None::<Uuid>))
+            .col_expr(direction::Column::UpdatedAt, Expr::value(now))
+            .filter(direction::Column::CategoryId.eq(category.id))
+            .exec(&txn)
+            .await?;
+        record_deletions::<_, Entity>(&txn, category.user_id, [category.id]).await?;
+        category.delete(&txn).await?;
+        txn.commit().await
     }
 }
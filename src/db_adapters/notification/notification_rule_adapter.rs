@@ -2,8 +2,8 @@ use std::future::Future;
 
 use chrono::{NaiveTime, Weekday};
 use sea_orm::{
-    ActiveValue::Set, ColumnTrait, DbConn, DbErr, EntityTrait, ModelTrait, PaginatorTrait,
-    QueryFilter, QueryOrder, Select,
+    ActiveValue::Set, ColumnTrait, DatabaseTransaction, DbConn, DbErr, EntityTrait, ModelTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, Select,
 };
 
 use entities::{
@@ -13,16 +13,25 @@ use entities::{
 };
 use uuid::Uuid;
 
+use crate::db_handle::DbHandle;
+
 #[derive(Clone)]
 pub struct NotificationRuleAdapter<'a> {
-    pub db: &'a DbConn,
+    pub db: DbHandle<'a>,
     pub query: Select<Entity>,
 }
 
 impl<'a> NotificationRuleAdapter<'a> {
     pub fn init(db: &'a DbConn) -> Self {
         Self {
-            db,
+            db: DbHandle::Connection(db),
+            query: Entity::find(),
+        }
+    }
+
+    pub fn init_in_transaction(txn: &'a DatabaseTransaction) -> Self {
+        Self {
+            db: DbHandle::Transaction(txn),
             query: Entity::find(),
         }
     }
@@ -83,11 +92,11 @@ pub trait NotificationRuleQuery {
 
 impl NotificationRuleQuery for NotificationRuleAdapter<'_> {
     async fn get_all(self) -> Result<Vec<Model>, DbErr> {
-        self.query.all(self.db).await
+        self.query.all(&self.db).await
     }
 
     async fn get_count(self) -> Result<u64, DbErr> {
-        self.query.count(self.db).await
+        self.query.count(&self.db).await
     }
 }
 
@@ -124,7 +133,7 @@ impl NotificationRuleMutation for NotificationRuleAdapter<'_> {
             .collect::<Vec<_>>();
         Entity::insert_many(notification_rules)
             .on_empty_do_nothing()
-            .exec(self.db)
+            .exec(&self.db)
             .await?;
         Ok(())
     }
@@ -133,7 +142,7 @@ impl NotificationRuleMutation for NotificationRuleAdapter<'_> {
         // FIXME: SeaOrm delete_many methods needs filters to be chained after,
         //        find a way to incorporate into this adapter.
         for rule in notification_rules {
-            rule.delete(self.db).await.map(|_| ())?
+            rule.delete(&self.db).await.map(|_| ())?
         }
         Ok(())
     }
@@ -1,8 +1,8 @@
 use std::future::Future;
 
 use sea_orm::{
-    sea_query::OnConflict, ColumnTrait, DbConn, DbErr, EntityTrait, ModelTrait, QueryFilter,
-    Select, Set,
+    sea_query::OnConflict, ColumnTrait, DatabaseTransaction, DbConn, DbErr, EntityTrait,
+    ModelTrait, QueryFilter, Select, Set,
 };
 use uuid::Uuid;
 
@@ -11,16 +11,25 @@ use entities::{
     web_push_subscription::{ActiveModel, Column, Entity, Model},
 };
 
+use crate::db_handle::DbHandle;
+
 #[derive(Clone)]
 pub struct WebPushSubscriptionAdapter<'a> {
-    pub db: &'a DbConn,
+    pub db: DbHandle<'a>,
     pub query: Select<Entity>,
 }
 
 impl<'a> WebPushSubscriptionAdapter<'a> {
     pub fn init(db: &'a DbConn) -> Self {
         Self {
-            db,
+            db: DbHandle::Connection(db),
+            query: Entity::find(),
+        }
+    }
+
+    pub fn init_in_transaction(txn: &'a DatabaseTransaction) -> Self {
+        Self {
+            db: DbHandle::Transaction(txn),
             query: Entity::find(),
         }
     }
@@ -46,12 +55,12 @@ impl WebPushSubscriptionQuery for WebPushSubscriptionAdapter<'_> {
     async fn get_by_user(self, user: &user::Model) -> Result<Option<Model>, DbErr> {
         self.query
             .filter(Column::UserId.eq(user.id))
-            .one(self.db)
+            .one(&self.db)
             .await
     }
 
     async fn get_all(self) -> Result<Vec<Model>, DbErr> {
-        self.query.all(self.db).await
+        self.query.all(&self.db).await
     }
 }
 
@@ -96,7 +105,7 @@ impl WebPushSubscriptionMutation for WebPushSubscriptionAdapter<'_> {
                     ])
                     .to_owned(),
             )
-            .exec(self.db)
+            .exec(&self.db)
             .await
             .map(|res| Model {
                 id: res.last_insert_id,
@@ -110,6 +119,6 @@ impl WebPushSubscriptionMutation for WebPushSubscriptionAdapter<'_> {
     }
 
     async fn delete(self, web_push_subscription: Model) -> Result<(), DbErr> {
-        web_push_subscription.delete(self.db).await.map(|_| ())
+        web_push_subscription.delete(&self.db).await.map(|_| ())
     }
 }
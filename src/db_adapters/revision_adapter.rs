@@ -0,0 +1,112 @@
+use std::future::Future;
+
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseTransaction, DbConn, DbErr, EntityName,
+    EntityTrait, Order, QueryFilter, QueryOrder, Select, Set,
+};
+use serde::Serialize;
+use uuid::Uuid;
+
+use entities::{
+    revision::{ActiveModel, Column, Entity, Model},
+    user,
+};
+
+use crate::db_handle::DbHandle;
+
+#[derive(Clone)]
+pub struct RevisionAdapter<'a> {
+    pub db: DbHandle<'a>,
+    pub query: Select<Entity>,
+}
+
+impl<'a> RevisionAdapter<'a> {
+    pub fn init(db: &'a DbConn) -> Self {
+        Self {
+            db: DbHandle::Connection(db),
+            query: Entity::find(),
+        }
+    }
+
+    pub fn init_in_transaction(txn: &'a DatabaseTransaction) -> Self {
+        Self {
+            db: DbHandle::Transaction(txn),
+            query: Entity::find(),
+        }
+    }
+}
+
+pub trait RevisionFilter {
+    fn filter_eq_user(self, user: &user::Model) -> Self;
+    fn filter_eq_record<E: EntityName + Default>(self, record_id: Uuid) -> Self;
+}
+
+impl RevisionFilter for RevisionAdapter<'_> {
+    fn filter_eq_user(mut self, user: &user::Model) -> Self {
+        self.query = self.query.filter(Column::UserId.eq(user.id));
+        self
+    }
+
+    fn filter_eq_record<E: EntityName + Default>(mut self, record_id: Uuid) -> Self {
+        self.query = self
+            .query
+            .filter(Column::TableName.eq(E::default().table_name()))
+            .filter(Column::RecordId.eq(record_id));
+        self
+    }
+}
+
+pub trait RevisionOrder {
+    fn order_by_created_at(self, order: Order) -> Self;
+    fn order_by_id(self, order: Order) -> Self;
+}
+
+impl RevisionOrder for RevisionAdapter<'_> {
+    fn order_by_created_at(mut self, order: Order) -> Self {
+        self.query = self.query.order_by(Column::CreatedAt, order);
+        self
+    }
+
+    fn order_by_id(mut self, order: Order) -> Self {
+        self.query = self.query.order_by(Column::Id, order);
+        self
+    }
+}
+
+pub trait RevisionQuery {
+    fn get_all(self) -> impl Future<Output = Result<Vec<Model>, DbErr>>;
+    fn get_by_id(self, id: Uuid) -> impl Future<Output = Result<Option<Model>, DbErr>>;
+}
+
+impl RevisionQuery for RevisionAdapter<'_> {
+    async fn get_all(self) -> Result<Vec<Model>, DbErr> {
+        self.query.all(&self.db).await
+    }
+
+    async fn get_by_id(self, id: Uuid) -> Result<Option<Model>, DbErr> {
+        self.query.filter(Column::Id.eq(id)).one(&self.db).await
+    }
+}
+
+/// Keeps `record` as it is before an update overwrites it, so that the version can be browsed
+/// and restored later. Call this within the transaction that updates the record.
+pub(crate) async fn record_revision<C: ConnectionTrait, E: EntityName + Default>(
+    db: &C,
+    user_id: Uuid,
+    record_id: Uuid,
+    record: &impl Serialize,
+) -> Result<(), DbErr> {
+    let snapshot = serde_json::to_value(record).map_err(|e| DbErr::Json(e.to_string()))?;
+    ActiveModel {
+        id: Set(Uuid::now_v7()),
+        user_id: Set(user_id),
+        table_name: Set(E::default().table_name().to_string()),
+        record_id: Set(record_id),
+        snapshot: Set(snapshot),
+        created_at: Set(Utc::now().into()),
+    }
+    .insert(db)
+    .await
+    .map(|_| ())
+}
@@ -0,0 +1,314 @@
+use std::future::Future;
+
+use sea_orm::{
+    prelude::Expr, ColumnTrait, Condition, ConnectionTrait, DatabaseTransaction, DbBackend, DbConn,
+    DbErr, EntityTrait, FromQueryResult, ModelTrait, QueryFilter, QueryOrder, QueryResult,
+    QuerySelect, Value,
+};
+
+use entities::{
+    action, ambition, diary, direction, reading_note, sea_orm_active_enums::TagType, tag,
+    thinking_note, user,
+};
+
+use crate::db_handle::DbHandle;
+
+/// Kana, kanji and hangul. Runs of them are searched by bigrams since they are written without
+/// spaces between words. Must match the character class of `search_cjk_bigrams` in the migration.
+pub fn is_cjk(c: char) -> bool {
+    matches!(
+        c,
+        '\u{3005}'..='\u{3007}'
+            | '\u{3040}'..='\u{30ff}'
+            | '\u{3400}'..='\u{4dbf}'
+            | '\u{4e00}'..='\u{9fff}'
+            | '\u{ac00}'..='\u{d7af}'
+            | '\u{f900}'..='\u{faff}'
+            | '\u{ff66}'..='\u{ff9f}'
+    )
+}
+
+/// Splits a search query into lowercase words and runs of CJK characters, dropping everything
+/// else. e.g. "Rust 東京都、 rust!" => ["rust", "東京都"]
+pub fn search_terms(query: &str) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut current_is_cjk = false;
+    for c in query.chars() {
+        let is_word_char = c.is_alphanumeric();
+        if !current.is_empty() && (!is_word_char || is_cjk(c) != current_is_cjk) {
+            let term = std::mem::take(&mut current);
+            if !terms.contains(&term) {
+                terms.push(term);
+            }
+        }
+        if is_word_char {
+            current_is_cjk = is_cjk(c);
+            current.extend(c.to_lowercase());
+        }
+    }
+    if !current.is_empty() && !terms.contains(&current) {
+        terms.push(current);
+    }
+    terms
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Ranked<M> {
+    pub model: M,
+    pub rank: f32,
+}
+
+impl<M: FromQueryResult> FromQueryResult for Ranked<M> {
+    fn from_query_result(res: &QueryResult, pre: &str) -> Result<Self, DbErr> {
+        Ok(Self {
+            model: M::from_query_result(res, pre)?,
+            rank: res.try_get(pre, RANK_ALIAS)?,
+        })
+    }
+}
+
+/// Records matching all the terms, best match first.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct SearchResults {
+    pub ambitions: Vec<Ranked<ambition::Model>>,
+    pub directions: Vec<Ranked<direction::Model>>,
+    pub actions: Vec<Ranked<action::Model>>,
+    pub tags: Vec<Ranked<tag::Model>>,
+    pub diaries: Vec<Ranked<diary::Model>>,
+    pub reading_notes: Vec<Ranked<reading_note::Model>>,
+    pub thinking_notes: Vec<Ranked<thinking_note::Model>>,
+}
+
+#[derive(Clone)]
+pub struct SearchAdapter<'a> {
+    pub db: DbHandle<'a>,
+}
+
+impl<'a> SearchAdapter<'a> {
+    pub fn init(db: &'a DbConn) -> Self {
+        Self {
+            db: DbHandle::Connection(db),
+        }
+    }
+
+    pub fn init_in_transaction(txn: &'a DatabaseTransaction) -> Self {
+        Self {
+            db: DbHandle::Transaction(txn),
+        }
+    }
+}
+
+pub trait SearchQuery {
+    /// `terms` are from `search_terms`. At most `limit` records of each kind are returned.
+    fn search(
+        self,
+        user: &user::Model,
+        terms: &[String],
+        limit: u64,
+    ) -> impl Future<Output = Result<SearchResults, DbErr>>;
+}
+
+impl SearchQuery for SearchAdapter<'_> {
+    async fn search(
+        self,
+        user: &user::Model,
+        terms: &[String],
+        limit: u64,
+    ) -> Result<SearchResults, DbErr> {
+        if terms.is_empty() {
+            return Ok(SearchResults::default());
+        }
+        Ok(SearchResults {
+            ambitions: search_in::<ambition::Entity>(&self.db, user, terms, limit).await?,
+            directions: search_in::<direction::Entity>(&self.db, user, terms, limit).await?,
+            actions: search_in::<action::Entity>(&self.db, user, terms, limit).await?,
+            tags: search_in::<tag::Entity>(&self.db, user, terms, limit).await?,
+            diaries: search_in::<diary::Entity>(&self.db, user, terms, limit).await?,
+            reading_notes: search_in::<reading_note::Entity>(&self.db, user, terms, limit).await?,
+            thinking_notes: search_in::<thinking_note::Entity>(&self.db, user, terms, limit)
+                .await?,
+        })
+    }
+}
+
+const RANK_ALIAS: &str = "search_rank";
+
+/// Words match as prefixes. CJK runs match as a phrase of their hex encoded bigrams, the same
+/// way `search_cjk_bigrams` indexes them, and a single CJK character matches any bigram it
+/// starts. Terms only contain alphanumeric characters, so they need no escaping.
+fn to_tsquery_text(terms: &[String]) -> String {
+    terms
+        .iter()
+        .map(|term| {
+            let chars = term.chars().collect::<Vec<_>>();
+            if !chars.iter().all(|c| is_cjk(*c)) {
+                format!("'{term}':*")
+            } else if chars.len() == 1 {
+                format!("'{}':*", hex(&chars))
+            } else {
+                let bigrams = chars
+                    .windows(2)
+                    .map(|bigram| format!("'{}'", hex(bigram)))
+                    .collect::<Vec<_>>();
+                format!("({})", bigrams.join(" <-> "))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" & ")
+}
+
+fn hex(chars: &[char]) -> String {
+    chars
+        .iter()
+        .collect::<String>()
+        .bytes()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+trait Searchable: EntityTrait {
+    const ID: Self::Column;
+    const USER_ID: Self::Column;
+    /// Searched columns and their weights, the same as in the search documents of the
+    /// migration: 1.0 for weight A and 0.4 for weight B, as `ts_rank` weighs them.
+    const FIELDS: &'static [(Self::Column, f32)];
+
+    /// Trashed records are not searched.
+    fn searchable() -> Condition;
+}
+
+const A: f32 = 1.0;
+const B: f32 = 0.4;
+
+macro_rules! impl_searchable {
+    ($($entity:ident: [$($field:ident => $weight:ident),*]),*) => {
+        $(impl Searchable for $entity::Entity {
+            const ID: Self::Column = $entity::Column::Id;
+            const USER_ID: Self::Column = $entity::Column::UserId;
+            const FIELDS: &'static [(Self::Column, f32)] = &[$(($entity::Column::$field, $weight)),*];
+
+            fn searchable() -> Condition {
+                Condition::all().add($entity::Column::DeletedAt.is_null())
+            }
+        })*
+    };
+}
+
+impl_searchable!(
+    ambition: [Name => A, Description => B],
+    direction: [Name => A, Description => B],
+    action: [Name => A, Discipline => B, Memo => B],
+    diary: [Text => B],
+    reading_note: [Title => A, Text => B],
+    thinking_note: [Question => A, Thought => B, Answer => B]
+);
+
+/// Tags of ambitions, directions and actions have no name of their own. They are found as
+/// the ambitions, directions and actions.
+impl Searchable for tag::Entity {
+    const ID: Self::Column = tag::Column::Id;
+    const USER_ID: Self::Column = tag::Column::UserId;
+    const FIELDS: &'static [(Self::Column, f32)] = &[(tag::Column::Name, A)];
+
+    fn searchable() -> Condition {
+        Condition::all().add(tag::Column::Type.eq(TagType::Plain))
+    }
+}
+
+async fn search_in<E: Searchable>(
+    db: &DbHandle<'_>,
+    user: &user::Model,
+    terms: &[String],
+    limit: u64,
+) -> Result<Vec<Ranked<E::Model>>, DbErr> {
+    match db.get_database_backend() {
+        DbBackend::Postgres => search_by_tsquery::<E>(db, user, terms, limit).await,
+        _ => search_by_like::<E>(db, user, terms, limit).await,
+    }
+}
+
+async fn search_by_tsquery<E: Searchable>(
+    db: &DbHandle<'_>,
+    user: &user::Model,
+    terms: &[String],
+    limit: u64,
+) -> Result<Vec<Ranked<E::Model>>, DbErr> {
+    let query = to_tsquery_text(terms);
+    E::find()
+        .column_as(
+            Expr::cust_with_values("ts_rank(search_vector, to_tsquery('simple', $1))", [&query]),
+            RANK_ALIAS,
+        )
+        .filter(E::USER_ID.eq(user.id))
+        .filter(E::searchable())
+        .filter(Expr::cust_with_values(
+            "search_vector @@ to_tsquery('simple', $1)",
+            [&query],
+        ))
+        .order_by_desc(Expr::cust(RANK_ALIAS))
+        .order_by_asc(E::ID)
+        .limit(limit)
+        .into_model::<Ranked<E::Model>>()
+        .all(db)
+        .await
+}
+
+/// Fallback for databases without full text search, i.e. SQLite in development and tests.
+/// Terms match anywhere in the text rather than as prefixes of words, and the rank is the
+/// weight of the fields they are found in.
+async fn search_by_like<E: Searchable>(
+    db: &DbHandle<'_>,
+    user: &user::Model,
+    terms: &[String],
+    limit: u64,
+) -> Result<Vec<Ranked<E::Model>>, DbErr> {
+    let matches_all_terms = terms.iter().fold(Condition::all(), |condition, term| {
+        condition.add(E::FIELDS.iter().fold(Condition::any(), |any, (field, _)| {
+            any.add(field.like(format!("%{term}%")))
+        }))
+    });
+    let mut ranked = E::find()
+        .filter(E::USER_ID.eq(user.id))
+        .filter(E::searchable())
+        .filter(matches_all_terms)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| {
+            let rank = like_rank::<E>(&model, terms);
+            Ranked { model, rank }
+        })
+        .collect::<Vec<_>>();
+    ranked.sort_by(|a, b| {
+        b.rank
+            .total_cmp(&a.rank)
+            .then_with(|| uuid_of::<E>(&a.model).cmp(&uuid_of::<E>(&b.model)))
+    });
+    ranked.truncate(limit as usize);
+    Ok(ranked)
+}
+
+fn uuid_of<E: Searchable>(model: &E::Model) -> Option<uuid::Uuid> {
+    match model.get(E::ID) {
+        Value::Uuid(Some(id)) => Some(*id),
+        _ => None,
+    }
+}
+
+fn like_rank<E: Searchable>(model: &E::Model, terms: &[String]) -> f32 {
+    E::FIELDS
+        .iter()
+        .filter_map(|(field, weight)| match model.get(*field) {
+            Value::String(Some(text)) => Some((text.to_lowercase(), weight)),
+            _ => None,
+        })
+        .map(|(text, weight)| {
+            let found = terms
+                .iter()
+                .filter(|term| text.contains(term.as_str()))
+                .count();
+            found as f32 * weight
+        })
+        .sum()
+}
@@ -1,34 +1,55 @@
 use std::future::Future;
 
+use chrono::{DateTime, FixedOffset, Utc};
 use sea_orm::{
     prelude::Expr,
-    sea_query::NullOrdering::{First, Last},
-    ActiveModelTrait, ColumnAsExpr, ColumnTrait, DbConn, DbErr, EntityTrait, FromQueryResult,
-    IntoActiveModel,
+    sea_query::{
+        IntoCondition,
+        NullOrdering::{First, Last},
+        Query, SimpleExpr,
+    },
+    ActiveModelTrait, ColumnAsExpr, ColumnTrait, ConnectionTrait, DatabaseTransaction, DbConn,
+    DbErr, EntityTrait, FromQueryResult, IntoActiveModel,
     JoinType::LeftJoin,
     ModelTrait, Order, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Select, Set,
+    TransactionTrait,
 };
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
+use common::db::in_utc;
 use entities::{
-    action, ambition, direction,
+    action, ambition, diaries_tags, diary, direction, reading_note, reading_notes_tags,
     sea_orm_active_enums::TagType,
     tag::{ActiveModel, Column, Entity, Model, Relation},
-    user,
+    thinking_note, thinking_note_tags, user,
+};
+
+use crate::{
+    db_handle::DbHandle,
+    tombstone_adapter::{clear_deletions, record_deletions},
+    trash_adapter::tag_is_untrashed,
 };
 
 #[derive(Clone)]
 pub struct TagAdapter<'a> {
-    pub db: &'a DbConn,
+    pub db: DbHandle<'a>,
     pub query: Select<Entity>,
 }
 
 impl<'a> TagAdapter<'a> {
     pub fn init(db: &'a DbConn) -> Self {
         Self {
-            db,
-            query: Entity::find(),
+            db: DbHandle::Connection(db),
+            query: Entity::find().filter(tag_is_untrashed()),
+        }
+    }
+
+    pub fn init_in_transaction(txn: &'a DatabaseTransaction) -> Self {
+        Self {
+            db: DbHandle::Transaction(txn),
+            query: Entity::find().filter(tag_is_untrashed()),
         }
     }
 }
@@ -58,6 +79,7 @@ impl TagJoin for TagAdapter<'_> {
 
 pub trait TagFilter {
     fn filter_eq_user(self, user: &user::Model) -> Self;
+    fn filter_updated_at_gte(self, updated_at: DateTime<FixedOffset>) -> Self;
 }
 
 impl TagFilter for TagAdapter<'_> {
@@ -65,6 +87,11 @@ impl TagFilter for TagAdapter<'_> {
         self.query = self.query.filter(Column::UserId.eq(user.id));
         self
     }
+
+    fn filter_updated_at_gte(mut self, updated_at: DateTime<FixedOffset>) -> Self {
+        self.query = self.query.filter(Column::UpdatedAt.gte(in_utc(updated_at)));
+        self
+    }
 }
 
 pub trait TagOrder {
@@ -102,13 +129,20 @@ impl TagOrder for TagAdapter<'_> {
         self
     }
 
+    /// In the order the types are declared in the database, as Postgres orders enums, also on
+    /// SQLite where they are text.
     fn order_by_type(mut self, order: Order) -> Self {
-        self.query = self.query.order_by(Column::Type, order);
+        let declaration_order: SimpleExpr = Expr::case(Column::Type.eq(TagType::Ambition), 0)
+            .case(Column::Type.eq(TagType::Direction), 1)
+            .case(Column::Type.eq(TagType::Action), 2)
+            .finally(3)
+            .into();
+        self.query = self.query.order_by(declaration_order, order);
         self
     }
 }
 
-#[derive(FromQueryResult, Debug, Serialize, Deserialize, PartialEq)]
+#[derive(FromQueryResult, Debug, Serialize, Deserialize, PartialEq, ToSchema)]
 pub struct TagWithName {
     pub id: uuid::Uuid,
     pub name: String,
@@ -117,11 +151,16 @@ pub struct TagWithName {
 }
 
 pub trait TagQuery {
+    fn get_all(self) -> impl Future<Output = Result<Vec<Model>, DbErr>>;
     fn get_all_tags(self) -> impl Future<Output = Result<Vec<TagWithName>, DbErr>>;
     fn get_by_id(self, id: Uuid) -> impl Future<Output = Result<Option<Model>, DbErr>>;
 }
 
 impl TagQuery for TagAdapter<'_> {
+    async fn get_all(self) -> Result<Vec<Model>, DbErr> {
+        self.query.all(&self.db).await
+    }
+
     async fn get_all_tags(self) -> Result<Vec<TagWithName>, DbErr> {
         self.query
             .expr_as(
@@ -149,12 +188,12 @@ impl TagQuery for TagAdapter<'_> {
                 "name",
             )
             .into_model::<TagWithName>()
-            .all(self.db)
+            .all(&self.db)
             .await
     }
 
     async fn get_by_id(self, id: Uuid) -> Result<Option<Model>, DbErr> {
-        self.query.filter(Column::Id.eq(id)).one(self.db).await
+        self.query.filter(Column::Id.eq(id)).one(&self.db).await
     }
 }
 
@@ -184,23 +223,128 @@ pub trait TagMutation {
 
 impl TagMutation for TagAdapter<'_> {
     async fn create_plain(self, params: CreatePlainTagParams) -> Result<Model, DbErr> {
+        let now = Utc::now();
         ActiveModel {
             id: Set(Uuid::now_v7()),
             user_id: Set(params.user_id),
             name: Set(Some(params.name.to_owned())),
+            created_at: Set(now.into()),
+            updated_at: Set(now.into()),
             ..Default::default()
         }
-        .insert(self.db)
+        .insert(&self.db)
         .await
     }
 
     async fn update_plain(self, tag: Model, params: UpdatePlainTagParams) -> Result<Model, DbErr> {
         let mut tag = tag.into_active_model();
         tag.name = Set(Some(params.name));
-        tag.update(self.db).await
+        tag.updated_at = Set(Utc::now().into());
+        tag.update(&self.db).await
     }
 
     async fn delete(self, tag: Model) -> Result<(), DbErr> {
-        tag.delete(self.db).await.map(|_| ())
+        let txn = self.db.begin().await?;
+        record_tag_deletions(&txn, tag.user_id, Column::Id.eq(tag.id)).await?;
+        tag.delete(&txn).await?;
+        txn.commit().await
     }
 }
+
+/// Leaves tombstones for the tags matching `condition` before they are deleted, directly or by
+/// cascade. Journals linked to them are touched as well, since they lose those links.
+pub(crate) async fn record_tag_deletions<C: ConnectionTrait>(
+    db: &C,
+    user_id: Uuid,
+    condition: impl IntoCondition,
+) -> Result<(), DbErr> {
+    let tag_ids = Entity::find()
+        .select_only()
+        .column(Column::Id)
+        .filter(condition)
+        .into_tuple::<Uuid>()
+        .all(db)
+        .await?;
+    if tag_ids.is_empty() {
+        return Ok(());
+    }
+
+    touch_journals_tagged_with(db, &tag_ids).await?;
+    record_deletions::<_, Entity>(db, user_id, tag_ids).await
+}
+
+/// Brings back the tags matching `condition` after the record they belong to is restored from
+/// the trash, touching them and their journals so that clients syncing later pick them up again.
+pub(crate) async fn restore_tags<C: ConnectionTrait>(
+    db: &C,
+    condition: impl IntoCondition,
+) -> Result<(), DbErr> {
+    let tag_ids = Entity::find()
+        .select_only()
+        .column(Column::Id)
+        .filter(condition)
+        .into_tuple::<Uuid>()
+        .all(db)
+        .await?;
+    if tag_ids.is_empty() {
+        return Ok(());
+    }
+
+    Entity::update_many()
+        .col_expr(
+            Column::UpdatedAt,
+            Expr::value(DateTime::<FixedOffset>::from(Utc::now())),
+        )
+        .filter(Column::Id.is_in(tag_ids.clone()))
+        .exec(db)
+        .await?;
+    touch_journals_tagged_with(db, &tag_ids).await?;
+    clear_deletions(db, tag_ids).await
+}
+
+async fn touch_journals_tagged_with<C: ConnectionTrait>(
+    db: &C,
+    tag_ids: &[Uuid],
+) -> Result<(), DbErr> {
+    let now: DateTime<FixedOffset> = Utc::now().into();
+    diary::Entity::update_many()
+        .col_expr(diary::Column::UpdatedAt, Expr::value(now))
+        .filter(
+            diary::Column::Id.in_subquery(
+                Query::select()
+                    .column(diaries_tags::Column::DiaryId)
+                    .from(diaries_tags::Entity)
+                    .and_where(diaries_tags::Column::TagId.is_in(tag_ids.to_vec()))
+                    .to_owned(),
+            ),
+        )
+        .exec(db)
+        .await?;
+    reading_note::Entity::update_many()
+        .col_expr(reading_note::Column::UpdatedAt, Expr::value(now))
+        .filter(
+            reading_note::Column::Id.in_subquery(
+                Query::select()
+                    .column(reading_notes_tags::Column::ReadingNoteId)
+                    .from(reading_notes_tags::Entity)
+                    .and_where(reading_notes_tags::Column::TagId.is_in(tag_ids.to_vec()))
+                    .to_owned(),
+            ),
+        )
+        .exec(db)
+        .await?;
+    thinking_note::Entity::update_many()
+        .col_expr(thinking_note::Column::UpdatedAt, Expr::value(now))
+        .filter(
+            thinking_note::Column::Id.in_subquery(
+                Query::select()
+                    .column(thinking_note_tags::Column::ThinkingNoteId)
+                    .from(thinking_note_tags::Entity)
+                    .and_where(thinking_note_tags::Column::TagId.is_in(tag_ids.to_vec()))
+                    .to_owned(),
+            ),
+        )
+        .exec(db)
+        .await
+        .map(|_| ())
+}
@@ -0,0 +1,130 @@
+use std::future::Future;
+
+use chrono::{DateTime, FixedOffset, Utc};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseTransaction, DbConn, DbErr, EntityName, EntityTrait,
+    Order, QueryFilter, QueryOrder, Select, Set,
+};
+use uuid::Uuid;
+
+use common::db::in_utc;
+use entities::{
+    tombstone::{ActiveModel, Column, Entity, Model},
+    user,
+};
+
+use crate::db_handle::DbHandle;
+
+#[derive(Clone)]
+pub struct TombstoneAdapter<'a> {
+    pub db: DbHandle<'a>,
+    pub query: Select<Entity>,
+}
+
+impl<'a> TombstoneAdapter<'a> {
+    pub fn init(db: &'a DbConn) -> Self {
+        Self {
+            db: DbHandle::Connection(db),
+            query: Entity::find(),
+        }
+    }
+
+    pub fn init_in_transaction(txn: &'a DatabaseTransaction) -> Self {
+        Self {
+            db: DbHandle::Transaction(txn),
+            query: Entity::find(),
+        }
+    }
+}
+
+pub trait TombstoneFilter {
+    fn filter_eq_user(self, user: &user::Model) -> Self;
+    fn filter_deleted_at_gte(self, deleted_at: DateTime<FixedOffset>) -> Self;
+}
+
+impl TombstoneFilter for TombstoneAdapter<'_> {
+    fn filter_eq_user(mut self, user: &user::Model) -> Self {
+        self.query = self.query.filter(Column::UserId.eq(user.id));
+        self
+    }
+
+    fn filter_deleted_at_gte(mut self, deleted_at: DateTime<FixedOffset>) -> Self {
+        self.query = self.query.filter(Column::DeletedAt.gte(in_utc(deleted_at)));
+        self
+    }
+}
+
+pub trait TombstoneOrder {
+    fn order_by_deleted_at(self, order: Order) -> Self;
+}
+
+impl TombstoneOrder for TombstoneAdapter<'_> {
+    fn order_by_deleted_at(mut self, order: Order) -> Self {
+        self.query = self.query.order_by(Column::DeletedAt, order);
+        self
+    }
+}
+
+pub trait TombstoneQuery {
+    fn get_all(self) -> impl Future<Output = Result<Vec<Model>, DbErr>>;
+}
+
+impl TombstoneQuery for TombstoneAdapter<'_> {
+    async fn get_all(self) -> Result<Vec<Model>, DbErr> {
+        self.query.all(&self.db).await
+    }
+}
+
+pub trait TombstoneMutation {
+    fn prune(
+        self,
+        deleted_before: DateTime<FixedOffset>,
+    ) -> impl Future<Output = Result<u64, DbErr>>;
+}
+
+impl TombstoneMutation for TombstoneAdapter<'_> {
+    async fn prune(self, deleted_before: DateTime<FixedOffset>) -> Result<u64, DbErr> {
+        Entity::delete_many()
+            .filter(Column::DeletedAt.lt(in_utc(deleted_before)))
+            .exec(&self.db)
+            .await
+            .map(|res| res.rows_affected)
+    }
+}
+
+/// Leaves tombstones for deleted records so that clients syncing later can drop them too.
+/// Call this within the transaction that deletes the records, including the ones deleted by
+/// cascade.
+pub(crate) async fn record_deletions<C: ConnectionTrait, E: EntityName + Default>(
+    db: &C,
+    user_id: Uuid,
+    record_ids: impl IntoIterator<Item = Uuid>,
+) -> Result<(), DbErr> {
+    let table_name = E::default().table_name().to_string();
+    let now = Utc::now();
+    let tombstones = record_ids.into_iter().map(|record_id| ActiveModel {
+        id: Set(Uuid::now_v7()),
+        user_id: Set(user_id),
+        table_name: Set(table_name.clone()),
+        record_id: Set(record_id),
+        deleted_at: Set(now.into()),
+    });
+    Entity::insert_many(tombstones)
+        .on_empty_do_nothing()
+        .exec(db)
+        .await
+        .map(|_| ())
+}
+
+/// Drops the tombstones of records restored from the trash. Restoring them also touches
+/// `updated_at`, so clients which already dropped them receive them again on their next sync.
+pub(crate) async fn clear_deletions<C: ConnectionTrait>(
+    db: &C,
+    record_ids: impl IntoIterator<Item = Uuid>,
+) -> Result<(), DbErr> {
+    Entity::delete_many()
+        .filter(Column::RecordId.is_in(record_ids))
+        .exec(db)
+        .await
+        .map(|_| ())
+}
@@ -0,0 +1,345 @@
+use std::future::Future;
+
+use chrono::{DateTime, FixedOffset, Utc};
+use sea_orm::{
+    prelude::Expr,
+    sea_query::{Query, SelectStatement},
+    ColumnTrait, Condition, ConnectionTrait, DatabaseTransaction, DbConn, DbErr, EntityTrait,
+    QueryFilter, QuerySelect, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use common::db::in_utc;
+use entities::{
+    action, action_goal, action_track, ambition, diary, direction, reading_note, revision, tag,
+    thinking_note, user,
+};
+
+use crate::{
+    db_handle::DbHandle, tag_adapter::restore_tags, tombstone_adapter::clear_deletions, CustomDbErr,
+};
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum TrashKind {
+    Ambition,
+    Direction,
+    Action,
+    Diary,
+    ReadingNote,
+    ThinkingNote,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct TrashedRecord {
+    pub kind: TrashKind,
+    pub id: Uuid,
+    pub summary: Option<String>,
+    pub deleted_at: DateTime<FixedOffset>,
+}
+
+#[derive(Clone)]
+pub struct TrashAdapter<'a> {
+    pub db: DbHandle<'a>,
+}
+
+impl<'a> TrashAdapter<'a> {
+    pub fn init(db: &'a DbConn) -> Self {
+        Self {
+            db: DbHandle::Connection(db),
+        }
+    }
+
+    pub fn init_in_transaction(txn: &'a DatabaseTransaction) -> Self {
+        Self {
+            db: DbHandle::Transaction(txn),
+        }
+    }
+}
+
+pub trait TrashQuery {
+    fn get_all_of_user(
+        self,
+        user: &user::Model,
+    ) -> impl Future<Output = Result<Vec<TrashedRecord>, DbErr>>;
+}
+
+impl TrashQuery for TrashAdapter<'_> {
+    async fn get_all_of_user(self, user: &user::Model) -> Result<Vec<TrashedRecord>, DbErr> {
+        let mut records = [
+            get_trashed::<ambition::Entity>(
+                &self.db,
+                user,
+                TrashKind::Ambition,
+                ambition::Column::Name,
+            )
+            .await?,
+            get_trashed::<direction::Entity>(
+                &self.db,
+                user,
+                TrashKind::Direction,
+                direction::Column::Name,
+            )
+            .await?,
+            get_trashed::<action::Entity>(&self.db, user, TrashKind::Action, action::Column::Name)
+                .await?,
+            get_trashed::<diary::Entity>(&self.db, user, TrashKind::Diary, diary::Column::Date)
+                .await?,
+            get_trashed::<reading_note::Entity>(
+                &self.db,
+                user,
+                TrashKind::ReadingNote,
+                reading_note::Column::Title,
+            )
+            .await?,
+            get_trashed::<thinking_note::Entity>(
+                &self.db,
+                user,
+                TrashKind::ThinkingNote,
+                thinking_note::Column::Question,
+            )
+            .await?,
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+        records.sort_by_key(|record| std::cmp::Reverse((record.deleted_at, record.id)));
+        Ok(records)
+    }
+}
+
+pub trait TrashMutation {
+    fn restore(
+        self,
+        user: &user::Model,
+        kind: TrashKind,
+        id: Uuid,
+    ) -> impl Future<Output = Result<(), DbErr>>;
+    fn purge(
+        self,
+        deleted_before: DateTime<FixedOffset>,
+    ) -> impl Future<Output = Result<u64, DbErr>>;
+}
+
+impl TrashMutation for TrashAdapter<'_> {
+    async fn restore(self, user: &user::Model, kind: TrashKind, id: Uuid) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+        match kind {
+            TrashKind::Ambition => {
+                restore_record::<ambition::Entity>(&txn, user, id).await?;
+                restore_tags(&txn, tag::Column::AmbitionId.eq(id)).await?;
+            }
+            TrashKind::Direction => {
+                restore_record::<direction::Entity>(&txn, user, id).await?;
+                restore_tags(&txn, tag::Column::DirectionId.eq(id)).await?;
+            }
+            TrashKind::Action => {
+                restore_record::<action::Entity>(&txn, user, id).await?;
+                restore_action_children(&txn, id).await?;
+                restore_tags(&txn, tag::Column::ActionId.eq(id)).await?;
+            }
+            TrashKind::Diary => restore_record::<diary::Entity>(&txn, user, id).await?,
+            TrashKind::ReadingNote => {
+                restore_record::<reading_note::Entity>(&txn, user, id).await?
+            }
+            TrashKind::ThinkingNote => {
+                restore_record::<thinking_note::Entity>(&txn, user, id).await?
+            }
+        }
+        txn.commit().await
+    }
+
+    async fn purge(self, deleted_before: DateTime<FixedOffset>) -> Result<u64, DbErr> {
+        let deleted_before = in_utc(deleted_before);
+        let txn = self.db.begin().await?;
+        let mut purged = 0;
+        purged += purge_table::<ambition::Entity>(&txn, deleted_before).await?;
+        purged += purge_table::<direction::Entity>(&txn, deleted_before).await?;
+        purged += purge_table::<action::Entity>(&txn, deleted_before).await?;
+        purged += purge_table::<diary::Entity>(&txn, deleted_before).await?;
+        purged += purge_table::<reading_note::Entity>(&txn, deleted_before).await?;
+        purged += purge_table::<thinking_note::Entity>(&txn, deleted_before).await?;
+        txn.commit().await?;
+        Ok(purged)
+    }
+}
+
+/// Ids of the actions which are not in the trash. Goals and tracks of trashed actions are
+/// hidden along with them.
+pub(crate) fn untrashed_action_ids() -> SelectStatement {
+    Query::select()
+        .column(action::Column::Id)
+        .from(action::Entity)
+        .and_where(action::Column::DeletedAt.is_null())
+        .to_owned()
+}
+
+/// Tags of trashed ambitions, directions and actions are hidden along with them.
+pub(crate) fn tag_is_untrashed() -> Condition {
+    Condition::all()
+        .add(
+            Condition::any().add(tag::Column::AmbitionId.is_null()).add(
+                tag::Column::AmbitionId.in_subquery(
+                    Query::select()
+                        .column(ambition::Column::Id)
+                        .from(ambition::Entity)
+                        .and_where(ambition::Column::DeletedAt.is_null())
+                        .to_owned(),
+                ),
+            ),
+        )
+        .add(
+            Condition::any()
+                .add(tag::Column::DirectionId.is_null())
+                .add(
+                    tag::Column::DirectionId.in_subquery(
+                        Query::select()
+                            .column(direction::Column::Id)
+                            .from(direction::Entity)
+                            .and_where(direction::Column::DeletedAt.is_null())
+                            .to_owned(),
+                    ),
+                ),
+        )
+        .add(
+            Condition::any()
+                .add(tag::Column::ActionId.is_null())
+                .add(tag::Column::ActionId.in_subquery(untrashed_action_ids())),
+        )
+}
+
+trait Trashable: EntityTrait {
+    const ID: Self::Column;
+    const USER_ID: Self::Column;
+    const UPDATED_AT: Self::Column;
+    const DELETED_AT: Self::Column;
+}
+
+macro_rules! impl_trashable {
+    ($($entity:ident),*) => {
+        $(impl Trashable for $entity::Entity {
+            const ID: Self::Column = $entity::Column::Id;
+            const USER_ID: Self::Column = $entity::Column::UserId;
+            const UPDATED_AT: Self::Column = $entity::Column::UpdatedAt;
+            const DELETED_AT: Self::Column = $entity::Column::DeletedAt;
+        })*
+    };
+}
+
+impl_trashable!(
+    ambition,
+    direction,
+    action,
+    diary,
+    reading_note,
+    thinking_note
+);
+
+async fn get_trashed<E: Trashable>(
+    db: &DbHandle<'_>,
+    user: &user::Model,
+    kind: TrashKind,
+    summary: E::Column,
+) -> Result<Vec<TrashedRecord>, DbErr> {
+    let rows = E::find()
+        .select_only()
+        .column(E::ID)
+        .column_as(Expr::col(summary).cast_as("text"), "summary")
+        .column(E::DELETED_AT)
+        .filter(E::USER_ID.eq(user.id))
+        .filter(E::DELETED_AT.is_not_null())
+        .into_tuple::<(Uuid, Option<String>, DateTime<FixedOffset>)>()
+        .all(db)
+        .await?;
+    Ok(rows
+        .into_iter()
+        .map(|(id, summary, deleted_at)| TrashedRecord {
+            kind,
+            id,
+            summary,
+            deleted_at,
+        })
+        .collect())
+}
+
+async fn restore_record<E: Trashable>(
+    db: &impl ConnectionTrait,
+    user: &user::Model,
+    id: Uuid,
+) -> Result<(), DbErr> {
+    let result = E::update_many()
+        .col_expr(
+            E::DELETED_AT,
+            Expr::value(Option::<DateTime<FixedOffset>>::None),
+        )
+        .col_expr(
+            E::UPDATED_AT,
+            Expr::value(DateTime::<FixedOffset>::from(Utc::now())),
+        )
+        .filter(E::ID.eq(id))
+        .filter(E::USER_ID.eq(user.id))
+        .filter(E::DELETED_AT.is_not_null())
+        .exec(db)
+        .await?;
+    if result.rows_affected == 0 {
+        return Err(DbErr::Custom(CustomDbErr::NotFound.to_string()));
+    }
+    clear_deletions(db, [id]).await
+}
+
+async fn restore_action_children(db: &impl ConnectionTrait, action_id: Uuid) -> Result<(), DbErr> {
+    let now: DateTime<FixedOffset> = Utc::now().into();
+    let action_goal_ids = action_goal::Entity::find()
+        .select_only()
+        .column(action_goal::Column::Id)
+        .filter(action_goal::Column::ActionId.eq(action_id))
+        .into_tuple::<Uuid>()
+        .all(db)
+        .await?;
+    action_goal::Entity::update_many()
+        .col_expr(action_goal::Column::UpdatedAt, Expr::value(now))
+        .filter(action_goal::Column::ActionId.eq(action_id))
+        .exec(db)
+        .await?;
+    let action_track_ids = action_track::Entity::find()
+        .select_only()
+        .column(action_track::Column::Id)
+        .filter(action_track::Column::ActionId.eq(action_id))
+        .into_tuple::<Uuid>()
+        .all(db)
+        .await?;
+    action_track::Entity::update_many()
+        .col_expr(action_track::Column::UpdatedAt, Expr::value(now))
+        .filter(action_track::Column::ActionId.eq(action_id))
+        .exec(db)
+        .await?;
+    clear_deletions(db, action_goal_ids.into_iter().chain(action_track_ids)).await
+}
+
+/// Records in the trash are deleted for good once they have been there past the retention period.
+/// Their goals, tracks, tags and links go with them by cascade, their revisions are deleted here.
+async fn purge_table<E: Trashable + Default>(
+    db: &impl ConnectionTrait,
+    deleted_before: DateTime<FixedOffset>,
+) -> Result<u64, DbErr> {
+    revision::Entity::delete_many()
+        .filter(revision::Column::TableName.eq(E::default().table_name()))
+        .filter(
+            revision::Column::RecordId.in_subquery(
+                Query::select()
+                    .column(E::ID)
+                    .from(E::default())
+                    .and_where(E::DELETED_AT.lt(deleted_before))
+                    .to_owned(),
+            ),
+        )
+        .exec(db)
+        .await?;
+    E::delete_many()
+        .filter(E::DELETED_AT.lt(deleted_before))
+        .exec(db)
+        .await
+        .map(|result| result.rows_affected)
+}
@@ -2,26 +2,36 @@ use std::future::Future;
 
 use chrono::{DateTime, FixedOffset, Utc};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DbConn, DbErr, EntityTrait, IntoActiveModel, QueryFilter,
-    Select, Set,
+    ActiveModelTrait, ColumnTrait, DatabaseTransaction, DbConn, DbErr, EntityTrait,
+    IntoActiveModel, QueryFilter, Select, Set,
 };
 use uuid::Uuid;
 
+use common::db::in_utc;
 use entities::{
     sea_orm_active_enums::TimezoneEnum,
     user::{ActiveModel, Column, Entity, Model},
 };
 
+use crate::db_handle::DbHandle;
+
 #[derive(Clone)]
 pub struct UserAdapter<'a> {
-    pub db: &'a DbConn,
+    pub db: DbHandle<'a>,
     pub query: Select<Entity>,
 }
 
 impl<'a> UserAdapter<'a> {
     pub fn init(db: &'a DbConn) -> Self {
         Self {
-            db,
+            db: DbHandle::Connection(db),
+            query: Entity::find(),
+        }
+    }
+
+    pub fn init_in_transaction(txn: &'a DatabaseTransaction) -> Self {
+        Self {
+            db: DbHandle::Transaction(txn),
             query: Entity::find(),
         }
     }
@@ -45,13 +55,13 @@ pub trait UserQuery {
 
 impl UserQuery for UserAdapter<'_> {
     async fn get_by_id(self, id: Uuid) -> Result<Option<Model>, DbErr> {
-        self.query.filter(Column::Id.eq(id)).one(self.db).await
+        self.query.filter(Column::Id.eq(id)).one(&self.db).await
     }
 
     async fn get_by_email(self, email: String) -> Result<Option<Model>, DbErr> {
         self.query
             .filter(Column::Email.eq(email))
-            .one(self.db)
+            .one(&self.db)
             .await
     }
 }
@@ -95,7 +105,7 @@ impl UserMutation for UserAdapter<'_> {
             created_at: Set(now.into()),
             updated_at: Set(now.into()),
         }
-        .insert(self.db)
+        .insert(&self.db)
         .await
     }
 
@@ -103,14 +113,14 @@ impl UserMutation for UserAdapter<'_> {
         let mut user = user.into_active_model();
         user.is_active = Set(true);
         user.updated_at = Set(Utc::now().into());
-        user.update(self.db).await
+        user.update(&self.db).await
     }
 
     async fn update_password(self, user: Model, password: String) -> Result<Model, DbErr> {
         let mut user = user.into_active_model();
         user.password = Set(password);
         user.updated_at = Set(Utc::now().into());
-        user.update(self.db).await
+        user.update(&self.db).await
     }
 
     async fn update_first_track_at(
@@ -119,8 +129,8 @@ impl UserMutation for UserAdapter<'_> {
         first_track_at: Option<DateTime<FixedOffset>>,
     ) -> Result<Model, DbErr> {
         let mut user = user.into_active_model();
-        user.first_track_at = Set(first_track_at);
+        user.first_track_at = Set(first_track_at.map(in_utc));
         user.updated_at = Set(Utc::now().into());
-        user.update(self.db).await
+        user.update(&self.db).await
     }
 }
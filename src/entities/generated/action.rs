@@ -19,6 +19,7 @@ pub struct Model {
     pub color: String,
     pub track_type: ActionTrackType,
     pub memo: Option<String>,
+    pub deleted_at: Option<DateTimeWithTimeZone>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -27,6 +28,8 @@ pub enum Relation {
     ActionGoal,
     #[sea_orm(has_many = "super::action_track::Entity")]
     ActionTrack,
+    #[sea_orm(has_many = "super::directions_actions::Entity")]
+    DirectionsActions,
     #[sea_orm(has_many = "super::notification_rule::Entity")]
     NotificationRule,
     #[sea_orm(has_one = "super::tag::Entity")]
@@ -53,6 +56,12 @@ impl Related<super::action_track::Entity> for Entity {
     }
 }
 
+impl Related<super::directions_actions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::DirectionsActions.def()
+    }
+}
+
 impl Related<super::notification_rule::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::NotificationRule.def()
@@ -71,4 +80,13 @@ impl Related<super::user::Entity> for Entity {
     }
 }
 
+impl Related<super::direction::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::directions_actions::Relation::Direction.def()
+    }
+    fn via() -> Option<RelationDef> {
+        Some(super::directions_actions::Relation::Action.def().rev())
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -14,6 +14,7 @@ pub struct Model {
     pub to_date: Option<Date>,
     pub duration_seconds: Option<i32>,
     pub count: Option<i32>,
+    pub updated_at: DateTimeWithTimeZone,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -15,10 +15,13 @@ pub struct Model {
     pub updated_at: DateTimeWithTimeZone,
     pub archived: bool,
     pub ordering: Option<i32>,
+    pub deleted_at: Option<DateTimeWithTimeZone>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::ambitions_directions::Entity")]
+    AmbitionsDirections,
     #[sea_orm(has_one = "super::tag::Entity")]
     Tag,
     #[sea_orm(
@@ -31,6 +34,12 @@ pub enum Relation {
     User,
 }
 
+impl Related<super::ambitions_directions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AmbitionsDirections.def()
+    }
+}
+
 impl Related<super::tag::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Tag.def()
@@ -43,4 +52,13 @@ impl Related<super::user::Entity> for Entity {
     }
 }
 
+impl Related<super::direction::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::ambitions_directions::Relation::Direction.def()
+    }
+    fn via() -> Option<RelationDef> {
+        Some(super::ambitions_directions::Relation::Ambition.def().rev())
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
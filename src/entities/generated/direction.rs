@@ -16,10 +16,13 @@ pub struct Model {
     pub archived: bool,
     pub ordering: Option<i32>,
     pub category_id: Option<Uuid>,
+    pub deleted_at: Option<DateTimeWithTimeZone>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::ambitions_directions::Entity")]
+    AmbitionsDirections,
     #[sea_orm(
         belongs_to = "super::direction_category::Entity",
         from = "Column::CategoryId",
@@ -28,6 +31,8 @@ pub enum Relation {
         on_delete = "SetNull"
     )]
     DirectionCategory,
+    #[sea_orm(has_many = "super::directions_actions::Entity")]
+    DirectionsActions,
     #[sea_orm(has_many = "super::tag::Entity")]
     Tag,
     #[sea_orm(
@@ -40,12 +45,24 @@ pub enum Relation {
     User,
 }
 
+impl Related<super::ambitions_directions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AmbitionsDirections.def()
+    }
+}
+
 impl Related<super::direction_category::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::DirectionCategory.def()
     }
 }
 
+impl Related<super::directions_actions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::DirectionsActions.def()
+    }
+}
+
 impl Related<super::tag::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Tag.def()
@@ -58,4 +75,22 @@ impl Related<super::user::Entity> for Entity {
     }
 }
 
+impl Related<super::action::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::directions_actions::Relation::Action.def()
+    }
+    fn via() -> Option<RelationDef> {
+        Some(super::directions_actions::Relation::Direction.def().rev())
+    }
+}
+
+impl Related<super::ambition::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::ambitions_directions::Relation::Ambition.def()
+    }
+    fn via() -> Option<RelationDef> {
+        Some(super::ambitions_directions::Relation::Direction.def().rev())
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -11,12 +11,22 @@ pub struct Model {
     pub user_id: Uuid,
     pub name: String,
     pub ordering: Option<i32>,
+    pub updated_at: DateTimeWithTimeZone,
+    pub parent_id: Option<Uuid>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
     #[sea_orm(has_many = "super::direction::Entity")]
     Direction,
+    #[sea_orm(
+        belongs_to = "Entity",
+        from = "Column::ParentId",
+        to = "Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    SelfRef,
     #[sea_orm(
         belongs_to = "super::user::Entity",
         from = "Column::UserId",
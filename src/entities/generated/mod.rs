@@ -6,16 +6,20 @@ pub mod action;
 pub mod action_goal;
 pub mod action_track;
 pub mod ambition;
+pub mod ambitions_directions;
 pub mod diaries_tags;
 pub mod diary;
 pub mod direction;
 pub mod direction_category;
+pub mod directions_actions;
 pub mod notification_rule;
 pub mod reading_note;
 pub mod reading_notes_tags;
+pub mod revision;
 pub mod sea_orm_active_enums;
 pub mod tag;
 pub mod thinking_note;
 pub mod thinking_note_tags;
+pub mod tombstone;
 pub mod user;
 pub mod web_push_subscription;
@@ -4,15 +4,19 @@ pub use super::action::Entity as Action;
 pub use super::action_goal::Entity as ActionGoal;
 pub use super::action_track::Entity as ActionTrack;
 pub use super::ambition::Entity as Ambition;
+pub use super::ambitions_directions::Entity as AmbitionsDirections;
 pub use super::diaries_tags::Entity as DiariesTags;
 pub use super::diary::Entity as Diary;
 pub use super::direction::Entity as Direction;
 pub use super::direction_category::Entity as DirectionCategory;
+pub use super::directions_actions::Entity as DirectionsActions;
 pub use super::notification_rule::Entity as NotificationRule;
 pub use super::reading_note::Entity as ReadingNote;
 pub use super::reading_notes_tags::Entity as ReadingNotesTags;
+pub use super::revision::Entity as Revision;
 pub use super::tag::Entity as Tag;
 pub use super::thinking_note::Entity as ThinkingNote;
 pub use super::thinking_note_tags::Entity as ThinkingNoteTags;
+pub use super::tombstone::Entity as Tombstone;
 pub use super::user::Entity as User;
 pub use super::web_push_subscription::Entity as WebPushSubscription;
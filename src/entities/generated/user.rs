@@ -41,10 +41,14 @@ pub enum Relation {
     NotificationRule,
     #[sea_orm(has_many = "super::reading_note::Entity")]
     ReadingNote,
+    #[sea_orm(has_many = "super::revision::Entity")]
+    Revision,
     #[sea_orm(has_many = "super::tag::Entity")]
     Tag,
     #[sea_orm(has_many = "super::thinking_note::Entity")]
     ThinkingNote,
+    #[sea_orm(has_many = "super::tombstone::Entity")]
+    Tombstone,
     #[sea_orm(has_one = "super::web_push_subscription::Entity")]
     WebPushSubscription,
 }
@@ -103,6 +107,12 @@ impl Related<super::reading_note::Entity> for Entity {
     }
 }
 
+impl Related<super::revision::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Revision.def()
+    }
+}
+
 impl Related<super::tag::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Tag.def()
@@ -115,6 +125,12 @@ impl Related<super::thinking_note::Entity> for Entity {
     }
 }
 
+impl Related<super::tombstone::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Tombstone.def()
+    }
+}
+
 impl Related<super::web_push_subscription::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::WebPushSubscription.def()
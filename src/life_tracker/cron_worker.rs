@@ -0,0 +1,36 @@
+use common::settings::get_settings;
+use cron_processes::notification::run_cron_processes;
+use tracing::{event, Level};
+
+mod telemetry;
+
+/// Runs cron processes without the web server.
+/// Each job run takes a Redis lock, so this can run alongside web processes and other workers.
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    let settings = get_settings(".env").expect("Error on getting settings.");
+
+    let _guard = telemetry::init_subscriber(
+        settings.debug,
+        settings.application.max_log_files,
+        Some("cron_worker"),
+    );
+
+    if let Err(e) = run_cron_processes(settings).await {
+        event!(
+            Level::ERROR,
+            "Some error in run_cron_processes, stopping cron_worker. {:?}",
+            e
+        );
+        return Ok(());
+    };
+
+    event!(target: "backend", Level::INFO, "cron_worker started.");
+
+    actix_web::rt::signal::ctrl_c().await?;
+
+    event!(target: "backend", Level::INFO, "cron_worker stopping.");
+
+    drop(_guard);
+    Ok(())
+}
@@ -9,20 +9,22 @@ mod telemetry;
 async fn main() -> std::io::Result<()> {
     let settings = get_settings(".env").expect("Error on getting settings.");
 
-    // MYMEMO: introduce tracing_actix_web
+    // MYMEMO: Request logs are now traced by RequestTracing middleware.
     // - Should log some message at the beginning of all main functions.
-    // - Logs should be more readable.
     // - More system info should be collected automatically.
-    let _guard = telemetry::init_subscriber(settings.debug, settings.application.max_log_files);
-
-    if let Err(e) = run_cron_processes(settings.clone()).await {
-        event!(
-            Level::ERROR,
-            "Some error in run_cron_processes, stopping server. {:?}",
-            e
-        );
-        return Ok(());
-    };
+    let _guard =
+        telemetry::init_subscriber(settings.debug, settings.application.max_log_files, None);
+
+    if settings.application.run_cron_processes {
+        if let Err(e) = run_cron_processes(settings.clone()).await {
+            event!(
+                Level::ERROR,
+                "Some error in run_cron_processes, stopping server. {:?}",
+                e
+            );
+            return Ok(());
+        };
+    }
 
     let application = startup::Application::build(settings).await?;
 
@@ -3,7 +3,8 @@ use actix_web::{dev::Server, middleware::Compress, web::Data, App, HttpServer};
 use common::{db::init_db, redis::init_redis_pool, settings::types::Settings};
 use sea_orm::DatabaseConnection;
 use server::{
-    auth_middleware::AuthenticateUser, get_preps_for_redis_session_store, get_routes,
+    auth_middleware::AuthenticateUser, csrf_middleware::CsrfProtection, get_health_routes,
+    get_preps_for_redis_session_store, get_routes, request_tracing_middleware::RequestTracing,
     setup_session_middleware_builder,
 };
 
@@ -52,6 +53,7 @@ async fn run(
         App::new()
             .wrap(Compress::default())
             .wrap(AuthenticateUser)
+            .wrap(CsrfProtection)
             .wrap(
                 setup_session_middleware_builder(
                     SessionMiddleware::builder(redis_store.clone(), secret_key.clone()),
@@ -59,6 +61,8 @@ async fn run(
                 )
                 .build(),
             )
+            .wrap(RequestTracing)
+            .service(get_health_routes())
             .service(get_routes())
             .app_data(Data::new(db.clone()))
             .app_data(Data::new(redis_pool.clone()))
@@ -7,6 +7,7 @@ use tracing_subscriber::layer::SubscriberExt;
 pub fn get_subscriber(
     debug: bool,
     max_log_files: usize,
+    log_file_prefix: Option<&str>,
 ) -> (impl tracing::Subscriber + Send + Sync, WorkerGuard) {
     let env_filter = if debug {
         "debug".to_string()
@@ -23,10 +24,14 @@ pub fn get_subscriber(
         None
     };
 
-    let file_appender = RollingFileAppender::builder()
+    let mut file_appender_builder = RollingFileAppender::builder()
         .rotation(Rotation::DAILY)
         .filename_suffix("log")
-        .max_log_files(max_log_files)
+        .max_log_files(max_log_files);
+    if let Some(prefix) = log_file_prefix {
+        file_appender_builder = file_appender_builder.filename_prefix(prefix);
+    }
+    let file_appender = file_appender_builder
         .build("./logs")
         .expect("initializing rolling file appender failed");
     let (non_blocking, _guard) = tracing_appender::non_blocking(file_appender);
@@ -42,8 +47,12 @@ pub fn get_subscriber(
     (subscriber, _guard)
 }
 
-pub fn init_subscriber(debug: bool, max_log_files: usize) -> WorkerGuard {
-    let (subscriber, _guard) = get_subscriber(debug, max_log_files);
+pub fn init_subscriber(
+    debug: bool,
+    max_log_files: usize,
+    log_file_prefix: Option<&str>,
+) -> WorkerGuard {
+    let (subscriber, _guard) = get_subscriber(debug, max_log_files, log_file_prefix);
     tracing::subscriber::set_global_default(subscriber).expect("Failed to set subscriber");
     _guard
 }
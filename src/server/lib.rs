@@ -2,16 +2,34 @@ use actix_session::{
     config::{PersistentSession, SessionMiddlewareBuilder},
     storage::RedisSessionStore,
 };
-use actix_web::{cookie, web::scope, Scope};
+use actix_web::{
+    body::{BoxBody, EitherBody},
+    cookie,
+    dev::{ServiceFactory, ServiceRequest, ServiceResponse},
+    get,
+    web::{JsonConfig, PathConfig, QueryConfig},
+    Error,
+};
 use common::settings::types::Settings;
+use openapi::openapi_routes;
 use web_adapters::{
     action_goal_routes, action_routes, action_track_routes, ambition_routes, auth_routes,
-    diary_routes, direction_category_routes, direction_routes, journal_routes,
-    notification_rule_routes, reading_note_routes, tag_routes, thinking_note_routes,
-    web_push_subscription_routes,
+    batch_routes, diary_routes, direction_category_routes, direction_routes, event_routes,
+    health_routes, journal_routes, json_error_handler, my_way_tree_routes,
+    notification_rule_routes, path_error_handler, query_error_handler, reading_note_routes,
+    search_routes, sync_routes, tag_routes, thinking_note_routes, trash_routes,
+    web_push_subscription_routes, SESSION_COOKIE_NAME,
+};
+use utoipa_actix_web::scope::{scope, Scope};
+
+pub use openapi::api_doc;
+pub use web_adapters::{
+    auth_middleware, csrf_middleware, rate_limit_middleware, request_tracing_middleware,
 };
 
-pub use web_adapters::auth_middleware;
+use rate_limit_middleware::{RateLimitScope, RateLimiter};
+
+mod openapi;
 
 pub async fn get_preps_for_redis_session_store(
     settings: &Settings,
@@ -33,7 +51,7 @@ pub fn setup_session_middleware_builder(
             .session_lifecycle(PersistentSession::default().session_ttl(
                 cookie::time::Duration::days(settings.application.session_lifetime_days),
             ))
-            .cookie_name("sessionId".to_string())
+            .cookie_name(SESSION_COOKIE_NAME.to_string())
             .cookie_same_site(cookie::SameSite::None)
             .cookie_secure(false)
     } else {
@@ -41,17 +59,30 @@ pub fn setup_session_middleware_builder(
             .session_lifecycle(PersistentSession::default().session_ttl(
                 cookie::time::Duration::days(settings.application.session_lifetime_days),
             ))
-            .cookie_name("sessionId".to_string())
+            .cookie_name(SESSION_COOKIE_NAME.to_string())
     }
 }
 
-pub fn get_routes() -> Scope {
+pub fn get_routes() -> Scope<
+    impl ServiceFactory<
+        ServiceRequest,
+        Config = (),
+        Response = ServiceResponse<EitherBody<BoxBody>>,
+        Error = Error,
+        InitError = (),
+    >,
+> {
     scope("/api")
+        .wrap(RateLimiter::new(RateLimitScope::Api))
+        .app_data(JsonConfig::default().error_handler(json_error_handler))
+        .app_data(QueryConfig::default().error_handler(query_error_handler))
+        .app_data(PathConfig::default().error_handler(path_error_handler))
         .service(health_check)
         .configure(auth_routes)
         .configure(ambition_routes)
         .configure(direction_routes)
         .configure(action_routes)
+        .configure(my_way_tree_routes)
         .configure(reading_note_routes)
         .configure(tag_routes)
         .configure(action_track_routes)
@@ -62,9 +93,38 @@ pub fn get_routes() -> Scope {
         .configure(thinking_note_routes)
         .configure(web_push_subscription_routes)
         .configure(notification_rule_routes)
+        .configure(sync_routes)
+        .configure(event_routes)
+        .configure(batch_routes)
+        .configure(trash_routes)
+        .configure(search_routes)
+        .configure(openapi_routes)
+}
+
+/// Probes from load balancers and orchestrators, which come from a few IPs, so they are kept out
+/// of the rate limited `get_routes`. Register this before `get_routes`, which would take every
+/// path under /api otherwise.
+pub fn get_health_routes() -> Scope<
+    impl ServiceFactory<
+        ServiceRequest,
+        Config = (),
+        Response = ServiceResponse,
+        Error = Error,
+        InitError = (),
+    >,
+> {
+    scope("/api/health").configure(health_routes)
 }
 
-#[actix_web::get("/health-check")]
+/// Kept for existing monitors. Same as /health/live.
+#[utoipa::path(
+    tag = "health",
+    security(()),
+    responses(
+        (status = 200, body = String),
+    )
+)]
+#[get("/health-check")]
 pub async fn health_check() -> actix_web::HttpResponse {
     actix_web::HttpResponse::Ok().json("Application is safe and healthy.")
 }
@@ -0,0 +1,216 @@
+use std::sync::OnceLock;
+
+use actix_web::{get, http::header::ContentType, App, HttpResponse};
+use utoipa::{
+    openapi::{
+        security::{ApiKey, ApiKeyValue, SecurityScheme},
+        Components,
+    },
+    Modify, OpenApi,
+};
+use utoipa_actix_web::{service_config::ServiceConfig, AppExt};
+use utoipa_scalar::Scalar;
+use web_adapters::SESSION_COOKIE_NAME;
+
+use crate::{get_health_routes, get_routes};
+
+/// What the OpenAPI document has apart from paths, which `api_doc` collects from the routes.
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "LifeTracker API"),
+    modifiers(&SessionCookie),
+    security(("session_cookie" = [])),
+)]
+struct ApiDoc;
+
+/// OpenAPI document of every route in `get_health_routes` and `get_routes`. Scopes and handlers
+/// there are registered with utoipa_actix_web, which collects their paths as actix registers them,
+/// so a route cannot be served without being in the document.
+pub fn api_doc() -> &'static utoipa::openapi::OpenApi {
+    static API_DOC: OnceLock<utoipa::openapi::OpenApi> = OnceLock::new();
+    API_DOC.get_or_init(|| {
+        let (_, mut openapi) = App::new()
+            .into_utoipa_app()
+            .openapi(ApiDoc::openapi())
+            .service(get_health_routes())
+            .service(get_routes())
+            .split_for_parts();
+        ScopeTags.modify(&mut openapi);
+        openapi
+    })
+}
+
+/// Endpoints without a user are marked with `security(())` on their own.
+struct SessionCookie;
+
+impl Modify for SessionCookie {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        openapi
+            .components
+            .get_or_insert_with(Components::new)
+            .add_security_scheme(
+                "session_cookie",
+                SecurityScheme::ApiKey(ApiKey::Cookie(ApiKeyValue::new(SESSION_COOKIE_NAME))),
+            );
+    }
+}
+
+/// Operations without a tag of their own are grouped by their scope under /api, e.g.
+/// `ambitions` for /api/ambitions/{ambition_id}.
+struct ScopeTags;
+
+impl Modify for ScopeTags {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        for (path, item) in openapi.paths.paths.iter_mut() {
+            let scope = path
+                .trim_start_matches("/api/")
+                .split('/')
+                .next()
+                .unwrap_or_default();
+            for operation in [
+                item.get.as_mut(),
+                item.post.as_mut(),
+                item.put.as_mut(),
+                item.patch.as_mut(),
+                item.delete.as_mut(),
+            ]
+            .into_iter()
+            .flatten()
+            {
+                operation
+                    .tags
+                    .get_or_insert_with(|| vec![scope.to_string()]);
+            }
+        }
+    }
+}
+
+/// The document and its interactive docs, which are not in the document themselves.
+pub fn openapi_routes(cfg: &mut ServiceConfig) {
+    cfg.service(openapi_json).map(|cfg| cfg.service(docs));
+}
+
+#[utoipa::path(
+    tag = "docs",
+    security(()),
+    responses(
+        (status = 200, description = "This document"),
+    )
+)]
+#[get("/openapi.json")]
+async fn openapi_json() -> HttpResponse {
+    HttpResponse::Ok().json(api_doc())
+}
+
+#[get("/docs")]
+async fn docs() -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type(ContentType::html())
+        .body(Scalar::new(api_doc().clone()).to_html())
+}
+
+#[cfg(test)]
+mod tests {
+    use utoipa::openapi::{path::Operation, PathItem};
+
+    use super::*;
+
+    fn get_operation<'a>(item: &'a PathItem, method: &str) -> Option<&'a Operation> {
+        match method {
+            "get" => item.get.as_ref(),
+            "post" => item.post.as_ref(),
+            "put" => item.put.as_ref(),
+            "patch" => item.patch.as_ref(),
+            "delete" => item.delete.as_ref(),
+            _ => None,
+        }
+    }
+
+    fn operations(spec: &utoipa::openapi::OpenApi) -> Vec<(&String, &Operation)> {
+        spec.paths
+            .paths
+            .iter()
+            .flat_map(|(path, item)| {
+                ["get", "post", "put", "patch", "delete"]
+                    .into_iter()
+                    .filter_map(move |method| get_operation(item, method))
+                    .map(move |operation| (path, operation))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn paths_are_under_their_scopes() {
+        let spec = api_doc();
+        for (path, method, operation_id) in [
+            ("/api/health-check", "get", "health_check"),
+            ("/api/health/live", "get", "liveness_endpoint"),
+            ("/api/openapi.json", "get", "openapi_json"),
+            ("/api/users/register", "post", "register"),
+            (
+                "/api/users/password-change",
+                "post",
+                "submit_password_change",
+            ),
+            (
+                "/api/ambitions/{ambition_id}",
+                "get",
+                "get_ambition_endpoint",
+            ),
+            (
+                "/api/web_push_subscription",
+                "post",
+                "create_web_push_subscription_endpoint",
+            ),
+        ] {
+            let operation = spec
+                .paths
+                .paths
+                .get(path)
+                .and_then(|item| get_operation(item, method))
+                .unwrap_or_else(|| panic!("{method} {path} is not in the spec"));
+            assert_eq!(operation.operation_id.as_deref(), Some(operation_id));
+        }
+        assert!(!spec.paths.paths.contains_key("/api/docs"));
+    }
+
+    #[test]
+    fn operations_are_tagged_with_their_scope() {
+        let spec = api_doc();
+        for (path, operation) in operations(spec) {
+            let tags = operation.tags.as_deref().unwrap_or_default();
+            assert_eq!(tags.len(), 1, "{path} has tags {tags:?}");
+        }
+        let tags_of = |path: &str| {
+            spec.paths.paths[path]
+                .get
+                .as_ref()
+                .and_then(|operation| operation.tags.clone())
+        };
+        assert_eq!(
+            tags_of("/api/ambitions"),
+            Some(vec!["ambitions".to_string()])
+        );
+        assert_eq!(
+            tags_of("/api/health/ready"),
+            Some(vec!["health".to_string()])
+        );
+        assert_eq!(tags_of("/api/openapi.json"), Some(vec!["docs".to_string()]));
+        assert_eq!(
+            tags_of("/api/web_push_subscription"),
+            Some(vec!["web_push_subscriptions".to_string()])
+        );
+    }
+
+    #[test]
+    fn openapi_spec_has_no_duplicate_operation_ids() {
+        let mut operation_ids = operations(api_doc())
+            .into_iter()
+            .filter_map(|(_, operation)| operation.operation_id.clone())
+            .collect::<Vec<_>>();
+        let count = operation_ids.len();
+        operation_ids.sort();
+        operation_ids.dedup();
+        assert_eq!(operation_ids.len(), count);
+    }
+}
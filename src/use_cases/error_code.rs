@@ -0,0 +1,278 @@
+use serde::{Serialize, Serializer};
+
+/// Stable identifier of every failure the API reports. Clients match on `as_str()`, so never
+/// rename one once released; add a new code instead. Messages are for humans and may change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    // my_way
+    AmbitionNotFound,
+    DirectionNotFound,
+    DirectionCategoryNotFound,
+    DirectionCategoryParentCycle,
+    ActionNotFound,
+    ActionGoalDurationSecondsRequired,
+    ActionGoalDurationSecondsNotAllowed,
+    ActionGoalCountRequired,
+    ActionGoalCountNotAllowed,
+    ActionTrackNotFound,
+    ActionTrackDuplicate,
+    ActionTrackEndedAtBeforeStartedAt,
+    ActionTrackDatesWithStartedAtRange,
+    ActionTrackInvalidYearMonth,
+    RevisionNotFound,
+    MyWayProgressInvalidDateRange,
+
+    // journal
+    DiaryNotFound,
+    ReadingNoteNotFound,
+    ThinkingNoteNotFound,
+    JournalTagIdsNotFound,
+
+    // tags
+    TagNotFound,
+    TagNotPlain,
+
+    // notification
+    NotificationRuleDuplicateType,
+    NotificationRuleTimeSecondsNotZero,
+    NotificationRuleTimeMinutesNotMultipleOfTen,
+    NotificationRuleUnknownRecurrenceType,
+
+    // sync
+    SyncInvalidCursor,
+    SyncFullResyncRequired,
+
+    // search
+    SearchNoTerms,
+
+    // trash
+    TrashItemNotFound,
+
+    // pagination, see crate::pagination
+    PageInvalidLimit,
+    PageInvalidCursor,
+
+    // validation, see crate::validation
+    ValidationFailed,
+    FieldBlank,
+    FieldTooLong,
+    FieldNotHexColor,
+    FieldNegative,
+
+    // Reported by the web layer
+    NotLoggedIn,
+    LoginUserNotFound,
+    LoginLocked,
+    UserNotFound,
+    UserWithEmailNotFound,
+    UserToResendEmailNotFound,
+    UserActivationFailed,
+    InvalidToken,
+    CrossSiteRequest,
+    IdempotencyKeyInvalid,
+    IdempotencyKeyInProgress,
+    IdempotencyKeyReused,
+    RateLimited,
+    BatchTooManyOperations,
+    BatchDuplicateName,
+    BatchInvalidOperation,
+    BatchInvalidReference,
+    BatchSkipped,
+    InvalidBody,
+    InvalidQuery,
+    InvalidPath,
+    InternalError,
+}
+
+impl ErrorCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::AmbitionNotFound => "ambition.not_found",
+            Self::DirectionNotFound => "direction.not_found",
+            Self::DirectionCategoryNotFound => "direction_category.not_found",
+            Self::DirectionCategoryParentCycle => "direction_category.parent_cycle",
+            Self::ActionNotFound => "action.not_found",
+            Self::ActionGoalDurationSecondsRequired => "action_goal.duration_seconds_required",
+            Self::ActionGoalDurationSecondsNotAllowed => "action_goal.duration_seconds_not_allowed",
+            Self::ActionGoalCountRequired => "action_goal.count_required",
+            Self::ActionGoalCountNotAllowed => "action_goal.count_not_allowed",
+            Self::ActionTrackNotFound => "action_track.not_found",
+            Self::ActionTrackDuplicate => "action_track.duplicate",
+            Self::ActionTrackEndedAtBeforeStartedAt => "action_track.ended_at_before_started_at",
+            Self::ActionTrackDatesWithStartedAtRange => "action_track.dates_with_started_at_range",
+            Self::ActionTrackInvalidYearMonth => "action_track.invalid_year_month",
+            Self::RevisionNotFound => "revision.not_found",
+            Self::MyWayProgressInvalidDateRange => "my_way_progress.invalid_date_range",
+            Self::DiaryNotFound => "diary.not_found",
+            Self::ReadingNoteNotFound => "reading_note.not_found",
+            Self::ThinkingNoteNotFound => "thinking_note.not_found",
+            Self::JournalTagIdsNotFound => "journal.tag_ids_not_found",
+            Self::TagNotFound => "tag.not_found",
+            Self::TagNotPlain => "tag.not_plain",
+            Self::NotificationRuleDuplicateType => "notification_rule.duplicate_type",
+            Self::NotificationRuleTimeSecondsNotZero => "notification_rule.time_seconds_not_zero",
+            Self::NotificationRuleTimeMinutesNotMultipleOfTen => {
+                "notification_rule.time_minutes_not_multiple_of_ten"
+            }
+            Self::NotificationRuleUnknownRecurrenceType => {
+                "notification_rule.unknown_recurrence_type"
+            }
+            Self::SyncInvalidCursor => "sync.invalid_cursor",
+            Self::SyncFullResyncRequired => "sync.full_resync_required",
+            Self::SearchNoTerms => "search.no_terms",
+            Self::TrashItemNotFound => "trash.item_not_found",
+            Self::PageInvalidLimit => "page.invalid_limit",
+            Self::PageInvalidCursor => "page.invalid_cursor",
+            Self::ValidationFailed => "validation.failed",
+            Self::FieldBlank => "validation.blank",
+            Self::FieldTooLong => "validation.too_long",
+            Self::FieldNotHexColor => "validation.not_hex_color",
+            Self::FieldNegative => "validation.negative",
+            Self::NotLoggedIn => "auth.not_logged_in",
+            Self::LoginUserNotFound => "auth.login_user_not_found",
+            Self::LoginLocked => "auth.login_locked",
+            Self::UserNotFound => "user.not_found",
+            Self::UserWithEmailNotFound => "user.with_email_not_found",
+            Self::UserToResendEmailNotFound => "user.to_resend_email_not_found",
+            Self::UserActivationFailed => "user.activation_failed",
+            Self::InvalidToken => "auth.invalid_token",
+            Self::CrossSiteRequest => "request.cross_site",
+            Self::IdempotencyKeyInvalid => "idempotency.key_invalid",
+            Self::IdempotencyKeyInProgress => "idempotency.key_in_progress",
+            Self::IdempotencyKeyReused => "idempotency.key_reused",
+            Self::RateLimited => "request.rate_limited",
+            Self::BatchTooManyOperations => "batch.too_many_operations",
+            Self::BatchDuplicateName => "batch.duplicate_name",
+            Self::BatchInvalidOperation => "batch.invalid_operation",
+            Self::BatchInvalidReference => "batch.invalid_reference",
+            Self::BatchSkipped => "batch.skipped",
+            Self::InvalidBody => "request.invalid_body",
+            Self::InvalidQuery => "request.invalid_query",
+            Self::InvalidPath => "request.invalid_path",
+            Self::InternalError => "internal_error",
+        }
+    }
+
+    pub fn message(&self) -> &'static str {
+        match self {
+            Self::AmbitionNotFound => "Ambition with this id was not found.",
+            Self::DirectionNotFound => "Direction with this id was not found.",
+            Self::DirectionCategoryNotFound => "Category with this id was not found.",
+            Self::DirectionCategoryParentCycle => {
+                "A category cannot be under itself or one of its subcategories."
+            }
+            Self::ActionNotFound => "Action with this id was not found.",
+            Self::ActionGoalDurationSecondsRequired => {
+                "duration_seconds cannot be empty for this action."
+            }
+            Self::ActionGoalDurationSecondsNotAllowed => {
+                "duration_seconds must be empty for this action."
+            }
+            Self::ActionGoalCountRequired => "count cannot be empty for this action.",
+            Self::ActionGoalCountNotAllowed => "count must be empty for this action.",
+            Self::ActionTrackNotFound => "ActionTrack with this id was not found.",
+            Self::ActionTrackDuplicate => {
+                "A track for the same action which starts at the same time exists."
+            }
+            Self::ActionTrackEndedAtBeforeStartedAt => "Ended_at must be later than started_at.",
+            Self::ActionTrackDatesWithStartedAtRange => {
+                "dates and started_at_gte/lte cannot be queried at the same time."
+            }
+            Self::ActionTrackInvalidYearMonth => "Year_month cannot be parsed into a date.",
+            Self::RevisionNotFound => "Revision with this id was not found.",
+            Self::MyWayProgressInvalidDateRange => {
+                "to must not be earlier than from, and the range must be at most 366 days."
+            }
+            Self::DiaryNotFound => "Diary with this id was not found.",
+            Self::ReadingNoteNotFound => "Reading note with this id was not found.",
+            Self::ThinkingNoteNotFound => "Thinking note with this id was not found.",
+            Self::JournalTagIdsNotFound => "One or more of the tag_ids do not exist.",
+            Self::TagNotFound => "Tag with this id was not found.",
+            Self::TagNotPlain => "Only plain tags can be updated or deleted.",
+            Self::NotificationRuleDuplicateType => {
+                "Notification rules for the same type already exists."
+            }
+            Self::NotificationRuleTimeSecondsNotZero => "Seconds in time fields must be zero.",
+            Self::NotificationRuleTimeMinutesNotMultipleOfTen => {
+                "Minutes in time fields must be multiples of ten."
+            }
+            Self::NotificationRuleUnknownRecurrenceType => "Unknown recurrence_type.",
+            Self::SyncInvalidCursor => "since is not a valid cursor.",
+            Self::SyncFullResyncRequired => {
+                "since is too old to tell what was deleted. Sync again without since."
+            }
+            Self::SearchNoTerms => "q must contain at least one word to search for.",
+            Self::TrashItemNotFound => "Item with this kind and id was not found in the trash.",
+            Self::PageInvalidLimit => "limit must be between 1 and 100.",
+            Self::PageInvalidCursor => "cursor is not a valid cursor.",
+            Self::ValidationFailed => "One or more fields are invalid.",
+            Self::FieldBlank => "must not be blank.",
+            Self::FieldTooLong => "is too long.",
+            Self::FieldNotHexColor => "must be a hex color code.",
+            Self::FieldNegative => "must not be negative.",
+            Self::NotLoggedIn => "You are not logged in.",
+            Self::LoginUserNotFound => "A user with these details does not exist. If you registered with these details, ensure you activate your account by clicking on the link sent to your e-mail address.",
+            Self::LoginLocked => "Your account is temporarily locked. Please wait for 1 hour.",
+            Self::UserNotFound => "User not found.",
+            Self::UserWithEmailNotFound => "An active user with this email does not exist.",
+            Self::UserToResendEmailNotFound => "User with this email was not found. This happens if you have already activated this user.",
+            Self::UserActivationFailed => "We cannot activate your account at the moment.",
+            Self::InvalidToken => "It appears that your token has expired or previously used.",
+            Self::CrossSiteRequest => "Cross-site request is not allowed.",
+            Self::IdempotencyKeyInvalid => "Idempotency-Key must be 1 to 255 printable ASCII characters.",
+            Self::IdempotencyKeyInProgress => {
+                "A request with the same Idempotency-Key is still in progress."
+            }
+            Self::IdempotencyKeyReused => {
+                "Idempotency-Key has already been used for another request."
+            }
+            Self::RateLimited => "Too many requests. Please try again later.",
+            Self::BatchTooManyOperations => "A batch can have at most 100 operations.",
+            Self::BatchDuplicateName => "Names of operations in a batch must be unique.",
+            Self::BatchInvalidOperation => "The operation lacks its id or body, or they cannot be parsed.",
+            Self::BatchInvalidReference => {
+                "$ref must point to an output of an earlier successful operation."
+            }
+            Self::BatchSkipped => "Skipped because an earlier operation failed.",
+            Self::InvalidBody => "Request body cannot be parsed.",
+            Self::InvalidQuery => "Query string cannot be parsed.",
+            Self::InvalidPath => "Path parameters cannot be parsed.",
+            Self::InternalError => "Some unexpected error happened. Please try again later.",
+        }
+    }
+
+    /// Request field the failure is about, for codes that are about a single field.
+    pub fn field(&self) -> Option<&'static str> {
+        match self {
+            Self::DirectionCategoryParentCycle => Some("parent_id"),
+            Self::ActionGoalDurationSecondsRequired | Self::ActionGoalDurationSecondsNotAllowed => {
+                Some("duration_seconds")
+            }
+            Self::ActionGoalCountRequired | Self::ActionGoalCountNotAllowed => Some("count"),
+            Self::ActionTrackDuplicate => Some("started_at"),
+            Self::ActionTrackEndedAtBeforeStartedAt => Some("ended_at"),
+            Self::ActionTrackDatesWithStartedAtRange => Some("dates"),
+            Self::ActionTrackInvalidYearMonth => Some("year_month"),
+            Self::MyWayProgressInvalidDateRange => Some("to"),
+            Self::JournalTagIdsNotFound => Some("tag_ids"),
+            Self::NotificationRuleDuplicateType => Some("type"),
+            Self::NotificationRuleTimeSecondsNotZero
+            | Self::NotificationRuleTimeMinutesNotMultipleOfTen => Some("time"),
+            Self::NotificationRuleUnknownRecurrenceType => Some("recurrence_type"),
+            Self::SyncInvalidCursor | Self::SyncFullResyncRequired => Some("since"),
+            Self::SearchNoTerms => Some("q"),
+            Self::PageInvalidLimit => Some("limit"),
+            Self::PageInvalidCursor => Some("cursor"),
+            Self::IdempotencyKeyInvalid => Some("Idempotency-Key"),
+            Self::BatchTooManyOperations => Some("operations"),
+            Self::BatchDuplicateName => Some("name"),
+            _ => None,
+        }
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
@@ -7,7 +7,7 @@ use sea_orm::DbErr;
 
 use crate::{
     journal::diaries::types::{DiaryCreateRequest, DiaryVisible},
-    UseCaseError,
+    ErrorCode, UseCaseError,
 };
 
 pub async fn create_diary<'a>(
@@ -39,9 +39,7 @@ pub async fn create_diary<'a>(
             // FIXME: diary creation should be canceled.
             DbErr::Custom(ce) => match CustomDbErr::from(ce) {
                 CustomDbErr::NotFound => {
-                    return Err(UseCaseError::NotFound(
-                        "One or more of the tag_ids do not exist.".to_string(),
-                    ))
+                    return Err(UseCaseError::NotFound(ErrorCode::JournalTagIdsNotFound))
                 }
                 _ => return Err(UseCaseError::InternalServerError(format!("{:?}", e))),
             },
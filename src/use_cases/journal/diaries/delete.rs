@@ -20,7 +20,7 @@ pub async fn delete_diary<'a>(
         None => return Ok(()),
     };
     diary_adapter
-        .delete(diary)
+        .trash(diary)
         .await
         .map(|_| ())
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))
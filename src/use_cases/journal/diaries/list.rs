@@ -1,5 +1,7 @@
 use db_adapters::{
-    diary_adapter::{DiaryAdapter, DiaryFilter, DiaryJoin, DiaryOrder, DiaryQuery, DiaryWithTag},
+    diary_adapter::{
+        DiaryCursor, DiaryFilter, DiaryJoin, DiaryLimit, DiaryOrder, DiaryQuery, DiaryWithTag,
+    },
     Order::{Asc, Desc},
 };
 use entities::user as user_entity;
@@ -10,20 +12,49 @@ use crate::{
         diaries::types::{DiaryListQuery, DiaryVisibleWithTags},
         types::IntoJournalVisibleWithTags,
     },
+    pagination::PageParams,
     tags::types::TagVisible,
     UseCaseError,
 };
 
-pub async fn list_diaries<'a>(
+pub async fn list_diaries(
     user: user_entity::Model,
-    diary_adapter: DiaryAdapter<'a>,
+    diary_adapter: impl Clone + DiaryFilter + DiaryJoin + DiaryOrder + DiaryLimit + DiaryQuery,
     params: DiaryListQuery,
+) -> Result<Vec<DiaryVisibleWithTags>, UseCaseError> {
+    list_diaries_after(user, diary_adapter, params, None).await
+}
+
+/// Lists at most `page.limit` diaries after `page.after`, or all of them when page is None.
+pub async fn list_diaries_after(
+    user: user_entity::Model,
+    diary_adapter: impl Clone + DiaryFilter + DiaryJoin + DiaryOrder + DiaryLimit + DiaryQuery,
+    params: DiaryListQuery,
+    page: Option<PageParams<DiaryCursor>>,
 ) -> Result<Vec<DiaryVisibleWithTags>, UseCaseError> {
     let params = validate_params(params)?;
-    let diaries = diary_adapter
+    let mut query = diary_adapter.filter_eq_user(&user);
+    if let Some(tag_id_or) = params.tag_id_or {
+        query = query.filter_tagged_with_any(tag_id_or);
+    }
+    // NOTE: Joined tags multiply rows, so the page is decided by ids first.
+    if let Some(page) = page {
+        let mut page_query = query.clone();
+        if let Some(after) = &page.after {
+            page_query = page_query.filter_after_cursor(after);
+        }
+        let ids = page_query
+            .order_by_date(Desc)
+            .order_by_id(Desc)
+            .limit(page.limit)
+            .get_all_ids()
+            .await
+            .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?;
+        query = query.filter_id_in(ids);
+    }
+    let diaries = query
         .join_tags()
         .join_my_way_via_tags()
-        .filter_eq_user(&user)
         .order_by_date(Desc)
         .order_by_id(Desc)
         .order_by_ambition_created_at_nulls_last(Asc)
@@ -57,21 +88,6 @@ pub async fn list_diaries<'a>(
         }
     }
 
-    // NOTE: This filtering cannot be done in Db query.
-    // If done in DB query, tags not in tag_id_or will be returned.
-    if let Some(tag_id_or) = params.tag_id_or {
-        res = res
-            .into_iter()
-            .filter(|diary| {
-                diary
-                    .tags
-                    .iter()
-                    .find(|tag| tag_id_or.contains(&tag.id))
-                    .is_some()
-            })
-            .collect();
-    }
-
     Ok(res)
 }
 
@@ -2,10 +2,17 @@ use db_adapters::diary_adapter::DiaryUpdateKey;
 use entities::{diary, prelude::Diary};
 use sea_orm::{DerivePartialModel, FromQueryResult};
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
-use crate::{journal::types::IntoJournalVisibleWithTags, tags::types::TagVisible};
+use crate::{
+    journal::types::IntoJournalVisibleWithTags,
+    tags::types::TagVisible,
+    validation::{FieldViolation, MaxChars, Validate, Validator, TEXT_MAX_CHARS},
+};
 
-#[derive(Serialize, Deserialize, DerivePartialModel, FromQueryResult, PartialEq, Debug)]
+#[derive(
+    Serialize, Deserialize, DerivePartialModel, FromQueryResult, PartialEq, Debug, ToSchema,
+)]
 #[sea_orm(entity = "Diary")]
 pub struct DiaryVisible {
     pub id: uuid::Uuid,
@@ -23,7 +30,7 @@ impl From<diary::Model> for DiaryVisible {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
 pub struct DiaryVisibleWithTags {
     pub id: uuid::Uuid,
     pub text: Option<String>,
@@ -55,22 +62,38 @@ impl IntoJournalVisibleWithTags for DiaryVisibleWithTags {
     }
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, IntoParams)]
 pub struct DiaryListQuery {
     pub tag_id_or: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Serialize, ToSchema)]
 pub struct DiaryCreateRequest {
     pub text: Option<String>,
     pub date: chrono::NaiveDate,
     pub tag_ids: Vec<uuid::Uuid>,
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+impl Validate for DiaryCreateRequest {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        Validator::new()
+            .optional_field("text", self.text.as_deref(), &[&MaxChars(TEXT_MAX_CHARS)])
+            .finish()
+    }
+}
+
+#[derive(Deserialize, Debug, Serialize, ToSchema)]
 pub struct DiaryUpdateRequest {
     pub text: Option<String>,
     pub date: chrono::NaiveDate,
     pub tag_ids: Vec<uuid::Uuid>,
     pub update_keys: Vec<DiaryUpdateKey>,
 }
+
+impl Validate for DiaryUpdateRequest {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        Validator::new()
+            .optional_field("text", self.text.as_deref(), &[&MaxChars(TEXT_MAX_CHARS)])
+            .finish()
+    }
+}
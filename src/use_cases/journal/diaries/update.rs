@@ -11,7 +11,7 @@ use uuid::Uuid;
 
 use crate::{
     journal::diaries::types::{DiaryUpdateRequest, DiaryVisible},
-    UseCaseError,
+    ErrorCode, UseCaseError,
 };
 
 pub async fn update_diary<'a>(
@@ -28,9 +28,7 @@ pub async fn update_diary<'a>(
         .get_with_tags()
         .await
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
-        .ok_or(UseCaseError::NotFound(
-            "Diary with this id was not found".to_string(),
-        ))?;
+        .ok_or(UseCaseError::NotFound(ErrorCode::DiaryNotFound))?;
 
     let diary = match diary_adapter
         .clone()
@@ -58,9 +56,7 @@ pub async fn update_diary<'a>(
             match &e {
                 DbErr::Custom(ce) => match CustomDbErr::from(ce) {
                     CustomDbErr::NotFound => {
-                        return Err(UseCaseError::NotFound(
-                            "One or more of the tag_ids do not exist.".to_string(),
-                        ))
+                        return Err(UseCaseError::NotFound(ErrorCode::JournalTagIdsNotFound))
                     }
                     _ => return Err(UseCaseError::InternalServerError(format!("{:?}", e))),
                 },
@@ -3,49 +3,89 @@ use std::collections::VecDeque;
 use futures::join;
 
 use db_adapters::{
-    diary_adapter::DiaryAdapter, reading_note_adapter::ReadingNoteAdapter,
-    thinking_note_adapter::ThinkingNoteAdapter,
+    diary_adapter::{DiaryFilter, DiaryJoin, DiaryLimit, DiaryOrder, DiaryQuery},
+    reading_note_adapter::{
+        ReadingNoteFilter, ReadingNoteJoin, ReadingNoteLimit, ReadingNoteOrder, ReadingNoteQuery,
+    },
+    thinking_note_adapter::{
+        ThinkingNoteFilter, ThinkingNoteJoin, ThinkingNoteLimit, ThinkingNoteOrder,
+        ThinkingNoteQuery,
+    },
 };
 use entities::user as user_entity;
 
 use crate::{
     journal::{
-        diaries::{list::list_diaries, types::DiaryListQuery},
-        reading_notes::{list::list_reading_notes, types::ReadingNoteListQuery},
-        thinking_notes::{list::list_thinking_notes, types::ThinkingNoteListQuery},
-        types::{IntoJournalVisibleWithTags, JournalListQuery, JournalVisibleWithTags},
+        diaries::{list::list_diaries_after, types::DiaryListQuery},
+        reading_notes::{list::list_reading_notes_after, types::ReadingNoteListQuery},
+        thinking_notes::{list::list_thinking_notes_after, types::ThinkingNoteListQuery},
+        types::{
+            IntoJournalVisibleWithTags, JournalCursor, JournalListQuery, JournalVisibleWithTags,
+        },
     },
+    pagination::{encode_cursor, Page, PageParams},
     UseCaseError,
 };
 
-pub async fn list_journals<'a>(
+pub async fn list_journals(
     user: user_entity::Model,
     query: JournalListQuery,
-    diary_adapter: DiaryAdapter<'a>,
-    reading_note_adapter: ReadingNoteAdapter<'a>,
-    thinking_note_adapter: ThinkingNoteAdapter<'a>,
-) -> Result<Vec<JournalVisibleWithTags>, UseCaseError> {
-    let diaries_future = list_diaries(
+    diary_adapter: impl Clone + DiaryFilter + DiaryJoin + DiaryOrder + DiaryLimit + DiaryQuery,
+    reading_note_adapter: impl Clone
+        + ReadingNoteFilter
+        + ReadingNoteJoin
+        + ReadingNoteOrder
+        + ReadingNoteLimit
+        + ReadingNoteQuery,
+    thinking_note_adapter: impl Clone
+        + ThinkingNoteFilter
+        + ThinkingNoteJoin
+        + ThinkingNoteOrder
+        + ThinkingNoteLimit
+        + ThinkingNoteQuery,
+) -> Result<Page<JournalVisibleWithTags>, UseCaseError> {
+    let page = PageParams::<JournalCursor>::parse(query.limit, query.cursor.as_deref())?;
+    let after = page
+        .as_ref()
+        .and_then(|page| page.after.clone())
+        .unwrap_or_default();
+    // NOTE: A page can be filled with a single kind, so each kind lists up to limit of them
+    //       after its own cursor. One more of them tells whether there is a next page.
+    let limit_of_each_kind = page.as_ref().map(|page| page.limit + 1);
+
+    let diaries_future = list_diaries_after(
         user.clone(),
         diary_adapter,
         DiaryListQuery {
             tag_id_or: query.tag_id_or.clone(),
         },
+        limit_of_each_kind.map(|limit| PageParams {
+            limit,
+            after: after.diary.clone(),
+        }),
     );
-    let reading_notes_future = list_reading_notes(
+    let reading_notes_future = list_reading_notes_after(
         user.clone(),
         reading_note_adapter,
         ReadingNoteListQuery {
             tag_id_or: query.tag_id_or.clone(),
         },
+        limit_of_each_kind.map(|limit| PageParams {
+            limit,
+            after: after.reading_note.clone(),
+        }),
     );
-    let thinking_notes_future = list_thinking_notes(
+    let thinking_notes_future = list_thinking_notes_after(
         user.clone(),
         ThinkingNoteListQuery {
             resolved: None,
             tag_id_or: query.tag_id_or,
         },
         thinking_note_adapter,
+        limit_of_each_kind.map(|limit| PageParams {
+            limit,
+            after: after.thinking_note.clone(),
+        }),
     );
     let (diaries, reading_notes, thinking_notes) =
         join!(diaries_future, reading_notes_future, thinking_notes_future);
@@ -55,12 +95,15 @@ pub async fn list_journals<'a>(
 
     let mut res = vec![];
     let count = diaries.len() + reading_notes.len() + thinking_notes.len();
+    let page_size = page
+        .as_ref()
+        .map_or(count, |page| count.min(page.limit as usize));
 
     let mut first_thinking_note_is_unresolved = thinking_notes
         .front()
         .is_some_and(|t| t.resolved_at.is_none());
 
-    for _ in 0..count {
+    for _ in 0..page_size {
         if first_thinking_note_is_unresolved {
             res.push(thinking_notes.pop_front().unwrap().into());
             first_thinking_note_is_unresolved = thinking_notes
@@ -92,7 +135,21 @@ pub async fn list_journals<'a>(
         res.push(first);
     }
 
-    Ok(res)
+    // NOTE: The merge only looks at the heads of each kind, so resuming each kind after its last
+    //       listed one continues the merge exactly where this page stopped.
+    let next_cursor = match count > page_size {
+        true => {
+            let mut next = after;
+            res.iter().for_each(|journal| next.advance(journal));
+            Some(encode_cursor(&next))
+        }
+        false => None,
+    };
+
+    Ok(Page {
+        items: res,
+        next_cursor,
+    })
 }
 
 fn a_is_newer<T: IntoJournalVisibleWithTags, U: IntoJournalVisibleWithTags>(
@@ -115,3 +172,139 @@ fn pop_front_from_newer<
         b.pop_front().unwrap().into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use futures::executor::block_on;
+    use sea_orm::{Set, TryIntoModel};
+
+    use common::factory::{self, DiaryFactory, ReadingNoteFactory, ThinkingNoteFactory};
+    use db_adapters::in_memory::{
+        InMemoryDb, InMemoryDiaryAdapter, InMemoryReadingNoteAdapter, InMemoryThinkingNoteAdapter,
+    };
+    use entities::{diaries_tags, sea_orm_active_enums::TagType, tag};
+
+    use super::*;
+    use crate::journal::types::JournalKind;
+
+    fn list(
+        db: &InMemoryDb,
+        user: &user_entity::Model,
+        limit: Option<u64>,
+        cursor: Option<String>,
+    ) -> Page<JournalVisibleWithTags> {
+        block_on(list_journals(
+            user.clone(),
+            JournalListQuery {
+                tag_id_or: None,
+                limit,
+                cursor,
+            },
+            InMemoryDiaryAdapter::init(db),
+            InMemoryReadingNoteAdapter::init(db),
+            InMemoryThinkingNoteAdapter::init(db),
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn lists_unresolved_thinking_notes_first_then_newer_journals() {
+        let db = InMemoryDb::default();
+        let user = factory::user().try_into_model().unwrap();
+        let now = Utc::now();
+        let today = now.date_naive();
+        let diary = db.insert(factory::diary(user.id).date(today - Duration::days(2)));
+        db.insert(
+            factory::diary(user.id)
+                .date(today)
+                .deleted_at(Some(now.into())),
+        );
+        let reading_note =
+            db.insert(factory::reading_note(user.id).date(today - Duration::days(1)));
+        let resolved_thinking_note = db.insert(
+            factory::thinking_note(user.id).resolved_at(Some((now - Duration::days(3)).into())),
+        );
+        let unresolved_thinking_note =
+            db.insert(factory::thinking_note(user.id).updated_at((now - Duration::days(5)).into()));
+
+        let ambition = db.insert(factory::ambition(user.id));
+        let plain_tag = db.insert(tag::ActiveModel {
+            r#type: Set(TagType::Plain),
+            created_at: Set(now.into()),
+            ..factory::tag(user.id)
+        });
+        let ambition_tag = db.insert(tag::ActiveModel {
+            ambition_id: Set(Some(ambition.id)),
+            name: Set(None),
+            r#type: Set(TagType::Ambition),
+            created_at: Set(now.into()),
+            ..factory::tag(user.id)
+        });
+        for tag in [&plain_tag, &ambition_tag] {
+            db.insert(diaries_tags::ActiveModel {
+                diary_id: Set(diary.id),
+                tag_id: Set(tag.id),
+            });
+        }
+
+        let res = list(&db, &user, None, None);
+
+        assert_eq!(
+            res.items.iter().map(|j| &j.kind).collect::<Vec<_>>(),
+            vec![
+                &JournalKind::ThinkingNote,
+                &JournalKind::ReadingNote,
+                &JournalKind::Diary,
+                &JournalKind::ThinkingNote,
+            ]
+        );
+        assert_eq!(
+            res.items[0].thinking_note.as_ref().unwrap().id,
+            unresolved_thinking_note.id
+        );
+        assert_eq!(
+            res.items[1].reading_note.as_ref().unwrap().id,
+            reading_note.id
+        );
+        assert_eq!(
+            res.items[3].thinking_note.as_ref().unwrap().id,
+            resolved_thinking_note.id
+        );
+        let listed_diary = res.items[2].diary.as_ref().unwrap();
+        assert_eq!(listed_diary.id, diary.id);
+        assert_eq!(
+            listed_diary
+                .tags
+                .iter()
+                .map(|tag| tag.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["ambition", "plain_tag"]
+        );
+        assert_eq!(res.next_cursor, None);
+    }
+
+    #[test]
+    fn continues_after_cursor_of_previous_page() {
+        let db = InMemoryDb::default();
+        let user = factory::user().try_into_model().unwrap();
+        let today = Utc::now().date_naive();
+        let diaries = (0..3)
+            .map(|days| db.insert(factory::diary(user.id).date(today - Duration::days(days))))
+            .collect::<Vec<_>>();
+
+        let first_page = list(&db, &user, Some(2), None);
+        let second_page = list(&db, &user, Some(2), first_page.next_cursor.clone());
+
+        let ids = |page: &Page<JournalVisibleWithTags>| {
+            page.items
+                .iter()
+                .map(|journal| journal.diary.as_ref().unwrap().id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(&first_page), vec![diaries[0].id, diaries[1].id]);
+        assert!(first_page.next_cursor.is_some());
+        assert_eq!(ids(&second_page), vec![diaries[2].id]);
+        assert_eq!(second_page.next_cursor, None);
+    }
+}
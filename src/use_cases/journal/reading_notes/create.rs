@@ -7,7 +7,7 @@ use sea_orm::DbErr;
 
 use crate::{
     journal::reading_notes::types::{ReadingNoteCreateRequest, ReadingNoteVisible},
-    UseCaseError,
+    ErrorCode, UseCaseError,
 };
 
 pub async fn create_reading_note<'a>(
@@ -35,9 +35,9 @@ pub async fn create_reading_note<'a>(
         // FIXME: reading_note creation should be canceled.
         Err(e) => match &e {
             DbErr::Custom(ce) => match CustomDbErr::from(ce) {
-                CustomDbErr::NotFound => Err(UseCaseError::NotFound(
-                    "One or more of the tag_ids do not exist.".to_string(),
-                )),
+                CustomDbErr::NotFound => {
+                    Err(UseCaseError::NotFound(ErrorCode::JournalTagIdsNotFound))
+                }
                 _ => Err(UseCaseError::InternalServerError(format!("{:?}", e))),
             },
             _ => Err(UseCaseError::InternalServerError(format!("{:?}", e))),
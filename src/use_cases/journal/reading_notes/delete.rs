@@ -23,7 +23,7 @@ pub async fn delete_reading_note<'a>(
     };
 
     reading_note_adapter
-        .delete(reading_note)
+        .trash(reading_note)
         .await
         .map(|_| ())
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))
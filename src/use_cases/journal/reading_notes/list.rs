@@ -1,7 +1,7 @@
 use db_adapters::{
     reading_note_adapter::{
-        ReadingNoteAdapter, ReadingNoteFilter, ReadingNoteJoin, ReadingNoteOrder, ReadingNoteQuery,
-        ReadingNoteWithTag,
+        ReadingNoteCursor, ReadingNoteFilter, ReadingNoteJoin, ReadingNoteLimit, ReadingNoteOrder,
+        ReadingNoteQuery, ReadingNoteWithTag,
     },
     Order::{Asc, Desc},
 };
@@ -13,22 +13,63 @@ use crate::{
         reading_notes::types::{ReadingNoteListQuery, ReadingNoteVisibleWithTags},
         types::IntoJournalVisibleWithTags,
     },
+    pagination::PageParams,
     tags::types::TagVisible,
     UseCaseError,
 };
 
-pub async fn list_reading_notes<'a>(
+pub async fn list_reading_notes(
     user: user_entity::Model,
-    reading_note_adapter: ReadingNoteAdapter<'a>,
+    reading_note_adapter: impl Clone
+        + ReadingNoteFilter
+        + ReadingNoteJoin
+        + ReadingNoteOrder
+        + ReadingNoteLimit
+        + ReadingNoteQuery,
     params: ReadingNoteListQuery,
+) -> Result<Vec<ReadingNoteVisibleWithTags>, UseCaseError> {
+    list_reading_notes_after(user, reading_note_adapter, params, None).await
+}
+
+/// Lists at most `page.limit` reading notes after `page.after`, or all of them when page is None.
+pub async fn list_reading_notes_after(
+    user: user_entity::Model,
+    reading_note_adapter: impl Clone
+        + ReadingNoteFilter
+        + ReadingNoteJoin
+        + ReadingNoteOrder
+        + ReadingNoteLimit
+        + ReadingNoteQuery,
+    params: ReadingNoteListQuery,
+    page: Option<PageParams<ReadingNoteCursor>>,
 ) -> Result<Vec<ReadingNoteVisibleWithTags>, UseCaseError> {
     let params = validate_params(params)?;
-    let reading_notes = reading_note_adapter
+    let mut query = reading_note_adapter.filter_eq_user(&user);
+    if let Some(tag_id_or) = params.tag_id_or {
+        query = query.filter_tagged_with_any(tag_id_or);
+    }
+    // NOTE: Joined tags multiply rows, so the page is decided by ids first.
+    if let Some(page) = page {
+        let mut page_query = query.clone();
+        if let Some(after) = &page.after {
+            page_query = page_query.filter_after_cursor(after);
+        }
+        let ids = page_query
+            .order_by_date(Desc)
+            .order_by_created_at(Desc)
+            .order_by_id(Desc)
+            .limit(page.limit)
+            .get_all_ids()
+            .await
+            .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?;
+        query = query.filter_id_in(ids);
+    }
+    let reading_notes = query
         .join_tags()
         .join_my_way_via_tags()
-        .filter_eq_user(&user)
         .order_by_date(Desc)
         .order_by_created_at(Desc)
+        .order_by_id(Desc)
         .order_by_ambition_created_at_nulls_last(Asc)
         .order_by_direction_created_at_nulls_last(Asc)
         .order_by_action_created_at_nulls_last(Asc)
@@ -64,21 +105,6 @@ pub async fn list_reading_notes<'a>(
         }
     }
 
-    // NOTE: This filtering cannot be done in Db query.
-    // If done in DB query, tags not in tag_id_or will be returned.
-    if let Some(tag_id_or) = params.tag_id_or {
-        res = res
-            .into_iter()
-            .filter(|reading_note| {
-                reading_note
-                    .tags
-                    .iter()
-                    .find(|tag| tag_id_or.contains(&tag.id))
-                    .is_some()
-            })
-            .collect();
-    }
-
     Ok(res)
 }
 
@@ -1,10 +1,20 @@
 use entities::{prelude::ReadingNote, reading_note};
 use sea_orm::{DerivePartialModel, FromQueryResult};
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
-use crate::{journal::types::IntoJournalVisibleWithTags, tags::types::TagVisible};
+use crate::{
+    journal::types::IntoJournalVisibleWithTags,
+    tags::types::TagVisible,
+    validation::{
+        FieldViolation, MaxChars, NonNegative, NotBlank, Validate, Validator, NAME_MAX_CHARS,
+        TEXT_MAX_CHARS,
+    },
+};
 
-#[derive(Serialize, Deserialize, DerivePartialModel, FromQueryResult, PartialEq, Debug)]
+#[derive(
+    Serialize, Deserialize, DerivePartialModel, FromQueryResult, PartialEq, Debug, ToSchema,
+)]
 #[sea_orm(entity = "ReadingNote")]
 pub struct ReadingNoteVisible {
     pub id: uuid::Uuid,
@@ -30,7 +40,7 @@ impl From<reading_note::Model> for ReadingNoteVisible {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
 pub struct ReadingNoteVisibleWithTags {
     pub id: uuid::Uuid,
     pub title: String,
@@ -70,12 +80,12 @@ impl IntoJournalVisibleWithTags for ReadingNoteVisibleWithTags {
     }
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, IntoParams)]
 pub struct ReadingNoteListQuery {
     pub tag_id_or: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Serialize, ToSchema)]
 pub struct ReadingNoteCreateRequest {
     pub title: String,
     pub page_number: i16,
@@ -84,7 +94,21 @@ pub struct ReadingNoteCreateRequest {
     pub tag_ids: Vec<uuid::Uuid>,
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+impl Validate for ReadingNoteCreateRequest {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        Validator::new()
+            .field(
+                "title",
+                self.title.as_str(),
+                &[&NotBlank, &MaxChars(NAME_MAX_CHARS)],
+            )
+            .field("page_number", &self.page_number, &[&NonNegative])
+            .field("text", self.text.as_str(), &[&MaxChars(TEXT_MAX_CHARS)])
+            .finish()
+    }
+}
+
+#[derive(Deserialize, Debug, Serialize, ToSchema)]
 pub struct ReadingNoteUpdateRequest {
     pub title: Option<String>,
     pub page_number: Option<i16>,
@@ -92,3 +116,17 @@ pub struct ReadingNoteUpdateRequest {
     pub date: Option<chrono::NaiveDate>,
     pub tag_ids: Option<Vec<uuid::Uuid>>,
 }
+
+impl Validate for ReadingNoteUpdateRequest {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        Validator::new()
+            .optional_field(
+                "title",
+                self.title.as_deref(),
+                &[&NotBlank, &MaxChars(NAME_MAX_CHARS)],
+            )
+            .optional_field("page_number", self.page_number.as_ref(), &[&NonNegative])
+            .optional_field("text", self.text.as_deref(), &[&MaxChars(TEXT_MAX_CHARS)])
+            .finish()
+    }
+}
@@ -11,7 +11,7 @@ use uuid::Uuid;
 
 use crate::{
     journal::reading_notes::types::{ReadingNoteUpdateRequest, ReadingNoteVisible},
-    UseCaseError,
+    ErrorCode, UseCaseError,
 };
 
 pub async fn update_reading_note<'a>(
@@ -28,9 +28,7 @@ pub async fn update_reading_note<'a>(
         .get_with_tags()
         .await
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
-        .ok_or(UseCaseError::NotFound(
-            "Reading note with this id was not found".to_string(),
-        ))?;
+        .ok_or(UseCaseError::NotFound(ErrorCode::ReadingNoteNotFound))?;
 
     let reading_note = reading_note_adapter
         .clone()
@@ -53,9 +51,7 @@ pub async fn update_reading_note<'a>(
             match &e {
                 DbErr::Custom(ce) => match CustomDbErr::from(ce) {
                     CustomDbErr::NotFound => {
-                        return Err(UseCaseError::NotFound(
-                            "One or more of the tag_ids do not exist.".to_string(),
-                        ))
+                        return Err(UseCaseError::NotFound(ErrorCode::JournalTagIdsNotFound))
                     }
                     _ => return Err(UseCaseError::InternalServerError(format!("{:?}", e))),
                 },
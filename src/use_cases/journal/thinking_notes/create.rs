@@ -7,7 +7,7 @@ use sea_orm::DbErr;
 
 use crate::{
     journal::thinking_notes::types::{ThinkingNoteCreateRequest, ThinkingNoteVisible},
-    UseCaseError,
+    ErrorCode, UseCaseError,
 };
 
 pub async fn create_thinking_note<'a>(
@@ -40,9 +40,7 @@ pub async fn create_thinking_note<'a>(
             // FIXME: thinking_note creation should be canceled.
             DbErr::Custom(ce) => match CustomDbErr::from(ce) {
                 CustomDbErr::NotFound => {
-                    return Err(UseCaseError::NotFound(
-                        "One or more of the tag_ids do not exist.".to_string(),
-                    ))
+                    return Err(UseCaseError::NotFound(ErrorCode::JournalTagIdsNotFound))
                 }
                 _ => return Err(UseCaseError::InternalServerError(format!("{:?}", e))),
             },
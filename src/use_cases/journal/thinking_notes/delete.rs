@@ -23,7 +23,7 @@ pub async fn delete_thinking_note<'a>(
     };
 
     thinking_note_adapter
-        .delete(thinking_note)
+        .trash(thinking_note)
         .await
         .map(|_| ())
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))
@@ -1,7 +1,7 @@
 use db_adapters::{
     thinking_note_adapter::{
-        ThinkingNoteAdapter, ThinkingNoteFilter, ThinkingNoteJoin, ThinkingNoteOrder,
-        ThinkingNoteQuery, ThinkingNoteWithTag,
+        ThinkingNoteCursor, ThinkingNoteFilter, ThinkingNoteJoin, ThinkingNoteLimit,
+        ThinkingNoteOrder, ThinkingNoteQuery, ThinkingNoteWithTag,
     },
     Order::{Asc, Desc},
 };
@@ -13,28 +13,68 @@ use crate::{
         thinking_notes::types::{ThinkingNoteListQuery, ThinkingNoteVisibleWithTags},
         types::IntoJournalVisibleWithTags,
     },
+    pagination::PageParams,
     tags::types::TagVisible,
     UseCaseError,
 };
 
-pub async fn list_thinking_notes<'a>(
+pub async fn list_thinking_notes(
     user: user_entity::Model,
     params: ThinkingNoteListQuery,
-    thinking_note_adapter: ThinkingNoteAdapter<'a>,
+    thinking_note_adapter: impl Clone
+        + ThinkingNoteFilter
+        + ThinkingNoteJoin
+        + ThinkingNoteOrder
+        + ThinkingNoteLimit
+        + ThinkingNoteQuery,
+) -> Result<Vec<ThinkingNoteVisibleWithTags>, UseCaseError> {
+    list_thinking_notes_after(user, params, thinking_note_adapter, None).await
+}
+
+/// Lists at most `page.limit` thinking notes after `page.after`, or all of them when page is None.
+pub async fn list_thinking_notes_after(
+    user: user_entity::Model,
+    params: ThinkingNoteListQuery,
+    thinking_note_adapter: impl Clone
+        + ThinkingNoteFilter
+        + ThinkingNoteJoin
+        + ThinkingNoteOrder
+        + ThinkingNoteLimit
+        + ThinkingNoteQuery,
+    page: Option<PageParams<ThinkingNoteCursor>>,
 ) -> Result<Vec<ThinkingNoteVisibleWithTags>, UseCaseError> {
     let params = validate_params(params)?;
-    let mut query = thinking_note_adapter
-        .join_tags()
-        .join_my_way_via_tags()
-        .filter_eq_user(&user);
+    let mut query = thinking_note_adapter.filter_eq_user(&user);
 
     if let Some(resolved) = params.resolved {
         query = query.filter_null_resolved_at(!resolved);
     }
+    if let Some(tag_id_or) = params.tag_id_or {
+        query = query.filter_tagged_with_any(tag_id_or);
+    }
+    // NOTE: Joined tags multiply rows, so the page is decided by ids first.
+    if let Some(page) = page {
+        let mut page_query = query.clone();
+        if let Some(after) = &page.after {
+            page_query = page_query.filter_after_cursor(after);
+        }
+        let ids = page_query
+            .order_by_resolved_at_nulls_first(Desc)
+            .order_by_updated_at(Desc)
+            .order_by_id(Desc)
+            .limit(page.limit)
+            .get_all_ids()
+            .await
+            .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?;
+        query = query.filter_id_in(ids);
+    }
 
     let thinking_notes = query
+        .join_tags()
+        .join_my_way_via_tags()
         .order_by_resolved_at_nulls_first(Desc)
         .order_by_updated_at(Desc)
+        .order_by_id(Desc)
         .order_by_ambition_created_at_nulls_last(Asc)
         .order_by_direction_created_at_nulls_last(Asc)
         .order_by_action_created_at_nulls_last(Asc)
@@ -70,21 +110,6 @@ pub async fn list_thinking_notes<'a>(
         }
     }
 
-    // NOTE: This filtering cannot be done in Db query.
-    // If done in DB query, tags not in tag_id_or will be returned.
-    if let Some(tag_id_or) = params.tag_id_or {
-        res = res
-            .into_iter()
-            .filter(|thinking_note| {
-                thinking_note
-                    .tags
-                    .iter()
-                    .find(|tag| tag_id_or.contains(&tag.id))
-                    .is_some()
-            })
-            .collect();
-    }
-
     Ok(res)
 }
 
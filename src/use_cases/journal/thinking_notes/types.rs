@@ -2,11 +2,20 @@ use chrono::{DateTime, FixedOffset};
 use entities::{prelude::ThinkingNote, thinking_note};
 use sea_orm::{DerivePartialModel, FromQueryResult};
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use crate::{journal::types::IntoJournalVisibleWithTags, tags::types::TagVisible};
+use crate::{
+    journal::types::IntoJournalVisibleWithTags,
+    tags::types::TagVisible,
+    validation::{
+        FieldViolation, MaxChars, Validate, Validator, DESCRIPTION_MAX_CHARS, TEXT_MAX_CHARS,
+    },
+};
 
-#[derive(Serialize, Deserialize, DerivePartialModel, FromQueryResult, PartialEq, Debug)]
+#[derive(
+    Serialize, Deserialize, DerivePartialModel, FromQueryResult, PartialEq, Debug, ToSchema,
+)]
 #[sea_orm(entity = "ThinkingNote")]
 pub struct ThinkingNoteVisible {
     pub id: Uuid,
@@ -32,7 +41,7 @@ impl From<thinking_note::Model> for ThinkingNoteVisible {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
 pub struct ThinkingNoteVisibleWithTags {
     pub id: Uuid,
     pub question: Option<String>,
@@ -75,13 +84,13 @@ impl IntoJournalVisibleWithTags for ThinkingNoteVisibleWithTags {
     }
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, IntoParams)]
 pub struct ThinkingNoteListQuery {
     pub resolved: Option<bool>,
     pub tag_id_or: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Serialize, Default)]
+#[derive(Deserialize, Debug, Serialize, Default, ToSchema)]
 pub struct ThinkingNoteCreateRequest {
     pub question: Option<String>,
     pub thought: Option<String>,
@@ -89,7 +98,29 @@ pub struct ThinkingNoteCreateRequest {
     pub tag_ids: Vec<uuid::Uuid>,
 }
 
-#[derive(Deserialize, Debug, Serialize, Default)]
+impl Validate for ThinkingNoteCreateRequest {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        Validator::new()
+            .optional_field(
+                "question",
+                self.question.as_deref(),
+                &[&MaxChars(DESCRIPTION_MAX_CHARS)],
+            )
+            .optional_field(
+                "thought",
+                self.thought.as_deref(),
+                &[&MaxChars(TEXT_MAX_CHARS)],
+            )
+            .optional_field(
+                "answer",
+                self.answer.as_deref(),
+                &[&MaxChars(TEXT_MAX_CHARS)],
+            )
+            .finish()
+    }
+}
+
+#[derive(Deserialize, Debug, Serialize, Default, ToSchema)]
 pub struct ThinkingNoteUpdateRequest {
     pub question: Option<String>,
     pub thought: Option<String>,
@@ -97,3 +128,25 @@ pub struct ThinkingNoteUpdateRequest {
     pub tag_ids: Vec<uuid::Uuid>,
     pub resolved_at: Option<DateTime<FixedOffset>>,
 }
+
+impl Validate for ThinkingNoteUpdateRequest {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        Validator::new()
+            .optional_field(
+                "question",
+                self.question.as_deref(),
+                &[&MaxChars(DESCRIPTION_MAX_CHARS)],
+            )
+            .optional_field(
+                "thought",
+                self.thought.as_deref(),
+                &[&MaxChars(TEXT_MAX_CHARS)],
+            )
+            .optional_field(
+                "answer",
+                self.answer.as_deref(),
+                &[&MaxChars(TEXT_MAX_CHARS)],
+            )
+            .finish()
+    }
+}
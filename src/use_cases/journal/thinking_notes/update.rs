@@ -11,7 +11,7 @@ use uuid::Uuid;
 
 use crate::{
     journal::thinking_notes::types::{ThinkingNoteUpdateRequest, ThinkingNoteVisible},
-    UseCaseError,
+    ErrorCode, UseCaseError,
 };
 
 pub async fn update_thinking_note<'a>(
@@ -28,9 +28,7 @@ pub async fn update_thinking_note<'a>(
         .get_with_tags()
         .await
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
-        .ok_or(UseCaseError::NotFound(
-            "Thinking note with this id was not found".to_string(),
-        ))?;
+        .ok_or(UseCaseError::NotFound(ErrorCode::ThinkingNoteNotFound))?;
 
     let thinking_note = thinking_note_adapter
         .clone()
@@ -57,9 +55,7 @@ pub async fn update_thinking_note<'a>(
         match &e {
             DbErr::Custom(ce) => match CustomDbErr::from(ce) {
                 CustomDbErr::NotFound => {
-                    return Err(UseCaseError::NotFound(
-                        "One or more of the tag_ids do not exist.".to_string(),
-                    ))
+                    return Err(UseCaseError::NotFound(ErrorCode::JournalTagIdsNotFound))
                 }
                 _ => return Err(UseCaseError::InternalServerError(format!("{:?}", e))),
             },
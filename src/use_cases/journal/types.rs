@@ -1,5 +1,10 @@
 use chrono::NaiveDate;
+use db_adapters::{
+    diary_adapter::DiaryCursor, reading_note_adapter::ReadingNoteCursor,
+    thinking_note_adapter::ThinkingNoteCursor,
+};
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
 use crate::{
     journal::{
@@ -9,14 +14,14 @@ use crate::{
     tags::types::TagVisible,
 };
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
 pub enum JournalKind {
     Diary,
     ReadingNote,
     ThinkingNote,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
 pub struct JournalVisibleWithTags {
     pub diary: Option<DiaryVisibleWithTags>,
     pub reading_note: Option<ReadingNoteVisibleWithTags>,
@@ -59,7 +64,44 @@ impl From<ThinkingNoteVisibleWithTags> for JournalVisibleWithTags {
     }
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, IntoParams)]
 pub struct JournalListQuery {
     pub tag_id_or: Option<String>,
+    /// Page size up to 100. Everything is returned when both limit and cursor are omitted.
+    pub limit: Option<u64>,
+    /// `x-next-cursor` header of the previous page.
+    pub cursor: Option<String>,
+}
+
+/// Last journal of each kind on the pages so far. None if none of the kind has been listed.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct JournalCursor {
+    pub diary: Option<DiaryCursor>,
+    pub reading_note: Option<ReadingNoteCursor>,
+    pub thinking_note: Option<ThinkingNoteCursor>,
+}
+
+impl JournalCursor {
+    pub fn advance(&mut self, journal: &JournalVisibleWithTags) {
+        if let Some(diary) = &journal.diary {
+            self.diary = Some(DiaryCursor {
+                date: diary.date,
+                id: diary.id,
+            });
+        }
+        if let Some(reading_note) = &journal.reading_note {
+            self.reading_note = Some(ReadingNoteCursor {
+                date: reading_note.date,
+                created_at: reading_note.created_at,
+                id: reading_note.id,
+            });
+        }
+        if let Some(thinking_note) = &journal.thinking_note {
+            self.thinking_note = Some(ThinkingNoteCursor {
+                resolved_at: thinking_note.resolved_at,
+                updated_at: thinking_note.updated_at,
+                id: thinking_note.id,
+            });
+        }
+    }
 }
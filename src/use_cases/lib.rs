@@ -1,18 +1,26 @@
 use std::fmt::Debug;
 
+pub mod error_code;
 pub mod journal;
 pub mod my_way;
 pub mod notification;
+pub mod pagination;
+pub mod search;
+pub mod sync;
 pub mod tags;
+pub mod trash;
 pub mod users;
+pub mod validation;
+
+pub use error_code::ErrorCode;
 
 #[derive(Debug)]
 pub enum UseCaseError {
-    BadRequest(String),          // 400
+    BadRequest(ErrorCode),       // 400
     Unauthorized,                // 401
     Forbidden,                   // 403
-    NotFound(String),            // 404
-    Conflict(String),            // 409
+    NotFound(ErrorCode),         // 404
+    Conflict(ErrorCode),         // 409
     Gone,                        // 410
     InternalServerError(String), // 500
 }
@@ -1,13 +1,13 @@
 use crate::{
     my_way::action_goals::types::{ActionGoalSetNewRequest, ActionGoalVisible},
-    UseCaseError,
+    ErrorCode, UseCaseError,
 };
 use chrono::{Duration, Utc};
 use db_adapters::{
-    action_adapter::{ActionAdapter, ActionFilter, ActionQuery},
+    action_adapter::{ActionFilter, ActionQuery},
     action_goal_adapter::{
-        ActionGoalAdapter, ActionGoalFilter, ActionGoalMutation, ActionGoalQuery,
-        CreateActionGoalParams, UpdateActionGoalParams,
+        ActionGoalFilter, ActionGoalMutation, ActionGoalQuery, CreateActionGoalParams,
+        UpdateActionGoalParams,
     },
 };
 use entities::{
@@ -15,11 +15,11 @@ use entities::{
     user as user_entity,
 };
 
-pub async fn set_new_action_goal<'a>(
+pub async fn set_new_action_goal(
     user: user_entity::Model,
     params: ActionGoalSetNewRequest,
-    action_adapter: ActionAdapter<'a>,
-    action_goal_adapter: ActionGoalAdapter<'a>,
+    action_adapter: impl ActionFilter + ActionQuery,
+    action_goal_adapter: impl Clone + ActionGoalFilter + ActionGoalQuery + ActionGoalMutation,
 ) -> Result<ActionGoalVisible, UseCaseError> {
     let action = action_adapter
         .filter_eq_user(&user)
@@ -78,29 +78,29 @@ fn _parse_params(
     action: Option<action::Model>,
     user: &user_entity::Model,
 ) -> Result<(CreateActionGoalParams, action::Model), UseCaseError> {
-    let action = action.ok_or(UseCaseError::NotFound("This action not found.".to_string()))?;
+    let action = action.ok_or(UseCaseError::NotFound(ErrorCode::ActionNotFound))?;
 
-    let mut bad_request_message: Option<&str> = None;
+    let mut bad_request_code: Option<ErrorCode> = None;
     match action.track_type {
         ActionTrackType::TimeSpan => {
             if params.duration_seconds.is_none() {
-                bad_request_message = Some("duration_seconds cannot be empty for this action.");
+                bad_request_code = Some(ErrorCode::ActionGoalDurationSecondsRequired);
             }
             if params.count.is_some() {
-                bad_request_message = Some("count must be empty for this action.");
+                bad_request_code = Some(ErrorCode::ActionGoalCountNotAllowed);
             }
         }
         ActionTrackType::Count => {
             if params.count.is_none() {
-                bad_request_message = Some("count cannot be empty for this action.");
+                bad_request_code = Some(ErrorCode::ActionGoalCountRequired);
             }
             if params.duration_seconds.is_some() {
-                bad_request_message = Some("duration_seconds must be empty for this action.");
+                bad_request_code = Some(ErrorCode::ActionGoalDurationSecondsNotAllowed);
             }
         }
     }
-    if let Some(message) = bad_request_message {
-        return Err(UseCaseError::BadRequest(message.to_string()));
+    if let Some(code) = bad_request_code {
+        return Err(UseCaseError::BadRequest(code));
     }
 
     let user_today = user.to_user_timezone(Utc::now()).date_naive();
@@ -117,8 +117,8 @@ fn _parse_params(
     ))
 }
 
-async fn _create_action_goal<'a>(
-    action_goal_adapter: ActionGoalAdapter<'a>,
+async fn _create_action_goal(
+    action_goal_adapter: impl ActionGoalMutation,
     params: CreateActionGoalParams,
 ) -> Result<ActionGoalVisible, UseCaseError> {
     action_goal_adapter
@@ -127,3 +127,162 @@ async fn _create_action_goal<'a>(
         .map(|action_goal| ActionGoalVisible::from(action_goal))
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))
 }
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use sea_orm::TryIntoModel;
+
+    use common::factory::{self, ActionFactory, ActionGoalFactory};
+    use db_adapters::in_memory::{InMemoryActionAdapter, InMemoryActionGoalAdapter, InMemoryDb};
+
+    use super::*;
+
+    fn set_new(
+        db: &InMemoryDb,
+        user: &user_entity::Model,
+        params: ActionGoalSetNewRequest,
+    ) -> Result<ActionGoalVisible, UseCaseError> {
+        block_on(set_new_action_goal(
+            user.clone(),
+            params,
+            InMemoryActionAdapter::init(db),
+            InMemoryActionGoalAdapter::init(db),
+        ))
+    }
+
+    #[test]
+    fn creates_goal_from_today() {
+        let db = InMemoryDb::default();
+        let user = factory::user().try_into_model().unwrap();
+        let action = db.insert(factory::action(user.id));
+
+        let res = set_new(
+            &db,
+            &user,
+            ActionGoalSetNewRequest {
+                action_id: action.id,
+                duration_seconds: Some(3600),
+                count: None,
+            },
+        )
+        .unwrap();
+
+        let today = user.to_user_timezone(Utc::now()).date_naive();
+        assert_eq!(res.from_date, today);
+        assert_eq!(res.to_date, None);
+        assert_eq!(res.duration_seconds, Some(3600));
+        assert_eq!(db.tables().action_goals.len(), 1);
+    }
+
+    #[test]
+    fn updates_active_goal_from_today() {
+        let db = InMemoryDb::default();
+        let user = factory::user().try_into_model().unwrap();
+        let action = db.insert(factory::action(user.id).track_type(ActionTrackType::Count));
+        let today = user.to_user_timezone(Utc::now()).date_naive();
+        let active_goal = db.insert(
+            factory::action_goal(user.id, action.id)
+                .from_date(today)
+                .count(Some(1)),
+        );
+
+        let res = set_new(
+            &db,
+            &user,
+            ActionGoalSetNewRequest {
+                action_id: action.id,
+                duration_seconds: None,
+                count: Some(3),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(res.id, active_goal.id);
+        assert_eq!(res.count, Some(3));
+        assert_eq!(db.tables().action_goals.len(), 1);
+    }
+
+    #[test]
+    fn ends_earlier_active_goal_yesterday() {
+        let db = InMemoryDb::default();
+        let user = factory::user().try_into_model().unwrap();
+        let action = db.insert(factory::action(user.id));
+        let today = user.to_user_timezone(Utc::now()).date_naive();
+        let active_goal = db.insert(
+            factory::action_goal(user.id, action.id)
+                .from_date(today - Duration::days(7))
+                .duration_seconds(Some(600)),
+        );
+
+        let res = set_new(
+            &db,
+            &user,
+            ActionGoalSetNewRequest {
+                action_id: action.id,
+                duration_seconds: Some(1200),
+                count: None,
+            },
+        )
+        .unwrap();
+
+        assert_ne!(res.id, active_goal.id);
+        assert_eq!(res.from_date, today);
+        let tables = db.tables();
+        let ended_goal = tables
+            .action_goals
+            .iter()
+            .find(|goal| goal.id == active_goal.id)
+            .unwrap();
+        assert_eq!(ended_goal.to_date, Some(today - Duration::days(1)));
+        assert_eq!(ended_goal.duration_seconds, Some(600));
+    }
+
+    #[test]
+    fn rejects_count_for_time_span_action() {
+        let db = InMemoryDb::default();
+        let user = factory::user().try_into_model().unwrap();
+        let action = db.insert(factory::action(user.id));
+
+        let res = set_new(
+            &db,
+            &user,
+            ActionGoalSetNewRequest {
+                action_id: action.id,
+                duration_seconds: None,
+                count: Some(3),
+            },
+        );
+
+        assert!(matches!(
+            res,
+            Err(UseCaseError::BadRequest(
+                ErrorCode::ActionGoalCountNotAllowed
+            ))
+        ));
+        assert!(db.tables().action_goals.is_empty());
+    }
+
+    #[test]
+    fn does_not_set_goal_of_action_of_other_user() {
+        let db = InMemoryDb::default();
+        let user = factory::user().try_into_model().unwrap();
+        let other_user = factory::user().try_into_model().unwrap();
+        let action = db.insert(factory::action(other_user.id));
+
+        let res = set_new(
+            &db,
+            &user,
+            ActionGoalSetNewRequest {
+                action_id: action.id,
+                duration_seconds: Some(3600),
+                count: None,
+            },
+        );
+
+        assert!(matches!(
+            res,
+            Err(UseCaseError::NotFound(ErrorCode::ActionNotFound))
+        ));
+    }
+}
@@ -1,11 +1,16 @@
 use chrono::NaiveDate;
 use sea_orm::{DerivePartialModel, FromQueryResult};
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 use entities::{action_goal, prelude::ActionGoal};
 
-#[derive(Serialize, Deserialize, DerivePartialModel, FromQueryResult, PartialEq, Debug)]
+use crate::validation::{FieldViolation, NonNegative, Validate, Validator};
+
+#[derive(
+    Serialize, Deserialize, DerivePartialModel, FromQueryResult, PartialEq, Debug, ToSchema,
+)]
 #[sea_orm(entity = "ActionGoal")]
 pub struct ActionGoalVisible {
     pub id: Uuid,
@@ -33,14 +38,27 @@ impl From<action_goal::Model> for ActionGoalVisible {
     }
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, IntoParams)]
 pub struct ActionGoalRemoveQuery {
     pub action_id: Uuid,
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Serialize, ToSchema)]
 pub struct ActionGoalSetNewRequest {
     pub action_id: Uuid,
     pub duration_seconds: Option<i32>,
     pub count: Option<i32>,
 }
+
+impl Validate for ActionGoalSetNewRequest {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        Validator::new()
+            .optional_field(
+                "duration_seconds",
+                self.duration_seconds.as_ref(),
+                &[&NonNegative],
+            )
+            .optional_field("count", self.count.as_ref(), &[&NonNegative])
+            .finish()
+    }
+}
@@ -2,21 +2,19 @@ use crate::{
     my_way::action_tracks::types::{
         ActionTrackAggregation, ActionTrackAggregationDuration, ActionTrackAggregationQuery,
     },
-    UseCaseError,
+    ErrorCode, UseCaseError,
 };
 use chrono::{DateTime, Datelike, FixedOffset, NaiveDate};
 use db_adapters::{
-    action_track_adapter::{
-        ActionTrackAdapter, ActionTrackFilter, ActionTrackOrder, ActionTrackQuery,
-    },
+    action_track_adapter::{ActionTrackFilter, ActionTrackOrder, ActionTrackQuery},
     Order,
 };
 use entities::user as user_entity;
 
-pub async fn aggregate_action_tracks<'a>(
+pub async fn aggregate_action_tracks(
     user: user_entity::Model,
     params: ActionTrackAggregationQuery,
-    action_track_adapter: ActionTrackAdapter<'a>,
+    action_track_adapter: impl ActionTrackFilter + ActionTrackOrder + ActionTrackQuery,
 ) -> Result<ActionTrackAggregation, UseCaseError> {
     let params = parse_params(params)?;
     let mut query = action_track_adapter.filter_eq_user(&user);
@@ -67,7 +65,7 @@ fn parse_params(params: ActionTrackAggregationQuery) -> Result<ParsedParams, Use
     match params.dates {
         Some(dates) => match params.started_at_gte.is_some() || params.started_at_lte.is_some() {
             true => Err(UseCaseError::BadRequest(
-                "dates and started_at_gte/lte cannot be queried at the same time.".to_string(),
+                ErrorCode::ActionTrackDatesWithStartedAtRange,
             )),
             false => {
                 let parsed_dates: Vec<NaiveDate> = dates
@@ -98,3 +96,119 @@ fn parse_params(params: ActionTrackAggregationQuery) -> Result<ParsedParams, Use
         }),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use futures::executor::block_on;
+    use sea_orm::TryIntoModel;
+
+    use common::factory::{self, ActionFactory, ActionTrackFactory};
+    use db_adapters::in_memory::{InMemoryActionTrackAdapter, InMemoryDb};
+
+    use super::*;
+
+    fn query() -> ActionTrackAggregationQuery {
+        ActionTrackAggregationQuery {
+            started_at_gte: None,
+            started_at_lte: None,
+            dates: None,
+        }
+    }
+
+    #[test]
+    fn sums_durations_of_ended_tracks_by_action() {
+        let db = InMemoryDb::default();
+        let user = factory::user().try_into_model().unwrap();
+        let other_user = factory::user().try_into_model().unwrap();
+        let action = db.insert(factory::action(user.id));
+        let archived_action = db.insert(factory::action(user.id).archived(true));
+        let other_action = db.insert(factory::action(other_user.id));
+        for duration in [Some(120), Some(30), None] {
+            db.insert(
+                factory::action_track(user.id)
+                    .action_id(action.id)
+                    .duration(duration),
+            );
+        }
+        db.insert(
+            factory::action_track(user.id)
+                .action_id(archived_action.id)
+                .duration(Some(60)),
+        );
+        db.insert(
+            factory::action_track(other_user.id)
+                .action_id(other_action.id)
+                .duration(Some(60)),
+        );
+
+        let res = block_on(aggregate_action_tracks(
+            user,
+            query(),
+            InMemoryActionTrackAdapter::init(&db),
+        ))
+        .unwrap();
+
+        assert_eq!(res.durations_by_action.len(), 1);
+        assert_eq!(res.durations_by_action[0].action_id, action.id);
+        assert_eq!(res.durations_by_action[0].duration, 150);
+        assert_eq!(res.durations_by_action[0].count, 2);
+    }
+
+    #[test]
+    fn aggregates_dates_in_user_timezone() {
+        let db = InMemoryDb::default();
+        let user = factory::user().try_into_model().unwrap();
+        let action = db.insert(factory::action(user.id));
+        // NOTE: 2024-01-02 00:30 in Asia/Tokyo, the timezone of the user.
+        let started_at = DateTime::parse_from_rfc3339("2024-01-01T15:30:00Z").unwrap();
+        db.insert(
+            factory::action_track(user.id)
+                .action_id(action.id)
+                .started_at(started_at)
+                .duration(Some(60)),
+        );
+        db.insert(
+            factory::action_track(user.id)
+                .action_id(action.id)
+                .started_at(started_at - Duration::hours(1))
+                .duration(Some(30)),
+        );
+
+        let res = block_on(aggregate_action_tracks(
+            user,
+            ActionTrackAggregationQuery {
+                dates: Some("20240102".to_string()),
+                ..query()
+            },
+            InMemoryActionTrackAdapter::init(&db),
+        ))
+        .unwrap();
+
+        assert_eq!(res.durations_by_action.len(), 1);
+        assert_eq!(res.durations_by_action[0].duration, 60);
+    }
+
+    #[test]
+    fn rejects_dates_with_started_at_range() {
+        let db = InMemoryDb::default();
+        let user = factory::user().try_into_model().unwrap();
+
+        let res = block_on(aggregate_action_tracks(
+            user,
+            ActionTrackAggregationQuery {
+                started_at_gte: Some(Utc::now().into()),
+                dates: Some("20240102".to_string()),
+                ..query()
+            },
+            InMemoryActionTrackAdapter::init(&db),
+        ));
+
+        assert!(matches!(
+            res,
+            Err(UseCaseError::BadRequest(
+                ErrorCode::ActionTrackDatesWithStartedAtRange
+            ))
+        ));
+    }
+}
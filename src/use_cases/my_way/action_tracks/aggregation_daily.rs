@@ -5,7 +5,7 @@ use crate::{
         ActionTrackAggregationDailyQuery, ActionTrackAggregationDuration,
         ActionTrackDailyAggregationItem,
     },
-    UseCaseError,
+    ErrorCode, UseCaseError,
 };
 use chrono::{DateTime, Datelike, Duration, FixedOffset};
 use db_adapters::{
@@ -81,27 +81,25 @@ struct ParsedParams {
     pub year_month: String,
 }
 
-const INVALID_YEAR_MONTH_MESSAGE: &str = "Year_month cannot be parsed into a date.";
-
 fn parse_params(
     params: ActionTrackAggregationDailyQuery,
     user_timezone: &TimezoneEnum,
 ) -> Result<ParsedParams, UseCaseError> {
     let year: u32 = params.year_month[0..4]
         .parse()
-        .map_err(|_| UseCaseError::BadRequest(INVALID_YEAR_MONTH_MESSAGE.to_string()))?;
+        .map_err(|_| UseCaseError::BadRequest(ErrorCode::ActionTrackInvalidYearMonth))?;
     let month: u32 = params.year_month[4..6]
         .parse()
-        .map_err(|_| UseCaseError::BadRequest(INVALID_YEAR_MONTH_MESSAGE.to_string()))?;
+        .map_err(|_| UseCaseError::BadRequest(ErrorCode::ActionTrackInvalidYearMonth))?;
 
     let utc_start = DateTime::parse_from_rfc3339(&format!("{year}-{:0>2}-01T00:00:00Z", month))
-        .map_err(|_| UseCaseError::BadRequest(INVALID_YEAR_MONTH_MESSAGE.to_string()))?;
+        .map_err(|_| UseCaseError::BadRequest(ErrorCode::ActionTrackInvalidYearMonth))?;
 
     let utc_end = match month == 12 {
         true => DateTime::parse_from_rfc3339(&format!("{}-01-01T23:59:59Z", year + 1)),
         false => DateTime::parse_from_rfc3339(&format!("{year}-{:0>2}-01T23:59:59Z", month + 1)),
     }
-    .map_err(|_| UseCaseError::BadRequest(INVALID_YEAR_MONTH_MESSAGE.to_string()))?
+    .map_err(|_| UseCaseError::BadRequest(ErrorCode::ActionTrackInvalidYearMonth))?
         - Duration::days(1);
 
     match user_timezone {
@@ -4,7 +4,7 @@ use sea_orm::DbErr;
 use crate::{
     my_way::action_tracks::types::{ActionTrackCreateRequest, ActionTrackVisible},
     users::first_track_at_synchronizer::FirstTrackAtSynchronizer,
-    UseCaseError,
+    ErrorCode, UseCaseError,
 };
 use db_adapters::{
     action_adapter::{ActionAdapter, ActionFilter, ActionQuery},
@@ -26,9 +26,7 @@ pub async fn create_action_track<'a>(
         .get_by_id(req.action_id)
         .await
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
-        .ok_or(UseCaseError::NotFound(
-            "An action with that id does not exist.".to_string(),
-        ))?;
+        .ok_or(UseCaseError::NotFound(ErrorCode::ActionNotFound))?;
 
     let params = match action.track_type {
         ActionTrackType::TimeSpan => CreateActionTrackParams {
@@ -52,9 +50,7 @@ pub async fn create_action_track<'a>(
         .await
         .map_err(|e| match &e {
             DbErr::Custom(message) => match CustomDbErr::from(message) {
-                CustomDbErr::Duplicate => UseCaseError::Conflict(
-                    "A track for the same action which starts at the same time exists.".to_string(),
-                ),
+                CustomDbErr::Duplicate => UseCaseError::Conflict(ErrorCode::ActionTrackDuplicate),
                 _ => UseCaseError::InternalServerError(format!("{:?}", e)),
             },
             _ => UseCaseError::InternalServerError(format!("{:?}", e)),
@@ -1,10 +1,12 @@
 use crate::{
     my_way::action_tracks::types::{ActionTrackListQuery, ActionTrackVisible},
+    pagination::{Page, PageParams},
     UseCaseError,
 };
 use db_adapters::{
     action_track_adapter::{
-        ActionTrackAdapter, ActionTrackFilter, ActionTrackOrder, ActionTrackQuery,
+        ActionTrackAdapter, ActionTrackCursor, ActionTrackFilter, ActionTrackLimit,
+        ActionTrackOrder, ActionTrackQuery,
     },
     Order,
 };
@@ -14,7 +16,8 @@ pub async fn list_action_tracks<'a>(
     user: user_entity::Model,
     params: ActionTrackListQuery,
     action_track_adapter: ActionTrackAdapter<'a>,
-) -> Result<Vec<ActionTrackVisible>, UseCaseError> {
+) -> Result<Page<ActionTrackVisible>, UseCaseError> {
+    let page = PageParams::<ActionTrackCursor>::parse(params.limit, params.cursor.as_deref())?;
     let mut query = action_track_adapter
         .filter_eq_user(&user)
         .filter_eq_archived_action(false);
@@ -29,14 +32,27 @@ pub async fn list_action_tracks<'a>(
     if let Some(started_at_lte) = params.started_at_lte {
         query = query.filter_started_at_lte(started_at_lte);
     }
+    if let Some(page) = &page {
+        if let Some(after) = &page.after {
+            query = query.filter_after_cursor(after);
+        }
+        query = query.limit(page.limit + 1);
+    }
     let action_tracks = query
         .order_by_started_at(Order::Desc)
+        .order_by_id(Order::Desc)
         .get_all()
         .await
-        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?;
-
-    Ok(action_tracks
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
         .iter()
         .map(|at| ActionTrackVisible::from(at))
-        .collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    Ok(match page {
+        Some(page) => Page::from_overfetched(action_tracks, page.limit, |at| ActionTrackCursor {
+            started_at: at.started_at,
+            id: at.id,
+        }),
+        None => Page::all(action_tracks),
+    })
 }
@@ -2,8 +2,11 @@ use chrono::{DateTime, FixedOffset};
 use entities::{action_track, prelude::ActionTrack};
 use sea_orm::{DerivePartialModel, FromQueryResult};
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
-#[derive(Serialize, Deserialize, DerivePartialModel, FromQueryResult, PartialEq, Debug)]
+#[derive(
+    Serialize, Deserialize, DerivePartialModel, FromQueryResult, PartialEq, Debug, ToSchema,
+)]
 #[sea_orm(entity = "ActionTrack")]
 pub struct ActionTrackVisible {
     pub id: uuid::Uuid,
@@ -31,50 +34,54 @@ impl From<&action_track::Model> for ActionTrackVisible {
     }
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, IntoParams)]
 pub struct ActionTrackListQuery {
     pub active_only: Option<bool>,
     pub started_at_gte: Option<DateTime<FixedOffset>>,
     pub started_at_lte: Option<DateTime<FixedOffset>>,
+    /// Page size up to 100. Everything is returned when both limit and cursor are omitted.
+    pub limit: Option<u64>,
+    /// `x-next-cursor` header of the previous page.
+    pub cursor: Option<String>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, IntoParams)]
 pub struct ActionTrackAggregationQuery {
     pub started_at_gte: Option<DateTime<FixedOffset>>,
     pub started_at_lte: Option<DateTime<FixedOffset>>,
     // NOTE: multiple yyyymmdd strings connected with "," (example: ?dates=20250601,20250625)
     pub dates: Option<String>,
 }
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, IntoParams)]
 pub struct ActionTrackAggregationDailyQuery {
     pub year_month: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
 pub struct ActionTrackAggregation {
     pub durations_by_action: Vec<ActionTrackAggregationDuration>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
 pub struct ActionTrackAggregationDuration {
     pub action_id: uuid::Uuid,
     pub duration: i64,
     pub count: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
 pub struct ActionTrackDailyAggregationItem {
     pub date: u32,
     pub aggregation: Vec<ActionTrackAggregationDuration>,
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Serialize, ToSchema)]
 pub struct ActionTrackCreateRequest {
     pub started_at: DateTime<FixedOffset>,
     pub action_id: uuid::Uuid,
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Serialize, ToSchema)]
 pub struct ActionTrackUpdateRequest {
     pub action_id: uuid::Uuid,
     pub started_at: DateTime<FixedOffset>,
@@ -5,7 +5,7 @@ use uuid::Uuid;
 use crate::{
     my_way::action_tracks::types::{ActionTrackUpdateRequest, ActionTrackVisible},
     users::first_track_at_synchronizer::FirstTrackAtSynchronizer,
-    UseCaseError,
+    ErrorCode, UseCaseError,
 };
 use db_adapters::{
     action_track_adapter::{
@@ -32,9 +32,7 @@ pub async fn update_action_track<'a>(
         .get_by_id(action_track_id)
         .await
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
-        .ok_or(UseCaseError::NotFound(
-            "ActionTrack with this id was not found".to_string(),
-        ))?;
+        .ok_or(UseCaseError::NotFound(ErrorCode::ActionTrackNotFound))?;
     let old_action_track = action_track.clone();
 
     let new_action_track = action_track_adapter
@@ -58,9 +56,7 @@ pub async fn update_action_track<'a>(
         .await
         .map_err(|e| match &e {
             DbErr::Custom(ce) => match CustomDbErr::from(ce) {
-                CustomDbErr::Duplicate => UseCaseError::Conflict(
-                    "A track for the same action which starts at the same time exists.".to_string(),
-                ),
+                CustomDbErr::Duplicate => UseCaseError::Conflict(ErrorCode::ActionTrackDuplicate),
                 _ => UseCaseError::InternalServerError(format!("{:?}", e)),
             },
             _ => UseCaseError::InternalServerError(format!("{:?}", e)),
@@ -81,7 +77,7 @@ fn _parse_params(
         .is_some_and(|ended_at| ended_at < params.started_at)
     {
         return Err(UseCaseError::BadRequest(
-            "Ended_at must be later than started_at.".to_string(),
+            ErrorCode::ActionTrackEndedAtBeforeStartedAt,
         ));
     }
 
@@ -3,7 +3,7 @@ use uuid::Uuid;
 
 use crate::{
     my_way::actions::types::{ActionTrackTypeConversionRequest, ActionVisible},
-    UseCaseError,
+    ErrorCode, UseCaseError,
 };
 use db_adapters::{
     action_adapter::{ActionAdapter, ActionFilter, ActionMutation, ActionQuery},
@@ -27,9 +27,7 @@ pub async fn convert_action_track_type<'a>(
         .get_by_id(action_id)
         .await
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
-        .ok_or(UseCaseError::NotFound(
-            "Action with this id was not found".to_string(),
-        ))?;
+        .ok_or(UseCaseError::NotFound(ErrorCode::ActionNotFound))?;
 
     if action.track_type == params.track_type {
         return Ok(ActionVisible::from(action));
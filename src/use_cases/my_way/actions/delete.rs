@@ -21,7 +21,7 @@ pub async fn delete_action<'a>(
     };
 
     action_adapter
-        .delete(action)
+        .trash(action)
         .await
         .map(|_| ())
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))
@@ -1,6 +1,6 @@
 use uuid::Uuid;
 
-use crate::{my_way::actions::types::ActionVisible, UseCaseError};
+use crate::{my_way::actions::types::ActionVisible, ErrorCode, UseCaseError};
 use db_adapters::action_adapter::{ActionAdapter, ActionFilter, ActionQuery};
 use entities::user as user_entity;
 
@@ -15,7 +15,5 @@ pub async fn get_action<'a>(
         .await
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
         .map(|action| ActionVisible::from(action))
-        .ok_or(UseCaseError::NotFound(
-            "Action with this id was not found".to_string(),
-        ))
+        .ok_or(UseCaseError::NotFound(ErrorCode::ActionNotFound))
 }
@@ -0,0 +1,37 @@
+use uuid::Uuid;
+
+use crate::{my_way::actions::types::ActionRevisionVisible, ErrorCode, UseCaseError};
+use db_adapters::{
+    action_adapter::{ActionAdapter, ActionFilter, ActionQuery},
+    revision_adapter::{RevisionAdapter, RevisionFilter, RevisionOrder, RevisionQuery},
+    Order::Desc,
+};
+use entities::{action, user as user_entity};
+
+/// Lists the earlier versions of the action, most recent first.
+pub async fn list_action_revisions<'a>(
+    user: user_entity::Model,
+    action_id: Uuid,
+    action_adapter: ActionAdapter<'a>,
+    revision_adapter: RevisionAdapter<'a>,
+) -> Result<Vec<ActionRevisionVisible>, UseCaseError> {
+    action_adapter
+        .filter_eq_user(&user)
+        .get_by_id(action_id)
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
+        .ok_or(UseCaseError::NotFound(ErrorCode::ActionNotFound))?;
+
+    revision_adapter
+        .filter_eq_user(&user)
+        .filter_eq_record::<action::Entity>(action_id)
+        .order_by_created_at(Desc)
+        .order_by_id(Desc)
+        .get_all()
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
+        .into_iter()
+        .map(ActionRevisionVisible::try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))
+}
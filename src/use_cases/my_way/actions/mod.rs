@@ -7,5 +7,7 @@ pub mod create;
 pub mod delete;
 pub mod get;
 pub mod list;
+pub mod list_revisions;
+pub mod restore_revision;
 pub mod unarchive;
 pub mod update;
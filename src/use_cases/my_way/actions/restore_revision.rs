@@ -0,0 +1,55 @@
+use uuid::Uuid;
+
+use crate::{
+    my_way::actions::types::{ActionRevisionVisible, ActionVisible},
+    ErrorCode, UseCaseError,
+};
+use db_adapters::{
+    action_adapter::{
+        ActionAdapter, ActionFilter, ActionMutation, ActionQuery, UpdateActionParams,
+    },
+    revision_adapter::{RevisionAdapter, RevisionFilter, RevisionQuery},
+};
+use entities::{action, user as user_entity};
+
+/// Brings the text of an earlier version back. This is an update like any other, so the version
+/// it replaces is kept as a revision too.
+/// The color stays as it is.
+pub async fn restore_action_revision<'a>(
+    user: user_entity::Model,
+    action_id: Uuid,
+    revision_id: Uuid,
+    action_adapter: ActionAdapter<'a>,
+    revision_adapter: RevisionAdapter<'a>,
+) -> Result<ActionVisible, UseCaseError> {
+    let action = action_adapter
+        .clone()
+        .filter_eq_user(&user)
+        .get_by_id(action_id)
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
+        .ok_or(UseCaseError::NotFound(ErrorCode::ActionNotFound))?;
+    let revision = revision_adapter
+        .filter_eq_user(&user)
+        .filter_eq_record::<action::Entity>(action_id)
+        .get_by_id(revision_id)
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
+        .ok_or(UseCaseError::NotFound(ErrorCode::RevisionNotFound))?;
+    let revision = ActionRevisionVisible::try_from(revision)
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?;
+
+    action_adapter
+        .update(
+            action,
+            UpdateActionParams {
+                name: revision.name,
+                discipline: revision.discipline,
+                memo: revision.memo,
+                color: None,
+            },
+        )
+        .await
+        .map(ActionVisible::from)
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))
+}
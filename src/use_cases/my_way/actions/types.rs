@@ -1,13 +1,24 @@
 use chrono::{DateTime, FixedOffset};
 use sea_orm::{DerivePartialModel, FromQueryResult};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-use entities::{action, action_goal, prelude::Action, sea_orm_active_enums::ActionTrackType};
+use entities::{
+    action, action_goal, prelude::Action, revision, sea_orm_active_enums::ActionTrackType,
+};
 
-use crate::my_way::action_goals::types::ActionGoalVisible;
+use crate::{
+    my_way::action_goals::types::ActionGoalVisible,
+    validation::{
+        FieldViolation, HexColor, MaxChars, NotBlank, Validate, Validator, DESCRIPTION_MAX_CHARS,
+        NAME_MAX_CHARS,
+    },
+};
 
-#[derive(Serialize, Deserialize, DerivePartialModel, FromQueryResult, PartialEq, Debug)]
+#[derive(
+    Serialize, Deserialize, DerivePartialModel, FromQueryResult, PartialEq, Debug, ToSchema,
+)]
 #[sea_orm(entity = "Action")]
 pub struct ActionVisible {
     pub id: Uuid,
@@ -43,7 +54,7 @@ impl From<action::Model> for ActionVisible {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
 pub struct ActionVisibleWithGoal {
     pub id: Uuid,
     pub name: String,
@@ -83,7 +94,7 @@ impl From<(action::Model, Option<action_goal::Model>)> for ActionVisibleWithGoal
     }
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Serialize, ToSchema)]
 pub struct ActionCreateRequest {
     pub name: String,
     pub discipline: Option<String>,
@@ -91,7 +102,29 @@ pub struct ActionCreateRequest {
     pub track_type: ActionTrackType,
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+impl Validate for ActionCreateRequest {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        Validator::new()
+            .field(
+                "name",
+                self.name.as_str(),
+                &[&NotBlank, &MaxChars(NAME_MAX_CHARS)],
+            )
+            .optional_field(
+                "discipline",
+                self.discipline.as_deref(),
+                &[&MaxChars(DESCRIPTION_MAX_CHARS)],
+            )
+            .optional_field(
+                "memo",
+                self.memo.as_deref(),
+                &[&MaxChars(DESCRIPTION_MAX_CHARS)],
+            )
+            .finish()
+    }
+}
+
+#[derive(Deserialize, Debug, Serialize, ToSchema)]
 pub struct ActionUpdateRequest {
     pub name: String,
     pub discipline: Option<String>,
@@ -99,12 +132,71 @@ pub struct ActionUpdateRequest {
     pub color: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+impl Validate for ActionUpdateRequest {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        Validator::new()
+            .field(
+                "name",
+                self.name.as_str(),
+                &[&NotBlank, &MaxChars(NAME_MAX_CHARS)],
+            )
+            .optional_field(
+                "discipline",
+                self.discipline.as_deref(),
+                &[&MaxChars(DESCRIPTION_MAX_CHARS)],
+            )
+            .optional_field(
+                "memo",
+                self.memo.as_deref(),
+                &[&MaxChars(DESCRIPTION_MAX_CHARS)],
+            )
+            .optional_field("color", self.color.as_deref(), &[&HexColor])
+            .finish()
+    }
+}
+
+#[derive(Deserialize, Debug, Serialize, ToSchema)]
 pub struct ActionBulkUpdateOrderRequest {
     pub ordering: Vec<uuid::Uuid>,
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Serialize, ToSchema)]
 pub struct ActionTrackTypeConversionRequest {
     pub track_type: ActionTrackType,
 }
+
+/// A version of the action as it was until an update replaced it at `replaced_at`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct ActionRevisionVisible {
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub discipline: Option<String>,
+    pub memo: Option<String>,
+    pub updated_at: DateTime<FixedOffset>,
+    pub replaced_at: DateTime<FixedOffset>,
+}
+
+/// Fields of `action::Model` kept in `revision.snapshot` which a revision shows.
+#[derive(Deserialize)]
+struct ActionSnapshot {
+    name: String,
+    discipline: Option<String>,
+    memo: Option<String>,
+    updated_at: DateTime<FixedOffset>,
+}
+
+impl TryFrom<revision::Model> for ActionRevisionVisible {
+    type Error = serde_json::Error;
+
+    fn try_from(item: revision::Model) -> Result<Self, Self::Error> {
+        let snapshot: ActionSnapshot = serde_json::from_value(item.snapshot)?;
+        Ok(ActionRevisionVisible {
+            id: item.id,
+            name: snapshot.name,
+            discipline: snapshot.discipline,
+            memo: snapshot.memo,
+            updated_at: snapshot.updated_at,
+            replaced_at: item.created_at,
+        })
+    }
+}
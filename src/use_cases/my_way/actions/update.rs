@@ -2,7 +2,7 @@ use uuid::Uuid;
 
 use crate::{
     my_way::actions::types::{ActionUpdateRequest, ActionVisible},
-    UseCaseError,
+    ErrorCode, UseCaseError,
 };
 use db_adapters::action_adapter::{
     ActionAdapter, ActionFilter, ActionMutation, ActionQuery, UpdateActionParams,
@@ -15,17 +15,13 @@ pub async fn update_action<'a>(
     action_id: Uuid,
     action_adapter: ActionAdapter<'a>,
 ) -> Result<ActionVisible, UseCaseError> {
-    let params = _parse_params(params)?;
-
     let action = action_adapter
         .clone()
         .filter_eq_user(&user)
         .get_by_id(action_id)
         .await
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
-        .ok_or(UseCaseError::NotFound(
-            "Action with this id was not found".to_string(),
-        ))?;
+        .ok_or(UseCaseError::NotFound(ErrorCode::ActionNotFound))?;
 
     action_adapter
         .update(
@@ -41,27 +37,3 @@ pub async fn update_action<'a>(
         .map(|action| ActionVisible::from(action))
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))
 }
-
-fn _parse_params(params: ActionUpdateRequest) -> Result<ActionUpdateRequest, UseCaseError> {
-    if let Some(color) = &params.color {
-        if color.len() != 7 {
-            return Err(UseCaseError::BadRequest(
-                "color must be 7 characters long.".to_string(),
-            ));
-        }
-        if !color.starts_with('#') {
-            return Err(UseCaseError::BadRequest(
-                "color must be hex color code.".to_string(),
-            ));
-        }
-        for c in color.split_at(1).1.chars() {
-            if !c.is_ascii_hexdigit() {
-                return Err(UseCaseError::BadRequest(
-                    "color must be hex color code.".to_string(),
-                ));
-            }
-        }
-    }
-
-    Ok(params)
-}
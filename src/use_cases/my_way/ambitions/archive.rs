@@ -1,6 +1,6 @@
 use uuid::Uuid;
 
-use crate::{my_way::ambitions::types::AmbitionVisible, UseCaseError};
+use crate::{my_way::ambitions::types::AmbitionVisible, ErrorCode, UseCaseError};
 use db_adapters::ambition_adapter::{
     AmbitionAdapter, AmbitionFilter, AmbitionMutation, AmbitionQuery,
 };
@@ -17,9 +17,7 @@ pub async fn archive_ambition<'a>(
         .get_by_id(ambition_id)
         .await
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
-        .ok_or(UseCaseError::NotFound(
-            "Ambition with this id was not found".to_string(),
-        ))?;
+        .ok_or(UseCaseError::NotFound(ErrorCode::AmbitionNotFound))?;
 
     ambition_adapter
         .archive(ambition)
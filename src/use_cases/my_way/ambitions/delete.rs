@@ -22,7 +22,7 @@ pub async fn delete_ambition<'a>(
         None => return Ok(()),
     };
     ambition_adapter
-        .delete(ambition)
+        .trash(ambition)
         .await
         .map(|_| ())
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))
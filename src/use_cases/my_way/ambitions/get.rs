@@ -1,6 +1,6 @@
 use uuid::Uuid;
 
-use crate::{my_way::ambitions::types::AmbitionVisible, UseCaseError};
+use crate::{my_way::ambitions::types::AmbitionVisible, ErrorCode, UseCaseError};
 use db_adapters::ambition_adapter::{AmbitionAdapter, AmbitionFilter, AmbitionQuery};
 use entities::user as user_entity;
 
@@ -14,8 +14,6 @@ pub async fn get_ambition<'a>(
         .get_by_id(ambition_id)
         .await
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
-        .ok_or(UseCaseError::NotFound(
-            "Ambition with this id was not found".to_string(),
-        ))
+        .ok_or(UseCaseError::NotFound(ErrorCode::AmbitionNotFound))
         .map(|ambition| AmbitionVisible::from(ambition))
 }
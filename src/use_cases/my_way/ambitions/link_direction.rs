@@ -0,0 +1,35 @@
+use uuid::Uuid;
+
+use crate::{ErrorCode, UseCaseError};
+use db_adapters::{
+    ambition_adapter::{AmbitionAdapter, AmbitionFilter, AmbitionMutation, AmbitionQuery},
+    direction_adapter::{DirectionAdapter, DirectionFilter, DirectionQuery},
+};
+use entities::user as user_entity;
+
+pub async fn link_direction<'a>(
+    user: user_entity::Model,
+    ambition_id: Uuid,
+    direction_id: Uuid,
+    ambition_adapter: AmbitionAdapter<'a>,
+    direction_adapter: DirectionAdapter<'a>,
+) -> Result<(), UseCaseError> {
+    let ambition = ambition_adapter
+        .clone()
+        .filter_eq_user(&user)
+        .get_by_id(ambition_id)
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
+        .ok_or(UseCaseError::NotFound(ErrorCode::AmbitionNotFound))?;
+    let direction = direction_adapter
+        .filter_eq_user(&user)
+        .get_by_id(direction_id)
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
+        .ok_or(UseCaseError::NotFound(ErrorCode::DirectionNotFound))?;
+
+    ambition_adapter
+        .link_direction(&ambition, direction.id)
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))
+}
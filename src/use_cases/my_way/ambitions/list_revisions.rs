@@ -0,0 +1,37 @@
+use uuid::Uuid;
+
+use crate::{my_way::ambitions::types::AmbitionRevisionVisible, ErrorCode, UseCaseError};
+use db_adapters::{
+    ambition_adapter::{AmbitionAdapter, AmbitionFilter, AmbitionQuery},
+    revision_adapter::{RevisionAdapter, RevisionFilter, RevisionOrder, RevisionQuery},
+    Order::Desc,
+};
+use entities::{ambition, user as user_entity};
+
+/// Lists the earlier versions of the ambition, most recent first.
+pub async fn list_ambition_revisions<'a>(
+    user: user_entity::Model,
+    ambition_id: Uuid,
+    ambition_adapter: AmbitionAdapter<'a>,
+    revision_adapter: RevisionAdapter<'a>,
+) -> Result<Vec<AmbitionRevisionVisible>, UseCaseError> {
+    ambition_adapter
+        .filter_eq_user(&user)
+        .get_by_id(ambition_id)
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
+        .ok_or(UseCaseError::NotFound(ErrorCode::AmbitionNotFound))?;
+
+    revision_adapter
+        .filter_eq_user(&user)
+        .filter_eq_record::<ambition::Entity>(ambition_id)
+        .order_by_created_at(Desc)
+        .order_by_id(Desc)
+        .get_all()
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
+        .into_iter()
+        .map(AmbitionRevisionVisible::try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))
+}
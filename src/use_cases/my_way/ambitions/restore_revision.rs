@@ -0,0 +1,52 @@
+use uuid::Uuid;
+
+use crate::{
+    my_way::ambitions::types::{AmbitionRevisionVisible, AmbitionVisible},
+    ErrorCode, UseCaseError,
+};
+use db_adapters::{
+    ambition_adapter::{
+        AmbitionAdapter, AmbitionFilter, AmbitionMutation, AmbitionQuery, UpdateAmbitionParams,
+    },
+    revision_adapter::{RevisionAdapter, RevisionFilter, RevisionQuery},
+};
+use entities::{ambition, user as user_entity};
+
+/// Brings the text of an earlier version back. This is an update like any other, so the version
+/// it replaces is kept as a revision too.
+pub async fn restore_ambition_revision<'a>(
+    user: user_entity::Model,
+    ambition_id: Uuid,
+    revision_id: Uuid,
+    ambition_adapter: AmbitionAdapter<'a>,
+    revision_adapter: RevisionAdapter<'a>,
+) -> Result<AmbitionVisible, UseCaseError> {
+    let ambition = ambition_adapter
+        .clone()
+        .filter_eq_user(&user)
+        .get_by_id(ambition_id)
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
+        .ok_or(UseCaseError::NotFound(ErrorCode::AmbitionNotFound))?;
+    let revision = revision_adapter
+        .filter_eq_user(&user)
+        .filter_eq_record::<ambition::Entity>(ambition_id)
+        .get_by_id(revision_id)
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
+        .ok_or(UseCaseError::NotFound(ErrorCode::RevisionNotFound))?;
+    let revision = AmbitionRevisionVisible::try_from(revision)
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?;
+
+    ambition_adapter
+        .update(
+            ambition,
+            UpdateAmbitionParams {
+                name: revision.name,
+                description: revision.description,
+            },
+        )
+        .await
+        .map(AmbitionVisible::from)
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))
+}
@@ -1,10 +1,17 @@
 use chrono::{DateTime, FixedOffset};
 use sea_orm::{DerivePartialModel, FromQueryResult};
 
-use entities::{ambition, prelude::Ambition};
+use entities::{ambition, prelude::Ambition, revision};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Serialize, Deserialize, DerivePartialModel, FromQueryResult, PartialEq, Debug)]
+use crate::validation::{
+    FieldViolation, MaxChars, NotBlank, Validate, Validator, DESCRIPTION_MAX_CHARS, NAME_MAX_CHARS,
+};
+
+#[derive(
+    Serialize, Deserialize, DerivePartialModel, FromQueryResult, PartialEq, Debug, ToSchema,
+)]
 #[sea_orm(entity = "Ambition")]
 pub struct AmbitionVisible {
     pub id: uuid::Uuid,
@@ -34,19 +41,86 @@ impl From<ambition::Model> for AmbitionVisible {
     }
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Serialize, ToSchema)]
 pub struct AmbitionCreateRequest {
     pub name: String,
     pub description: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+impl Validate for AmbitionCreateRequest {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        Validator::new()
+            .field(
+                "name",
+                self.name.as_str(),
+                &[&NotBlank, &MaxChars(NAME_MAX_CHARS)],
+            )
+            .optional_field(
+                "description",
+                self.description.as_deref(),
+                &[&MaxChars(DESCRIPTION_MAX_CHARS)],
+            )
+            .finish()
+    }
+}
+
+#[derive(Deserialize, Debug, Serialize, ToSchema)]
 pub struct AmbitionUpdateRequest {
     pub name: String,
     pub description: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+impl Validate for AmbitionUpdateRequest {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        Validator::new()
+            .field(
+                "name",
+                self.name.as_str(),
+                &[&NotBlank, &MaxChars(NAME_MAX_CHARS)],
+            )
+            .optional_field(
+                "description",
+                self.description.as_deref(),
+                &[&MaxChars(DESCRIPTION_MAX_CHARS)],
+            )
+            .finish()
+    }
+}
+
+#[derive(Deserialize, Debug, Serialize, ToSchema)]
 pub struct AmbitionBulkUpdateOrderingRequest {
     pub ordering: Vec<uuid::Uuid>,
 }
+
+/// A version of the ambition as it was until an update replaced it at `replaced_at`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct AmbitionRevisionVisible {
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub updated_at: DateTime<FixedOffset>,
+    pub replaced_at: DateTime<FixedOffset>,
+}
+
+/// Fields of `ambition::Model` kept in `revision.snapshot` which a revision shows.
+#[derive(Deserialize)]
+struct AmbitionSnapshot {
+    name: String,
+    description: Option<String>,
+    updated_at: DateTime<FixedOffset>,
+}
+
+impl TryFrom<revision::Model> for AmbitionRevisionVisible {
+    type Error = serde_json::Error;
+
+    fn try_from(item: revision::Model) -> Result<Self, Self::Error> {
+        let snapshot: AmbitionSnapshot = serde_json::from_value(item.snapshot)?;
+        Ok(AmbitionRevisionVisible {
+            id: item.id,
+            name: snapshot.name,
+            description: snapshot.description,
+            updated_at: snapshot.updated_at,
+            replaced_at: item.created_at,
+        })
+    }
+}
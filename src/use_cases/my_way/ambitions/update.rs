@@ -2,7 +2,7 @@ use uuid::Uuid;
 
 use crate::{
     my_way::ambitions::types::{AmbitionUpdateRequest, AmbitionVisible},
-    UseCaseError,
+    ErrorCode, UseCaseError,
 };
 use db_adapters::ambition_adapter::{
     AmbitionAdapter, AmbitionFilter, AmbitionMutation, AmbitionQuery, UpdateAmbitionParams,
@@ -21,9 +21,7 @@ pub async fn update_ambition<'a>(
         .get_by_id(ambition_id)
         .await
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
-        .ok_or(UseCaseError::NotFound(
-            "Ambition with this id was not found".to_string(),
-        ))?;
+        .ok_or(UseCaseError::NotFound(ErrorCode::AmbitionNotFound))?;
 
     ambition_adapter
         .update(
@@ -2,7 +2,7 @@ use crate::{
     my_way::direction_categories::types::{
         DirectionCategoryCreateRequest, DirectionCategoryVisible,
     },
-    UseCaseError,
+    ErrorCode, UseCaseError,
 };
 use db_adapters::direction_category_adapter::{
     CreateDirectionCategoryParams, DirectionCategoryAdapter, DirectionCategoryFilter,
@@ -15,9 +15,20 @@ pub async fn create_direction_category<'a>(
     params: DirectionCategoryCreateRequest,
     category_adapter: DirectionCategoryAdapter<'a>,
 ) -> Result<DirectionCategoryVisible, UseCaseError> {
-    let category_count = category_adapter
+    if let Some(parent_id) = params.parent_id {
+        category_adapter
+            .clone()
+            .filter_eq_user(&user)
+            .get_by_id(parent_id)
+            .await
+            .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
+            .ok_or(UseCaseError::NotFound(ErrorCode::DirectionCategoryNotFound))?;
+    }
+    // Ordering is within the parent.
+    let sibling_count = category_adapter
         .clone()
         .filter_eq_user(&user)
+        .filter_eq_parent_id(params.parent_id)
         .get_count()
         .await
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?;
@@ -26,10 +37,11 @@ pub async fn create_direction_category<'a>(
         .create(CreateDirectionCategoryParams {
             name: params.name.clone(),
             ordering: Some(
-                (category_count + 1)
+                (sibling_count + 1)
                     .try_into()
                     .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?,
             ),
+            parent_id: params.parent_id,
             user_id: user.id,
         })
         .await
@@ -5,14 +5,18 @@ use db_adapters::{
     },
     Order::Asc,
 };
-use entities::user as user_entity;
+use entities::{direction_category, user as user_entity};
+use uuid::Uuid;
 
-use crate::{my_way::direction_categories::types::DirectionCategoryVisible, UseCaseError};
+use crate::{
+    my_way::direction_categories::types::{DirectionCategoryNode, DirectionCategoryVisible},
+    UseCaseError,
+};
 
 pub async fn list_direction_categories<'a>(
     user: user_entity::Model,
     category_adapter: DirectionCategoryAdapter<'a>,
-) -> Result<Vec<DirectionCategoryVisible>, UseCaseError> {
+) -> Result<Vec<DirectionCategoryNode>, UseCaseError> {
     match category_adapter
         .filter_eq_user(&user)
         .order_by_ordering_nulls_last(Asc)
@@ -20,10 +24,22 @@ pub async fn list_direction_categories<'a>(
         .get_all()
         .await
     {
-        Ok(categories) => Ok(categories
-            .iter()
-            .map(|category| DirectionCategoryVisible::from(category))
-            .collect::<Vec<_>>()),
+        Ok(categories) => Ok(to_nodes(&categories, None)),
         Err(e) => Err(UseCaseError::InternalServerError(format!("{:?}", e))),
     }
 }
+
+/// Children of `parent_id` in the order of `categories`, which is kept within each parent.
+fn to_nodes(
+    categories: &[direction_category::Model],
+    parent_id: Option<Uuid>,
+) -> Vec<DirectionCategoryNode> {
+    categories
+        .iter()
+        .filter(|category| category.parent_id == parent_id)
+        .map(|category| DirectionCategoryNode {
+            category: DirectionCategoryVisible::from(category),
+            children: to_nodes(categories, Some(category.id)),
+        })
+        .collect()
+}
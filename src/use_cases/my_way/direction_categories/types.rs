@@ -2,12 +2,18 @@ use sea_orm::{DerivePartialModel, FromQueryResult};
 
 use entities::{direction_category, prelude::DirectionCategory};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Serialize, Deserialize, DerivePartialModel, FromQueryResult, PartialEq, Debug)]
+use crate::validation::{FieldViolation, MaxChars, NotBlank, Validate, Validator, NAME_MAX_CHARS};
+
+#[derive(
+    Serialize, Deserialize, DerivePartialModel, FromQueryResult, PartialEq, Debug, ToSchema,
+)]
 #[sea_orm(entity = "DirectionCategory")]
 pub struct DirectionCategoryVisible {
     pub id: uuid::Uuid,
     pub name: String,
+    pub parent_id: Option<uuid::Uuid>,
 }
 
 impl From<&direction_category::Model> for DirectionCategoryVisible {
@@ -15,6 +21,7 @@ impl From<&direction_category::Model> for DirectionCategoryVisible {
         DirectionCategoryVisible {
             id: item.id,
             name: item.name.clone(),
+            parent_id: item.parent_id,
         }
     }
 }
@@ -25,17 +32,55 @@ impl From<direction_category::Model> for DirectionCategoryVisible {
     }
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+/// A category with its subcategories, each in the order of `ordering` within the parent.
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct DirectionCategoryNode {
+    #[serde(flatten)]
+    pub category: DirectionCategoryVisible,
+    #[schema(no_recursion)]
+    pub children: Vec<DirectionCategoryNode>,
+}
+
+#[derive(Deserialize, Debug, Serialize, ToSchema)]
 pub struct DirectionCategoryCreateRequest {
     pub name: String,
+    /// Top-level if None.
+    pub parent_id: Option<uuid::Uuid>,
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+impl Validate for DirectionCategoryCreateRequest {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        Validator::new()
+            .field(
+                "name",
+                self.name.as_str(),
+                &[&NotBlank, &MaxChars(NAME_MAX_CHARS)],
+            )
+            .finish()
+    }
+}
+
+#[derive(Deserialize, Debug, Serialize, ToSchema)]
 pub struct DirectionCategoryUpdateRequest {
     pub name: String,
+    /// Top-level if None. Cannot be the category itself or one of its subcategories.
+    pub parent_id: Option<uuid::Uuid>,
+}
+
+impl Validate for DirectionCategoryUpdateRequest {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        Validator::new()
+            .field(
+                "name",
+                self.name.as_str(),
+                &[&NotBlank, &MaxChars(NAME_MAX_CHARS)],
+            )
+            .finish()
+    }
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+/// Ids of the children of one parent, or of the top-level categories, in their new order.
+#[derive(Deserialize, Debug, Serialize, ToSchema)]
 pub struct DirectionCategoryBulkUpdateOrderingRequest {
     pub ordering: Vec<uuid::Uuid>,
 }
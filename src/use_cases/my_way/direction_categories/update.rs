@@ -4,13 +4,13 @@ use crate::{
     my_way::direction_categories::types::{
         DirectionCategoryUpdateRequest, DirectionCategoryVisible,
     },
-    UseCaseError,
+    ErrorCode, UseCaseError,
 };
 use db_adapters::direction_category_adapter::{
     DirectionCategoryAdapter, DirectionCategoryFilter, DirectionCategoryMutation,
     DirectionCategoryQuery, UpdateDirectionCategoryParams,
 };
-use entities::user as user_entity;
+use entities::{direction_category, user as user_entity};
 
 pub async fn update_direction_category<'a>(
     user: user_entity::Model,
@@ -18,22 +18,75 @@ pub async fn update_direction_category<'a>(
     category_id: Uuid,
     category_adapter: DirectionCategoryAdapter<'a>,
 ) -> Result<DirectionCategoryVisible, UseCaseError> {
-    let category = category_adapter
+    let categories = category_adapter
         .clone()
         .filter_eq_user(&user)
-        .get_by_id(category_id)
+        .get_all()
         .await
-        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
-        .ok_or(UseCaseError::NotFound("Category not found".to_string()))?;
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?;
+    let category = categories
+        .iter()
+        .find(|category| category.id == category_id)
+        .cloned()
+        .ok_or(UseCaseError::NotFound(ErrorCode::DirectionCategoryNotFound))?;
+    let parent_id = params.parent_id;
+    if let Some(parent_id) = parent_id {
+        if !categories.iter().any(|category| category.id == parent_id) {
+            return Err(UseCaseError::NotFound(ErrorCode::DirectionCategoryNotFound));
+        }
+        if is_self_or_descendant(&categories, parent_id, category_id) {
+            return Err(UseCaseError::BadRequest(
+                ErrorCode::DirectionCategoryParentCycle,
+            ));
+        }
+    }
+    // Ordering is within the parent, so a moved category goes last among its new siblings.
+    let ordering = match parent_id == category.parent_id {
+        true => category.ordering,
+        false => Some(
+            categories
+                .iter()
+                .filter(|sibling| sibling.parent_id == parent_id && sibling.id != category_id)
+                .filter_map(|sibling| sibling.ordering)
+                .max()
+                .unwrap_or(0)
+                + 1,
+        ),
+    };
 
     category_adapter
         .update(
             category,
             UpdateDirectionCategoryParams {
                 name: params.name.clone(),
+                ordering,
+                parent_id,
             },
         )
         .await
         .map(|category| DirectionCategoryVisible::from(category))
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))
 }
+
+/// Whether `id` is `category_id` itself or one of its descendants.
+pub(crate) fn is_self_or_descendant(
+    categories: &[direction_category::Model],
+    id: Uuid,
+    category_id: Uuid,
+) -> bool {
+    let mut current = Some(id);
+    // NOTE: At most as many steps as categories, should the data already have a cycle.
+    for _ in 0..=categories.len() {
+        match current {
+            Some(id) if id == category_id => return true,
+            Some(id) => {
+                current = categories
+                    .iter()
+                    .find(|category| category.id == id)
+                    .and_then(|category| category.parent_id)
+            }
+            None => return false,
+        }
+    }
+    false
+}
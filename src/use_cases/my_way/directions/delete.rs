@@ -22,7 +22,7 @@ pub async fn delete_direction<'a>(
         None => return Ok(()),
     };
     direction_adapter
-        .delete(direction)
+        .trash(direction)
         .await
         .map(|_| ())
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))
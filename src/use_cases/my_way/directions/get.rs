@@ -2,7 +2,7 @@ use db_adapters::direction_adapter::{DirectionAdapter, DirectionFilter, Directio
 use entities::user as user_entity;
 use uuid::Uuid;
 
-use crate::{my_way::directions::types::DirectionVisible, UseCaseError};
+use crate::{my_way::directions::types::DirectionVisible, ErrorCode, UseCaseError};
 
 pub async fn get_direction<'a>(
     user: user_entity::Model,
@@ -14,8 +14,6 @@ pub async fn get_direction<'a>(
         .get_by_id(direction_id)
         .await
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
-        .ok_or(UseCaseError::NotFound(
-            "Direction with this id was not found".to_string(),
-        ))
+        .ok_or(UseCaseError::NotFound(ErrorCode::DirectionNotFound))
         .map(|direction| DirectionVisible::from(direction))
 }
@@ -0,0 +1,35 @@
+use uuid::Uuid;
+
+use crate::{ErrorCode, UseCaseError};
+use db_adapters::{
+    action_adapter::{ActionAdapter, ActionFilter, ActionQuery},
+    direction_adapter::{DirectionAdapter, DirectionFilter, DirectionMutation, DirectionQuery},
+};
+use entities::user as user_entity;
+
+pub async fn link_action<'a>(
+    user: user_entity::Model,
+    direction_id: Uuid,
+    action_id: Uuid,
+    direction_adapter: DirectionAdapter<'a>,
+    action_adapter: ActionAdapter<'a>,
+) -> Result<(), UseCaseError> {
+    let direction = direction_adapter
+        .clone()
+        .filter_eq_user(&user)
+        .get_by_id(direction_id)
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
+        .ok_or(UseCaseError::NotFound(ErrorCode::DirectionNotFound))?;
+    let action = action_adapter
+        .filter_eq_user(&user)
+        .get_by_id(action_id)
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
+        .ok_or(UseCaseError::NotFound(ErrorCode::ActionNotFound))?;
+
+    direction_adapter
+        .link_action(&direction, action.id)
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))
+}
@@ -0,0 +1,37 @@
+use uuid::Uuid;
+
+use crate::{my_way::directions::types::DirectionRevisionVisible, ErrorCode, UseCaseError};
+use db_adapters::{
+    direction_adapter::{DirectionAdapter, DirectionFilter, DirectionQuery},
+    revision_adapter::{RevisionAdapter, RevisionFilter, RevisionOrder, RevisionQuery},
+    Order::Desc,
+};
+use entities::{direction, user as user_entity};
+
+/// Lists the earlier versions of the direction, most recent first.
+pub async fn list_direction_revisions<'a>(
+    user: user_entity::Model,
+    direction_id: Uuid,
+    direction_adapter: DirectionAdapter<'a>,
+    revision_adapter: RevisionAdapter<'a>,
+) -> Result<Vec<DirectionRevisionVisible>, UseCaseError> {
+    direction_adapter
+        .filter_eq_user(&user)
+        .get_by_id(direction_id)
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
+        .ok_or(UseCaseError::NotFound(ErrorCode::DirectionNotFound))?;
+
+    revision_adapter
+        .filter_eq_user(&user)
+        .filter_eq_record::<direction::Entity>(direction_id)
+        .order_by_created_at(Desc)
+        .order_by_id(Desc)
+        .get_all()
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
+        .into_iter()
+        .map(DirectionRevisionVisible::try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))
+}
@@ -5,6 +5,10 @@ pub mod bulk_update_ordering;
 pub mod create;
 pub mod delete;
 pub mod get;
+pub mod link_action;
 pub mod list;
+pub mod list_revisions;
+pub mod restore_revision;
 pub mod unarchive;
+pub mod unlink_action;
 pub mod update;
@@ -0,0 +1,56 @@
+use uuid::Uuid;
+
+use crate::{
+    my_way::directions::types::{DirectionRevisionVisible, DirectionVisible},
+    ErrorCode, UseCaseError,
+};
+use db_adapters::{
+    direction_adapter::{
+        DirectionAdapter, DirectionFilter, DirectionMutation, DirectionQuery, UpdateDirectionParams,
+    },
+    revision_adapter::{RevisionAdapter, RevisionFilter, RevisionQuery},
+};
+use entities::{direction, user as user_entity};
+
+/// Brings the text of an earlier version back. This is an update like any other, so the version
+/// it replaces is kept as a revision too.
+/// The category stays as it is.
+pub async fn restore_direction_revision<'a>(
+    user: user_entity::Model,
+    direction_id: Uuid,
+    revision_id: Uuid,
+    direction_adapter: DirectionAdapter<'a>,
+    revision_adapter: RevisionAdapter<'a>,
+) -> Result<DirectionVisible, UseCaseError> {
+    let direction = direction_adapter
+        .clone()
+        .filter_eq_user(&user)
+        .get_by_id(direction_id)
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
+        .ok_or(UseCaseError::NotFound(ErrorCode::DirectionNotFound))?;
+    let revision = revision_adapter
+        .filter_eq_user(&user)
+        .filter_eq_record::<direction::Entity>(direction_id)
+        .get_by_id(revision_id)
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
+        .ok_or(UseCaseError::NotFound(ErrorCode::RevisionNotFound))?;
+    let revision = DirectionRevisionVisible::try_from(revision)
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?;
+
+    let category_id = direction.category_id;
+
+    direction_adapter
+        .update(
+            direction,
+            UpdateDirectionParams {
+                name: revision.name,
+                description: revision.description,
+                category_id,
+            },
+        )
+        .await
+        .map(DirectionVisible::from)
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))
+}
@@ -1,11 +1,18 @@
 use chrono::{DateTime, FixedOffset};
 use sea_orm::{DerivePartialModel, FromQueryResult};
 
-use entities::{direction, prelude::Direction};
+use entities::{direction, prelude::Direction, revision};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Serialize, Deserialize, DerivePartialModel, FromQueryResult, PartialEq, Debug)]
+use crate::validation::{
+    FieldViolation, MaxChars, NotBlank, Validate, Validator, DESCRIPTION_MAX_CHARS, NAME_MAX_CHARS,
+};
+
+#[derive(
+    Serialize, Deserialize, DerivePartialModel, FromQueryResult, PartialEq, Debug, ToSchema,
+)]
 #[sea_orm(entity = "Direction")]
 pub struct DirectionVisible {
     pub id: uuid::Uuid,
@@ -37,21 +44,88 @@ impl From<direction::Model> for DirectionVisible {
     }
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Serialize, ToSchema)]
 pub struct DirectionCreateRequest {
     pub name: String,
     pub description: Option<String>,
     pub category_id: Option<Uuid>,
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+impl Validate for DirectionCreateRequest {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        Validator::new()
+            .field(
+                "name",
+                self.name.as_str(),
+                &[&NotBlank, &MaxChars(NAME_MAX_CHARS)],
+            )
+            .optional_field(
+                "description",
+                self.description.as_deref(),
+                &[&MaxChars(DESCRIPTION_MAX_CHARS)],
+            )
+            .finish()
+    }
+}
+
+#[derive(Deserialize, Debug, Serialize, ToSchema)]
 pub struct DirectionUpdateRequest {
     pub name: String,
     pub description: Option<String>,
     pub category_id: Option<Uuid>,
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+impl Validate for DirectionUpdateRequest {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        Validator::new()
+            .field(
+                "name",
+                self.name.as_str(),
+                &[&NotBlank, &MaxChars(NAME_MAX_CHARS)],
+            )
+            .optional_field(
+                "description",
+                self.description.as_deref(),
+                &[&MaxChars(DESCRIPTION_MAX_CHARS)],
+            )
+            .finish()
+    }
+}
+
+#[derive(Deserialize, Debug, Serialize, ToSchema)]
 pub struct DirectionBulkUpdateOrderingRequest {
     pub ordering: Vec<uuid::Uuid>,
 }
+
+/// A version of the direction as it was until an update replaced it at `replaced_at`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct DirectionRevisionVisible {
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub updated_at: DateTime<FixedOffset>,
+    pub replaced_at: DateTime<FixedOffset>,
+}
+
+/// Fields of `direction::Model` kept in `revision.snapshot` which a revision shows.
+#[derive(Deserialize)]
+struct DirectionSnapshot {
+    name: String,
+    description: Option<String>,
+    updated_at: DateTime<FixedOffset>,
+}
+
+impl TryFrom<revision::Model> for DirectionRevisionVisible {
+    type Error = serde_json::Error;
+
+    fn try_from(item: revision::Model) -> Result<Self, Self::Error> {
+        let snapshot: DirectionSnapshot = serde_json::from_value(item.snapshot)?;
+        Ok(DirectionRevisionVisible {
+            id: item.id,
+            name: snapshot.name,
+            description: snapshot.description,
+            updated_at: snapshot.updated_at,
+            replaced_at: item.created_at,
+        })
+    }
+}
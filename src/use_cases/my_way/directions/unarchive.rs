@@ -4,7 +4,7 @@ use db_adapters::direction_adapter::{
 use entities::user as user_entity;
 use uuid::Uuid;
 
-use crate::{my_way::directions::types::DirectionVisible, UseCaseError};
+use crate::{my_way::directions::types::DirectionVisible, ErrorCode, UseCaseError};
 
 pub async fn unarchive_direction<'a>(
     user: user_entity::Model,
@@ -17,9 +17,7 @@ pub async fn unarchive_direction<'a>(
         .get_by_id(direction_id)
         .await
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
-        .ok_or(UseCaseError::NotFound(
-            "Direction with this id was not found".to_string(),
-        ))?;
+        .ok_or(UseCaseError::NotFound(ErrorCode::DirectionNotFound))?;
 
     direction_adapter
         .unarchive(direction)
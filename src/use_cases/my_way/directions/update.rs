@@ -1,7 +1,6 @@
 use db_adapters::{
     direction_adapter::{
-        DirectionAdapter, DirectionFilter, DirectionMutation, DirectionQuery,
-        UpdateDirectionParams,
+        DirectionAdapter, DirectionFilter, DirectionMutation, DirectionQuery, UpdateDirectionParams,
     },
     direction_category_adapter::{
         DirectionCategoryAdapter, DirectionCategoryFilter, DirectionCategoryQuery,
@@ -12,7 +11,7 @@ use uuid::Uuid;
 
 use crate::{
     my_way::directions::types::{DirectionUpdateRequest, DirectionVisible},
-    UseCaseError,
+    ErrorCode, UseCaseError,
 };
 
 pub async fn update_direction<'a>(
@@ -38,9 +37,7 @@ pub async fn update_direction<'a>(
         .get_by_id(direction_id)
         .await
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
-        .ok_or(UseCaseError::NotFound(
-            "Direction with this id was not found".to_string(),
-        ))?;
+        .ok_or(UseCaseError::NotFound(ErrorCode::DirectionNotFound))?;
 
     direction_adapter
         .update(
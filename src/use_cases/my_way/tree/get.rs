@@ -0,0 +1,121 @@
+use std::collections::HashSet;
+
+use db_adapters::{
+    action_adapter::{ActionAdapter, ActionFilter, ActionOrder, ActionQuery},
+    ambition_adapter::{AmbitionAdapter, AmbitionFilter, AmbitionOrder, AmbitionQuery},
+    direction_adapter::{
+        DirectionAdapter, DirectionFilter, DirectionJoin, DirectionOrder, DirectionQuery,
+    },
+    Order::Asc,
+};
+use entities::{direction, user as user_entity};
+use uuid::Uuid;
+
+use crate::{
+    my_way::{
+        actions::types::ActionVisible,
+        ambitions::types::AmbitionVisible,
+        directions::types::DirectionVisible,
+        tree::types::{AmbitionNode, DirectionNode, MyWayTree},
+    },
+    UseCaseError,
+};
+
+pub async fn get_my_way_tree<'a>(
+    user: user_entity::Model,
+    ambition_adapter: AmbitionAdapter<'a>,
+    direction_adapter: DirectionAdapter<'a>,
+    action_adapter: ActionAdapter<'a>,
+) -> Result<MyWayTree, UseCaseError> {
+    let ambition_adapter = ambition_adapter
+        .filter_eq_user(&user)
+        .filter_eq_archived(false);
+    let direction_adapter = direction_adapter
+        .filter_eq_user(&user)
+        .filter_eq_archived(false);
+
+    // Orders are those of the list endpoints of each.
+    let ambitions = ambition_adapter
+        .clone()
+        .order_by_ordering_nulls_last(Asc)
+        .order_by_created_at(Asc)
+        .get_all()
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?;
+    let directions = direction_adapter
+        .clone()
+        .join_category()
+        .order_by_category_ordering_nulls_last(Asc)
+        .order_by_ordering_nulls_first(Asc)
+        .order_by_created_at(Asc)
+        .get_all()
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?;
+    let actions = action_adapter
+        .filter_eq_user(&user)
+        .filter_eq_archived(false)
+        .order_by_ordering_nulls_last(Asc)
+        .order_by_created_at(Asc)
+        .get_all()
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?;
+    let ambition_direction_links = ambition_adapter
+        .get_direction_links()
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
+        .into_iter()
+        .map(|link| (link.ambition_id, link.direction_id))
+        .collect::<HashSet<_>>();
+    let direction_action_links = direction_adapter
+        .get_action_links()
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
+        .into_iter()
+        .map(|link| (link.direction_id, link.action_id))
+        .collect::<HashSet<_>>();
+
+    let to_direction_node = |direction: &direction::Model| DirectionNode {
+        direction: DirectionVisible::from(direction),
+        actions: actions
+            .iter()
+            .filter(|action| direction_action_links.contains(&(direction.id, action.id)))
+            .map(ActionVisible::from)
+            .collect(),
+    };
+    // NOTE: Links are only of the ambitions and directions above, since they are queried through
+    //       the same adapters. Links to archived actions and directions find nothing to show.
+    let linked_direction_ids = ambition_direction_links
+        .iter()
+        .map(|(_, direction_id)| *direction_id)
+        .collect::<HashSet<Uuid>>();
+    let linked_action_ids = direction_action_links
+        .iter()
+        .map(|(_, action_id)| *action_id)
+        .collect::<HashSet<Uuid>>();
+
+    Ok(MyWayTree {
+        ambitions: ambitions
+            .iter()
+            .map(|ambition| AmbitionNode {
+                ambition: AmbitionVisible::from(ambition),
+                directions: directions
+                    .iter()
+                    .filter(|direction| {
+                        ambition_direction_links.contains(&(ambition.id, direction.id))
+                    })
+                    .map(to_direction_node)
+                    .collect(),
+            })
+            .collect(),
+        unlinked_directions: directions
+            .iter()
+            .filter(|direction| !linked_direction_ids.contains(&direction.id))
+            .map(to_direction_node)
+            .collect(),
+        unlinked_actions: actions
+            .iter()
+            .filter(|action| !linked_action_ids.contains(&action.id))
+            .map(ActionVisible::from)
+            .collect(),
+    })
+}
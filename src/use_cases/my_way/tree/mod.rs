@@ -0,0 +1,4 @@
+pub mod types;
+
+pub mod get;
+pub mod progress;
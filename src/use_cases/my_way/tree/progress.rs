@@ -0,0 +1,298 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{Duration, NaiveDate};
+use db_adapters::{
+    action_adapter::{ActionAdapter, ActionFilter, ActionOrder, ActionQuery},
+    action_goal_adapter::{ActionGoalAdapter, ActionGoalFilter, ActionGoalQuery},
+    action_track_adapter::{ActionTrackAdapter, ActionTrackFilter, ActionTrackQuery},
+    ambition_adapter::{AmbitionAdapter, AmbitionFilter, AmbitionOrder, AmbitionQuery},
+    direction_adapter::{
+        DirectionAdapter, DirectionFilter, DirectionJoin, DirectionOrder, DirectionQuery,
+    },
+    direction_category_adapter::{
+        DirectionCategoryAdapter, DirectionCategoryFilter, DirectionCategoryOrder,
+        DirectionCategoryQuery,
+    },
+    Order::Asc,
+};
+use entities::{
+    action, action_goal, custom_methods::user::UserTimezoneTrait, direction_category,
+    sea_orm_active_enums::ActionTrackType, user as user_entity,
+};
+use uuid::Uuid;
+
+use crate::{
+    my_way::{
+        direction_categories::update::is_self_or_descendant,
+        tree::types::{
+            ActionProgress, AmbitionProgress, DirectionCategoryProgress, DirectionProgress,
+            MyWayProgress, MyWayProgressQuery, Progress,
+        },
+    },
+    ErrorCode, UseCaseError,
+};
+
+const MAX_DAYS: i64 = 366;
+
+#[allow(clippy::too_many_arguments)]
+pub async fn get_my_way_progress<'a>(
+    user: user_entity::Model,
+    params: MyWayProgressQuery,
+    ambition_adapter: AmbitionAdapter<'a>,
+    direction_adapter: DirectionAdapter<'a>,
+    direction_category_adapter: DirectionCategoryAdapter<'a>,
+    action_adapter: ActionAdapter<'a>,
+    action_track_adapter: ActionTrackAdapter<'a>,
+    action_goal_adapter: ActionGoalAdapter<'a>,
+) -> Result<MyWayProgress, UseCaseError> {
+    let MyWayProgressQuery { from, to } = params;
+    if to < from || (to - from).num_days() >= MAX_DAYS {
+        return Err(UseCaseError::BadRequest(
+            ErrorCode::MyWayProgressInvalidDateRange,
+        ));
+    }
+    let ambition_adapter = ambition_adapter
+        .filter_eq_user(&user)
+        .filter_eq_archived(false);
+    let direction_adapter = direction_adapter
+        .filter_eq_user(&user)
+        .filter_eq_archived(false);
+
+    // Orders are those of the tree.
+    let ambitions = ambition_adapter
+        .clone()
+        .order_by_ordering_nulls_last(Asc)
+        .order_by_created_at(Asc)
+        .get_all()
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?;
+    let directions = direction_adapter
+        .clone()
+        .join_category()
+        .order_by_category_ordering_nulls_last(Asc)
+        .order_by_ordering_nulls_first(Asc)
+        .order_by_created_at(Asc)
+        .get_all()
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?;
+    let categories = direction_category_adapter
+        .filter_eq_user(&user)
+        .order_by_ordering_nulls_last(Asc)
+        .order_by_id(Asc)
+        .get_all()
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?;
+    let actions = action_adapter
+        .filter_eq_user(&user)
+        .filter_eq_archived(false)
+        .order_by_ordering_nulls_last(Asc)
+        .order_by_created_at(Asc)
+        .get_all()
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?;
+    let ambition_direction_links = ambition_adapter
+        .get_direction_links()
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?;
+    let direction_action_links = direction_adapter
+        .get_action_links()
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?;
+    let action_tracks = action_track_adapter
+        .filter_eq_user(&user)
+        .filter_started_at_in_date_range(from, to, user.timezone.clone())
+        .filter_ended_at_is_null(false)
+        .get_all()
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?;
+    let action_goals = action_goal_adapter
+        .filter_eq_user(&user)
+        .filter_overlaps_dates(from, to)
+        .get_all()
+        .await
+        .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?;
+
+    // Duration and count of each action on each date in the user's timezone.
+    let mut daily: HashMap<(Uuid, NaiveDate), (i64, i64)> = HashMap::new();
+    for track in action_tracks {
+        let date = user
+            .to_user_timezone(track.started_at.to_utc())
+            .date_naive();
+        let day = daily.entry((track.action_id, date)).or_default();
+        day.0 += track.duration.unwrap_or(0);
+        day.1 += 1;
+    }
+    let dates = (0..=(to - from).num_days())
+        .map(|days| from + Duration::days(days))
+        .collect::<Vec<_>>();
+    let progress_by_action = actions
+        .iter()
+        .map(|action| {
+            let goals = action_goals
+                .iter()
+                .filter(|goal| goal.action_id == action.id)
+                .collect::<Vec<_>>();
+            (action.id, action_progress(action, &goals, &dates, &daily))
+        })
+        .collect::<HashMap<_, _>>();
+
+    // NOTE: Links to archived actions find no progress, like they find no node in the tree.
+    let mut action_ids_by_direction: HashMap<Uuid, HashSet<Uuid>> = HashMap::new();
+    for link in direction_action_links {
+        action_ids_by_direction
+            .entry(link.direction_id)
+            .or_default()
+            .insert(link.action_id);
+    }
+    let mut direction_ids_by_ambition: HashMap<Uuid, HashSet<Uuid>> = HashMap::new();
+    for link in ambition_direction_links {
+        direction_ids_by_ambition
+            .entry(link.ambition_id)
+            .or_default()
+            .insert(link.direction_id);
+    }
+    let sum = |action_ids: HashSet<Uuid>| {
+        let mut progress = Progress::default();
+        for action_id in action_ids {
+            if let Some(action_progress) = progress_by_action.get(&action_id) {
+                progress += action_progress;
+            }
+        }
+        progress
+    };
+
+    // A category rolls up the directions of its descendants as well, and is left out when
+    // there are none in it or below.
+    let mut category_progresses = categories_in_tree_order(&categories, None)
+        .into_iter()
+        .filter_map(|category| {
+            let direction_ids = directions
+                .iter()
+                .filter(|direction| {
+                    direction.category_id.is_some_and(|category_id| {
+                        is_self_or_descendant(&categories, category_id, category.id)
+                    })
+                })
+                .map(|direction| &direction.id)
+                .collect::<Vec<_>>();
+            match direction_ids.is_empty() {
+                true => None,
+                false => Some(DirectionCategoryProgress {
+                    direction_category_id: Some(category.id),
+                    progress: sum(action_ids_of_directions(
+                        direction_ids,
+                        &action_ids_by_direction,
+                    )),
+                }),
+            }
+        })
+        .collect::<Vec<_>>();
+    if directions
+        .iter()
+        .any(|direction| direction.category_id.is_none())
+    {
+        category_progresses.push(DirectionCategoryProgress {
+            direction_category_id: None,
+            progress: sum(action_ids_of_directions(
+                directions
+                    .iter()
+                    .filter(|direction| direction.category_id.is_none())
+                    .map(|direction| &direction.id),
+                &action_ids_by_direction,
+            )),
+        });
+    }
+    Ok(MyWayProgress {
+        ambitions: ambitions
+            .iter()
+            .map(|ambition| AmbitionProgress {
+                ambition_id: ambition.id,
+                progress: sum(action_ids_of_directions(
+                    direction_ids_by_ambition
+                        .get(&ambition.id)
+                        .into_iter()
+                        .flatten(),
+                    &action_ids_by_direction,
+                )),
+            })
+            .collect(),
+        directions: directions
+            .iter()
+            .map(|direction| DirectionProgress {
+                direction_id: direction.id,
+                progress: sum(action_ids_of_directions(
+                    [&direction.id],
+                    &action_ids_by_direction,
+                )),
+            })
+            .collect(),
+        direction_categories: category_progresses,
+        actions: actions
+            .iter()
+            .map(|action| ActionProgress {
+                action_id: action.id,
+                progress: progress_by_action[&action.id].clone(),
+            })
+            .collect(),
+    })
+}
+
+/// Descendants of `parent_id`, each followed by its own, in the order of `categories`, which is
+/// kept within each parent.
+fn categories_in_tree_order(
+    categories: &[direction_category::Model],
+    parent_id: Option<Uuid>,
+) -> Vec<&direction_category::Model> {
+    categories
+        .iter()
+        .filter(|category| category.parent_id == parent_id)
+        .flat_map(|category| {
+            std::iter::once(category).chain(categories_in_tree_order(categories, Some(category.id)))
+        })
+        .collect()
+}
+
+/// Distinct actions linked to any of the directions.
+fn action_ids_of_directions<'a>(
+    direction_ids: impl IntoIterator<Item = &'a Uuid>,
+    action_ids_by_direction: &HashMap<Uuid, HashSet<Uuid>>,
+) -> HashSet<Uuid> {
+    direction_ids
+        .into_iter()
+        .filter_map(|direction_id| action_ids_by_direction.get(direction_id))
+        .flatten()
+        .copied()
+        .collect()
+}
+
+fn action_progress(
+    action: &action::Model,
+    goals: &[&action_goal::Model],
+    dates: &[NaiveDate],
+    daily: &HashMap<(Uuid, NaiveDate), (i64, i64)>,
+) -> Progress {
+    let mut progress = Progress::default();
+    for date in dates {
+        let (duration, count) = daily.get(&(action.id, *date)).copied().unwrap_or_default();
+        progress.duration += duration;
+        progress.count += count;
+        let goal = goals.iter().find(|goal| {
+            goal.from_date <= *date && goal.to_date.is_none_or(|to_date| *date <= to_date)
+        });
+        // Goals hold the amount of the track type of the action only.
+        let achieved = match (goal, &action.track_type) {
+            (Some(goal), ActionTrackType::TimeSpan) => goal
+                .duration_seconds
+                .map(|seconds| duration >= i64::from(seconds)),
+            (Some(goal), ActionTrackType::Count) => {
+                goal.count.map(|goal_count| count >= i64::from(goal_count))
+            }
+            (None, _) => None,
+        };
+        if let Some(achieved) = achieved {
+            progress.goal_days += 1;
+            progress.achieved_goal_days += i64::from(achieved);
+        }
+    }
+    progress
+}
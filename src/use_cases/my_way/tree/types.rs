@@ -0,0 +1,105 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::my_way::{
+    actions::types::ActionVisible, ambitions::types::AmbitionVisible,
+    directions::types::DirectionVisible,
+};
+
+/// Ambitions → Directions → Actions. Archived items are left out, along with their links.
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct MyWayTree {
+    pub ambitions: Vec<AmbitionNode>,
+    /// Directions not linked to any of `ambitions`.
+    pub unlinked_directions: Vec<DirectionNode>,
+    /// Actions not linked to any direction of the tree.
+    pub unlinked_actions: Vec<ActionVisible>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct AmbitionNode {
+    #[serde(flatten)]
+    pub ambition: AmbitionVisible,
+    pub directions: Vec<DirectionNode>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct DirectionNode {
+    #[serde(flatten)]
+    pub direction: DirectionVisible,
+    pub actions: Vec<ActionVisible>,
+}
+
+#[derive(Deserialize, Debug, IntoParams)]
+pub struct MyWayProgressQuery {
+    /// First date in the user's timezone, e.g. 2025-06-01.
+    pub from: NaiveDate,
+    /// Last date in the user's timezone, inclusive. At most 366 days after `from`.
+    pub to: NaiveDate,
+}
+
+/// Effort toward each part of the tree from `from` to `to`. Archived items are left out.
+///
+/// An action counts once toward a direction, ambition or category however many links lead
+/// there. Goal days add up over actions, so two actions with goals on every day of a week make
+/// 14 goal days.
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct MyWayProgress {
+    pub ambitions: Vec<AmbitionProgress>,
+    pub directions: Vec<DirectionProgress>,
+    /// In the order of the category tree, parents before their children. A category includes
+    /// the directions of its descendants. Directions without a category come last with a null id.
+    pub direction_categories: Vec<DirectionCategoryProgress>,
+    pub actions: Vec<ActionProgress>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Default, Clone, ToSchema)]
+pub struct Progress {
+    /// Sum of the durations of ended tracks in seconds.
+    pub duration: i64,
+    /// Number of ended tracks.
+    pub count: i64,
+    /// Days on which the actions had a goal.
+    pub goal_days: i64,
+    /// Days of `goal_days` on which the goal was met.
+    pub achieved_goal_days: i64,
+}
+
+impl std::ops::AddAssign<&Progress> for Progress {
+    fn add_assign(&mut self, other: &Progress) {
+        self.duration += other.duration;
+        self.count += other.count;
+        self.goal_days += other.goal_days;
+        self.achieved_goal_days += other.achieved_goal_days;
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct AmbitionProgress {
+    pub ambition_id: Uuid,
+    #[serde(flatten)]
+    pub progress: Progress,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct DirectionProgress {
+    pub direction_id: Uuid,
+    #[serde(flatten)]
+    pub progress: Progress,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct DirectionCategoryProgress {
+    pub direction_category_id: Option<Uuid>,
+    #[serde(flatten)]
+    pub progress: Progress,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct ActionProgress {
+    pub action_id: Uuid,
+    #[serde(flatten)]
+    pub progress: Progress,
+}
@@ -13,7 +13,7 @@ use entities::{
 
 use crate::{
     notification::notification_rule::types::{NotificationRuleCreateRequest, RecurrenceType},
-    UseCaseError,
+    ErrorCode, UseCaseError,
 };
 
 pub async fn create_notification_rules<'a>(
@@ -63,7 +63,7 @@ async fn parse_params<'a>(
         > 0;
     if exists_same_type_rules {
         return Err(UseCaseError::Conflict(
-            "Notification rules for the same type already exists.".to_string(),
+            ErrorCode::NotificationRuleDuplicateType,
         ));
     }
 
@@ -74,12 +74,12 @@ async fn parse_params<'a>(
 
     if utc_time.second() != 0 {
         return Err(UseCaseError::BadRequest(
-            "Seconds in time fields must be zero.".to_string(),
+            ErrorCode::NotificationRuleTimeSecondsNotZero,
         ));
     }
     if utc_time.minute() % 10 != 0 {
         return Err(UseCaseError::BadRequest(
-            "Minutes in time fields must be multiples of ten.".to_string(),
+            ErrorCode::NotificationRuleTimeMinutesNotMultipleOfTen,
         ));
     }
 
@@ -105,7 +105,7 @@ async fn parse_params<'a>(
         }
         RecurrenceType::Unknown => {
             return Err(UseCaseError::BadRequest(
-                "Unknown recurrence_type".to_string(),
+                ErrorCode::NotificationRuleUnknownRecurrenceType,
             ))
         }
     };
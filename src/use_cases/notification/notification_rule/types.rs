@@ -2,8 +2,9 @@ use chrono::NaiveTime;
 
 use entities::sea_orm_active_enums::NotificationType;
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
-#[derive(Deserialize, Debug, Serialize, PartialEq, Clone)]
+#[derive(Deserialize, Debug, Serialize, PartialEq, Clone, ToSchema)]
 pub enum RecurrenceType {
     Everyday,
     Weekday,
@@ -11,21 +12,21 @@ pub enum RecurrenceType {
     Unknown,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
 pub struct NotificationRuleVisible {
     pub r#type: NotificationType,
     pub recurrence_type: RecurrenceType,
     pub time: NaiveTime,
 }
 
-#[derive(Deserialize, Debug, Serialize, Clone)]
+#[derive(Deserialize, Debug, Serialize, Clone, ToSchema)]
 pub struct NotificationRuleCreateRequest {
     pub r#type: NotificationType,
     pub recurrence_type: RecurrenceType,
     pub time: NaiveTime,
 }
 
-#[derive(Deserialize, Debug, Serialize, Clone)]
+#[derive(Deserialize, Debug, Serialize, Clone, IntoParams)]
 pub struct NotificationRuleDeleteQuery {
     pub r#type: NotificationType,
 }
@@ -2,8 +2,11 @@ use sea_orm::FromQueryResult;
 
 use entities::web_push_subscription;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Serialize, Deserialize, FromQueryResult, PartialEq, Debug)]
+use crate::validation::{FieldViolation, MaxChars, NotBlank, Validate, Validator};
+
+#[derive(Serialize, Deserialize, FromQueryResult, PartialEq, Debug, ToSchema)]
 #[sea_orm(entity = "WebPushSubscription")]
 pub struct WebPushSubscriptionVisible {
     pub device_name: String,
@@ -25,7 +28,7 @@ impl From<web_push_subscription::Model> for WebPushSubscriptionVisible {
     }
 }
 
-#[derive(Deserialize, Debug, Serialize, Clone, Default)]
+#[derive(Deserialize, Debug, Serialize, Clone, Default, ToSchema)]
 pub struct WebPushSubscriptionCreateRequest {
     pub device_name: String,
     pub endpoint: String,
@@ -33,3 +36,30 @@ pub struct WebPushSubscriptionCreateRequest {
     pub p256dh_key: String,
     pub auth_key: String,
 }
+
+impl Validate for WebPushSubscriptionCreateRequest {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        Validator::new()
+            .field(
+                "device_name",
+                self.device_name.as_str(),
+                &[&NotBlank, &MaxChars(64)],
+            )
+            .field(
+                "endpoint",
+                self.endpoint.as_str(),
+                &[&NotBlank, &MaxChars(2_048)],
+            )
+            .field(
+                "p256dh_key",
+                self.p256dh_key.as_str(),
+                &[&NotBlank, &MaxChars(255)],
+            )
+            .field(
+                "auth_key",
+                self.auth_key.as_str(),
+                &[&NotBlank, &MaxChars(255)],
+            )
+            .finish()
+    }
+}
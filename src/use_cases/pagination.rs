@@ -0,0 +1,76 @@
+use base64::{prelude::BASE64_URL_SAFE_NO_PAD, Engine};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{ErrorCode, UseCaseError};
+
+pub const DEFAULT_PAGE_LIMIT: u64 = 50;
+pub const MAX_PAGE_LIMIT: u64 = 100;
+
+/// One page of a list. Pass `next_cursor` as `cursor` to get the next page.
+/// `next_cursor` is None on the last page.
+#[derive(Debug, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// The whole list as a single page.
+    pub fn all(items: Vec<T>) -> Self {
+        Self {
+            items,
+            next_cursor: None,
+        }
+    }
+
+    /// Makes a page from up to `limit + 1` items. The extra one only tells that there are more.
+    pub fn from_overfetched<C: Serialize>(
+        mut items: Vec<T>,
+        limit: u64,
+        cursor_of: impl Fn(&T) -> C,
+    ) -> Self {
+        let has_more = items.len() as u64 > limit;
+        items.truncate(limit as usize);
+        let next_cursor = match has_more {
+            true => items.last().map(|item| encode_cursor(&cursor_of(item))),
+            false => None,
+        };
+        Self { items, next_cursor }
+    }
+}
+
+/// At most `limit` items after the cursor, or from the top when `after` is None.
+#[derive(Debug, Clone)]
+pub struct PageParams<C> {
+    pub limit: u64,
+    pub after: Option<C>,
+}
+
+impl<C: DeserializeOwned> PageParams<C> {
+    /// Returns None when neither is given, meaning that the whole list is wanted.
+    pub fn parse(limit: Option<u64>, cursor: Option<&str>) -> Result<Option<Self>, UseCaseError> {
+        if limit.is_none() && cursor.is_none() {
+            return Ok(None);
+        }
+        let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        if !(1..=MAX_PAGE_LIMIT).contains(&limit) {
+            return Err(UseCaseError::BadRequest(ErrorCode::PageInvalidLimit));
+        }
+        let after = cursor.map(decode_cursor).transpose()?;
+        Ok(Some(Self { limit, after }))
+    }
+}
+
+/// Cursors are opaque to clients: base64 encoded JSON of the last item's sort key.
+pub fn encode_cursor<C: Serialize>(cursor: &C) -> String {
+    BASE64_URL_SAFE_NO_PAD
+        .encode(serde_json::to_vec(cursor).expect("Cursor is always serializable."))
+}
+
+fn decode_cursor<C: DeserializeOwned>(cursor: &str) -> Result<C, UseCaseError> {
+    BASE64_URL_SAFE_NO_PAD
+        .decode(cursor)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .ok_or(UseCaseError::BadRequest(ErrorCode::PageInvalidCursor))
+}
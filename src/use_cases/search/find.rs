@@ -0,0 +1,106 @@
+use db_adapters::search_adapter::{search_terms, Ranked, SearchAdapter, SearchQuery};
+use entities::user as user_entity;
+
+use crate::{
+    search::{
+        snippet::snippet,
+        types::{SearchHitVisible, SearchParams, SearchResultsVisible},
+    },
+    ErrorCode, UseCaseError,
+};
+
+const HITS_PER_KIND: u64 = 20;
+/// Terms past this are ignored to keep the query cheap.
+const MAX_TERMS: usize = 10;
+
+pub async fn search_all<'a>(
+    user: user_entity::Model,
+    params: SearchParams,
+    search_adapter: SearchAdapter<'a>,
+) -> Result<SearchResultsVisible, UseCaseError> {
+    let mut terms = search_terms(&params.q);
+    if terms.is_empty() {
+        return Err(UseCaseError::BadRequest(ErrorCode::SearchNoTerms));
+    }
+    terms.truncate(MAX_TERMS);
+
+    match search_adapter.search(&user, &terms, HITS_PER_KIND).await {
+        Ok(results) => Ok(SearchResultsVisible {
+            ambitions: to_hits(results.ambitions, |a| {
+                (
+                    a.id,
+                    Some(a.name.clone()),
+                    snippet(&[a.description.as_deref(), Some(&a.name)], &terms),
+                )
+            }),
+            directions: to_hits(results.directions, |d| {
+                (
+                    d.id,
+                    Some(d.name.clone()),
+                    snippet(&[d.description.as_deref(), Some(&d.name)], &terms),
+                )
+            }),
+            actions: to_hits(results.actions, |a| {
+                (
+                    a.id,
+                    Some(a.name.clone()),
+                    snippet(
+                        &[a.discipline.as_deref(), a.memo.as_deref(), Some(&a.name)],
+                        &terms,
+                    ),
+                )
+            }),
+            tags: to_hits(results.tags, |t| {
+                (t.id, t.name.clone(), snippet(&[t.name.as_deref()], &terms))
+            }),
+            diaries: to_hits(results.diaries, |d| {
+                (
+                    d.id,
+                    Some(d.date.to_string()),
+                    snippet(&[d.text.as_deref()], &terms),
+                )
+            }),
+            reading_notes: to_hits(results.reading_notes, |r| {
+                (
+                    r.id,
+                    Some(r.title.clone()),
+                    snippet(&[Some(&r.text), Some(&r.title)], &terms),
+                )
+            }),
+            thinking_notes: to_hits(results.thinking_notes, |t| {
+                (
+                    t.id,
+                    t.question.clone(),
+                    snippet(
+                        &[
+                            t.thought.as_deref(),
+                            t.answer.as_deref(),
+                            t.question.as_deref(),
+                        ],
+                        &terms,
+                    ),
+                )
+            }),
+        }),
+        Err(e) => Err(UseCaseError::InternalServerError(format!("{:?}", e))),
+    }
+}
+
+/// `visible` returns (id, title, snippet) of the record.
+fn to_hits<M>(
+    ranked: Vec<Ranked<M>>,
+    visible: impl Fn(&M) -> (uuid::Uuid, Option<String>, String),
+) -> Vec<SearchHitVisible> {
+    ranked
+        .into_iter()
+        .map(|Ranked { model, rank }| {
+            let (id, title, snippet) = visible(&model);
+            SearchHitVisible {
+                id,
+                title,
+                snippet,
+                rank,
+            }
+        })
+        .collect()
+}
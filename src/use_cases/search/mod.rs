@@ -0,0 +1,4 @@
+mod snippet;
+pub mod types;
+
+pub mod find;
@@ -0,0 +1,95 @@
+const CHARS_BEFORE_MATCH: usize = 40;
+const SNIPPET_CHARS: usize = 160;
+
+/// Excerpt of the first text containing any of the terms, with the matches highlighted.
+/// Falls back to the beginning of the first non empty text, since words match as prefixes in
+/// the database and so a record can match without any text containing a term as is.
+pub(crate) fn snippet(texts: &[Option<&str>], terms: &[String]) -> String {
+    let texts = texts
+        .iter()
+        .flatten()
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>();
+    texts
+        .iter()
+        .find_map(|text| highlight(text, terms))
+        .or_else(|| texts.first().map(|text| highlight_in(text, &[], 0)))
+        .unwrap_or_default()
+}
+
+fn highlight(text: &str, terms: &[String]) -> Option<String> {
+    let chars = text.chars().collect::<Vec<_>>();
+    let matches = find_matches(&chars, terms);
+    let (first_match_start, _) = *matches.first()?;
+    Some(highlight_in(
+        text,
+        &matches,
+        first_match_start.saturating_sub(CHARS_BEFORE_MATCH),
+    ))
+}
+
+/// (start, length) in chars of the non overlapping matches, longer terms first.
+fn find_matches(chars: &[char], terms: &[String]) -> Vec<(usize, usize)> {
+    let lowered = chars.iter().map(|c| lowercase(*c)).collect::<Vec<_>>();
+    let mut terms = terms
+        .iter()
+        .map(|term| term.chars().map(lowercase).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    terms.sort_by_key(|term| std::cmp::Reverse(term.len()));
+
+    let mut matches = Vec::new();
+    let mut i = 0;
+    while i < lowered.len() {
+        match terms
+            .iter()
+            .find(|term| !term.is_empty() && lowered[i..].starts_with(term))
+        {
+            Some(term) => {
+                matches.push((i, term.len()));
+                i += term.len();
+            }
+            None => i += 1,
+        }
+    }
+    matches
+}
+
+/// Keeps chars aligned with the original text, unlike `str::to_lowercase`.
+fn lowercase(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+fn highlight_in(text: &str, matches: &[(usize, usize)], start: usize) -> String {
+    let chars = text.chars().collect::<Vec<_>>();
+    let end = chars.len().min(start + SNIPPET_CHARS);
+    let mut snippet = String::new();
+    if start > 0 {
+        snippet.push('…');
+    }
+    let mut mark_end = None;
+    for (i, c) in chars.iter().enumerate().take(end).skip(start) {
+        if mark_end == Some(i) {
+            snippet.push_str("</mark>");
+            mark_end = None;
+        }
+        if let Some((_, length)) = matches.iter().find(|(match_start, _)| *match_start == i) {
+            snippet.push_str("<mark>");
+            mark_end = Some(i + length);
+        }
+        match c {
+            '&' => snippet.push_str("&amp;"),
+            '<' => snippet.push_str("&lt;"),
+            '>' => snippet.push_str("&gt;"),
+            '"' => snippet.push_str("&quot;"),
+            '\'' => snippet.push_str("&#39;"),
+            _ => snippet.push(*c),
+        }
+    }
+    if mark_end.is_some() {
+        snippet.push_str("</mark>");
+    }
+    if end < chars.len() {
+        snippet.push('…');
+    }
+    snippet
+}
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug, IntoParams)]
+pub struct SearchParams {
+    /// Words to search for. Records containing all of them are returned. Japanese works
+    /// without spaces between words.
+    pub q: String,
+}
+
+/// `title` is the name of ambitions, directions, actions and tags, the date of diaries, the
+/// title of reading notes and the question of thinking notes.
+/// `snippet` is an excerpt of the text around the first match with every match wrapped in
+/// `<mark></mark>`. The rest of the text is HTML escaped.
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct SearchHitVisible {
+    pub id: Uuid,
+    pub title: Option<String>,
+    pub snippet: String,
+    pub rank: f32,
+}
+
+/// Up to 20 hits of each kind, best match first.
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct SearchResultsVisible {
+    pub ambitions: Vec<SearchHitVisible>,
+    pub directions: Vec<SearchHitVisible>,
+    pub actions: Vec<SearchHitVisible>,
+    pub tags: Vec<SearchHitVisible>,
+    pub diaries: Vec<SearchHitVisible>,
+    pub reading_notes: Vec<SearchHitVisible>,
+    pub thinking_notes: Vec<SearchHitVisible>,
+}
@@ -0,0 +1,172 @@
+use chrono::{DateTime, Duration, FixedOffset, SecondsFormat, Utc};
+use futures::try_join;
+
+use common::settings::types::Settings;
+use db_adapters::{
+    action_adapter::{ActionAdapter, ActionFilter, ActionQuery},
+    action_goal_adapter::{ActionGoalAdapter, ActionGoalFilter, ActionGoalQuery},
+    action_track_adapter::{ActionTrackAdapter, ActionTrackFilter, ActionTrackQuery},
+    ambition_adapter::{AmbitionAdapter, AmbitionFilter, AmbitionQuery},
+    diary_adapter::{DiaryAdapter, DiaryFilter, DiaryJoin, DiaryQuery},
+    direction_adapter::{DirectionAdapter, DirectionFilter, DirectionQuery},
+    direction_category_adapter::{
+        DirectionCategoryAdapter, DirectionCategoryFilter, DirectionCategoryQuery,
+    },
+    reading_note_adapter::{
+        ReadingNoteAdapter, ReadingNoteFilter, ReadingNoteJoin, ReadingNoteQuery,
+    },
+    tag_adapter::{TagAdapter, TagFilter, TagQuery},
+    thinking_note_adapter::{
+        ThinkingNoteAdapter, ThinkingNoteFilter, ThinkingNoteJoin, ThinkingNoteQuery,
+    },
+    tombstone_adapter::{TombstoneAdapter, TombstoneFilter, TombstoneOrder, TombstoneQuery},
+    Order::Asc,
+};
+use entities::user as user_entity;
+
+use crate::{
+    sync::types::{SyncChanges, SyncQuery},
+    ErrorCode, UseCaseError,
+};
+
+/// updated_at is set before the transaction commits, so a record committed right after a sync
+/// can carry a timestamp older than its cursor. Looking back this far from the cursor makes sure
+/// such records are picked up by the next sync, at the cost of sending a few records twice.
+const CURSOR_OVERLAP_SECONDS: i64 = 60;
+
+pub struct SyncAdapters<'a> {
+    pub ambition: AmbitionAdapter<'a>,
+    pub direction: DirectionAdapter<'a>,
+    pub direction_category: DirectionCategoryAdapter<'a>,
+    pub action: ActionAdapter<'a>,
+    pub action_goal: ActionGoalAdapter<'a>,
+    pub action_track: ActionTrackAdapter<'a>,
+    pub diary: DiaryAdapter<'a>,
+    pub reading_note: ReadingNoteAdapter<'a>,
+    pub thinking_note: ThinkingNoteAdapter<'a>,
+    pub tag: TagAdapter<'a>,
+    pub tombstone: TombstoneAdapter<'a>,
+}
+
+pub async fn list_changes<'a>(
+    user: user_entity::Model,
+    query: SyncQuery,
+    settings: &Settings,
+    adapters: SyncAdapters<'a>,
+) -> Result<SyncChanges, UseCaseError> {
+    let cursor = Utc::now();
+    let since = match query.since {
+        Some(since) => Some(parse_cursor(&since)? - Duration::seconds(CURSOR_OVERLAP_SECONDS)),
+        None => None,
+    };
+    // Tombstones older than this are pruned, so deletions since such a cursor may be missing.
+    if let Some(since) = since {
+        let max_age = Duration::days(settings.application.sync_cursor_max_age_days);
+        if since < cursor - max_age {
+            return Err(UseCaseError::BadRequest(ErrorCode::SyncFullResyncRequired));
+        }
+    }
+    // Far enough in the past to match every record on the first sync.
+    let updated_at_gte = since.unwrap_or(DateTime::UNIX_EPOCH.into());
+
+    let (
+        ambitions,
+        directions,
+        direction_categories,
+        actions,
+        action_goals,
+        action_tracks,
+        diaries,
+        reading_notes,
+        thinking_notes,
+        tags,
+    ) = try_join!(
+        adapters
+            .ambition
+            .filter_eq_user(&user)
+            .filter_updated_at_gte(updated_at_gte)
+            .get_all(),
+        adapters
+            .direction
+            .filter_eq_user(&user)
+            .filter_updated_at_gte(updated_at_gte)
+            .get_all(),
+        adapters
+            .direction_category
+            .filter_eq_user(&user)
+            .filter_updated_at_gte(updated_at_gte)
+            .get_all(),
+        adapters
+            .action
+            .filter_eq_user(&user)
+            .filter_updated_at_gte(updated_at_gte)
+            .get_all(),
+        adapters
+            .action_goal
+            .filter_eq_user(&user)
+            .filter_updated_at_gte(updated_at_gte)
+            .get_all(),
+        adapters
+            .action_track
+            .filter_eq_user(&user)
+            .filter_updated_at_gte(updated_at_gte)
+            .get_all(),
+        adapters
+            .diary
+            .join_tags()
+            .filter_eq_user(&user)
+            .filter_updated_at_gte(updated_at_gte)
+            .get_all_with_linked_tags(),
+        adapters
+            .reading_note
+            .join_tags()
+            .filter_eq_user(&user)
+            .filter_updated_at_gte(updated_at_gte)
+            .get_all_with_linked_tags(),
+        adapters
+            .thinking_note
+            .join_tags()
+            .filter_eq_user(&user)
+            .filter_updated_at_gte(updated_at_gte)
+            .get_all_with_linked_tags(),
+        adapters
+            .tag
+            .filter_eq_user(&user)
+            .filter_updated_at_gte(updated_at_gte)
+            .get_all(),
+    )
+    .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?;
+
+    // Nothing has been deleted from the client's point of view on the first sync.
+    let deleted = match since {
+        Some(since) => adapters
+            .tombstone
+            .filter_eq_user(&user)
+            .filter_deleted_at_gte(since)
+            .order_by_deleted_at(Asc)
+            .get_all()
+            .await
+            .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?,
+        None => vec![],
+    };
+
+    Ok(SyncChanges {
+        cursor: cursor.to_rfc3339_opts(SecondsFormat::Micros, true),
+        ambitions: ambitions.into_iter().map(Into::into).collect(),
+        directions: directions.into_iter().map(Into::into).collect(),
+        direction_categories: direction_categories.into_iter().map(Into::into).collect(),
+        actions: actions.into_iter().map(Into::into).collect(),
+        action_goals: action_goals.into_iter().map(Into::into).collect(),
+        action_tracks: action_tracks.into_iter().map(Into::into).collect(),
+        diaries: diaries.into_iter().map(Into::into).collect(),
+        reading_notes: reading_notes.into_iter().map(Into::into).collect(),
+        thinking_notes: thinking_notes.into_iter().map(Into::into).collect(),
+        tags: tags.into_iter().map(Into::into).collect(),
+        deleted: deleted.into_iter().map(Into::into).collect(),
+    })
+}
+
+fn parse_cursor(cursor: &str) -> Result<DateTime<FixedOffset>, UseCaseError> {
+    DateTime::parse_from_rfc3339(cursor)
+        .map_err(|_| UseCaseError::BadRequest(ErrorCode::SyncInvalidCursor))
+}
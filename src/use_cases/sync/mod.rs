@@ -0,0 +1,3 @@
+pub mod types;
+
+pub mod list;
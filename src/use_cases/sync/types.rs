@@ -0,0 +1,307 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use entities::{
+    action, action_goal, action_track, ambition, diary, direction, direction_category,
+    reading_note,
+    sea_orm_active_enums::{ActionTrackType, TagType},
+    tag, thinking_note, tombstone,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+#[derive(Deserialize, Debug, IntoParams)]
+pub struct SyncQuery {
+    /// `cursor` of the previous sync. Everything is returned when omitted.
+    pub since: Option<String>,
+}
+
+/// Records created or updated since the cursor, and ids of the ones deleted since then.
+/// Pass `cursor` as `since` on the next sync.
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct SyncChanges {
+    pub cursor: String,
+    pub ambitions: Vec<AmbitionSyncVisible>,
+    pub directions: Vec<DirectionSyncVisible>,
+    pub direction_categories: Vec<DirectionCategorySyncVisible>,
+    pub actions: Vec<ActionSyncVisible>,
+    pub action_goals: Vec<ActionGoalSyncVisible>,
+    pub action_tracks: Vec<ActionTrackSyncVisible>,
+    pub diaries: Vec<DiarySyncVisible>,
+    pub reading_notes: Vec<ReadingNoteSyncVisible>,
+    pub thinking_notes: Vec<ThinkingNoteSyncVisible>,
+    pub tags: Vec<TagSyncVisible>,
+    pub deleted: Vec<DeletedRecordVisible>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct AmbitionSyncVisible {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub archived: bool,
+    pub ordering: Option<i32>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl From<ambition::Model> for AmbitionSyncVisible {
+    fn from(item: ambition::Model) -> Self {
+        AmbitionSyncVisible {
+            id: item.id,
+            name: item.name,
+            description: item.description,
+            archived: item.archived,
+            ordering: item.ordering,
+            created_at: item.created_at,
+            updated_at: item.updated_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct DirectionSyncVisible {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub category_id: Option<Uuid>,
+    pub archived: bool,
+    pub ordering: Option<i32>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl From<direction::Model> for DirectionSyncVisible {
+    fn from(item: direction::Model) -> Self {
+        DirectionSyncVisible {
+            id: item.id,
+            name: item.name,
+            description: item.description,
+            category_id: item.category_id,
+            archived: item.archived,
+            ordering: item.ordering,
+            created_at: item.created_at,
+            updated_at: item.updated_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct DirectionCategorySyncVisible {
+    pub id: Uuid,
+    pub name: String,
+    pub ordering: Option<i32>,
+    pub parent_id: Option<Uuid>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl From<direction_category::Model> for DirectionCategorySyncVisible {
+    fn from(item: direction_category::Model) -> Self {
+        DirectionCategorySyncVisible {
+            id: item.id,
+            name: item.name,
+            ordering: item.ordering,
+            parent_id: item.parent_id,
+            updated_at: item.updated_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct ActionSyncVisible {
+    pub id: Uuid,
+    pub name: String,
+    pub discipline: Option<String>,
+    pub memo: Option<String>,
+    pub color: String,
+    pub track_type: ActionTrackType,
+    pub archived: bool,
+    pub ordering: Option<i32>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl From<action::Model> for ActionSyncVisible {
+    fn from(item: action::Model) -> Self {
+        ActionSyncVisible {
+            id: item.id,
+            name: item.name,
+            discipline: item.discipline,
+            memo: item.memo,
+            color: item.color,
+            track_type: item.track_type,
+            archived: item.archived,
+            ordering: item.ordering,
+            created_at: item.created_at,
+            updated_at: item.updated_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct ActionGoalSyncVisible {
+    pub id: Uuid,
+    pub action_id: Uuid,
+    pub from_date: NaiveDate,
+    pub to_date: Option<NaiveDate>,
+    pub duration_seconds: Option<i32>,
+    pub count: Option<i32>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl From<action_goal::Model> for ActionGoalSyncVisible {
+    fn from(item: action_goal::Model) -> Self {
+        ActionGoalSyncVisible {
+            id: item.id,
+            action_id: item.action_id,
+            from_date: item.from_date,
+            to_date: item.to_date,
+            duration_seconds: item.duration_seconds,
+            count: item.count,
+            updated_at: item.updated_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct ActionTrackSyncVisible {
+    pub id: Uuid,
+    pub action_id: Uuid,
+    pub started_at: DateTime<FixedOffset>,
+    pub ended_at: Option<DateTime<FixedOffset>>,
+    pub duration: Option<i64>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl From<action_track::Model> for ActionTrackSyncVisible {
+    fn from(item: action_track::Model) -> Self {
+        ActionTrackSyncVisible {
+            id: item.id,
+            action_id: item.action_id,
+            started_at: item.started_at,
+            ended_at: item.ended_at,
+            duration: item.duration,
+            updated_at: item.updated_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct DiarySyncVisible {
+    pub id: Uuid,
+    pub text: Option<String>,
+    pub date: NaiveDate,
+    pub tag_ids: Vec<Uuid>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl From<(diary::Model, Vec<tag::Model>)> for DiarySyncVisible {
+    fn from((item, tags): (diary::Model, Vec<tag::Model>)) -> Self {
+        DiarySyncVisible {
+            id: item.id,
+            text: item.text,
+            date: item.date,
+            tag_ids: tags.into_iter().map(|tag| tag.id).collect(),
+            updated_at: item.updated_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct ReadingNoteSyncVisible {
+    pub id: Uuid,
+    pub title: String,
+    pub page_number: i16,
+    pub text: String,
+    pub date: NaiveDate,
+    pub tag_ids: Vec<Uuid>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl From<(reading_note::Model, Vec<tag::Model>)> for ReadingNoteSyncVisible {
+    fn from((item, tags): (reading_note::Model, Vec<tag::Model>)) -> Self {
+        ReadingNoteSyncVisible {
+            id: item.id,
+            title: item.title,
+            page_number: item.page_number,
+            text: item.text,
+            date: item.date,
+            tag_ids: tags.into_iter().map(|tag| tag.id).collect(),
+            created_at: item.created_at,
+            updated_at: item.updated_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct ThinkingNoteSyncVisible {
+    pub id: Uuid,
+    pub question: Option<String>,
+    pub thought: Option<String>,
+    pub answer: Option<String>,
+    pub resolved_at: Option<DateTime<FixedOffset>>,
+    pub tag_ids: Vec<Uuid>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl From<(thinking_note::Model, Vec<tag::Model>)> for ThinkingNoteSyncVisible {
+    fn from((item, tags): (thinking_note::Model, Vec<tag::Model>)) -> Self {
+        ThinkingNoteSyncVisible {
+            id: item.id,
+            question: item.question,
+            thought: item.thought,
+            answer: item.answer,
+            resolved_at: item.resolved_at,
+            tag_ids: tags.into_iter().map(|tag| tag.id).collect(),
+            created_at: item.created_at,
+            updated_at: item.updated_at,
+        }
+    }
+}
+
+/// Names of ambition, direction and action tags are those of the linked records.
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct TagSyncVisible {
+    pub id: Uuid,
+    pub r#type: TagType,
+    pub name: Option<String>,
+    pub ambition_id: Option<Uuid>,
+    pub direction_id: Option<Uuid>,
+    pub action_id: Option<Uuid>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl From<tag::Model> for TagSyncVisible {
+    fn from(item: tag::Model) -> Self {
+        TagSyncVisible {
+            id: item.id,
+            r#type: item.r#type,
+            name: item.name,
+            ambition_id: item.ambition_id,
+            direction_id: item.direction_id,
+            action_id: item.action_id,
+            created_at: item.created_at,
+            updated_at: item.updated_at,
+        }
+    }
+}
+
+/// `table_name` is one of the tables above in singular, e.g. "ambition" or "reading_note".
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct DeletedRecordVisible {
+    pub table_name: String,
+    pub id: Uuid,
+    pub deleted_at: DateTime<FixedOffset>,
+}
+
+impl From<tombstone::Model> for DeletedRecordVisible {
+    fn from(item: tombstone::Model) -> Self {
+        DeletedRecordVisible {
+            table_name: item.table_name,
+            id: item.record_id,
+            deleted_at: item.deleted_at,
+        }
+    }
+}
@@ -2,7 +2,7 @@ use db_adapters::tag_adapter::{TagAdapter, TagFilter, TagMutation, TagQuery};
 use entities::{tag, user as user_entity};
 use uuid::Uuid;
 
-use crate::UseCaseError;
+use crate::{ErrorCode, UseCaseError};
 
 pub async fn delete_plain_tag<'a>(
     user: user_entity::Model,
@@ -21,9 +21,7 @@ pub async fn delete_plain_tag<'a>(
     };
 
     if !_is_plain_tag(&tag) {
-        return Err(UseCaseError::BadRequest(
-            "Tag to delete must be a plain tag.".to_string(),
-        ));
+        return Err(UseCaseError::BadRequest(ErrorCode::TagNotPlain));
     };
     tag_adapter
         .delete(tag)
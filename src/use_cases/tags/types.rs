@@ -1,12 +1,39 @@
 pub use db_adapters::tag_adapter::TagWithName as TagVisible;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+use crate::validation::{FieldViolation, MaxChars, NotBlank, Validate, Validator, NAME_MAX_CHARS};
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
 pub struct TagCreateRequest {
     pub name: String,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+impl Validate for TagCreateRequest {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        Validator::new()
+            .field(
+                "name",
+                self.name.as_str(),
+                &[&NotBlank, &MaxChars(NAME_MAX_CHARS)],
+            )
+            .finish()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
 pub struct TagUpdateRequest {
     pub name: String,
 }
+
+impl Validate for TagUpdateRequest {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        Validator::new()
+            .field(
+                "name",
+                self.name.as_str(),
+                &[&NotBlank, &MaxChars(NAME_MAX_CHARS)],
+            )
+            .finish()
+    }
+}
@@ -6,7 +6,7 @@ use uuid::Uuid;
 
 use crate::{
     tags::types::{TagUpdateRequest, TagVisible},
-    UseCaseError,
+    ErrorCode, UseCaseError,
 };
 
 pub async fn update_plain_tag<'a>(
@@ -21,14 +21,10 @@ pub async fn update_plain_tag<'a>(
         .get_by_id(tag_id)
         .await
         .map_err(|e| UseCaseError::InternalServerError(format!("{:?}", e)))?
-        .ok_or(UseCaseError::NotFound(
-            "Tag with this id was not found".to_string(),
-        ))?;
+        .ok_or(UseCaseError::NotFound(ErrorCode::TagNotFound))?;
 
     if tag.r#type != TagType::Plain {
-        return Err(UseCaseError::BadRequest(
-            "Tag to update must be a plain tag.".to_string(),
-        ));
+        return Err(UseCaseError::BadRequest(ErrorCode::TagNotPlain));
     };
 
     tag_adapter
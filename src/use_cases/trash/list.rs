@@ -0,0 +1,17 @@
+use crate::{trash::types::TrashItemVisible, UseCaseError};
+use db_adapters::trash_adapter::{TrashAdapter, TrashQuery};
+use entities::user as user_entity;
+
+/// Lists everything in the trash of the user, most recently deleted first.
+pub async fn list_trash<'a>(
+    user: user_entity::Model,
+    trash_adapter: TrashAdapter<'a>,
+) -> Result<Vec<TrashItemVisible>, UseCaseError> {
+    match trash_adapter.get_all_of_user(&user).await {
+        Ok(records) => Ok(records
+            .into_iter()
+            .map(TrashItemVisible::from)
+            .collect::<Vec<_>>()),
+        Err(e) => Err(UseCaseError::InternalServerError(format!("{:?}", e))),
+    }
+}
@@ -0,0 +1,4 @@
+pub mod types;
+
+pub mod list;
+pub mod restore;
@@ -0,0 +1,27 @@
+use db_adapters::{
+    trash_adapter::{TrashAdapter, TrashKind, TrashMutation},
+    CustomDbErr,
+};
+use entities::user as user_entity;
+use sea_orm::DbErr;
+use uuid::Uuid;
+
+use crate::{ErrorCode, UseCaseError};
+
+pub async fn restore_from_trash<'a>(
+    user: user_entity::Model,
+    kind: TrashKind,
+    id: Uuid,
+    trash_adapter: TrashAdapter<'a>,
+) -> Result<(), UseCaseError> {
+    match trash_adapter.restore(&user, kind, id).await {
+        Ok(_) => Ok(()),
+        Err(e) => match &e {
+            DbErr::Custom(ce) => match CustomDbErr::from(ce) {
+                CustomDbErr::NotFound => Err(UseCaseError::NotFound(ErrorCode::TrashItemNotFound)),
+                _ => Err(UseCaseError::InternalServerError(format!("{:?}", e))),
+            },
+            _ => Err(UseCaseError::InternalServerError(format!("{:?}", e))),
+        },
+    }
+}
@@ -0,0 +1,26 @@
+use chrono::{DateTime, FixedOffset};
+use db_adapters::trash_adapter::{TrashKind, TrashedRecord};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// `summary` is the name of ambitions, directions and actions, the date of diaries, the title of
+/// reading notes and the question of thinking notes.
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct TrashItemVisible {
+    pub kind: TrashKind,
+    pub id: Uuid,
+    pub summary: Option<String>,
+    pub deleted_at: DateTime<FixedOffset>,
+}
+
+impl From<TrashedRecord> for TrashItemVisible {
+    fn from(item: TrashedRecord) -> Self {
+        TrashItemVisible {
+            kind: item.kind,
+            id: item.id,
+            summary: item.summary,
+            deleted_at: item.deleted_at,
+        }
+    }
+}
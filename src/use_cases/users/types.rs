@@ -1,7 +1,8 @@
 use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, ToSchema)]
 pub struct UserVisible {
     pub id: uuid::Uuid,
     pub email: String,
@@ -11,7 +12,7 @@ pub struct UserVisible {
     pub first_track_at: Option<DateTime<FixedOffset>>,
 }
 
-#[derive(Deserialize, Debug, Serialize)]
+#[derive(Deserialize, Debug, Serialize, ToSchema)]
 pub struct LoginRequest {
     pub email: String,
     pub password: String,
@@ -0,0 +1,127 @@
+use crate::ErrorCode;
+
+pub const NAME_MAX_CHARS: usize = 255;
+pub const DESCRIPTION_MAX_CHARS: usize = 5_000;
+pub const TEXT_MAX_CHARS: usize = 50_000;
+
+/// Implemented by request payloads. Web adapters call `validate` before the use case,
+/// so use cases can assume the rules below hold.
+pub trait Validate {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>>;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldViolation {
+    pub field: &'static str,
+    pub code: ErrorCode,
+    pub detail: String,
+}
+
+pub trait Rule<T: ?Sized> {
+    /// Returns the code and a human readable detail if the value breaks the rule.
+    fn check(&self, value: &T) -> Option<(ErrorCode, String)>;
+}
+
+/// Rejects empty and whitespace only strings.
+pub struct NotBlank;
+
+impl Rule<str> for NotBlank {
+    fn check(&self, value: &str) -> Option<(ErrorCode, String)> {
+        value
+            .trim()
+            .is_empty()
+            .then(|| (ErrorCode::FieldBlank, "must not be blank.".to_string()))
+    }
+}
+
+/// Counts characters, not bytes, so that Japanese text gets the same limit.
+pub struct MaxChars(pub usize);
+
+impl Rule<str> for MaxChars {
+    fn check(&self, value: &str) -> Option<(ErrorCode, String)> {
+        (value.chars().count() > self.0).then(|| {
+            (
+                ErrorCode::FieldTooLong,
+                format!("must be at most {} characters long.", self.0),
+            )
+        })
+    }
+}
+
+/// e.g. "#212121"
+pub struct HexColor;
+
+impl Rule<str> for HexColor {
+    fn check(&self, value: &str) -> Option<(ErrorCode, String)> {
+        let is_hex_color = value.len() == 7
+            && value.starts_with('#')
+            && value[1..].chars().all(|c| c.is_ascii_hexdigit());
+        (!is_hex_color).then(|| {
+            (
+                ErrorCode::FieldNotHexColor,
+                "must be a hex color code like #212121.".to_string(),
+            )
+        })
+    }
+}
+
+pub struct NonNegative;
+
+impl<T: PartialOrd + Default> Rule<T> for NonNegative {
+    fn check(&self, value: &T) -> Option<(ErrorCode, String)> {
+        (*value < T::default()).then(|| {
+            (
+                ErrorCode::FieldNegative,
+                "must not be negative.".to_string(),
+            )
+        })
+    }
+}
+
+/// Collects violations of every field, reporting at most one per field.
+#[derive(Debug, Default)]
+pub struct Validator {
+    violations: Vec<FieldViolation>,
+}
+
+impl Validator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field<T: ?Sized>(
+        mut self,
+        field: &'static str,
+        value: &T,
+        rules: &[&dyn Rule<T>],
+    ) -> Self {
+        if let Some((code, detail)) = rules.iter().find_map(|rule| rule.check(value)) {
+            self.violations.push(FieldViolation {
+                field,
+                code,
+                detail,
+            });
+        }
+        self
+    }
+
+    /// None is always valid.
+    pub fn optional_field<T: ?Sized>(
+        self,
+        field: &'static str,
+        value: Option<&T>,
+        rules: &[&dyn Rule<T>],
+    ) -> Self {
+        match value {
+            Some(value) => self.field(field, value, rules),
+            None => self,
+        }
+    }
+
+    pub fn finish(self) -> Result<(), Vec<FieldViolation>> {
+        match self.violations.is_empty() {
+            true => Ok(()),
+            false => Err(self.violations),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+mod run;
+mod types;
+
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+pub fn batch_routes(cfg: &mut ServiceConfig) {
+    cfg.service(scope("/batch").service(run::run_batch_endpoint));
+}
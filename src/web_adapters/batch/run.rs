@@ -0,0 +1,532 @@
+use std::collections::{HashMap, HashSet};
+
+use actix_web::{
+    http::StatusCode,
+    post,
+    web::{Data, Json, ReqData},
+    HttpResponse,
+};
+use common::redis::change_events::{publish, ChangeEvent};
+use db_adapters::{
+    action_adapter::ActionAdapter, action_track_adapter::ActionTrackAdapter,
+    ambition_adapter::AmbitionAdapter, diary_adapter::DiaryAdapter,
+    direction_adapter::DirectionAdapter, reading_note_adapter::ReadingNoteAdapter,
+    tag_adapter::TagAdapter, thinking_note_adapter::ThinkingNoteAdapter, user_adapter::UserAdapter,
+};
+use deadpool_redis::Pool;
+use entities::user as user_entity;
+use sea_orm::{DatabaseTransaction, DbConn, DbErr, TransactionTrait};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use tracing::{event, Level};
+use use_cases::{
+    journal::{
+        diaries::{create::create_diary, delete::delete_diary, update::update_diary},
+        reading_notes::{
+            create::create_reading_note, delete::delete_reading_note, update::update_reading_note,
+        },
+        thinking_notes::{
+            create::create_thinking_note, delete::delete_thinking_note,
+            update::update_thinking_note,
+        },
+    },
+    my_way::{
+        action_tracks::{
+            create::create_action_track, delete::delete_action_track, update::update_action_track,
+        },
+        actions::{archive::archive_action, unarchive::unarchive_action},
+        ambitions::{archive::archive_ambition, unarchive::unarchive_ambition},
+        directions::{archive::archive_direction, unarchive::unarchive_direction},
+    },
+    tags::{create::create_plain_tag, delete::delete_plain_tag, update::update_plain_tag},
+    validation::Validate,
+    ErrorCode, UseCaseError,
+};
+use uuid::Uuid;
+
+use crate::{
+    batch::types::{
+        BatchOp, BatchOperationRequest, BatchOperationResult, BatchRequest, BatchResponse,
+    },
+    middlewares::idempotency::{Idempotency, IdempotencyKeyHeader},
+    utils::{response_400, response_401, response_500, ProblemDetails},
+};
+
+const MAX_OPERATIONS: usize = 100;
+
+/// Status code and body of a failed operation.
+type Failure = (StatusCode, Box<ProblemDetails>);
+
+#[utoipa::path(
+    params(IdempotencyKeyHeader),
+    responses(
+        (status = 200, body = BatchResponse, description = "Also when some of the operations failed. See the status of each result."),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+#[tracing::instrument(
+    name = "Running a batch of operations",
+    skip(db, redis_pool, user, req)
+)]
+#[post("", wrap = "Idempotency")]
+pub async fn run_batch_endpoint(
+    db: Data<DbConn>,
+    redis_pool: Data<Pool>,
+    user: Option<ReqData<user_entity::Model>>,
+    req: Json<BatchRequest>,
+) -> HttpResponse {
+    match user {
+        Some(user) => {
+            let req = req.into_inner();
+            if req.operations.len() > MAX_OPERATIONS {
+                return response_400(ErrorCode::BatchTooManyOperations);
+            }
+            let mut names = HashSet::new();
+            if !req
+                .operations
+                .iter()
+                .filter_map(|operation| operation.name.as_ref())
+                .all(|name| names.insert(name))
+            {
+                return response_400(ErrorCode::BatchDuplicateName);
+            }
+            let user_id = user.id;
+            match run_batch(&db, user.into_inner(), req).await {
+                Ok((res, events)) => {
+                    publish_changes(&redis_pool, user_id, events).await;
+                    HttpResponse::Ok().json(res)
+                }
+                Err(e) => response_500(e),
+            }
+        }
+        None => response_401(),
+    }
+}
+
+/// Same events as the corresponding endpoints publish through PublishChanges.
+/// Publishing failures are logged and do not affect the response.
+async fn publish_changes(redis_pool: &Pool, user_id: Uuid, events: Vec<ChangeEvent>) {
+    for event in events {
+        if let Err(e) = publish(redis_pool, user_id, &event).await {
+            event!(target: "redis", Level::WARN, "Error on publishing change event: {:?}", e);
+        }
+    }
+}
+
+/// Runs every operation in a savepoint of one transaction, so that a failed operation leaves
+/// nothing behind while the others can still be committed.
+/// Returns the change events of the committed operations.
+async fn run_batch(
+    db: &DbConn,
+    user: user_entity::Model,
+    req: BatchRequest,
+) -> Result<(BatchResponse, Vec<ChangeEvent>), DbErr> {
+    let txn = db.begin().await?;
+    let mut outputs: HashMap<String, Value> = HashMap::new();
+    let mut results = Vec::with_capacity(req.operations.len());
+    let mut events = vec![];
+    let mut failed = false;
+    for operation in req.operations {
+        if failed && req.all_or_nothing {
+            let (status, problem) = failure(StatusCode::FAILED_DEPENDENCY, ErrorCode::BatchSkipped);
+            results.push(BatchOperationResult {
+                name: operation.name,
+                status: status.as_u16(),
+                body: serde_json::to_value(problem).ok(),
+            });
+            continue;
+        }
+
+        let name = operation.name.clone();
+        let savepoint = txn.begin().await?;
+        match run_operation(&savepoint, &user, operation, &outputs).await {
+            Ok((status, body, event)) => {
+                savepoint.commit().await?;
+                if let (Some(name), Some(body)) = (&name, &body) {
+                    outputs.insert(name.clone(), body.clone());
+                }
+                events.push(event);
+                results.push(BatchOperationResult {
+                    name,
+                    status: status.as_u16(),
+                    body,
+                });
+            }
+            Err((status, problem)) => {
+                savepoint.rollback().await?;
+                failed = true;
+                results.push(BatchOperationResult {
+                    name,
+                    status: status.as_u16(),
+                    body: serde_json::to_value(problem).ok(),
+                });
+            }
+        }
+    }
+
+    let committed = !(failed && req.all_or_nothing);
+    match committed {
+        true => txn.commit().await?,
+        false => {
+            txn.rollback().await?;
+            events.clear();
+        }
+    }
+    Ok((BatchResponse { committed, results }, events))
+}
+
+async fn run_operation(
+    txn: &DatabaseTransaction,
+    user: &user_entity::Model,
+    operation: BatchOperationRequest,
+    outputs: &HashMap<String, Value>,
+) -> Result<(StatusCode, Option<Value>, ChangeEvent), Failure> {
+    let id = operation
+        .id
+        .map(|id| resolve_refs(id, outputs))
+        .transpose()?;
+    let body = operation
+        .body
+        .map(|body| resolve_refs(body, outputs))
+        .transpose()?;
+    let path_id = id
+        .as_ref()
+        .and_then(|id| serde_json::from_value::<Uuid>(id.clone()).ok());
+    let user = user.clone();
+    let op = operation.op;
+    let (status, output) = match op {
+        BatchOp::CreateDiary => respond(
+            StatusCode::CREATED,
+            create_diary(
+                user,
+                parse_valid_body(body)?,
+                DiaryAdapter::init_in_transaction(txn),
+            )
+            .await,
+        ),
+        BatchOp::UpdateDiary => respond(
+            StatusCode::OK,
+            update_diary(
+                user,
+                parse_valid_body(body)?,
+                parse_id(id)?,
+                DiaryAdapter::init_in_transaction(txn),
+            )
+            .await,
+        ),
+        BatchOp::DeleteDiary => respond_no_content(
+            delete_diary(user, parse_id(id)?, DiaryAdapter::init_in_transaction(txn)).await,
+        ),
+        BatchOp::CreateReadingNote => respond(
+            StatusCode::CREATED,
+            create_reading_note(
+                user,
+                parse_valid_body(body)?,
+                ReadingNoteAdapter::init_in_transaction(txn),
+            )
+            .await,
+        ),
+        BatchOp::UpdateReadingNote => respond(
+            StatusCode::OK,
+            update_reading_note(
+                user,
+                parse_valid_body(body)?,
+                parse_id(id)?,
+                ReadingNoteAdapter::init_in_transaction(txn),
+            )
+            .await,
+        ),
+        BatchOp::DeleteReadingNote => respond_no_content(
+            delete_reading_note(
+                user,
+                parse_id(id)?,
+                ReadingNoteAdapter::init_in_transaction(txn),
+            )
+            .await,
+        ),
+        BatchOp::CreateThinkingNote => respond(
+            StatusCode::CREATED,
+            create_thinking_note(
+                user,
+                parse_valid_body(body)?,
+                ThinkingNoteAdapter::init_in_transaction(txn),
+            )
+            .await,
+        ),
+        BatchOp::UpdateThinkingNote => respond(
+            StatusCode::OK,
+            update_thinking_note(
+                user,
+                parse_valid_body(body)?,
+                parse_id(id)?,
+                ThinkingNoteAdapter::init_in_transaction(txn),
+            )
+            .await,
+        ),
+        BatchOp::DeleteThinkingNote => respond_no_content(
+            delete_thinking_note(
+                user,
+                parse_id(id)?,
+                ThinkingNoteAdapter::init_in_transaction(txn),
+            )
+            .await,
+        ),
+        BatchOp::CreateTag => respond(
+            StatusCode::CREATED,
+            create_plain_tag(
+                user,
+                parse_valid_body(body)?,
+                TagAdapter::init_in_transaction(txn),
+            )
+            .await,
+        ),
+        BatchOp::UpdateTag => respond(
+            StatusCode::OK,
+            update_plain_tag(
+                user,
+                parse_valid_body(body)?,
+                parse_id(id)?,
+                TagAdapter::init_in_transaction(txn),
+            )
+            .await,
+        ),
+        BatchOp::DeleteTag => respond_no_content(
+            delete_plain_tag(user, parse_id(id)?, TagAdapter::init_in_transaction(txn)).await,
+        ),
+        BatchOp::ArchiveAmbition => respond(
+            StatusCode::OK,
+            archive_ambition(
+                user,
+                parse_id(id)?,
+                AmbitionAdapter::init_in_transaction(txn),
+            )
+            .await,
+        ),
+        BatchOp::UnarchiveAmbition => respond(
+            StatusCode::OK,
+            unarchive_ambition(
+                user,
+                parse_id(id)?,
+                AmbitionAdapter::init_in_transaction(txn),
+            )
+            .await,
+        ),
+        BatchOp::ArchiveDirection => respond(
+            StatusCode::OK,
+            archive_direction(
+                user,
+                parse_id(id)?,
+                DirectionAdapter::init_in_transaction(txn),
+            )
+            .await,
+        ),
+        BatchOp::UnarchiveDirection => respond(
+            StatusCode::OK,
+            unarchive_direction(
+                user,
+                parse_id(id)?,
+                DirectionAdapter::init_in_transaction(txn),
+            )
+            .await,
+        ),
+        BatchOp::ArchiveAction => respond(
+            StatusCode::OK,
+            archive_action(
+                user,
+                parse_id(id)?,
+                ActionAdapter::init_in_transaction(txn),
+                UserAdapter::init_in_transaction(txn),
+                ActionTrackAdapter::init_in_transaction(txn),
+            )
+            .await,
+        ),
+        BatchOp::UnarchiveAction => respond(
+            StatusCode::OK,
+            unarchive_action(
+                user,
+                parse_id(id)?,
+                ActionAdapter::init_in_transaction(txn),
+                UserAdapter::init_in_transaction(txn),
+                ActionTrackAdapter::init_in_transaction(txn),
+            )
+            .await,
+        ),
+        BatchOp::CreateActionTrack => respond(
+            StatusCode::CREATED,
+            create_action_track(
+                user,
+                parse_body(body)?,
+                ActionTrackAdapter::init_in_transaction(txn),
+                ActionAdapter::init_in_transaction(txn),
+                UserAdapter::init_in_transaction(txn),
+            )
+            .await,
+        ),
+        BatchOp::UpdateActionTrack => respond(
+            StatusCode::OK,
+            update_action_track(
+                user,
+                parse_body(body)?,
+                parse_id(id)?,
+                ActionTrackAdapter::init_in_transaction(txn),
+                UserAdapter::init_in_transaction(txn),
+            )
+            .await,
+        ),
+        BatchOp::DeleteActionTrack => respond_no_content(
+            delete_action_track(
+                user,
+                parse_id(id)?,
+                ActionTrackAdapter::init_in_transaction(txn),
+                UserAdapter::init_in_transaction(txn),
+            )
+            .await,
+        ),
+    }?;
+    let event = change_event(op, path_id, &output);
+    Ok((status, output, event))
+}
+
+/// Takes the id from the output like PublishChanges does, falling back to the operation's id.
+fn change_event(op: BatchOp, path_id: Option<Uuid>, output: &Option<Value>) -> ChangeEvent {
+    let (resource, kind) = op.change();
+    let id = output
+        .as_ref()
+        .and_then(|output| output.get("id"))
+        .and_then(|id| serde_json::from_value::<Uuid>(id.clone()).ok())
+        .or(path_id);
+    ChangeEvent {
+        resource,
+        kind,
+        id,
+        data: output.clone(),
+    }
+}
+
+/// Replaces every `{"$ref": "<name>.<field>..."}` in the value with the referred output.
+/// Array elements are referred by index, e.g. `{"$ref": "note.tags.0.id"}`.
+fn resolve_refs(value: Value, outputs: &HashMap<String, Value>) -> Result<Value, Failure> {
+    match value {
+        Value::Object(map) if map.len() == 1 && map.contains_key("$ref") => {
+            let path = map["$ref"].as_str().unwrap_or_default();
+            let mut segments = path.split('.');
+            let mut current = segments.next().and_then(|name| outputs.get(name));
+            for segment in segments {
+                current = current.and_then(|current| match current {
+                    Value::Array(items) => segment
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|index| items.get(index)),
+                    _ => current.get(segment),
+                });
+            }
+            current.cloned().ok_or_else(|| {
+                let (status, mut problem) =
+                    failure(StatusCode::BAD_REQUEST, ErrorCode::BatchInvalidReference);
+                problem.detail = format!("{} \"{}\" cannot be resolved.", problem.detail, path);
+                (status, problem)
+            })
+        }
+        Value::Object(map) => map
+            .into_iter()
+            .map(|(key, value)| Ok((key, resolve_refs(value, outputs)?)))
+            .collect::<Result<_, _>>()
+            .map(Value::Object),
+        Value::Array(items) => items
+            .into_iter()
+            .map(|item| resolve_refs(item, outputs))
+            .collect::<Result<_, _>>()
+            .map(Value::Array),
+        value => Ok(value),
+    }
+}
+
+fn parse_id(id: Option<Value>) -> Result<Uuid, Failure> {
+    id.and_then(|id| serde_json::from_value(id).ok())
+        .ok_or_else(|| failure(StatusCode::BAD_REQUEST, ErrorCode::BatchInvalidOperation))
+}
+
+fn parse_body<T: DeserializeOwned>(body: Option<Value>) -> Result<T, Failure> {
+    let body =
+        body.ok_or_else(|| failure(StatusCode::BAD_REQUEST, ErrorCode::BatchInvalidOperation))?;
+    serde_json::from_value(body).map_err(|e| {
+        let (status, mut problem) =
+            failure(StatusCode::BAD_REQUEST, ErrorCode::BatchInvalidOperation);
+        problem.detail = format!("{} {}", problem.detail, e);
+        (status, problem)
+    })
+}
+
+fn parse_valid_body<T: DeserializeOwned + Validate>(body: Option<Value>) -> Result<T, Failure> {
+    let body: T = parse_body(body)?;
+    body.validate().map_err(|violations| {
+        (
+            StatusCode::BAD_REQUEST,
+            Box::new(ProblemDetails::from_violations(violations)),
+        )
+    })?;
+    Ok(body)
+}
+
+fn respond<T: Serialize>(
+    status: StatusCode,
+    result: Result<T, UseCaseError>,
+) -> Result<(StatusCode, Option<Value>), Failure> {
+    result
+        .map(|output| (status, serde_json::to_value(output).ok()))
+        .map_err(use_case_failure)
+}
+
+fn respond_no_content(
+    result: Result<(), UseCaseError>,
+) -> Result<(StatusCode, Option<Value>), Failure> {
+    result
+        .map(|_| (StatusCode::NO_CONTENT, None))
+        .map_err(use_case_failure)
+}
+
+fn use_case_failure(e: UseCaseError) -> Failure {
+    match e {
+        UseCaseError::BadRequest(code) => failure(StatusCode::BAD_REQUEST, code),
+        UseCaseError::NotFound(code) => failure(StatusCode::NOT_FOUND, code),
+        UseCaseError::Conflict(code) => failure(StatusCode::CONFLICT, code),
+        _ => {
+            event!(target: "backend", Level::ERROR, "{:?}", e);
+            failure(StatusCode::INTERNAL_SERVER_ERROR, ErrorCode::InternalError)
+        }
+    }
+}
+
+fn failure(status: StatusCode, code: ErrorCode) -> Failure {
+    (status, Box::new(ProblemDetails::new(status, code)))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn resolve_refs_replaces_nested_refs() {
+        let tag_id = Uuid::now_v7();
+        let outputs = HashMap::from([("new_tag".to_string(), json!({"id": tag_id}))]);
+
+        let resolved = resolve_refs(
+            json!({"text": "$ref", "tag_ids": [{"$ref": "new_tag.id"}]}),
+            &outputs,
+        )
+        .unwrap();
+        assert_eq!(resolved, json!({"text": "$ref", "tag_ids": [tag_id]}));
+    }
+
+    #[test]
+    fn resolve_refs_fails_on_unknown_output() {
+        let (status, problem) =
+            resolve_refs(json!({"$ref": "missing.id"}), &HashMap::new()).unwrap_err();
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(problem.code, ErrorCode::BatchInvalidReference.as_str());
+    }
+}
@@ -0,0 +1,106 @@
+use common::redis::change_events::{ChangeKind, ChangedResource};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
+pub struct BatchRequest {
+    /// If true, any failure rolls back the whole batch and the operations after it are skipped.
+    /// Otherwise only the failed operations are rolled back and the rest are committed.
+    #[serde(default)]
+    pub all_or_nothing: bool,
+    pub operations: Vec<BatchOperationRequest>,
+}
+
+/// Anywhere in `id` and `body`, `{"$ref": "<name>.<field>..."}` is replaced with the output of
+/// the earlier operation with that name, e.g. `{"$ref": "new_tag.id"}`.
+/// `{"$ref": "<name>"}` is replaced with the whole output.
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
+pub struct BatchOperationRequest {
+    /// Unique within the batch. Needed only to refer to this operation's output.
+    pub name: Option<String>,
+    pub op: BatchOp,
+    /// Id of the record, for operations on an existing one.
+    #[schema(value_type = Option<Object>)]
+    pub id: Option<serde_json::Value>,
+    /// Same as the request body of the corresponding endpoint.
+    #[schema(value_type = Option<Object>)]
+    pub body: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchOp {
+    CreateDiary,
+    UpdateDiary,
+    DeleteDiary,
+    CreateReadingNote,
+    UpdateReadingNote,
+    DeleteReadingNote,
+    CreateThinkingNote,
+    UpdateThinkingNote,
+    DeleteThinkingNote,
+    CreateTag,
+    UpdateTag,
+    DeleteTag,
+    ArchiveAmbition,
+    UnarchiveAmbition,
+    ArchiveDirection,
+    UnarchiveDirection,
+    ArchiveAction,
+    UnarchiveAction,
+    CreateActionTrack,
+    UpdateActionTrack,
+    DeleteActionTrack,
+}
+
+impl BatchOp {
+    /// Resource and kind of the change event the corresponding endpoint publishes.
+    pub fn change(&self) -> (ChangedResource, ChangeKind) {
+        match self {
+            Self::CreateDiary => (ChangedResource::Diary, ChangeKind::Created),
+            Self::UpdateDiary => (ChangedResource::Diary, ChangeKind::Updated),
+            Self::DeleteDiary => (ChangedResource::Diary, ChangeKind::Deleted),
+            Self::CreateReadingNote => (ChangedResource::ReadingNote, ChangeKind::Created),
+            Self::UpdateReadingNote => (ChangedResource::ReadingNote, ChangeKind::Updated),
+            Self::DeleteReadingNote => (ChangedResource::ReadingNote, ChangeKind::Deleted),
+            Self::CreateThinkingNote => (ChangedResource::ThinkingNote, ChangeKind::Created),
+            Self::UpdateThinkingNote => (ChangedResource::ThinkingNote, ChangeKind::Updated),
+            Self::DeleteThinkingNote => (ChangedResource::ThinkingNote, ChangeKind::Deleted),
+            Self::CreateTag => (ChangedResource::Tag, ChangeKind::Created),
+            Self::UpdateTag => (ChangedResource::Tag, ChangeKind::Updated),
+            Self::DeleteTag => (ChangedResource::Tag, ChangeKind::Deleted),
+            Self::ArchiveAmbition | Self::UnarchiveAmbition => {
+                (ChangedResource::Ambition, ChangeKind::Updated)
+            }
+            Self::ArchiveDirection | Self::UnarchiveDirection => {
+                (ChangedResource::Direction, ChangeKind::Updated)
+            }
+            Self::ArchiveAction | Self::UnarchiveAction => {
+                (ChangedResource::Action, ChangeKind::Updated)
+            }
+            Self::CreateActionTrack => (ChangedResource::ActionTrack, ChangeKind::Created),
+            Self::UpdateActionTrack => (ChangedResource::ActionTrack, ChangeKind::Updated),
+            Self::DeleteActionTrack => (ChangedResource::ActionTrack, ChangeKind::Deleted),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
+pub struct BatchResponse {
+    /// False if the batch was rolled back as a whole.
+    pub committed: bool,
+    /// In the same order as the operations.
+    pub results: Vec<BatchOperationResult>,
+}
+
+#[derive(Deserialize, Serialize, Debug, ToSchema)]
+pub struct BatchOperationResult {
+    pub name: Option<String>,
+    /// Status code the corresponding endpoint would have responded with.
+    /// 424 means the operation was skipped.
+    pub status: u16,
+    /// Response body the corresponding endpoint would have responded with: the record, or
+    /// ProblemDetails on failure.
+    #[schema(value_type = Option<Object>)]
+    pub body: Option<serde_json::Value>,
+}
@@ -0,0 +1,7 @@
+mod stream;
+
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+pub fn event_routes(cfg: &mut ServiceConfig) {
+    cfg.service(scope("/events").service(stream::stream_events_endpoint));
+}
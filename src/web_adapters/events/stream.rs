@@ -0,0 +1,123 @@
+use std::time::Duration;
+
+use actix_web::{
+    get,
+    http::header::{CACHE_CONTROL, CONTENT_ENCODING},
+    web::{Bytes, Data, ReqData},
+    Error, HttpResponse,
+};
+use common::{
+    redis::change_events::{subscribe, ChangeEvent},
+    settings::types::Settings,
+};
+use deadpool_redis::redis::Msg;
+use entities::user as user_entity;
+use futures::{
+    future,
+    stream::{self, Stream, StreamExt},
+};
+
+use crate::utils::{response_401, response_500, ProblemDetails};
+
+/// Keeps proxies and load balancers from closing a connection that has been idle for a while.
+const HEARTBEAT_INTERVAL_SECONDS: u64 = 30;
+
+#[utoipa::path(
+    responses(
+        (status = 200, content_type = "text/event-stream", body = String, description = "Each event is named like `action_track.created` and its data is a ChangeEvent in JSON."),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+#[tracing::instrument(name = "Streaming change events", skip(settings, user))]
+#[get("")]
+pub async fn stream_events_endpoint(
+    settings: Data<Settings>,
+    user: Option<ReqData<user_entity::Model>>,
+) -> HttpResponse {
+    match user {
+        Some(user) => match subscribe(&settings.redis.url, user.id).await {
+            Ok(messages) => {
+                let events = messages.filter_map(|msg| async move { to_sse_message(&msg) });
+                let period = Duration::from_secs(HEARTBEAT_INTERVAL_SECONDS);
+                HttpResponse::Ok()
+                    .content_type("text/event-stream")
+                    .insert_header((CACHE_CONTROL, "no-cache"))
+                    // Compressing or buffering in a proxy would hold events back.
+                    .insert_header((CONTENT_ENCODING, "identity"))
+                    .insert_header(("X-Accel-Buffering", "no"))
+                    .streaming(with_heartbeats(events, period).map(Ok::<_, Error>))
+            }
+            Err(e) => response_500(e),
+        },
+        None => response_401(),
+    }
+}
+
+/// Interleaves heartbeats with `events` and ends along with them, e.g. when the Redis
+/// subscription is closed, so that the client reconnects instead of waiting forever.
+fn with_heartbeats(
+    events: impl Stream<Item = Bytes>,
+    period: Duration,
+) -> impl Stream<Item = Bytes> {
+    let heartbeats = stream::unfold(
+        tokio::time::interval_at(tokio::time::Instant::now() + period, period),
+        |mut interval| async move {
+            interval.tick().await;
+            Some((Some(Bytes::from_static(b": heartbeat\n\n")), interval))
+        },
+    );
+    // None marks the end of events, which would otherwise go unnoticed behind the heartbeats.
+    let events = events.map(Some).chain(stream::once(future::ready(None)));
+    stream::select(events, heartbeats)
+        .take_while(|message| future::ready(message.is_some()))
+        .filter_map(future::ready)
+}
+
+fn to_sse_message(msg: &Msg) -> Option<Bytes> {
+    match serde_json::from_slice::<ChangeEvent>(msg.get_payload_bytes()) {
+        Ok(event) => Some(Bytes::from(format!(
+            "event: {}\ndata: {}\n\n",
+            event.name(),
+            serde_json::to_string(&event).ok()?
+        ))),
+        Err(e) => {
+            tracing::event!(target: "redis", tracing::Level::WARN, "Error on parsing change event: {:?}", e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::body::to_bytes;
+
+    use super::*;
+
+    #[actix_web::test]
+    async fn body_ends_when_subscription_closes() {
+        // A subscription which delivers one event and is then closed.
+        let events = stream::iter(vec![Bytes::from_static(b"event: a\n\n")]);
+        let res = HttpResponse::Ok()
+            .streaming(with_heartbeats(events, Duration::from_secs(3600)).map(Ok::<_, Error>));
+
+        let body = tokio::time::timeout(Duration::from_secs(1), to_bytes(res.into_body()))
+            .await
+            .expect("body should end when the subscription closes")
+            .unwrap();
+
+        assert_eq!(body, Bytes::from_static(b"event: a\n\n"));
+    }
+
+    #[actix_web::test]
+    async fn with_heartbeats_sends_heartbeats_while_events_are_open() {
+        let events = stream::pending::<Bytes>();
+
+        let messages = with_heartbeats(events, Duration::from_millis(10))
+            .take(2)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(messages, vec![Bytes::from_static(b": heartbeat\n\n"); 2]);
+    }
+}
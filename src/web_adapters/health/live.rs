@@ -0,0 +1,17 @@
+use actix_web::{get, HttpResponse};
+
+use crate::health::types::{ComponentStatus, LivenessResponse};
+
+/// Only tells that the process can serve HTTP. Dependencies are checked by readiness.
+#[utoipa::path(
+    security(()),
+    responses(
+        (status = 200, body = LivenessResponse),
+    )
+)]
+#[get("/live")]
+pub async fn liveness_endpoint() -> HttpResponse {
+    HttpResponse::Ok().json(LivenessResponse {
+        status: ComponentStatus::Ok,
+    })
+}
@@ -0,0 +1,11 @@
+mod live;
+mod ready;
+pub mod types;
+
+use utoipa_actix_web::service_config::ServiceConfig;
+
+/// Without a scope of their own, so that they can be served outside the rate limited /api scope.
+pub fn health_routes(cfg: &mut ServiceConfig) {
+    cfg.service(live::liveness_endpoint)
+        .service(ready::readiness_endpoint);
+}
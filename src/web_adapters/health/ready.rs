@@ -0,0 +1,186 @@
+use std::{future::Future, time::Duration};
+
+use actix_web::{get, web::Data, HttpResponse};
+use common::{
+    db::get_pending_migrations_count,
+    redis::cron_heartbeat::{self, unix_timestamp_now, MonitoredJob, MONITORED_JOBS},
+};
+use deadpool_redis::{redis, Pool};
+use sea_orm::DbConn;
+
+use crate::health::types::{
+    ComponentHealth, ComponentStatus, ReadinessComponents, ReadinessResponse,
+};
+
+const CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+
+#[utoipa::path(
+    security(()),
+    responses(
+        (status = 200, body = ReadinessResponse),
+        (status = 503, body = ReadinessResponse),
+    )
+)]
+#[tracing::instrument(name = "Checking readiness", skip(db, redis_pool))]
+#[get("/ready")]
+pub async fn readiness_endpoint(db: Data<DbConn>, redis_pool: Data<Pool>) -> HttpResponse {
+    let (database, migrations, redis, cron) = futures::join!(
+        with_timeout("database", check_database(&db)),
+        with_timeout("migrations", check_migrations(&db)),
+        with_timeout("redis", check_redis(&redis_pool)),
+        with_timeout("cron", check_cron(&redis_pool)),
+    );
+    let res = ReadinessResponse::from(ReadinessComponents {
+        database,
+        migrations,
+        redis,
+        cron,
+    });
+    match res.status {
+        ComponentStatus::Ok => HttpResponse::Ok().json(res),
+        ComponentStatus::Error => HttpResponse::ServiceUnavailable().json(res),
+    }
+}
+
+/// Errors are only logged, as the endpoint is public and they may contain connection details.
+async fn with_timeout(
+    component: &str,
+    check: impl Future<Output = Result<(), String>>,
+) -> ComponentHealth {
+    let res = tokio::time::timeout(CHECK_TIMEOUT, check)
+        .await
+        .unwrap_or_else(|_| Err("Timed out.".to_string()));
+    match res {
+        Ok(_) => ComponentHealth::ok(),
+        Err(e) => {
+            tracing::event!(target: "backend", tracing::Level::WARN, "Not ready: {}: {}", component, e);
+            ComponentHealth::error()
+        }
+    }
+}
+
+async fn check_database(db: &DbConn) -> Result<(), String> {
+    db.ping().await.map_err(|e| e.to_string())
+}
+
+async fn check_migrations(db: &DbConn) -> Result<(), String> {
+    match get_pending_migrations_count(db).await {
+        Ok(0) => Ok(()),
+        Ok(count) => Err(format!("{} migrations are pending.", count)),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+async fn check_redis(redis_pool: &Pool) -> Result<(), String> {
+    let mut con = redis_pool.get().await.map_err(|e| e.to_string())?;
+    redis::cmd("PING")
+        .query_async::<String>(&mut con)
+        .await
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+async fn check_cron(redis_pool: &Pool) -> Result<(), String> {
+    let started_at = cron_heartbeat::get_started_at(redis_pool)
+        .await
+        .map_err(|e| format!("{:?}", e))?;
+    let mut last_runs = vec![];
+    for job in MONITORED_JOBS {
+        let last_run_at = cron_heartbeat::get_last_run_at(redis_pool, &job)
+            .await
+            .map_err(|e| format!("{:?}", e))?;
+        last_runs.push((job, last_run_at));
+    }
+    evaluate_cron(unix_timestamp_now(), started_at, last_runs)
+}
+
+fn evaluate_cron(
+    now: i64,
+    started_at: Option<i64>,
+    last_runs: Vec<(MonitoredJob, Option<i64>)>,
+) -> Result<(), String> {
+    let started_at = match started_at {
+        Some(started_at) => started_at,
+        None => return Err("Cron scheduler has not been started.".to_string()),
+    };
+    let stalled_jobs = last_runs
+        .into_iter()
+        .filter(|(job, last_run_at)| {
+            // A job which has not had its first slot yet is measured from the scheduler start.
+            let last_seen_at = last_run_at.unwrap_or(started_at).max(started_at);
+            now - last_seen_at > job.max_interval_seconds
+        })
+        .map(|(job, _)| job.name)
+        .collect::<Vec<_>>();
+    match stalled_jobs.is_empty() {
+        true => Ok(()),
+        false => Err(format!(
+            "Jobs have not run within their interval: {}",
+            stalled_jobs.join(", ")
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::redis::cron_heartbeat::{MY_WAY_REMINDER_JOB, SCHEDULER_HEARTBEAT_JOB};
+
+    use super::*;
+
+    const NOW: i64 = 1_800_000_000;
+
+    #[test]
+    fn evaluate_cron_not_started() {
+        let res = evaluate_cron(NOW, None, vec![(SCHEDULER_HEARTBEAT_JOB, Some(NOW))]);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn evaluate_cron_all_jobs_ran_recently() {
+        let res = evaluate_cron(
+            NOW,
+            Some(NOW - 3600),
+            vec![
+                (SCHEDULER_HEARTBEAT_JOB, Some(NOW - 60)),
+                (MY_WAY_REMINDER_JOB, Some(NOW - 600)),
+            ],
+        );
+        assert_eq!(res, Ok(()));
+    }
+
+    #[test]
+    fn evaluate_cron_jobs_not_run_yet_after_start() {
+        let res = evaluate_cron(
+            NOW,
+            Some(NOW - 30),
+            vec![(SCHEDULER_HEARTBEAT_JOB, None), (MY_WAY_REMINDER_JOB, None)],
+        );
+        assert_eq!(res, Ok(()));
+    }
+
+    #[test]
+    fn evaluate_cron_stalled_job() {
+        let res = evaluate_cron(
+            NOW,
+            Some(NOW - 3600),
+            vec![
+                (SCHEDULER_HEARTBEAT_JOB, Some(NOW - 60)),
+                (
+                    MY_WAY_REMINDER_JOB,
+                    Some(NOW - MY_WAY_REMINDER_JOB.max_interval_seconds - 1),
+                ),
+            ],
+        );
+        assert!(res.unwrap_err().contains(MY_WAY_REMINDER_JOB.name));
+    }
+
+    #[test]
+    fn evaluate_cron_last_run_before_restart_is_ignored() {
+        let res = evaluate_cron(
+            NOW,
+            Some(NOW - 60),
+            vec![(MY_WAY_REMINDER_JOB, Some(NOW - 86400))],
+        );
+        assert_eq!(res, Ok(()));
+    }
+}
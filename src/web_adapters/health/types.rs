@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentStatus {
+    Ok,
+    Error,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct ComponentHealth {
+    pub status: ComponentStatus,
+}
+
+impl ComponentHealth {
+    pub fn ok() -> Self {
+        Self {
+            status: ComponentStatus::Ok,
+        }
+    }
+
+    pub fn error() -> Self {
+        Self {
+            status: ComponentStatus::Error,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct LivenessResponse {
+    pub status: ComponentStatus,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct ReadinessComponents {
+    pub database: ComponentHealth,
+    pub migrations: ComponentHealth,
+    pub redis: ComponentHealth,
+    pub cron: ComponentHealth,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, ToSchema)]
+pub struct ReadinessResponse {
+    pub status: ComponentStatus,
+    pub components: ReadinessComponents,
+}
+
+impl From<ReadinessComponents> for ReadinessResponse {
+    fn from(components: ReadinessComponents) -> Self {
+        let all_ok = [
+            &components.database,
+            &components.migrations,
+            &components.redis,
+            &components.cron,
+        ]
+        .iter()
+        .all(|component| component.status == ComponentStatus::Ok);
+        Self {
+            status: match all_ok {
+                true => ComponentStatus::Ok,
+                false => ComponentStatus::Error,
+            },
+            components,
+        }
+    }
+}
@@ -8,13 +8,21 @@ use entities::user as user_entity;
 use sea_orm::DbConn;
 use use_cases::journal::diaries::delete::delete_diary;
 
-use crate::utils::{response_401, response_500};
+use crate::utils::{response_401, response_500, ProblemDetails};
 
-#[derive(serde::Deserialize, Debug, serde::Serialize)]
+#[derive(serde::Deserialize, Debug, serde::Serialize, utoipa::IntoParams)]
 struct PathParam {
     diary_id: uuid::Uuid,
 }
 
+#[utoipa::path(
+    params(PathParam),
+    responses(
+        (status = 204),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Deleting a diary", skip(db, user, path_param))]
 #[delete("/{diary_id}")]
 pub async fn delete_diary_endpoint(
@@ -2,11 +2,15 @@ mod create;
 mod delete;
 mod update;
 
-use actix_web::web::{scope, ServiceConfig};
+use common::redis::change_events::ChangedResource;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::middlewares::change_events::PublishChanges;
 
 pub fn diary_routes(cfg: &mut ServiceConfig) {
     cfg.service(
         scope("/diaries")
+            .wrap(PublishChanges(ChangedResource::Diary))
             .service(create::create_diary_endpoint)
             .service(update::update_diary_endpoint)
             .service(delete::delete_diary_endpoint),
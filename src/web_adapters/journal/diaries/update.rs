@@ -7,18 +7,34 @@ use db_adapters::diary_adapter::DiaryAdapter;
 use entities::user as user_entity;
 use sea_orm::DbConn;
 use use_cases::{
-    journal::diaries::{types::DiaryUpdateRequest, update::update_diary},
+    journal::diaries::{
+        types::{DiaryUpdateRequest, DiaryVisible},
+        update::update_diary,
+    },
+    validation::Validate,
     UseCaseError,
 };
 use uuid::Uuid;
 
-use crate::utils::{response_401, response_404, response_500};
+use crate::utils::{
+    response_401, response_404, response_500, response_validation_error, ProblemDetails,
+};
 
-#[derive(serde::Deserialize, Debug, serde::Serialize)]
+#[derive(serde::Deserialize, Debug, serde::Serialize, utoipa::IntoParams)]
 struct PathParam {
     diary_id: Uuid,
 }
 
+#[utoipa::path(
+    params(PathParam),
+    responses(
+        (status = 200, body = DiaryVisible),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Updating a diary", skip(db, user, req, path_param))]
 #[put("/{diary_id}")]
 pub async fn update_diary_endpoint(
@@ -29,6 +45,9 @@ pub async fn update_diary_endpoint(
 ) -> HttpResponse {
     match user {
         Some(user) => {
+            if let Err(violations) = req.validate() {
+                return response_validation_error(violations);
+            }
             match update_diary(
                 user.into_inner(),
                 req.into_inner(),
@@ -39,7 +58,7 @@ pub async fn update_diary_endpoint(
             {
                 Ok(res) => HttpResponse::Ok().json(res),
                 Err(e) => match &e {
-                    UseCaseError::NotFound(message) => response_404(message),
+                    UseCaseError::NotFound(code) => response_404(*code),
                     _ => response_500(e),
                 },
             }
@@ -9,10 +9,27 @@ use db_adapters::{
 };
 use entities::user as user_entity;
 use sea_orm::DbConn;
-use use_cases::journal::{list::list_journals, types::JournalListQuery};
+use use_cases::{
+    journal::{
+        list::list_journals,
+        types::{JournalListQuery, JournalVisibleWithTags},
+    },
+    UseCaseError,
+};
 
-use crate::utils::{response_401, response_500};
+use crate::utils::{response_400, response_401, response_500, response_page, ProblemDetails};
 
+#[utoipa::path(
+    params(JournalListQuery),
+    responses(
+        (status = 200, body = Vec<JournalVisibleWithTags>, headers(
+            ("x-next-cursor" = String, description = "Cursor of the next page. Absent on the last page.")
+        )),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Listing user's journals.", skip(db, user))]
 #[get("")]
 pub async fn list_journals_endpoint(
@@ -30,8 +47,11 @@ pub async fn list_journals_endpoint(
         )
         .await
         {
-            Ok(res) => HttpResponse::Ok().json(res),
-            Err(e) => response_500(e),
+            Ok(page) => response_page(page),
+            Err(e) => match &e {
+                UseCaseError::BadRequest(code) => response_400(*code),
+                _ => response_500(e),
+            },
         },
         None => response_401(),
     }
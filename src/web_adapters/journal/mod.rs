@@ -3,7 +3,7 @@ mod list;
 pub mod reading_notes;
 pub mod thinking_notes;
 
-use actix_web::web::{scope, ServiceConfig};
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
 
 pub fn journal_routes(cfg: &mut ServiceConfig) {
     cfg.service(scope("/journals").service(list::list_journals_endpoint));
@@ -7,14 +7,31 @@ use db_adapters::reading_note_adapter::ReadingNoteAdapter;
 use entities::user as user_entity;
 use sea_orm::DbConn;
 use use_cases::{
-    journal::reading_notes::{create::create_reading_note, types::ReadingNoteCreateRequest},
+    journal::reading_notes::{
+        create::create_reading_note,
+        types::{ReadingNoteCreateRequest, ReadingNoteVisible},
+    },
+    validation::Validate,
     UseCaseError,
 };
 
-use crate::utils::{response_401, response_404, response_500};
+use crate::{
+    middlewares::idempotency::{Idempotency, IdempotencyKeyHeader},
+    utils::{response_401, response_404, response_500, response_validation_error, ProblemDetails},
+};
 
+#[utoipa::path(
+    params(IdempotencyKeyHeader),
+    responses(
+        (status = 201, body = ReadingNoteVisible),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Creating a reading note", skip(db, user))]
-#[post("")]
+#[post("", wrap = "Idempotency")]
 pub async fn create_reading_note_endpoint(
     db: Data<DbConn>,
     user: Option<ReqData<user_entity::Model>>,
@@ -22,6 +39,9 @@ pub async fn create_reading_note_endpoint(
 ) -> HttpResponse {
     match user {
         Some(user) => {
+            if let Err(violations) = req.validate() {
+                return response_validation_error(violations);
+            }
             match create_reading_note(
                 user.into_inner(),
                 req.into_inner(),
@@ -31,7 +51,7 @@ pub async fn create_reading_note_endpoint(
             {
                 Ok(res) => HttpResponse::Created().json(res),
                 Err(e) => match &e {
-                    UseCaseError::NotFound(message) => response_404(message),
+                    UseCaseError::NotFound(code) => response_404(*code),
                     _ => response_500(e),
                 },
             }
@@ -8,10 +8,17 @@ use entities::user as user_entity;
 use sea_orm::DbConn;
 use use_cases::journal::reading_notes::list_titles::list_reading_note_titles;
 
-use crate::utils::{response_401, response_500};
+use crate::utils::{response_401, response_500, ProblemDetails};
 
+#[utoipa::path(
+    responses(
+        (status = 200, body = Vec<String>),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(skip(db, user))]
-#[get("titles")]
+#[get("/titles")]
 pub async fn list_reading_note_titles_endpoint(
     db: Data<DbConn>,
     user: Option<ReqData<user_entity::Model>>,
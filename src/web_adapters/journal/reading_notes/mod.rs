@@ -3,11 +3,15 @@ mod delete;
 mod list_titles;
 mod update;
 
-use actix_web::web::{scope, ServiceConfig};
+use common::redis::change_events::ChangedResource;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::middlewares::change_events::PublishChanges;
 
 pub fn reading_note_routes(cfg: &mut ServiceConfig) {
     cfg.service(
         scope("/reading_notes")
+            .wrap(PublishChanges(ChangedResource::ReadingNote))
             .service(create::create_reading_note_endpoint)
             .service(update::update_reading_note_endpoint)
             .service(delete::delete_reading_note_endpoint)
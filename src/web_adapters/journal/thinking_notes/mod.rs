@@ -2,11 +2,15 @@ mod create;
 mod delete;
 mod update;
 
-use actix_web::web::{scope, ServiceConfig};
+use common::redis::change_events::ChangedResource;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::middlewares::change_events::PublishChanges;
 
 pub fn thinking_note_routes(cfg: &mut ServiceConfig) {
     cfg.service(
         scope("/thinking_notes")
+            .wrap(PublishChanges(ChangedResource::ThinkingNote))
             .service(create::create_thinking_note_endpoint)
             .service(delete::delete_thinking_note_endpoint)
             .service(update::update_thinking_note_endpoint),
@@ -7,17 +7,33 @@ use db_adapters::thinking_note_adapter::ThinkingNoteAdapter;
 use entities::user as user_entity;
 use sea_orm::DbConn;
 use use_cases::{
-    journal::thinking_notes::{types::ThinkingNoteUpdateRequest, update::update_thinking_note},
+    journal::thinking_notes::{
+        types::{ThinkingNoteUpdateRequest, ThinkingNoteVisible},
+        update::update_thinking_note,
+    },
+    validation::Validate,
     UseCaseError,
 };
 
-use crate::utils::{response_401, response_404, response_500};
+use crate::utils::{
+    response_401, response_404, response_500, response_validation_error, ProblemDetails,
+};
 
-#[derive(serde::Deserialize, Debug, serde::Serialize)]
+#[derive(serde::Deserialize, Debug, serde::Serialize, utoipa::IntoParams)]
 struct PathParam {
     thinking_note_id: uuid::Uuid,
 }
 
+#[utoipa::path(
+    params(PathParam),
+    responses(
+        (status = 200, body = ThinkingNoteVisible),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Updating a thinking note", skip(db, user, req, path_param))]
 #[put("/{thinking_note_id}")]
 pub async fn update_thinking_note_endpoint(
@@ -28,6 +44,9 @@ pub async fn update_thinking_note_endpoint(
 ) -> HttpResponse {
     match user {
         Some(user) => {
+            if let Err(violations) = req.validate() {
+                return response_validation_error(violations);
+            }
             match update_thinking_note(
                 user.into_inner(),
                 req.into_inner(),
@@ -38,7 +57,7 @@ pub async fn update_thinking_note_endpoint(
             {
                 Ok(res) => HttpResponse::Ok().json(res),
                 Err(e) => match &e {
-                    UseCaseError::NotFound(message) => response_404(message),
+                    UseCaseError::NotFound(code) => response_404(*code),
                     _ => response_500(e),
                 },
             }
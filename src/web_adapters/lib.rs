@@ -1,11 +1,20 @@
+mod batch;
+mod events;
+mod health;
 mod journal;
 mod middlewares;
 mod my_way;
 mod notification;
+mod search;
+mod sync;
 mod tags;
+mod trash;
 mod users;
 mod utils;
 
+pub use batch::batch_routes;
+pub use events::event_routes;
+pub use health::health_routes;
 pub use journal::{
     diaries::diary_routes, journal_routes, reading_notes::reading_note_routes,
     thinking_notes::thinking_note_routes,
@@ -13,13 +22,20 @@ pub use journal::{
 pub use my_way::{
     action_goals::action_goal_routes, action_tracks::action_track_routes, actions::action_routes,
     ambitions::ambition_routes, direction_categories::direction_category_routes,
-    directions::direction_routes,
+    directions::direction_routes, tree::my_way_tree_routes,
 };
 pub use notification::{
     notification_rule::notification_rule_routes,
     web_push_subscription::web_push_subscription_routes,
 };
+pub use search::search_routes;
+pub use sync::sync_routes;
 pub use tags::tag_routes;
-pub use users::auth_routes;
+pub use trash::trash_routes;
+pub use users::{auth_routes, types::SESSION_COOKIE_NAME};
 
 pub use middlewares::auth as auth_middleware;
+pub use middlewares::csrf as csrf_middleware;
+pub use middlewares::rate_limit as rate_limit_middleware;
+pub use middlewares::request_tracing as request_tracing_middleware;
+pub use utils::{json_error_handler, path_error_handler, query_error_handler};
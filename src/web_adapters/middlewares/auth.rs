@@ -10,7 +10,9 @@ use actix_web::{
     web::Data,
     Error, HttpMessage,
 };
+use common::db::with_app_user_id;
 use db_adapters::user_adapter::{UserAdapter, UserQuery};
+use entities::user;
 use futures::future::LocalBoxFuture;
 use sea_orm::DbConn;
 
@@ -58,7 +60,12 @@ where
                 Ok(_) => (),
                 Err(_) => {}
             }
-            let res = svc.call(req).await?;
+            // NOTE: Row-level security of the DB lets the request see records of its user only.
+            let user_id = req.extensions().get::<user::Model>().map(|user| user.id);
+            let res = match user_id {
+                Some(user_id) => with_app_user_id(user_id, svc.call(req)).await?,
+                None => svc.call(req).await?,
+            };
             Ok(res)
         })
     }
@@ -101,13 +108,12 @@ mod tests {
     use sea_orm::prelude::ActiveModelTrait;
 
     use crate::users::types::{USER_EMAIL_KEY, USER_ID_KEY};
-    use common::{db::init_db, factory, settings::get_test_settings};
-    use entities::user;
+    use common::{db::test_db::init_test_db, factory, settings::get_test_settings};
 
     #[actix_web::test]
     async fn test_set_user() -> Result<(), String> {
         let settings = get_test_settings();
-        let db = init_db(&settings).await;
+        let db = init_test_db(&settings).await;
         let user = factory::user().insert(&db).await.unwrap();
         let srv_req = test::TestRequest::default()
             .app_data(Data::new(db.clone()))
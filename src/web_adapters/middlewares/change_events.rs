@@ -0,0 +1,196 @@
+use std::{
+    future::{ready, Ready},
+    rc::Rc,
+};
+
+use actix_web::{
+    body::{self, BoxBody, MessageBody},
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    http::{Method, StatusCode},
+    web::{Bytes, Data},
+    Error, HttpMessage,
+};
+use common::redis::change_events::{publish, ChangeEvent, ChangeKind, ChangedResource};
+use deadpool_redis::Pool;
+use entities::user;
+use futures::future::LocalBoxFuture;
+use uuid::Uuid;
+
+use crate::{middlewares::idempotency::IDEMPOTENCY_REPLAYED_HEADER, utils::response_500};
+
+/// Publishes a ChangeEvent of the resource to the user's subscribers after every successful
+/// request that is not a read, so that other tabs and devices can update without a reload.
+/// Publishing failures are logged and do not affect the response.
+///
+/// Needs Data<Pool>. Wrap this inside AuthenticateUser so that the user is known.
+pub struct PublishChanges(pub ChangedResource);
+
+impl<S: 'static, B> Transform<S, ServiceRequest> for PublishChanges
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<BoxBody>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = PublishChangesMiddleware<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(PublishChangesMiddleware {
+            service: Rc::new(service),
+            resource: self.0,
+        }))
+    }
+}
+
+pub struct PublishChangesMiddleware<S> {
+    service: Rc<S>,
+    resource: ChangedResource,
+}
+
+impl<S, B> Service<ServiceRequest> for PublishChangesMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<BoxBody>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let svc = self.service.clone();
+        let resource = self.resource;
+        Box::pin(async move {
+            if [Method::GET, Method::HEAD, Method::OPTIONS].contains(req.method()) {
+                return Ok(svc.call(req).await?.map_into_boxed_body());
+            }
+            let user_id = req.extensions().get::<user::Model>().map(|user| user.id);
+            let (user_id, pool) = match (user_id, req.app_data::<Data<Pool>>().cloned()) {
+                (Some(user_id), Some(pool)) => (user_id, pool),
+                _ => return Ok(svc.call(req).await?.map_into_boxed_body()),
+            };
+
+            let res = svc.call(req).await?;
+            // A replayed response has already been published when it was first made.
+            if !res.status().is_success() || res.headers().contains_key(IDEMPOTENCY_REPLAYED_HEADER)
+            {
+                return Ok(res.map_into_boxed_body());
+            }
+            let (req, res) = res.into_parts();
+            let (res, response_body) = res.into_parts();
+            let response_body = match body::to_bytes(response_body).await {
+                Ok(response_body) => response_body,
+                Err(_) => {
+                    return Ok(ServiceResponse::new(
+                        req,
+                        response_500("Error on reading response body."),
+                    ))
+                }
+            };
+
+            let path_id = req
+                .match_info()
+                .iter()
+                .find_map(|(_, value)| Uuid::parse_str(value).ok());
+            let event = build_event(
+                resource,
+                req.method(),
+                res.status(),
+                path_id,
+                &response_body,
+            );
+            if let Err(e) = publish(&pool, user_id, &event).await {
+                tracing::event!(target: "redis", tracing::Level::WARN, "Error on publishing change event: {:?}", e);
+            }
+
+            Ok(ServiceResponse::new(
+                req,
+                res.set_body(BoxBody::new(response_body)),
+            ))
+        })
+    }
+}
+
+fn build_event(
+    resource: ChangedResource,
+    method: &Method,
+    status: StatusCode,
+    path_id: Option<Uuid>,
+    response_body: &Bytes,
+) -> ChangeEvent {
+    let kind = match (method, status) {
+        (&Method::DELETE, _) => ChangeKind::Deleted,
+        (_, StatusCode::CREATED) => ChangeKind::Created,
+        _ => ChangeKind::Updated,
+    };
+    let data = serde_json::from_slice::<serde_json::Value>(response_body).ok();
+    let id = data
+        .as_ref()
+        .and_then(|data| data.get("id"))
+        .and_then(|id| id.as_str())
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .or(path_id);
+    ChangeEvent {
+        resource,
+        kind,
+        id,
+        data,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_event_for_creation_takes_id_from_body() {
+        let id = Uuid::now_v7();
+        let body = Bytes::from(format!("{{\"id\":\"{}\",\"name\":\"Test\"}}", id));
+        let event = build_event(
+            ChangedResource::Ambition,
+            &Method::POST,
+            StatusCode::CREATED,
+            None,
+            &body,
+        );
+        assert_eq!(event.kind, ChangeKind::Created);
+        assert_eq!(event.id, Some(id));
+        assert_eq!(
+            event.data,
+            Some(serde_json::json!({"id": id, "name": "Test"}))
+        );
+    }
+
+    #[test]
+    fn build_event_for_deletion_takes_id_from_path() {
+        let id = Uuid::now_v7();
+        let event = build_event(
+            ChangedResource::ActionTrack,
+            &Method::DELETE,
+            StatusCode::NO_CONTENT,
+            Some(id),
+            &Bytes::new(),
+        );
+        assert_eq!(event.kind, ChangeKind::Deleted);
+        assert_eq!(event.id, Some(id));
+        assert_eq!(event.data, None);
+    }
+
+    #[test]
+    fn build_event_for_bulk_update_has_no_id() {
+        let event = build_event(
+            ChangedResource::Direction,
+            &Method::PUT,
+            StatusCode::OK,
+            None,
+            &Bytes::from("[]"),
+        );
+        assert_eq!(event.kind, ChangeKind::Updated);
+        assert_eq!(event.id, None);
+    }
+}
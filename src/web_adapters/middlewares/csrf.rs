@@ -0,0 +1,212 @@
+use std::{
+    future::{ready, Ready},
+    rc::Rc,
+};
+
+use actix_web::{
+    body::EitherBody,
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    http::{
+        header::{HeaderMap, ORIGIN, REFERER},
+        Method,
+    },
+    web::Data,
+    Error,
+};
+use common::settings::types::Settings;
+use futures::future::LocalBoxFuture;
+use use_cases::ErrorCode;
+
+use crate::{users::types::SESSION_COOKIE_NAME, utils::response_403};
+
+/// Rejects cross-site mutations by checking Origin, or Referer when Origin is missing,
+/// against `frontend_url`. Cookies are sent along with cross-site requests while the session
+/// cookie is SameSite::None, so they alone do not prove where a request came from.
+///
+/// Safe methods are exempt. Requests without Origin, Referer or the session cookie are let
+/// through, since they do not come from a browser session.
+///
+/// Needs Data<Settings>. Wrap this next to AuthenticateUser.
+pub struct CsrfProtection;
+
+impl<S: 'static, B> Transform<S, ServiceRequest> for CsrfProtection
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = CsrfProtectionMiddleware<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(CsrfProtectionMiddleware {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+pub struct CsrfProtectionMiddleware<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for CsrfProtectionMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let svc = self.service.clone();
+        Box::pin(async move {
+            let frontend_url = req
+                .app_data::<Data<Settings>>()
+                .map(|settings| settings.application.frontend_url.clone())
+                .unwrap_or_default();
+            let has_session_cookie = req.cookie(SESSION_COOKIE_NAME).is_some();
+            match validate_request(
+                req.method(),
+                req.headers(),
+                has_session_cookie,
+                &frontend_url,
+            ) {
+                Ok(_) => Ok(svc.call(req).await?.map_into_left_body()),
+                Err(e) => {
+                    tracing::event!(target: "backend", tracing::Level::WARN, "Rejected a request as CSRF: {}", e);
+                    Ok(req
+                        .into_response(response_403(ErrorCode::CrossSiteRequest))
+                        .map_into_right_body())
+                }
+            }
+        })
+    }
+}
+
+fn validate_request(
+    method: &Method,
+    headers: &HeaderMap,
+    has_session_cookie: bool,
+    frontend_url: &str,
+) -> Result<(), String> {
+    if matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::OPTIONS | Method::TRACE
+    ) {
+        return Ok(());
+    }
+
+    let source = match headers.get(ORIGIN).or(headers.get(REFERER)) {
+        Some(value) => value
+            .to_str()
+            .map_err(|_| "Origin or Referer is not a valid string.".to_string())?,
+        None => {
+            return match has_session_cookie {
+                true => Err("Neither Origin nor Referer is present.".to_string()),
+                false => Ok(()),
+            }
+        }
+    };
+    match (get_origin(source), get_origin(frontend_url)) {
+        (Some(origin), Some(allowed)) if origin.eq_ignore_ascii_case(allowed) => Ok(()),
+        _ => Err(format!("{} is not allowed.", source)),
+    }
+}
+
+/// Returns `scheme://host[:port]` of a URL, or None if it has no scheme.
+fn get_origin(url: &str) -> Option<&str> {
+    let scheme_end = url.find("://")? + 3;
+    let host_end = url[scheme_end..]
+        .find(['/', '?', '#'])
+        .map_or(url.len(), |i| scheme_end + i);
+    match host_end > scheme_end {
+        true => Some(&url[..host_end]),
+        false => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::http::header::{HeaderName, HeaderValue, AUTHORIZATION};
+
+    use super::*;
+
+    const FRONTEND_URL: &str = "https://localhost:3000";
+
+    fn headers(pairs: &[(HeaderName, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(name.clone(), HeaderValue::from_str(value).unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn get_origin_of_urls() {
+        assert_eq!(
+            get_origin("https://localhost:3000"),
+            Some("https://localhost:3000")
+        );
+        assert_eq!(
+            get_origin("https://example.com/journals?page=1"),
+            Some("https://example.com")
+        );
+        assert_eq!(get_origin("https://"), None);
+        assert_eq!(get_origin("null"), None);
+    }
+
+    #[test]
+    fn safe_methods_are_allowed() {
+        let headers = headers(&[(ORIGIN, "https://evil.example.com")]);
+        assert!(validate_request(&Method::GET, &headers, true, FRONTEND_URL).is_ok());
+        assert!(validate_request(&Method::OPTIONS, &headers, true, FRONTEND_URL).is_ok());
+    }
+
+    #[test]
+    fn origin_of_frontend_is_allowed() {
+        let headers = headers(&[(ORIGIN, FRONTEND_URL)]);
+        for method in [Method::POST, Method::PUT, Method::PATCH, Method::DELETE] {
+            assert!(validate_request(&method, &headers, true, FRONTEND_URL).is_ok());
+        }
+    }
+
+    #[test]
+    fn other_origin_is_rejected() {
+        for origin in ["https://evil.example.com", "http://localhost:3000", "null"] {
+            let headers = headers(&[(ORIGIN, origin)]);
+            assert!(validate_request(&Method::POST, &headers, true, FRONTEND_URL).is_err());
+            assert!(validate_request(&Method::POST, &headers, false, FRONTEND_URL).is_err());
+        }
+    }
+
+    #[test]
+    fn referer_is_checked_without_origin() {
+        let headers_ok = headers(&[(REFERER, "https://localhost:3000/my-way")]);
+        assert!(validate_request(&Method::DELETE, &headers_ok, true, FRONTEND_URL).is_ok());
+
+        let headers_ng = headers(&[(REFERER, "https://localhost:3000.evil.example.com/")]);
+        assert!(validate_request(&Method::DELETE, &headers_ng, true, FRONTEND_URL).is_err());
+    }
+
+    #[test]
+    fn session_cookie_without_origin_and_referer_is_rejected() {
+        assert!(validate_request(&Method::POST, &HeaderMap::new(), true, FRONTEND_URL).is_err());
+        assert!(validate_request(&Method::POST, &HeaderMap::new(), false, FRONTEND_URL).is_ok());
+    }
+
+    #[test]
+    fn bearer_token_is_not_exempt() {
+        let headers = headers(&[
+            (AUTHORIZATION, "Bearer token"),
+            (ORIGIN, "https://evil.example.com"),
+        ]);
+        assert!(validate_request(&Method::POST, &headers, true, FRONTEND_URL).is_err());
+    }
+}
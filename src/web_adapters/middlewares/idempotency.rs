@@ -0,0 +1,287 @@
+use std::{
+    future::{ready, Ready},
+    rc::Rc,
+};
+
+use actix_web::{
+    body::{self, BoxBody, MessageBody},
+    dev::{forward_ready, Payload, Service, ServiceRequest, ServiceResponse, Transform},
+    http::{
+        header::{HeaderName, HeaderValue, CONTENT_TYPE},
+        StatusCode,
+    },
+    web::{Bytes, Data},
+    Error, HttpMessage, HttpResponse,
+};
+use common::redis::idempotency::{
+    abort, begin, complete, record_key, IdempotencyRecord, StoredResponse,
+};
+use deadpool_redis::Pool;
+use entities::user;
+use futures::future::LocalBoxFuture;
+use sha2::{Digest, Sha256};
+use use_cases::ErrorCode;
+
+use crate::utils::{response_400, response_409, response_422, response_500};
+
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+pub const IDEMPOTENCY_REPLAYED_HEADER: &str = "idempotency-replayed";
+const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 255;
+
+/// Documents the header on the endpoints wrapped with Idempotency.
+#[derive(serde::Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Header)]
+pub struct IdempotencyKeyHeader {
+    /// A retry with the same key gets the first response instead of running the request again.
+    #[serde(rename = "Idempotency-Key")]
+    #[allow(dead_code)]
+    idempotency_key: Option<String>,
+}
+
+/// Runs a request with an Idempotency-Key only once per user and key, and replays the stored
+/// response to retries. Responses are kept in Redis for IDEMPOTENCY_KEY_TTL_SECONDS, except for
+/// server errors, which can be retried.
+///
+/// Responds 409 while the first request is still running, and 422 if the key is reused
+/// for a different request. Requests are run as usual when Redis is unavailable.
+///
+/// Needs Data<Pool>. Wrap this inside AuthenticateUser so that the user is known.
+pub struct Idempotency;
+
+impl<S: 'static, B> Transform<S, ServiceRequest> for Idempotency
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<BoxBody>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = IdempotencyMiddleware<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(IdempotencyMiddleware {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+pub struct IdempotencyMiddleware<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for IdempotencyMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<BoxBody>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, mut req: ServiceRequest) -> Self::Future {
+        let svc = self.service.clone();
+        Box::pin(async move {
+            let idempotency_key = match req.headers().get(IDEMPOTENCY_KEY_HEADER) {
+                Some(value) => match validate_idempotency_key(value) {
+                    Ok(idempotency_key) => idempotency_key,
+                    Err(e) => return Ok(req.into_response(response_400(e))),
+                },
+                None => return Ok(svc.call(req).await?.map_into_boxed_body()),
+            };
+            let user_id = req.extensions().get::<user::Model>().map(|user| user.id);
+            let (user_id, pool) = match (user_id, req.app_data::<Data<Pool>>().cloned()) {
+                (Some(user_id), Some(pool)) => (user_id, pool),
+                _ => return Ok(svc.call(req).await?.map_into_boxed_body()),
+            };
+
+            let request_body = req.extract::<Bytes>().await?;
+            let fingerprint =
+                get_fingerprint(req.method().as_str(), &req.uri().to_string(), &request_body);
+            req.set_payload(Payload::from(request_body));
+
+            let key = record_key(user_id, &idempotency_key);
+            match begin(&pool, &key, &fingerprint).await {
+                Ok(None) => run_and_store(svc, req, &pool, &key, &fingerprint).await,
+                Ok(Some(record)) if record.fingerprint() != fingerprint => {
+                    Ok(req.into_response(response_422(ErrorCode::IdempotencyKeyReused)))
+                }
+                Ok(Some(IdempotencyRecord::Processing { .. })) => {
+                    Ok(req.into_response(response_409(ErrorCode::IdempotencyKeyInProgress)))
+                }
+                Ok(Some(IdempotencyRecord::Completed { response, .. })) => {
+                    Ok(req.into_response(replay(response)))
+                }
+                Err(e) => {
+                    tracing::event!(target: "redis", tracing::Level::WARN, "Error on checking Idempotency-Key: {:?}", e);
+                    Ok(svc.call(req).await?.map_into_boxed_body())
+                }
+            }
+        })
+    }
+}
+
+async fn run_and_store<S, B>(
+    svc: Rc<S>,
+    req: ServiceRequest,
+    pool: &Pool,
+    key: &str,
+    fingerprint: &str,
+) -> Result<ServiceResponse<BoxBody>, Error>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: MessageBody + 'static,
+{
+    let res = match svc.call(req).await {
+        Ok(res) => res,
+        Err(e) => {
+            release(pool, key).await;
+            return Err(e);
+        }
+    };
+    let (req, res) = res.into_parts();
+    let (res, response_body) = res.into_parts();
+    let response_body = match body::to_bytes(response_body).await {
+        Ok(response_body) => response_body,
+        Err(_) => {
+            release(pool, key).await;
+            return Ok(ServiceResponse::new(
+                req,
+                response_500("Error on reading response body."),
+            ));
+        }
+    };
+
+    match (
+        res.status().is_server_error(),
+        String::from_utf8(response_body.to_vec()),
+    ) {
+        (false, Ok(body)) => {
+            let stored = StoredResponse {
+                status: res.status().as_u16(),
+                content_type: res
+                    .headers()
+                    .get(CONTENT_TYPE)
+                    .and_then(|value| value.to_str().ok())
+                    .map(str::to_string),
+                body,
+            };
+            if let Err(e) = complete(pool, key, fingerprint, stored).await {
+                tracing::event!(target: "redis", tracing::Level::WARN, "Error on storing response for Idempotency-Key: {:?}", e);
+            }
+        }
+        _ => release(pool, key).await,
+    }
+    Ok(ServiceResponse::new(
+        req,
+        res.set_body(BoxBody::new(response_body)),
+    ))
+}
+
+async fn release(pool: &Pool, key: &str) {
+    if let Err(e) = abort(pool, key).await {
+        tracing::event!(target: "redis", tracing::Level::WARN, "Error on releasing Idempotency-Key: {:?}", e);
+    }
+}
+
+fn replay(stored: StoredResponse) -> HttpResponse {
+    let mut res = HttpResponse::build(
+        StatusCode::from_u16(stored.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+    );
+    if let Some(content_type) = stored.content_type {
+        res.insert_header((CONTENT_TYPE, content_type));
+    }
+    res.insert_header((
+        HeaderName::from_static(IDEMPOTENCY_REPLAYED_HEADER),
+        HeaderValue::from_static("true"),
+    ))
+    .body(stored.body)
+}
+
+fn validate_idempotency_key(value: &HeaderValue) -> Result<String, ErrorCode> {
+    match value.to_str() {
+        Ok(key)
+            if !key.is_empty()
+                && key.len() <= MAX_IDEMPOTENCY_KEY_LENGTH
+                && key.chars().all(|c| c.is_ascii_graphic()) =>
+        {
+            Ok(key.to_string())
+        }
+        _ => Err(ErrorCode::IdempotencyKeyInvalid),
+    }
+}
+
+/// Tells requests apart, so that a key reused for another request is not answered with
+/// an unrelated response.
+fn get_fingerprint(method: &str, uri: &str, body: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(method.as_bytes());
+    hasher.update(b" ");
+    hasher.update(uri.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(body);
+    hex::encode(hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::body::to_bytes;
+
+    use super::*;
+
+    #[test]
+    fn validate_idempotency_key_accepts_uuid() {
+        let key = uuid::Uuid::now_v7().to_string();
+        assert_eq!(
+            validate_idempotency_key(&HeaderValue::from_str(&key).unwrap()),
+            Ok(key)
+        );
+    }
+
+    #[test]
+    fn validate_idempotency_key_rejects_invalid() {
+        for invalid in ["", "has space", &"a".repeat(MAX_IDEMPOTENCY_KEY_LENGTH + 1)] {
+            assert!(validate_idempotency_key(&HeaderValue::from_str(invalid).unwrap()).is_err());
+        }
+    }
+
+    #[test]
+    fn get_fingerprint_differs_per_request() {
+        let fingerprint = get_fingerprint("POST", "/api/diaries", b"{\"text\":\"a\"}");
+        assert_eq!(
+            fingerprint,
+            get_fingerprint("POST", "/api/diaries", b"{\"text\":\"a\"}")
+        );
+        assert_ne!(
+            fingerprint,
+            get_fingerprint("POST", "/api/diaries", b"{\"text\":\"b\"}")
+        );
+        assert_ne!(
+            fingerprint,
+            get_fingerprint("POST", "/api/reading_notes", b"{\"text\":\"a\"}")
+        );
+    }
+
+    #[actix_web::test]
+    async fn replay_restores_response() {
+        let res = replay(StoredResponse {
+            status: 201,
+            content_type: Some("application/json".to_string()),
+            body: "{\"id\":1}".to_string(),
+        });
+        assert_eq!(res.status(), StatusCode::CREATED);
+        assert_eq!(res.headers().get(CONTENT_TYPE).unwrap(), "application/json");
+        assert_eq!(
+            res.headers().get(IDEMPOTENCY_REPLAYED_HEADER).unwrap(),
+            "true"
+        );
+        assert_eq!(
+            to_bytes(res.into_body()).await.unwrap(),
+            Bytes::from("{\"id\":1}")
+        );
+    }
+}
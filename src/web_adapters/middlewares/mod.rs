@@ -1 +1,6 @@
 pub mod auth;
+pub mod change_events;
+pub mod csrf;
+pub mod idempotency;
+pub mod rate_limit;
+pub mod request_tracing;
@@ -0,0 +1,325 @@
+use std::{
+    future::{ready, Ready},
+    net::IpAddr,
+    rc::Rc,
+    time::Duration,
+};
+
+use actix_web::{
+    body::EitherBody,
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    http::header::{HeaderMap, FORWARDED, X_FORWARDED_FOR},
+    web::Data,
+    Error, HttpMessage,
+};
+use common::{
+    redis::rate_limit::{take_token, RateLimitSubject},
+    settings::types::{RateLimit, RateLimitSettings, Settings},
+};
+use deadpool_redis::Pool;
+use entities::user;
+use futures::future::LocalBoxFuture;
+
+use crate::utils::response_429;
+
+/// Route scopes with their own limits in RateLimitSettings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RateLimitScope {
+    Registration,
+    PasswordChangeRequest,
+    ResendEmail,
+    Api,
+}
+
+impl RateLimitScope {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Registration => "registration",
+            Self::PasswordChangeRequest => "password_change_request",
+            Self::ResendEmail => "resend_email",
+            Self::Api => "api",
+        }
+    }
+
+    fn get_limit(&self, settings: &RateLimitSettings) -> RateLimit {
+        match self {
+            Self::Registration => settings.registration,
+            Self::PasswordChangeRequest => settings.password_change_request,
+            Self::ResendEmail => settings.resend_email,
+            Self::Api => settings.api,
+        }
+    }
+}
+
+/// Limits requests per client IP and per logged in user with token buckets in Redis,
+/// and responds 429 with Retry-After once either bucket is empty.
+///
+/// Needs Data<Pool> and Data<Settings>. Wrap this inside AuthenticateUser so that the user is known.
+/// Requests are let through when Redis is unavailable.
+pub struct RateLimiter {
+    scope: RateLimitScope,
+}
+
+impl RateLimiter {
+    pub fn new(scope: RateLimitScope) -> Self {
+        Self { scope }
+    }
+}
+
+impl<S: 'static, B> Transform<S, ServiceRequest> for RateLimiter
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = RateLimiterMiddleware<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RateLimiterMiddleware {
+            service: Rc::new(service),
+            scope: self.scope,
+        }))
+    }
+}
+
+pub struct RateLimiterMiddleware<S> {
+    service: Rc<S>,
+    scope: RateLimitScope,
+}
+
+impl<S, B> Service<ServiceRequest> for RateLimiterMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let svc = self.service.clone();
+        let scope = self.scope;
+        Box::pin(async move {
+            match check_rate_limit(&req, scope).await {
+                Some(retry_after) => {
+                    tracing::event!(target: "backend", tracing::Level::INFO, "Rate limit of {} exceeded.", scope.name());
+                    Ok(req
+                        .into_response(response_429(retry_after))
+                        .map_into_right_body())
+                }
+                None => Ok(svc.call(req).await?.map_into_left_body()),
+            }
+        })
+    }
+}
+
+async fn check_rate_limit(req: &ServiceRequest, scope: RateLimitScope) -> Option<Duration> {
+    let settings = req.app_data::<Data<Settings>>()?;
+    if !settings.rate_limit.enabled {
+        return None;
+    }
+    let pool = req.app_data::<Data<Pool>>()?;
+    let subjects = get_subjects(req, &settings.rate_limit.trusted_proxies);
+    match take_token(
+        pool,
+        scope.name(),
+        &subjects,
+        &scope.get_limit(&settings.rate_limit),
+    )
+    .await
+    {
+        Ok(retry_after) => retry_after,
+        Err(e) => {
+            tracing::event!(target: "redis", tracing::Level::WARN, "Error on checking rate limit: {:?}", e);
+            None
+        }
+    }
+}
+
+fn get_subjects(req: &ServiceRequest, trusted_proxies: &[IpAddr]) -> Vec<RateLimitSubject> {
+    let mut subjects = vec![];
+    if let Some(ip) = get_client_ip(req, trusted_proxies) {
+        subjects.push(RateLimitSubject::Ip(ip.to_string()));
+    }
+    if let Some(user) = req.extensions().get::<user::Model>() {
+        subjects.push(RateLimitSubject::User(user.id));
+    }
+    subjects
+}
+
+/// The peer IP, or the right-most hop not in trusted_proxies when the peer is a trusted proxy.
+/// Hops on the left are written by the client, so they are never believed.
+fn get_client_ip(req: &ServiceRequest, trusted_proxies: &[IpAddr]) -> Option<IpAddr> {
+    let peer_ip = req.peer_addr()?.ip();
+    if !trusted_proxies.contains(&peer_ip) {
+        return Some(peer_ip);
+    }
+    for hop in get_forwarded_hops(req.headers()).iter().rev() {
+        match hop {
+            Some(ip) if trusted_proxies.contains(ip) => continue,
+            Some(ip) => return Some(*ip),
+            // NOTE: A hop which cannot be read cannot be told apart from others, so the proxy is limited instead.
+            None => return Some(peer_ip),
+        }
+    }
+    Some(peer_ip)
+}
+
+/// Hops of Forwarded, or of X-Forwarded-For when Forwarded is absent, from the client to the nearest proxy.
+fn get_forwarded_hops(headers: &HeaderMap) -> Vec<Option<IpAddr>> {
+    let forwarded = headers
+        .get_all(FORWARDED)
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|element| {
+            element
+                .split(';')
+                .filter_map(|pair| pair.trim().split_once('='))
+                .find(|(key, _)| key.eq_ignore_ascii_case("for"))
+                .and_then(|(_, node)| parse_node(node.trim_matches('"')))
+        })
+        .collect::<Vec<_>>();
+    if !forwarded.is_empty() {
+        return forwarded;
+    }
+    headers
+        .get_all(X_FORWARDED_FOR)
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|node| parse_node(node.trim()))
+        .collect()
+}
+
+/// Parses `192.0.2.1`, `192.0.2.1:1234`, `2001:db8::1` or `[2001:db8::1]:1234`.
+fn parse_node(node: &str) -> Option<IpAddr> {
+    if let Ok(ip) = node.parse::<IpAddr>() {
+        return Some(ip);
+    }
+    if let Some(rest) = node.strip_prefix('[') {
+        return rest.split_once(']')?.0.parse::<IpAddr>().ok();
+    }
+    node.rsplit_once(':')?.0.parse::<IpAddr>().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::test::TestRequest;
+    use common::factory;
+    use sea_orm::TryIntoModel;
+
+    use super::*;
+
+    #[test]
+    fn get_limit_per_scope() {
+        let settings = Settings::base_settings().rate_limit;
+        assert_eq!(
+            RateLimitScope::Registration.get_limit(&settings),
+            settings.registration
+        );
+        assert_eq!(
+            RateLimitScope::PasswordChangeRequest.get_limit(&settings),
+            settings.password_change_request
+        );
+        assert_eq!(
+            RateLimitScope::ResendEmail.get_limit(&settings),
+            settings.resend_email
+        );
+        assert_eq!(RateLimitScope::Api.get_limit(&settings), settings.api);
+    }
+
+    const PROXY_IP: &str = "10.0.0.1";
+
+    #[actix_web::test]
+    async fn get_subjects_of_anonymous_request() {
+        let req = TestRequest::default()
+            .peer_addr("192.0.2.1:12345".parse().unwrap())
+            .to_srv_request();
+        assert_eq!(
+            get_subjects(&req, &[]),
+            vec![RateLimitSubject::Ip("192.0.2.1".to_string())]
+        );
+    }
+
+    #[actix_web::test]
+    async fn get_subjects_of_logged_in_request() {
+        let user = factory::user().try_into_model().unwrap();
+        let req = TestRequest::default()
+            .peer_addr("192.0.2.1:12345".parse().unwrap())
+            .to_srv_request();
+        req.extensions_mut().insert(user.clone());
+        assert_eq!(
+            get_subjects(&req, &[]),
+            vec![
+                RateLimitSubject::Ip("192.0.2.1".to_string()),
+                RateLimitSubject::User(user.id),
+            ]
+        );
+    }
+
+    #[actix_web::test]
+    async fn get_client_ip_ignores_forwarded_headers_from_untrusted_peer() {
+        let req = TestRequest::default()
+            .peer_addr("192.0.2.1:12345".parse().unwrap())
+            .insert_header(("x-forwarded-for", "198.51.100.1"))
+            .insert_header(("forwarded", "for=198.51.100.2"))
+            .to_srv_request();
+        assert_eq!(
+            get_client_ip(&req, &[PROXY_IP.parse().unwrap()]),
+            Some("192.0.2.1".parse().unwrap())
+        );
+    }
+
+    #[actix_web::test]
+    async fn get_client_ip_takes_right_most_untrusted_hop() {
+        let trusted_proxies = [PROXY_IP.parse().unwrap(), "10.0.0.2".parse().unwrap()];
+        let req = TestRequest::default()
+            .peer_addr(format!("{}:12345", PROXY_IP).parse().unwrap())
+            .insert_header(("x-forwarded-for", "203.0.113.9, 198.51.100.1, 10.0.0.2"))
+            .to_srv_request();
+        assert_eq!(
+            get_client_ip(&req, &trusted_proxies),
+            Some("198.51.100.1".parse().unwrap())
+        );
+
+        let req = TestRequest::default()
+            .peer_addr(format!("{}:12345", PROXY_IP).parse().unwrap())
+            .insert_header((
+                "forwarded",
+                r#"for=203.0.113.9, for="[2001:db8::1]:4711";proto=https"#,
+            ))
+            .insert_header(("x-forwarded-for", "198.51.100.1"))
+            .to_srv_request();
+        assert_eq!(
+            get_client_ip(&req, &trusted_proxies),
+            Some("2001:db8::1".parse().unwrap())
+        );
+    }
+
+    #[actix_web::test]
+    async fn get_client_ip_falls_back_to_trusted_peer() {
+        let trusted_proxies = [PROXY_IP.parse().unwrap()];
+        let req = TestRequest::default()
+            .peer_addr(format!("{}:12345", PROXY_IP).parse().unwrap())
+            .to_srv_request();
+        assert_eq!(
+            get_client_ip(&req, &trusted_proxies),
+            Some(PROXY_IP.parse().unwrap())
+        );
+
+        let req = TestRequest::default()
+            .peer_addr(format!("{}:12345", PROXY_IP).parse().unwrap())
+            .insert_header(("x-forwarded-for", "198.51.100.1, unknown"))
+            .to_srv_request();
+        assert_eq!(
+            get_client_ip(&req, &trusted_proxies),
+            Some(PROXY_IP.parse().unwrap())
+        );
+    }
+}
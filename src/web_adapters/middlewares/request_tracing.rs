@@ -0,0 +1,184 @@
+use std::{
+    future::{ready, Ready},
+    rc::Rc,
+    time::Instant,
+};
+
+use actix_web::{
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    http::header::{HeaderName, HeaderValue},
+    Error, HttpMessage,
+};
+use entities::user;
+use futures::future::LocalBoxFuture;
+use tracing::{field::Empty, Instrument, Level};
+
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// Returns the id of the request being handled, if called inside RequestTracing.
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(|request_id| request_id.clone()).ok()
+}
+
+/// Gives every request an id (taken from X-Request-Id or generated), runs it inside a span
+/// and echoes the id back in the response headers.
+///
+/// Wrap this outside of AuthenticateUser so that the user id can be recorded.
+pub struct RequestTracing;
+
+impl<S: 'static, B> Transform<S, ServiceRequest> for RequestTracing
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = RequestTracingMiddleware<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequestTracingMiddleware {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+pub struct RequestTracingMiddleware<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestTracingMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let svc = self.service.clone();
+        let request_id = get_request_id(
+            req.headers()
+                .get(REQUEST_ID_HEADER)
+                .and_then(|value| value.to_str().ok()),
+        );
+        let route = req
+            .match_pattern()
+            .unwrap_or_else(|| req.path().to_string());
+        let span = tracing::info_span!(
+            target: "backend",
+            "request",
+            request_id = %request_id,
+            method = %req.method(),
+            route = %route,
+            user_id = Empty,
+            status = Empty,
+            latency_ms = Empty,
+        );
+
+        Box::pin(REQUEST_ID.scope(
+            request_id.clone(),
+            async move {
+                let started_at = Instant::now();
+                let res = svc.call(req).await;
+                let latency_ms = started_at.elapsed().as_millis() as u64;
+                let span = tracing::Span::current();
+                span.record("latency_ms", latency_ms);
+                match res {
+                    Ok(mut res) => {
+                        if let Some(user) = res.request().extensions().get::<user::Model>() {
+                            span.record("user_id", tracing::field::display(user.id));
+                        }
+                        let status = res.status();
+                        span.record("status", status.as_u16());
+                        if let Ok(value) = HeaderValue::from_str(&request_id) {
+                            res.headers_mut()
+                                .insert(HeaderName::from_static(REQUEST_ID_HEADER), value);
+                        }
+                        if status.is_server_error() {
+                            tracing::event!(target: "backend", Level::ERROR, "Finished request.");
+                        } else {
+                            tracing::event!(target: "backend", Level::INFO, "Finished request.");
+                        }
+                        Ok(res)
+                    }
+                    Err(e) => {
+                        span.record("status", e.as_response_error().status_code().as_u16());
+                        tracing::event!(target: "backend", Level::ERROR, "Request failed: {:?}", e);
+                        Err(e)
+                    }
+                }
+            }
+            .instrument(span),
+        ))
+    }
+}
+
+/// Accepts the id sent by the client only when it is reasonably short and printable,
+/// since it ends up in logs and response headers.
+fn get_request_id(header_value: Option<&str>) -> String {
+    match header_value {
+        Some(id)
+            if !id.is_empty()
+                && id.len() <= MAX_REQUEST_ID_LENGTH
+                && id
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')) =>
+        {
+            id.to_string()
+        }
+        _ => uuid::Uuid::now_v7().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_request_id_accepts_valid_header() {
+        assert_eq!(
+            get_request_id(Some("abc-123_x.y")),
+            "abc-123_x.y".to_string()
+        );
+    }
+
+    #[test]
+    fn get_request_id_generates_when_header_is_missing() {
+        let request_id = get_request_id(None);
+        assert!(uuid::Uuid::parse_str(&request_id).is_ok());
+    }
+
+    #[test]
+    fn get_request_id_generates_when_header_is_invalid() {
+        for invalid in [
+            "",
+            "has space",
+            "a\"b",
+            &"a".repeat(MAX_REQUEST_ID_LENGTH + 1),
+        ] {
+            let request_id = get_request_id(Some(invalid));
+            assert!(uuid::Uuid::parse_str(&request_id).is_ok());
+        }
+    }
+
+    #[actix_web::test]
+    async fn current_request_id_is_available_inside_scope() {
+        assert_eq!(current_request_id(), None);
+        let request_id = REQUEST_ID
+            .scope("request-id".to_string(), async { current_request_id() })
+            .await;
+        assert_eq!(request_id, Some("request-id".to_string()));
+    }
+}
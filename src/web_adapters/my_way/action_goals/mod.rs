@@ -1,11 +1,15 @@
 mod remove;
 mod set_new;
 
-use actix_web::web::{scope, ServiceConfig};
+use common::redis::change_events::ChangedResource;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::middlewares::change_events::PublishChanges;
 
 pub fn action_goal_routes(cfg: &mut ServiceConfig) {
     cfg.service(
         scope("/action_goals")
+            .wrap(PublishChanges(ChangedResource::ActionGoal))
             .service(set_new::set_new_action_goal_endpoint)
             .service(remove::remove_action_goal_endpoint),
     );
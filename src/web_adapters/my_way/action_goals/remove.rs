@@ -8,8 +8,16 @@ use entities::user as user_entity;
 use sea_orm::DbConn;
 use use_cases::my_way::action_goals::{remove::remove_action_goal, types::ActionGoalRemoveQuery};
 
-use crate::utils::{response_401, response_500};
+use crate::utils::{response_401, response_500, ProblemDetails};
 
+#[utoipa::path(
+    params(ActionGoalRemoveQuery),
+    responses(
+        (status = 204),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Removing an action goal", skip(db, user))]
 #[delete("")]
 pub async fn remove_action_goal_endpoint(
@@ -7,14 +7,34 @@ use db_adapters::{action_adapter::ActionAdapter, action_goal_adapter::ActionGoal
 use entities::user as user_entity;
 use sea_orm::DbConn;
 use use_cases::{
-    my_way::action_goals::{set_new::set_new_action_goal, types::ActionGoalSetNewRequest},
+    my_way::action_goals::{
+        set_new::set_new_action_goal,
+        types::{ActionGoalSetNewRequest, ActionGoalVisible},
+    },
+    validation::Validate,
     UseCaseError,
 };
 
-use crate::utils::{response_400, response_401, response_404, response_500};
+use crate::{
+    middlewares::idempotency::{Idempotency, IdempotencyKeyHeader},
+    utils::{
+        response_400, response_401, response_404, response_500, response_validation_error,
+        ProblemDetails,
+    },
+};
 
+#[utoipa::path(
+    params(IdempotencyKeyHeader),
+    responses(
+        (status = 201, body = ActionGoalVisible),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Creating an action goal", skip(db, user))]
-#[post("")]
+#[post("", wrap = "Idempotency")]
 pub async fn set_new_action_goal_endpoint(
     db: Data<DbConn>,
     user: Option<ReqData<user_entity::Model>>,
@@ -22,6 +42,9 @@ pub async fn set_new_action_goal_endpoint(
 ) -> HttpResponse {
     match user {
         Some(user) => {
+            if let Err(violations) = req.validate() {
+                return response_validation_error(violations);
+            }
             match set_new_action_goal(
                 user.into_inner(),
                 req.into_inner(),
@@ -32,8 +55,8 @@ pub async fn set_new_action_goal_endpoint(
             {
                 Ok(res) => HttpResponse::Created().json(res),
                 Err(e) => match e {
-                    UseCaseError::BadRequest(message) => response_400(&message),
-                    UseCaseError::NotFound(message) => response_404(&message),
+                    UseCaseError::BadRequest(code) => response_400(code),
+                    UseCaseError::NotFound(code) => response_404(code),
                     _ => response_500(e),
                 },
             }
@@ -5,13 +5,26 @@ use actix_web::{
 };
 use sea_orm::DbConn;
 
-use crate::utils::{response_401, response_500};
+use crate::utils::{response_400, response_401, response_500, ProblemDetails};
 use db_adapters::action_track_adapter::ActionTrackAdapter;
 use entities::user as user_entity;
-use use_cases::my_way::action_tracks::{
-    aggregation_daily::aggregate_daily_action_tracks, types::ActionTrackAggregationDailyQuery,
+use use_cases::{
+    my_way::action_tracks::{
+        aggregation_daily::aggregate_daily_action_tracks,
+        types::{ActionTrackAggregationDailyQuery, ActionTrackDailyAggregationItem},
+    },
+    UseCaseError,
 };
 
+#[utoipa::path(
+    params(ActionTrackAggregationDailyQuery),
+    responses(
+        (status = 200, body = HashMap<String, Vec<ActionTrackDailyAggregationItem>>),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Aggregating a user's action tracks", skip(db, user))]
 #[get("/aggregation/daily")]
 pub async fn aggregate_daily_action_tracks_endpoint(
@@ -29,7 +42,10 @@ pub async fn aggregate_daily_action_tracks_endpoint(
             .await
             {
                 Ok(res) => HttpResponse::Ok().json(res),
-                Err(e) => response_500(e),
+                Err(e) => match &e {
+                    UseCaseError::BadRequest(code) => response_400(*code),
+                    _ => response_500(e),
+                },
             }
         }
         None => response_401(),
@@ -5,19 +5,35 @@ use actix_web::{
 };
 use sea_orm::DbConn;
 
-use crate::utils::{response_401, response_404, response_409, response_500};
+use crate::{
+    middlewares::idempotency::{Idempotency, IdempotencyKeyHeader},
+    utils::{response_401, response_404, response_409, response_500, ProblemDetails},
+};
 use db_adapters::{
     action_adapter::ActionAdapter, action_track_adapter::ActionTrackAdapter,
     user_adapter::UserAdapter,
 };
 use entities::user as user_entity;
 use use_cases::{
-    my_way::action_tracks::{create::create_action_track, types::ActionTrackCreateRequest},
+    my_way::action_tracks::{
+        create::create_action_track,
+        types::{ActionTrackCreateRequest, ActionTrackVisible},
+    },
     UseCaseError,
 };
 
+#[utoipa::path(
+    params(IdempotencyKeyHeader),
+    responses(
+        (status = 201, body = ActionTrackVisible),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Creating an action track", skip(db, user))]
-#[post("")]
+#[post("", wrap = "Idempotency")]
 pub async fn create_action_track_endpoint(
     db: Data<DbConn>,
     user: Option<ReqData<user_entity::Model>>,
@@ -36,8 +52,8 @@ pub async fn create_action_track_endpoint(
             {
                 Ok(res) => HttpResponse::Created().json(res),
                 Err(e) => match &e {
-                    UseCaseError::NotFound(message) => response_404(message),
-                    UseCaseError::Conflict(message) => response_409(message),
+                    UseCaseError::NotFound(code) => response_404(*code),
+                    UseCaseError::Conflict(code) => response_409(*code),
                     _ => response_500(e),
                 },
             }
@@ -5,11 +5,28 @@ use actix_web::{
 };
 use sea_orm::DbConn;
 
-use crate::utils::{response_401, response_500};
+use crate::utils::{response_400, response_401, response_500, response_page, ProblemDetails};
 use db_adapters::action_track_adapter::ActionTrackAdapter;
 use entities::user as user_entity;
-use use_cases::my_way::action_tracks::{list::list_action_tracks, types::ActionTrackListQuery};
+use use_cases::{
+    my_way::action_tracks::{
+        list::list_action_tracks,
+        types::{ActionTrackListQuery, ActionTrackVisible},
+    },
+    UseCaseError,
+};
 
+#[utoipa::path(
+    params(ActionTrackListQuery),
+    responses(
+        (status = 200, body = Vec<ActionTrackVisible>, headers(
+            ("x-next-cursor" = String, description = "Cursor of the next page. Absent on the last page.")
+        )),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Listing a user's action tracks", skip(db, user))]
 #[get("")]
 pub async fn list_action_tracks_endpoint(
@@ -25,8 +42,11 @@ pub async fn list_action_tracks_endpoint(
         )
         .await
         {
-            Ok(res) => HttpResponse::Ok().json(res),
-            Err(e) => response_500(e),
+            Ok(page) => response_page(page),
+            Err(e) => match &e {
+                UseCaseError::BadRequest(code) => response_400(*code),
+                _ => response_500(e),
+            },
         },
         None => response_401(),
     }
@@ -5,11 +5,15 @@ mod delete;
 mod list;
 mod update;
 
-use actix_web::web::{scope, ServiceConfig};
+use common::redis::change_events::ChangedResource;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::middlewares::change_events::PublishChanges;
 
 pub fn action_track_routes(cfg: &mut ServiceConfig) {
     cfg.service(
         scope("/action_tracks")
+            .wrap(PublishChanges(ChangedResource::ActionTrack))
             .service(list::list_action_tracks_endpoint)
             .service(create::create_action_track_endpoint)
             .service(update::update_action_track_endpoint)
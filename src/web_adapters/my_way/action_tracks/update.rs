@@ -5,19 +5,35 @@ use actix_web::{
 };
 use sea_orm::DbConn;
 
-use crate::utils::{response_400, response_401, response_404, response_409, response_500};
+use crate::utils::{
+    response_400, response_401, response_404, response_409, response_500, ProblemDetails,
+};
 use db_adapters::{action_track_adapter::ActionTrackAdapter, user_adapter::UserAdapter};
 use entities::user as user_entity;
 use use_cases::{
-    my_way::action_tracks::{types::ActionTrackUpdateRequest, update::update_action_track},
+    my_way::action_tracks::{
+        types::{ActionTrackUpdateRequest, ActionTrackVisible},
+        update::update_action_track,
+    },
     UseCaseError,
 };
 
-#[derive(serde::Deserialize, Debug, serde::Serialize)]
+#[derive(serde::Deserialize, Debug, serde::Serialize, utoipa::IntoParams)]
 struct PathParam {
     action_track_id: uuid::Uuid,
 }
 
+#[utoipa::path(
+    params(PathParam),
+    responses(
+        (status = 200, body = ActionTrackVisible),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Updating an action track", skip(db, user))]
 #[put("/{action_track_id}")]
 pub async fn update_action_track_endpoint(
@@ -39,9 +55,9 @@ pub async fn update_action_track_endpoint(
             {
                 Ok(res) => HttpResponse::Ok().json(res),
                 Err(e) => match &e {
-                    UseCaseError::BadRequest(message) => response_400(message),
-                    UseCaseError::NotFound(message) => response_404(message),
-                    UseCaseError::Conflict(message) => response_409(message),
+                    UseCaseError::BadRequest(code) => response_400(*code),
+                    UseCaseError::NotFound(code) => response_404(*code),
+                    UseCaseError::Conflict(code) => response_409(*code),
                     _ => response_500(e),
                 },
             }
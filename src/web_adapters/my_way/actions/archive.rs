@@ -9,15 +9,27 @@ use db_adapters::{
 };
 use entities::user as user_entity;
 use sea_orm::DbConn;
-use use_cases::{my_way::actions::archive::archive_action, UseCaseError};
+use use_cases::{
+    my_way::actions::{archive::archive_action, types::ActionVisible},
+    UseCaseError,
+};
 
-use crate::utils::{response_401, response_404, response_500};
+use crate::utils::{response_401, response_404, response_500, ProblemDetails};
 
-#[derive(serde::Deserialize, Debug, serde::Serialize)]
+#[derive(serde::Deserialize, Debug, serde::Serialize, utoipa::IntoParams)]
 struct PathParam {
     action_id: uuid::Uuid,
 }
 
+#[utoipa::path(
+    params(PathParam),
+    responses(
+        (status = 200, body = ActionVisible),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Archiving an action", skip(db, user, path_param))]
 #[put("/{action_id}/archive")]
 pub async fn archive_action_endpoint(
@@ -38,7 +50,7 @@ pub async fn archive_action_endpoint(
             {
                 Ok(res) => HttpResponse::Ok().json(res),
                 Err(e) => match &e {
-                    UseCaseError::NotFound(message) => response_404(message),
+                    UseCaseError::NotFound(code) => response_404(*code),
                     _ => response_500(e),
                 },
             }
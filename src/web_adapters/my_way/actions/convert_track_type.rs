@@ -8,18 +8,28 @@ use entities::user as user_entity;
 use sea_orm::DbConn;
 use use_cases::{
     my_way::actions::{
-        convert_track_type::convert_action_track_type, types::ActionTrackTypeConversionRequest,
+        convert_track_type::convert_action_track_type,
+        types::{ActionTrackTypeConversionRequest, ActionVisible},
     },
     UseCaseError,
 };
 
-use crate::utils::{response_401, response_404, response_500};
+use crate::utils::{response_401, response_404, response_500, ProblemDetails};
 
-#[derive(serde::Deserialize, Debug, serde::Serialize)]
+#[derive(serde::Deserialize, Debug, serde::Serialize, utoipa::IntoParams)]
 struct PathParam {
     action_id: uuid::Uuid,
 }
 
+#[utoipa::path(
+    params(PathParam),
+    responses(
+        (status = 200, body = ActionVisible),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Converting action type", skip(db, user, req, path_param))]
 #[put("/{action_id}/track_type")]
 pub async fn convert_action_track_type_endpoint(
@@ -41,7 +51,7 @@ pub async fn convert_action_track_type_endpoint(
             {
                 Ok(res) => HttpResponse::Ok().json(res),
                 Err(e) => match &e {
-                    UseCaseError::NotFound(message) => response_404(message),
+                    UseCaseError::NotFound(code) => response_404(*code),
                     _ => response_500(e),
                 },
             }
@@ -6,12 +6,30 @@ use actix_web::{
 use db_adapters::action_adapter::ActionAdapter;
 use entities::user as user_entity;
 use sea_orm::DbConn;
-use use_cases::my_way::actions::{create::create_action, types::ActionCreateRequest};
+use use_cases::{
+    my_way::actions::{
+        create::create_action,
+        types::{ActionCreateRequest, ActionVisible},
+    },
+    validation::Validate,
+};
 
-use crate::utils::{response_401, response_500};
+use crate::{
+    middlewares::idempotency::{Idempotency, IdempotencyKeyHeader},
+    utils::{response_401, response_500, response_validation_error, ProblemDetails},
+};
 
+#[utoipa::path(
+    params(IdempotencyKeyHeader),
+    responses(
+        (status = 201, body = ActionVisible),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Creating an action", skip(db, user))]
-#[post("")]
+#[post("", wrap = "Idempotency")]
 pub async fn create_action_endpoint(
     db: Data<DbConn>,
     user: Option<ReqData<user_entity::Model>>,
@@ -19,6 +37,9 @@ pub async fn create_action_endpoint(
 ) -> HttpResponse {
     match user {
         Some(user) => {
+            if let Err(violations) = req.validate() {
+                return response_validation_error(violations);
+            }
             match create_action(
                 user.into_inner(),
                 req.into_inner(),
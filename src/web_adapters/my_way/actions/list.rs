@@ -5,11 +5,18 @@ use actix_web::{
 };
 use sea_orm::DbConn;
 
-use crate::utils::{response_401, response_500};
+use crate::utils::{response_401, response_500, ProblemDetails};
 use db_adapters::action_adapter::ActionAdapter;
 use entities::user as user_entity;
-use use_cases::my_way::actions::list::list_actions;
+use use_cases::my_way::actions::{list::list_actions, types::ActionVisibleWithGoal};
 
+#[utoipa::path(
+    responses(
+        (status = 200, body = Vec<ActionVisibleWithGoal>),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Listing a user's actions", skip(db, user))]
 #[get("")]
 pub async fn list_actions_endpoint(
@@ -5,19 +5,27 @@ mod create;
 mod delete;
 mod get;
 mod list;
+mod list_revisions;
+mod restore_revision;
 mod unarchive;
 mod update;
 
-use actix_web::web::{scope, ServiceConfig};
+use common::redis::change_events::ChangedResource;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::middlewares::change_events::PublishChanges;
 
 pub fn action_routes(cfg: &mut ServiceConfig) {
     cfg.service(
         scope("/actions")
+            .wrap(PublishChanges(ChangedResource::Action))
             .service(list::list_actions_endpoint)
             .service(get::get_action_endpoint)
+            .service(list_revisions::list_action_revisions_endpoint)
             .service(create::create_action_endpoint)
             .service(bulk_update_ordering::bulk_update_action_ordering_endpoint)
             .service(update::update_action_endpoint)
+            .service(restore_revision::restore_action_revision_endpoint)
             .service(convert_track_type::convert_action_track_type_endpoint)
             .service(delete::delete_action_endpoint)
             .service(archive::archive_action_endpoint)
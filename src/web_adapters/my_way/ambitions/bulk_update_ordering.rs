@@ -10,7 +10,7 @@ use use_cases::my_way::ambitions::{
     bulk_update_ordering::bulk_update_ambition_ordering, types::AmbitionBulkUpdateOrderingRequest,
 };
 
-use crate::utils::{response_401, response_500};
+use crate::utils::{response_401, response_500, ProblemDetails};
 
 /// Fuzzy Ordering Design Decision
 /// Ordering doesn’t need to be correctly serialized in the backend
@@ -25,6 +25,13 @@ use crate::utils::{response_401, response_500};
 /// No need for handling ordering when creating, updating, archiving, un-archiving and deleting an ambition.
 /// Ordering numbers need only be updated on this endpoint.
 
+#[utoipa::path(
+    responses(
+        (status = 200),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Bulk updating ambition ordering", skip(db, user, req))]
 #[put("/bulk_update_ordering")]
 pub async fn bulk_update_ambition_ordering_endpoint(
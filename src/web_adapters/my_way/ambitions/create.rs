@@ -6,12 +6,30 @@ use actix_web::{
 use db_adapters::ambition_adapter::AmbitionAdapter;
 use entities::user as user_entity;
 use sea_orm::DbConn;
-use use_cases::my_way::ambitions::{create::create_ambition, types::AmbitionCreateRequest};
+use use_cases::{
+    my_way::ambitions::{
+        create::create_ambition,
+        types::{AmbitionCreateRequest, AmbitionVisible},
+    },
+    validation::Validate,
+};
 
-use crate::utils::{response_401, response_500};
+use crate::{
+    middlewares::idempotency::{Idempotency, IdempotencyKeyHeader},
+    utils::{response_401, response_500, response_validation_error, ProblemDetails},
+};
 
+#[utoipa::path(
+    params(IdempotencyKeyHeader),
+    responses(
+        (status = 201, body = AmbitionVisible),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Creating an ambition", skip(db, user))]
-#[post("")]
+#[post("", wrap = "Idempotency")]
 pub async fn create_ambition_endpoint(
     db: Data<DbConn>,
     user: Option<ReqData<user_entity::Model>>,
@@ -19,6 +37,9 @@ pub async fn create_ambition_endpoint(
 ) -> HttpResponse {
     match user {
         Some(user) => {
+            if let Err(violations) = req.validate() {
+                return response_validation_error(violations);
+            }
             match create_ambition(
                 user.into_inner(),
                 req.into_inner(),
@@ -6,15 +6,27 @@ use actix_web::{
 use db_adapters::ambition_adapter::AmbitionAdapter;
 use entities::user as user_entity;
 use sea_orm::DbConn;
-use use_cases::{my_way::ambitions::get::get_ambition, UseCaseError};
+use use_cases::{
+    my_way::ambitions::{get::get_ambition, types::AmbitionVisible},
+    UseCaseError,
+};
 
-use crate::utils::{response_401, response_404, response_500};
+use crate::utils::{response_401, response_404, response_500, ProblemDetails};
 
-#[derive(serde::Deserialize, Debug)]
+#[derive(serde::Deserialize, Debug, utoipa::IntoParams)]
 struct PathParam {
     ambition_id: uuid::Uuid,
 }
 
+#[utoipa::path(
+    params(PathParam),
+    responses(
+        (status = 200, body = AmbitionVisible),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Getting an ambition", skip(db, user))]
 #[get("/{ambition_id}")]
 pub async fn get_ambition_endpoint(
@@ -33,7 +45,7 @@ pub async fn get_ambition_endpoint(
             {
                 Ok(res) => HttpResponse::Ok().json(res),
                 Err(e) => match &e {
-                    UseCaseError::NotFound(message) => response_404(message),
+                    UseCaseError::NotFound(code) => response_404(*code),
                     _ => response_500(e),
                 },
             }
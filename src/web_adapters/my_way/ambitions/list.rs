@@ -6,10 +6,17 @@ use actix_web::{
 use db_adapters::ambition_adapter::AmbitionAdapter;
 use entities::user as user_entity;
 use sea_orm::DbConn;
-use use_cases::my_way::ambitions::list::list_ambitions;
+use use_cases::my_way::ambitions::{list::list_ambitions, types::AmbitionVisible};
 
-use crate::utils::{response_401, response_500};
+use crate::utils::{response_401, response_500, ProblemDetails};
 
+#[utoipa::path(
+    responses(
+        (status = 200, body = Vec<AmbitionVisible>),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Listing a user's ambitions", skip(db, user))]
 #[get("")]
 pub async fn list_ambitions_endpoint(
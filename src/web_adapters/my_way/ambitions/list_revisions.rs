@@ -0,0 +1,56 @@
+use actix_web::{
+    get,
+    web::{Data, Path, ReqData},
+    HttpResponse,
+};
+use db_adapters::{ambition_adapter::AmbitionAdapter, revision_adapter::RevisionAdapter};
+use entities::user as user_entity;
+use sea_orm::DbConn;
+use use_cases::{
+    my_way::ambitions::{list_revisions::list_ambition_revisions, types::AmbitionRevisionVisible},
+    UseCaseError,
+};
+
+use crate::utils::{response_401, response_404, response_500, ProblemDetails};
+
+#[derive(serde::Deserialize, Debug, utoipa::IntoParams)]
+struct PathParam {
+    ambition_id: uuid::Uuid,
+}
+
+#[utoipa::path(
+    params(PathParam),
+    responses(
+        (status = 200, body = [AmbitionRevisionVisible]),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+#[tracing::instrument(name = "Listing revisions of an ambition", skip(db, user))]
+#[get("/{ambition_id}/revisions")]
+pub async fn list_ambition_revisions_endpoint(
+    db: Data<DbConn>,
+    user: Option<ReqData<user_entity::Model>>,
+    path_param: Path<PathParam>,
+) -> HttpResponse {
+    match user {
+        Some(user) => {
+            match list_ambition_revisions(
+                user.into_inner(),
+                path_param.ambition_id,
+                AmbitionAdapter::init(&db),
+                RevisionAdapter::init(&db),
+            )
+            .await
+            {
+                Ok(res) => HttpResponse::Ok().json(res),
+                Err(e) => match &e {
+                    UseCaseError::NotFound(code) => response_404(*code),
+                    _ => response_500(e),
+                },
+            }
+        }
+        None => response_401(),
+    }
+}
@@ -4,19 +4,27 @@ mod create;
 mod delete;
 mod get;
 mod list;
+mod list_revisions;
+mod restore_revision;
 mod unarchive;
 mod update;
 
-use actix_web::web::{scope, ServiceConfig};
+use common::redis::change_events::ChangedResource;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::middlewares::change_events::PublishChanges;
 
 pub fn ambition_routes(cfg: &mut ServiceConfig) {
     cfg.service(
         scope("/ambitions")
+            .wrap(PublishChanges(ChangedResource::Ambition))
             .service(list::list_ambitions_endpoint)
             .service(get::get_ambition_endpoint)
+            .service(list_revisions::list_ambition_revisions_endpoint)
             .service(create::create_ambition_endpoint)
             .service(bulk_update_ordering::bulk_update_ambition_ordering_endpoint)
             .service(update::update_ambition_endpoint)
+            .service(restore_revision::restore_ambition_revision_endpoint)
             .service(delete::delete_ambition_endpoint)
             .service(archive::archive_ambition_endpoint)
             .service(unarchive::unarchive_ambition_endpoint),
@@ -0,0 +1,58 @@
+use actix_web::{
+    put,
+    web::{Data, Path, ReqData},
+    HttpResponse,
+};
+use db_adapters::{ambition_adapter::AmbitionAdapter, revision_adapter::RevisionAdapter};
+use entities::user as user_entity;
+use sea_orm::DbConn;
+use use_cases::{
+    my_way::ambitions::{restore_revision::restore_ambition_revision, types::AmbitionVisible},
+    UseCaseError,
+};
+
+use crate::utils::{response_401, response_404, response_500, ProblemDetails};
+
+#[derive(serde::Deserialize, Debug, utoipa::IntoParams)]
+struct PathParam {
+    ambition_id: uuid::Uuid,
+    revision_id: uuid::Uuid,
+}
+
+#[utoipa::path(
+    params(PathParam),
+    responses(
+        (status = 200, body = AmbitionVisible),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+#[tracing::instrument(name = "Restoring a revision of an ambition", skip(db, user))]
+#[put("/{ambition_id}/revisions/{revision_id}/restore")]
+pub async fn restore_ambition_revision_endpoint(
+    db: Data<DbConn>,
+    user: Option<ReqData<user_entity::Model>>,
+    path_param: Path<PathParam>,
+) -> HttpResponse {
+    match user {
+        Some(user) => {
+            match restore_ambition_revision(
+                user.into_inner(),
+                path_param.ambition_id,
+                path_param.revision_id,
+                AmbitionAdapter::init(&db),
+                RevisionAdapter::init(&db),
+            )
+            .await
+            {
+                Ok(res) => HttpResponse::Ok().json(res),
+                Err(e) => match &e {
+                    UseCaseError::NotFound(code) => response_404(*code),
+                    _ => response_500(e),
+                },
+            }
+        }
+        None => response_401(),
+    }
+}
@@ -7,17 +7,33 @@ use db_adapters::ambition_adapter::AmbitionAdapter;
 use entities::user as user_entity;
 use sea_orm::DbConn;
 use use_cases::{
-    my_way::ambitions::{types::AmbitionUpdateRequest, update::update_ambition},
+    my_way::ambitions::{
+        types::{AmbitionUpdateRequest, AmbitionVisible},
+        update::update_ambition,
+    },
+    validation::Validate,
     UseCaseError,
 };
 
-use crate::utils::{response_401, response_404, response_500};
+use crate::utils::{
+    response_401, response_404, response_500, response_validation_error, ProblemDetails,
+};
 
-#[derive(serde::Deserialize, Debug, serde::Serialize)]
+#[derive(serde::Deserialize, Debug, serde::Serialize, utoipa::IntoParams)]
 struct PathParam {
     ambition_id: uuid::Uuid,
 }
 
+#[utoipa::path(
+    params(PathParam),
+    responses(
+        (status = 200, body = AmbitionVisible),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Updating an ambition", skip(db, user, req, path_param))]
 #[put("/{ambition_id}")]
 pub async fn update_ambition_endpoint(
@@ -28,6 +44,9 @@ pub async fn update_ambition_endpoint(
 ) -> HttpResponse {
     match user {
         Some(user) => {
+            if let Err(violations) = req.validate() {
+                return response_validation_error(violations);
+            }
             match update_ambition(
                 user.into_inner(),
                 req.into_inner(),
@@ -38,7 +57,7 @@ pub async fn update_ambition_endpoint(
             {
                 Ok(res) => HttpResponse::Ok().json(res),
                 Err(e) => match &e {
-                    UseCaseError::NotFound(message) => response_404(message),
+                    UseCaseError::NotFound(code) => response_404(*code),
                     _ => response_500(e),
                 },
             }
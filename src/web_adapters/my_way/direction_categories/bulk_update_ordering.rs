@@ -11,7 +11,7 @@ use use_cases::my_way::direction_categories::{
     types::DirectionCategoryBulkUpdateOrderingRequest,
 };
 
-use crate::utils::{response_401, response_500};
+use crate::utils::{response_401, response_500, ProblemDetails};
 
 /// Fuzzy Ordering Design Decision
 /// Ordering doesn’t need to be correctly serialized in the backend
@@ -26,6 +26,13 @@ use crate::utils::{response_401, response_500};
 /// No need for handling ordering when creating, updating, archiving, un-archiving and deleting an direction_category.
 /// Ordering numbers need only be updated on this endpoint.
 
+#[utoipa::path(
+    responses(
+        (status = 200),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(
     name = "Bulk updating direction_category ordering",
     skip(db, user, req)
@@ -6,14 +6,32 @@ use actix_web::{
 use db_adapters::direction_category_adapter::DirectionCategoryAdapter;
 use entities::user as user_entity;
 use sea_orm::DbConn;
-use use_cases::my_way::direction_categories::{
-    create::create_direction_category, types::DirectionCategoryCreateRequest,
+use use_cases::{
+    my_way::direction_categories::{
+        create::create_direction_category,
+        types::{DirectionCategoryCreateRequest, DirectionCategoryVisible},
+    },
+    validation::Validate,
+    UseCaseError,
 };
 
-use crate::utils::{response_401, response_500};
+use crate::{
+    middlewares::idempotency::{Idempotency, IdempotencyKeyHeader},
+    utils::{response_401, response_404, response_500, response_validation_error, ProblemDetails},
+};
 
+#[utoipa::path(
+    params(IdempotencyKeyHeader),
+    responses(
+        (status = 201, body = DirectionCategoryVisible),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Creating an direction_category", skip(db, user))]
-#[post("")]
+#[post("", wrap = "Idempotency")]
 pub async fn create_direction_category_endpoint(
     db: Data<DbConn>,
     user: Option<ReqData<user_entity::Model>>,
@@ -21,6 +39,9 @@ pub async fn create_direction_category_endpoint(
 ) -> HttpResponse {
     match user {
         Some(user) => {
+            if let Err(violations) = req.validate() {
+                return response_validation_error(violations);
+            }
             match create_direction_category(
                 user.into_inner(),
                 req.into_inner(),
@@ -29,7 +50,10 @@ pub async fn create_direction_category_endpoint(
             .await
             {
                 Ok(res) => HttpResponse::Created().json(res),
-                Err(e) => response_500(e),
+                Err(e) => match &e {
+                    UseCaseError::NotFound(code) => response_404(*code),
+                    _ => response_500(e),
+                },
             }
         }
         None => response_401(),
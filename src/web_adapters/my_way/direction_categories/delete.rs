@@ -9,13 +9,21 @@ use sea_orm::DbConn;
 use use_cases::my_way::direction_categories::delete::delete_direction_category;
 use uuid::Uuid;
 
-use crate::utils::{response_401, response_500};
+use crate::utils::{response_401, response_500, ProblemDetails};
 
-#[derive(serde::Deserialize, Debug, serde::Serialize)]
+#[derive(serde::Deserialize, Debug, serde::Serialize, utoipa::IntoParams)]
 struct PathParam {
     category_id: Uuid,
 }
 
+#[utoipa::path(
+    params(PathParam),
+    responses(
+        (status = 204),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Deleting an direction_category", skip(db, user))]
 #[delete("/{category_id}")]
 pub async fn delete_direction_category_endpoint(
@@ -6,10 +6,19 @@ use actix_web::{
 use db_adapters::direction_category_adapter::DirectionCategoryAdapter;
 use entities::user as user_entity;
 use sea_orm::DbConn;
-use use_cases::my_way::direction_categories::list::list_direction_categories;
+use use_cases::my_way::direction_categories::{
+    list::list_direction_categories, types::DirectionCategoryNode,
+};
 
-use crate::utils::{response_401, response_500};
+use crate::utils::{response_401, response_500, ProblemDetails};
 
+#[utoipa::path(
+    responses(
+        (status = 200, body = Vec<DirectionCategoryNode>),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Listing a user's direction_categories", skip(db, user))]
 #[get("")]
 pub async fn list_direction_categories_endpoint(
@@ -18,11 +27,8 @@ pub async fn list_direction_categories_endpoint(
 ) -> HttpResponse {
     match user {
         Some(user) => {
-            match list_direction_categories(
-                user.into_inner(),
-                DirectionCategoryAdapter::init(&db),
-            )
-            .await
+            match list_direction_categories(user.into_inner(), DirectionCategoryAdapter::init(&db))
+                .await
             {
                 Ok(res) => HttpResponse::Ok().json(res),
                 Err(e) => response_500(e),
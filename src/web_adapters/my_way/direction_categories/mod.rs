@@ -4,11 +4,15 @@ mod delete;
 mod list;
 mod update;
 
-use actix_web::web::{scope, ServiceConfig};
+use common::redis::change_events::ChangedResource;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::middlewares::change_events::PublishChanges;
 
 pub fn direction_category_routes(cfg: &mut ServiceConfig) {
     cfg.service(
         scope("/direction_categories")
+            .wrap(PublishChanges(ChangedResource::DirectionCategory))
             .service(list::list_direction_categories_endpoint)
             .service(bulk_update_ordering::bulk_update_direction_category_ordering_endpoint)
             .service(create::create_direction_category_endpoint)
@@ -8,19 +8,34 @@ use entities::user as user_entity;
 use sea_orm::DbConn;
 use use_cases::{
     my_way::direction_categories::{
-        types::DirectionCategoryUpdateRequest, update::update_direction_category,
+        types::{DirectionCategoryUpdateRequest, DirectionCategoryVisible},
+        update::update_direction_category,
     },
+    validation::Validate,
     UseCaseError,
 };
 use uuid::Uuid;
 
-use crate::utils::{response_401, response_404, response_500};
+use crate::utils::{
+    response_400, response_401, response_404, response_500, response_validation_error,
+    ProblemDetails,
+};
 
-#[derive(serde::Deserialize, Debug, serde::Serialize)]
+#[derive(serde::Deserialize, Debug, serde::Serialize, utoipa::IntoParams)]
 struct PathParam {
     category_id: Uuid,
 }
 
+#[utoipa::path(
+    params(PathParam),
+    responses(
+        (status = 200, body = DirectionCategoryVisible),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Updating an direction_category", skip(db, user))]
 #[put("/{category_id}")]
 pub async fn update_direction_category_endpoint(
@@ -31,6 +46,9 @@ pub async fn update_direction_category_endpoint(
 ) -> HttpResponse {
     match user {
         Some(user) => {
+            if let Err(violations) = req.validate() {
+                return response_validation_error(violations);
+            }
             match update_direction_category(
                 user.into_inner(),
                 req.into_inner(),
@@ -41,7 +59,8 @@ pub async fn update_direction_category_endpoint(
             {
                 Ok(res) => HttpResponse::Ok().json(res),
                 Err(e) => match &e {
-                    UseCaseError::NotFound(message) => response_404(message),
+                    UseCaseError::BadRequest(code) => response_400(*code),
+                    UseCaseError::NotFound(code) => response_404(*code),
                     _ => response_500(e),
                 },
             }
@@ -6,15 +6,27 @@ use actix_web::{
 use db_adapters::direction_adapter::DirectionAdapter;
 use entities::user as user_entity;
 use sea_orm::DbConn;
-use use_cases::{my_way::directions::archive::archive_direction, UseCaseError};
+use use_cases::{
+    my_way::directions::{archive::archive_direction, types::DirectionVisible},
+    UseCaseError,
+};
 
-use crate::utils::{response_401, response_404, response_500};
+use crate::utils::{response_401, response_404, response_500, ProblemDetails};
 
-#[derive(serde::Deserialize, Debug, serde::Serialize)]
+#[derive(serde::Deserialize, Debug, serde::Serialize, utoipa::IntoParams)]
 struct PathParam {
     direction_id: uuid::Uuid,
 }
 
+#[utoipa::path(
+    params(PathParam),
+    responses(
+        (status = 200, body = DirectionVisible),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Archiving an direction", skip(db, user, path_param))]
 #[put("/{direction_id}/archive")]
 pub async fn archive_direction_endpoint(
@@ -33,7 +45,7 @@ pub async fn archive_direction_endpoint(
             {
                 Ok(res) => HttpResponse::Ok().json(res),
                 Err(e) => match &e {
-                    UseCaseError::NotFound(message) => response_404(message),
+                    UseCaseError::NotFound(code) => response_404(*code),
                     _ => response_500(e),
                 },
             }
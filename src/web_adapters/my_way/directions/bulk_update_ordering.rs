@@ -7,11 +7,10 @@ use db_adapters::direction_adapter::DirectionAdapter;
 use entities::user as user_entity;
 use sea_orm::DbConn;
 use use_cases::my_way::directions::{
-    bulk_update_ordering::bulk_update_direction_ordering,
-    types::DirectionBulkUpdateOrderingRequest,
+    bulk_update_ordering::bulk_update_direction_ordering, types::DirectionBulkUpdateOrderingRequest,
 };
 
-use crate::utils::{response_401, response_500};
+use crate::utils::{response_401, response_500, ProblemDetails};
 
 /// Fuzzy Ordering Design Decision
 /// Ordering doesn’t need to be correctly serialized in the backend
@@ -26,6 +25,13 @@ use crate::utils::{response_401, response_500};
 /// No need for handling ordering when creating, updating, archiving, un-archiving and deleting an direction.
 /// Ordering numbers need only be updated on this endpoint.
 
+#[utoipa::path(
+    responses(
+        (status = 200),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Bulk updating direction ordering", skip(db, user, req))]
 #[put("/bulk_update_ordering")]
 pub async fn bulk_update_direction_ordering_endpoint(
@@ -4,19 +4,34 @@ use actix_web::{
     HttpResponse,
 };
 use db_adapters::{
-    direction_adapter::DirectionAdapter,
-    direction_category_adapter::DirectionCategoryAdapter,
+    direction_adapter::DirectionAdapter, direction_category_adapter::DirectionCategoryAdapter,
 };
 use entities::user as user_entity;
 use sea_orm::DbConn;
-use use_cases::my_way::directions::{
-    create::create_direction, types::DirectionCreateRequest,
+use use_cases::{
+    my_way::directions::{
+        create::create_direction,
+        types::{DirectionCreateRequest, DirectionVisible},
+    },
+    validation::Validate,
 };
 
-use crate::utils::{response_401, response_500};
+use crate::{
+    middlewares::idempotency::{Idempotency, IdempotencyKeyHeader},
+    utils::{response_401, response_500, response_validation_error, ProblemDetails},
+};
 
+#[utoipa::path(
+    params(IdempotencyKeyHeader),
+    responses(
+        (status = 201, body = DirectionVisible),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Creating an direction", skip(db, user))]
-#[post("")]
+#[post("", wrap = "Idempotency")]
 pub async fn create_direction_endpoint(
     db: Data<DbConn>,
     user: Option<ReqData<user_entity::Model>>,
@@ -24,6 +39,9 @@ pub async fn create_direction_endpoint(
 ) -> HttpResponse {
     match user {
         Some(user) => {
+            if let Err(violations) = req.validate() {
+                return response_validation_error(violations);
+            }
             match create_direction(
                 user.into_inner(),
                 req.into_inner(),
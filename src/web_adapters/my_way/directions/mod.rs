@@ -4,19 +4,27 @@ mod create;
 mod delete;
 mod get;
 mod list;
+mod list_revisions;
+mod restore_revision;
 mod unarchive;
 mod update;
 
-use actix_web::web::{scope, ServiceConfig};
+use common::redis::change_events::ChangedResource;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::middlewares::change_events::PublishChanges;
 
 pub fn direction_routes(cfg: &mut ServiceConfig) {
     cfg.service(
         scope("/directions")
+            .wrap(PublishChanges(ChangedResource::Direction))
             .service(list::list_directions_endpoint)
             .service(get::get_direction_endpoint)
+            .service(list_revisions::list_direction_revisions_endpoint)
             .service(create::create_direction_endpoint)
             .service(bulk_update_ordering::bulk_update_direction_ordering_endpoint)
             .service(update::update_direction_endpoint)
+            .service(restore_revision::restore_direction_revision_endpoint)
             .service(delete::delete_direction_endpoint)
             .service(archive::archive_direction_endpoint)
             .service(unarchive::unarchive_direction_endpoint),
@@ -4,23 +4,38 @@ use actix_web::{
     HttpResponse,
 };
 use db_adapters::{
-    direction_adapter::DirectionAdapter,
-    direction_category_adapter::DirectionCategoryAdapter,
+    direction_adapter::DirectionAdapter, direction_category_adapter::DirectionCategoryAdapter,
 };
 use entities::user as user_entity;
 use sea_orm::DbConn;
 use use_cases::{
-    my_way::directions::{types::DirectionUpdateRequest, update::update_direction},
+    my_way::directions::{
+        types::{DirectionUpdateRequest, DirectionVisible},
+        update::update_direction,
+    },
+    validation::Validate,
     UseCaseError,
 };
 
-use crate::utils::{response_401, response_404, response_500};
+use crate::utils::{
+    response_401, response_404, response_500, response_validation_error, ProblemDetails,
+};
 
-#[derive(serde::Deserialize, Debug, serde::Serialize)]
+#[derive(serde::Deserialize, Debug, serde::Serialize, utoipa::IntoParams)]
 struct PathParam {
     direction_id: uuid::Uuid,
 }
 
+#[utoipa::path(
+    params(PathParam),
+    responses(
+        (status = 200, body = DirectionVisible),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Updating an direction", skip(db, user, req, path_param))]
 #[put("/{direction_id}")]
 pub async fn update_direction_endpoint(
@@ -31,6 +46,9 @@ pub async fn update_direction_endpoint(
 ) -> HttpResponse {
     match user {
         Some(user) => {
+            if let Err(violations) = req.validate() {
+                return response_validation_error(violations);
+            }
             match update_direction(
                 user.into_inner(),
                 req.into_inner(),
@@ -42,7 +60,7 @@ pub async fn update_direction_endpoint(
             {
                 Ok(res) => HttpResponse::Ok().json(res),
                 Err(e) => match &e {
-                    UseCaseError::NotFound(message) => response_404(message),
+                    UseCaseError::NotFound(code) => response_404(*code),
                     _ => response_500(e),
                 },
             }
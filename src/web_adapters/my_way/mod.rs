@@ -4,3 +4,4 @@ pub mod actions;
 pub mod ambitions;
 pub mod direction_categories;
 pub mod directions;
+pub mod tree;
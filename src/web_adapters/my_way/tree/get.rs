@@ -0,0 +1,43 @@
+use actix_web::{
+    get,
+    web::{Data, ReqData},
+    HttpResponse,
+};
+use db_adapters::{
+    action_adapter::ActionAdapter, ambition_adapter::AmbitionAdapter,
+    direction_adapter::DirectionAdapter,
+};
+use entities::user as user_entity;
+use sea_orm::DbConn;
+use use_cases::my_way::tree::{get::get_my_way_tree, types::MyWayTree};
+
+use crate::utils::{response_401, response_500, ProblemDetails};
+
+#[utoipa::path(
+    responses(
+        (status = 200, body = MyWayTree),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+#[tracing::instrument(name = "Getting a user's my_way tree", skip(db, user))]
+#[get("/tree")]
+pub async fn get_my_way_tree_endpoint(
+    db: Data<DbConn>,
+    user: Option<ReqData<user_entity::Model>>,
+) -> HttpResponse {
+    match user {
+        Some(user) => match get_my_way_tree(
+            user.into_inner(),
+            AmbitionAdapter::init(&db),
+            DirectionAdapter::init(&db),
+            ActionAdapter::init(&db),
+        )
+        .await
+        {
+            Ok(res) => HttpResponse::Ok().json(res),
+            Err(e) => response_500(e),
+        },
+        None => response_401(),
+    }
+}
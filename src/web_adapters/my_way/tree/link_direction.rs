@@ -0,0 +1,58 @@
+use actix_web::{
+    put,
+    web::{Data, Path, ReqData},
+    HttpResponse,
+};
+use db_adapters::{ambition_adapter::AmbitionAdapter, direction_adapter::DirectionAdapter};
+use entities::user as user_entity;
+use sea_orm::DbConn;
+use use_cases::{my_way::ambitions::link_direction::link_direction, UseCaseError};
+
+use crate::utils::{response_401, response_404, response_500, ProblemDetails};
+
+#[derive(serde::Deserialize, Debug, serde::Serialize, utoipa::IntoParams)]
+struct PathParam {
+    ambition_id: uuid::Uuid,
+    direction_id: uuid::Uuid,
+}
+
+#[utoipa::path(
+    params(PathParam),
+    responses(
+        (status = 204),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+#[tracing::instrument(
+    name = "Linking a direction to an ambition",
+    skip(db, user, path_param)
+)]
+#[put("/ambitions/{ambition_id}/directions/{direction_id}")]
+pub async fn link_ambition_direction_endpoint(
+    db: Data<DbConn>,
+    user: Option<ReqData<user_entity::Model>>,
+    path_param: Path<PathParam>,
+) -> HttpResponse {
+    match user {
+        Some(user) => {
+            match link_direction(
+                user.into_inner(),
+                path_param.ambition_id,
+                path_param.direction_id,
+                AmbitionAdapter::init(&db),
+                DirectionAdapter::init(&db),
+            )
+            .await
+            {
+                Ok(_) => HttpResponse::NoContent().finish(),
+                Err(e) => match &e {
+                    UseCaseError::NotFound(code) => response_404(*code),
+                    _ => response_500(e),
+                },
+            }
+        }
+        None => response_401(),
+    }
+}
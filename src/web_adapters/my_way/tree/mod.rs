@@ -0,0 +1,26 @@
+mod get;
+mod link_action;
+mod link_direction;
+mod progress;
+mod unlink_action;
+mod unlink_direction;
+
+use common::redis::change_events::ChangedResource;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::middlewares::change_events::PublishChanges;
+
+/// The hierarchy of ambitions, directions and actions, the links that make it up, and the
+/// progress rolled up along it.
+pub fn my_way_tree_routes(cfg: &mut ServiceConfig) {
+    cfg.service(
+        scope("/my_way")
+            .wrap(PublishChanges(ChangedResource::MyWayLink))
+            .service(get::get_my_way_tree_endpoint)
+            .service(progress::get_my_way_progress_endpoint)
+            .service(link_direction::link_ambition_direction_endpoint)
+            .service(unlink_direction::unlink_ambition_direction_endpoint)
+            .service(link_action::link_direction_action_endpoint)
+            .service(unlink_action::unlink_direction_action_endpoint),
+    );
+}
@@ -0,0 +1,63 @@
+use actix_web::{
+    get,
+    web::{Data, Query, ReqData},
+    HttpResponse,
+};
+use db_adapters::{
+    action_adapter::ActionAdapter, action_goal_adapter::ActionGoalAdapter,
+    action_track_adapter::ActionTrackAdapter, ambition_adapter::AmbitionAdapter,
+    direction_adapter::DirectionAdapter, direction_category_adapter::DirectionCategoryAdapter,
+};
+use entities::user as user_entity;
+use sea_orm::DbConn;
+use use_cases::{
+    my_way::tree::{
+        progress::get_my_way_progress,
+        types::{MyWayProgress, MyWayProgressQuery},
+    },
+    UseCaseError,
+};
+
+use crate::utils::{response_400, response_401, response_500, ProblemDetails};
+
+#[utoipa::path(
+    params(MyWayProgressQuery),
+    responses(
+        (status = 200, body = MyWayProgress),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+#[tracing::instrument(
+    name = "Rolling up a user's progress over the my_way tree",
+    skip(db, user)
+)]
+#[get("/progress")]
+pub async fn get_my_way_progress_endpoint(
+    db: Data<DbConn>,
+    user: Option<ReqData<user_entity::Model>>,
+    query: Query<MyWayProgressQuery>,
+) -> HttpResponse {
+    match user {
+        Some(user) => match get_my_way_progress(
+            user.into_inner(),
+            query.into_inner(),
+            AmbitionAdapter::init(&db),
+            DirectionAdapter::init(&db),
+            DirectionCategoryAdapter::init(&db),
+            ActionAdapter::init(&db),
+            ActionTrackAdapter::init(&db),
+            ActionGoalAdapter::init(&db),
+        )
+        .await
+        {
+            Ok(res) => HttpResponse::Ok().json(res),
+            Err(e) => match &e {
+                UseCaseError::BadRequest(code) => response_400(*code),
+                _ => response_500(e),
+            },
+        },
+        None => response_401(),
+    }
+}
@@ -0,0 +1,58 @@
+use actix_web::{
+    delete,
+    web::{Data, Path, ReqData},
+    HttpResponse,
+};
+use db_adapters::{action_adapter::ActionAdapter, direction_adapter::DirectionAdapter};
+use entities::user as user_entity;
+use sea_orm::DbConn;
+use use_cases::{my_way::directions::unlink_action::unlink_action, UseCaseError};
+
+use crate::utils::{response_401, response_404, response_500, ProblemDetails};
+
+#[derive(serde::Deserialize, Debug, serde::Serialize, utoipa::IntoParams)]
+struct PathParam {
+    direction_id: uuid::Uuid,
+    action_id: uuid::Uuid,
+}
+
+#[utoipa::path(
+    params(PathParam),
+    responses(
+        (status = 204),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+#[tracing::instrument(
+    name = "Unlinking an action from a direction",
+    skip(db, user, path_param)
+)]
+#[delete("/directions/{direction_id}/actions/{action_id}")]
+pub async fn unlink_direction_action_endpoint(
+    db: Data<DbConn>,
+    user: Option<ReqData<user_entity::Model>>,
+    path_param: Path<PathParam>,
+) -> HttpResponse {
+    match user {
+        Some(user) => {
+            match unlink_action(
+                user.into_inner(),
+                path_param.direction_id,
+                path_param.action_id,
+                DirectionAdapter::init(&db),
+                ActionAdapter::init(&db),
+            )
+            .await
+            {
+                Ok(_) => HttpResponse::NoContent().finish(),
+                Err(e) => match &e {
+                    UseCaseError::NotFound(code) => response_404(*code),
+                    _ => response_500(e),
+                },
+            }
+        }
+        None => response_401(),
+    }
+}
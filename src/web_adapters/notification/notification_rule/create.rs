@@ -13,10 +13,23 @@ use use_cases::{
     UseCaseError,
 };
 
-use crate::utils::{response_400, response_401, response_409, response_500};
+use crate::{
+    middlewares::idempotency::{Idempotency, IdempotencyKeyHeader},
+    utils::{response_400, response_401, response_409, response_500, ProblemDetails},
+};
 
+#[utoipa::path(
+    params(IdempotencyKeyHeader),
+    responses(
+        (status = 201, body = NotificationRuleCreateRequest),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Creating user's notification_rules.", skip(db, user))]
-#[post("")]
+#[post("", wrap = "Idempotency")]
 pub async fn create_notification_rules_endpoint(
     db: Data<DbConn>,
     user: Option<ReqData<user_entity::Model>>,
@@ -33,8 +46,8 @@ pub async fn create_notification_rules_endpoint(
             {
                 Ok(res) => HttpResponse::Created().json(res),
                 Err(e) => match &e {
-                    UseCaseError::BadRequest(message) => response_400(message),
-                    UseCaseError::Conflict(message) => response_409(message),
+                    UseCaseError::BadRequest(code) => response_400(*code),
+                    UseCaseError::Conflict(code) => response_409(*code),
                     _ => response_500(e),
                 },
             }
@@ -10,8 +10,16 @@ use use_cases::notification::notification_rule::{
     delete::delete_notification_rules, types::NotificationRuleDeleteQuery,
 };
 
-use crate::utils::{response_401, response_500};
+use crate::utils::{response_401, response_500, ProblemDetails};
 
+#[utoipa::path(
+    params(NotificationRuleDeleteQuery),
+    responses(
+        (status = 204),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Deleting user's notification_rules.", skip(db, user))]
 #[delete("")]
 pub async fn delete_notification_rules_endpoint(
@@ -6,10 +6,19 @@ use actix_web::{
 use db_adapters::notification_rule_adapter::NotificationRuleAdapter;
 use entities::user as user_entity;
 use sea_orm::DbConn;
-use use_cases::notification::notification_rule::list::list_notification_rules;
+use use_cases::notification::notification_rule::{
+    list::list_notification_rules, types::NotificationRuleVisible,
+};
 
-use crate::utils::{response_401, response_500};
+use crate::utils::{response_401, response_500, ProblemDetails};
 
+#[utoipa::path(
+    responses(
+        (status = 200, body = Vec<NotificationRuleVisible>),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Listing user's notification_rules.", skip(db, user))]
 #[get("")]
 pub async fn list_notification_rules_endpoint(
@@ -2,7 +2,7 @@ pub mod create;
 pub mod delete;
 pub mod list;
 
-use actix_web::web::{scope, ServiceConfig};
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
 
 pub fn notification_rule_routes(cfg: &mut ServiceConfig) {
     cfg.service(
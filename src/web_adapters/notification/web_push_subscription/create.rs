@@ -7,17 +7,34 @@ use common::settings::types::Settings;
 use db_adapters::web_push_subscription_adapter::WebPushSubscriptionAdapter;
 use entities::user as user_entity;
 use sea_orm::DbConn;
-use use_cases::notification::web_push_subscription::{
-    create::create_web_push_subscription, types::WebPushSubscriptionCreateRequest,
+use use_cases::{
+    notification::web_push_subscription::{
+        create::create_web_push_subscription,
+        types::{WebPushSubscriptionCreateRequest, WebPushSubscriptionVisible},
+    },
+    validation::Validate,
 };
 
-use crate::utils::{response_401, response_500};
+use crate::{
+    middlewares::idempotency::{Idempotency, IdempotencyKeyHeader},
+    utils::{response_401, response_500, response_validation_error, ProblemDetails},
+};
 
+#[utoipa::path(
+    tag = "web_push_subscriptions",
+    params(IdempotencyKeyHeader),
+    responses(
+        (status = 201, body = WebPushSubscriptionVisible),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(
     name = "Registering a web push subscription",
     skip(db, user, settings, req)
 )]
-#[post("")]
+#[post("", wrap = "Idempotency")]
 pub async fn create_web_push_subscription_endpoint(
     db: Data<DbConn>,
     settings: Data<Settings>,
@@ -26,6 +43,9 @@ pub async fn create_web_push_subscription_endpoint(
 ) -> HttpResponse {
     match user {
         Some(user) => {
+            if let Err(violations) = req.validate() {
+                return response_validation_error(violations);
+            }
             match create_web_push_subscription(
                 user.into_inner(),
                 &settings,
@@ -8,8 +8,16 @@ use entities::user as user_entity;
 use sea_orm::DbConn;
 use use_cases::notification::web_push_subscription::delete::delete_web_push_subscription;
 
-use crate::utils::{response_401, response_500};
+use crate::utils::{response_401, response_500, ProblemDetails};
 
+#[utoipa::path(
+    tag = "web_push_subscriptions",
+    responses(
+        (status = 204),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Deleting a user's web_push_subscription.", skip(db, user))]
 #[delete("")]
 pub async fn delete_web_push_subscription_endpoint(
@@ -6,10 +6,20 @@ use actix_web::{
 use db_adapters::web_push_subscription_adapter::WebPushSubscriptionAdapter;
 use entities::user as user_entity;
 use sea_orm::DbConn;
-use use_cases::notification::web_push_subscription::list::list_web_push_subscription;
+use use_cases::notification::web_push_subscription::{
+    list::list_web_push_subscription, types::WebPushSubscriptionVisible,
+};
 
-use crate::utils::{response_401, response_500};
+use crate::utils::{response_401, response_500, ProblemDetails};
 
+#[utoipa::path(
+    tag = "web_push_subscriptions",
+    responses(
+        (status = 200, body = Option<WebPushSubscriptionVisible>),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Listing a user's web_push_subscription.", skip(db, user))]
 #[get("")]
 pub async fn list_web_push_subscription_endpoint(
@@ -2,7 +2,7 @@ mod create;
 mod delete;
 mod list;
 
-use actix_web::web::{scope, ServiceConfig};
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
 
 pub fn web_push_subscription_routes(cfg: &mut ServiceConfig) {
     cfg.service(
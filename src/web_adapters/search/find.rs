@@ -0,0 +1,53 @@
+use actix_web::{
+    get,
+    web::{Data, Query, ReqData},
+    HttpResponse,
+};
+use db_adapters::search_adapter::SearchAdapter;
+use entities::user as user_entity;
+use sea_orm::DbConn;
+use use_cases::{
+    search::{
+        find::search_all,
+        types::{SearchParams, SearchResultsVisible},
+    },
+    UseCaseError,
+};
+
+use crate::utils::{response_400, response_401, response_500, ProblemDetails};
+
+#[utoipa::path(
+    params(SearchParams),
+    responses(
+        (status = 200, body = SearchResultsVisible),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+#[tracing::instrument(name = "Searching user's records", skip(db, user))]
+#[get("")]
+pub async fn search_endpoint(
+    db: Data<DbConn>,
+    user: Option<ReqData<user_entity::Model>>,
+    query: Query<SearchParams>,
+) -> HttpResponse {
+    match user {
+        Some(user) => {
+            match search_all(
+                user.into_inner(),
+                query.into_inner(),
+                SearchAdapter::init(&db),
+            )
+            .await
+            {
+                Ok(res) => HttpResponse::Ok().json(res),
+                Err(e) => match &e {
+                    UseCaseError::BadRequest(code) => response_400(*code),
+                    _ => response_500(e),
+                },
+            }
+        }
+        None => response_401(),
+    }
+}
@@ -0,0 +1,7 @@
+mod find;
+
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+pub fn search_routes(cfg: &mut ServiceConfig) {
+    cfg.service(scope("/search").service(find::search_endpoint));
+}
@@ -0,0 +1,76 @@
+use actix_web::{
+    get,
+    web::{Data, Query, ReqData},
+    HttpResponse,
+};
+use common::settings::types::Settings;
+use db_adapters::{
+    action_adapter::ActionAdapter, action_goal_adapter::ActionGoalAdapter,
+    action_track_adapter::ActionTrackAdapter, ambition_adapter::AmbitionAdapter,
+    diary_adapter::DiaryAdapter, direction_adapter::DirectionAdapter,
+    direction_category_adapter::DirectionCategoryAdapter, reading_note_adapter::ReadingNoteAdapter,
+    tag_adapter::TagAdapter, thinking_note_adapter::ThinkingNoteAdapter,
+    tombstone_adapter::TombstoneAdapter,
+};
+use entities::user as user_entity;
+use sea_orm::DbConn;
+use use_cases::{
+    sync::{
+        list::{list_changes, SyncAdapters},
+        types::{SyncChanges, SyncQuery},
+    },
+    UseCaseError,
+};
+
+use crate::utils::{response_400, response_401, response_500, ProblemDetails};
+
+#[utoipa::path(
+    params(SyncQuery),
+    responses(
+        (status = 200, body = SyncChanges),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+#[tracing::instrument(
+    name = "Listing changes since the last sync.",
+    skip(db, settings, user)
+)]
+#[get("")]
+pub async fn list_changes_endpoint(
+    db: Data<DbConn>,
+    settings: Data<Settings>,
+    user: Option<ReqData<user_entity::Model>>,
+    query: Query<SyncQuery>,
+) -> HttpResponse {
+    match user {
+        Some(user) => match list_changes(
+            user.into_inner(),
+            query.into_inner(),
+            &settings,
+            SyncAdapters {
+                ambition: AmbitionAdapter::init(&db),
+                direction: DirectionAdapter::init(&db),
+                direction_category: DirectionCategoryAdapter::init(&db),
+                action: ActionAdapter::init(&db),
+                action_goal: ActionGoalAdapter::init(&db),
+                action_track: ActionTrackAdapter::init(&db),
+                diary: DiaryAdapter::init(&db),
+                reading_note: ReadingNoteAdapter::init(&db),
+                thinking_note: ThinkingNoteAdapter::init(&db),
+                tag: TagAdapter::init(&db),
+                tombstone: TombstoneAdapter::init(&db),
+            },
+        )
+        .await
+        {
+            Ok(res) => HttpResponse::Ok().json(res),
+            Err(e) => match &e {
+                UseCaseError::BadRequest(code) => response_400(*code),
+                _ => response_500(e),
+            },
+        },
+        None => response_401(),
+    }
+}
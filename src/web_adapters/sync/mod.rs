@@ -0,0 +1,7 @@
+mod list;
+
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+pub fn sync_routes(cfg: &mut ServiceConfig) {
+    cfg.service(scope("/sync").service(list::list_changes_endpoint));
+}
@@ -8,13 +8,22 @@ use entities::user as user_entity;
 use sea_orm::DbConn;
 use use_cases::{tags::delete::delete_plain_tag, UseCaseError};
 
-use crate::utils::{response_400, response_401, response_500};
+use crate::utils::{response_400, response_401, response_500, ProblemDetails};
 
-#[derive(serde::Deserialize, Debug, serde::Serialize)]
+#[derive(serde::Deserialize, Debug, serde::Serialize, utoipa::IntoParams)]
 struct PathParam {
     tag_id: uuid::Uuid,
 }
 
+#[utoipa::path(
+    params(PathParam),
+    responses(
+        (status = 204),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Deleting a plain tag", skip(db, user))]
 #[delete("/plain/{tag_id}")]
 pub async fn delete_plain_tag_endpoint(
@@ -29,7 +38,7 @@ pub async fn delete_plain_tag_endpoint(
             {
                 Ok(_) => HttpResponse::NoContent().finish(),
                 Err(e) => match &e {
-                    UseCaseError::BadRequest(message) => response_400(message),
+                    UseCaseError::BadRequest(code) => response_400(*code),
                     _ => response_500(e),
                 },
             }
@@ -6,10 +6,17 @@ use actix_web::{
 use db_adapters::tag_adapter::TagAdapter;
 use entities::user as user_entity;
 use sea_orm::DbConn;
-use use_cases::tags::list::list_tags;
+use use_cases::tags::{list::list_tags, types::TagVisible};
 
-use crate::utils::{response_401, response_500};
+use crate::utils::{response_401, response_500, ProblemDetails};
 
+#[utoipa::path(
+    responses(
+        (status = 200, body = Vec<TagVisible>),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Listing a user's tags.", skip(db, user))]
 #[get("")]
 pub async fn list_tags_endpoint(
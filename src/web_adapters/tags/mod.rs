@@ -3,11 +3,15 @@ mod delete;
 mod list;
 mod update;
 
-use actix_web::web::{scope, ServiceConfig};
+use common::redis::change_events::ChangedResource;
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+use crate::middlewares::change_events::PublishChanges;
 
 pub fn tag_routes(cfg: &mut ServiceConfig) {
     cfg.service(
         scope("/tags")
+            .wrap(PublishChanges(ChangedResource::Tag))
             .service(create::create_plain_tag_endpoint)
             .service(delete::delete_plain_tag_endpoint)
             .service(list::list_tags_endpoint)
@@ -7,17 +7,34 @@ use db_adapters::tag_adapter::TagAdapter;
 use entities::user as user_entity;
 use sea_orm::DbConn;
 use use_cases::{
-    tags::{types::TagUpdateRequest, update::update_plain_tag},
+    tags::{
+        types::{TagUpdateRequest, TagVisible},
+        update::update_plain_tag,
+    },
+    validation::Validate,
     UseCaseError,
 };
 
-use crate::utils::{response_400, response_401, response_404, response_500};
+use crate::utils::{
+    response_400, response_401, response_404, response_500, response_validation_error,
+    ProblemDetails,
+};
 
-#[derive(serde::Deserialize, Debug, serde::Serialize)]
+#[derive(serde::Deserialize, Debug, serde::Serialize, utoipa::IntoParams)]
 struct PathParam {
     tag_id: uuid::Uuid,
 }
 
+#[utoipa::path(
+    params(PathParam),
+    responses(
+        (status = 200, body = TagVisible),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Updating a plain tag", skip(db, user))]
 #[put("/plain/{tag_id}")]
 pub async fn update_plain_tag_endpoint(
@@ -28,6 +45,9 @@ pub async fn update_plain_tag_endpoint(
 ) -> HttpResponse {
     match user {
         Some(user) => {
+            if let Err(violations) = req.validate() {
+                return response_validation_error(violations);
+            }
             match update_plain_tag(
                 user.into_inner(),
                 req.into_inner(),
@@ -38,8 +58,8 @@ pub async fn update_plain_tag_endpoint(
             {
                 Ok(res) => HttpResponse::Ok().json(res),
                 Err(e) => match &e {
-                    UseCaseError::BadRequest(message) => response_400(message),
-                    UseCaseError::NotFound(message) => response_404(message),
+                    UseCaseError::BadRequest(code) => response_400(*code),
+                    UseCaseError::NotFound(code) => response_404(*code),
                     _ => response_500(e),
                 },
             }
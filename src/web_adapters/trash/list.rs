@@ -0,0 +1,33 @@
+use actix_web::{
+    get,
+    web::{Data, ReqData},
+    HttpResponse,
+};
+use db_adapters::trash_adapter::TrashAdapter;
+use entities::user as user_entity;
+use sea_orm::DbConn;
+use use_cases::trash::{list::list_trash, types::TrashItemVisible};
+
+use crate::utils::{response_401, response_500, ProblemDetails};
+
+#[utoipa::path(
+    responses(
+        (status = 200, body = [TrashItemVisible]),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+#[tracing::instrument(name = "Listing the trash", skip(db, user))]
+#[get("")]
+pub async fn list_trash_endpoint(
+    db: Data<DbConn>,
+    user: Option<ReqData<user_entity::Model>>,
+) -> HttpResponse {
+    match user {
+        Some(user) => match list_trash(user.into_inner(), TrashAdapter::init(&db)).await {
+            Ok(res) => HttpResponse::Ok().json(res),
+            Err(e) => response_500(e),
+        },
+        None => response_401(),
+    }
+}
@@ -0,0 +1,12 @@
+mod list;
+mod restore;
+
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
+
+pub fn trash_routes(cfg: &mut ServiceConfig) {
+    cfg.service(
+        scope("/trash")
+            .service(list::list_trash_endpoint)
+            .service(restore::restore_from_trash_endpoint),
+    );
+}
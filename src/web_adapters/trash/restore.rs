@@ -0,0 +1,88 @@
+use actix_web::{
+    put,
+    web::{Data, Path, ReqData},
+    HttpResponse,
+};
+use common::redis::change_events::{publish, ChangeEvent, ChangeKind, ChangedResource};
+use db_adapters::trash_adapter::{TrashAdapter, TrashKind};
+use deadpool_redis::Pool;
+use entities::user as user_entity;
+use sea_orm::DbConn;
+use use_cases::{trash::restore::restore_from_trash, UseCaseError};
+
+use crate::utils::{response_401, response_404, response_500, ProblemDetails};
+
+#[derive(serde::Deserialize, Debug, serde::Serialize, utoipa::IntoParams)]
+struct PathParam {
+    kind: TrashKind,
+    id: uuid::Uuid,
+}
+
+#[utoipa::path(
+    params(PathParam),
+    responses(
+        (status = 204),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+#[tracing::instrument(name = "Restoring an item from the trash", skip(db, redis_pool, user))]
+#[put("/{kind}/{id}/restore")]
+pub async fn restore_from_trash_endpoint(
+    db: Data<DbConn>,
+    redis_pool: Data<Pool>,
+    user: Option<ReqData<user_entity::Model>>,
+    path_param: Path<PathParam>,
+) -> HttpResponse {
+    match user {
+        Some(user) => {
+            let user_id = user.id;
+            match restore_from_trash(
+                user.into_inner(),
+                path_param.kind,
+                path_param.id,
+                TrashAdapter::init(&db),
+            )
+            .await
+            {
+                Ok(_) => {
+                    publish_restoration(&redis_pool, user_id, path_param.kind, path_param.id).await;
+                    HttpResponse::NoContent().finish()
+                }
+                Err(e) => match &e {
+                    UseCaseError::NotFound(code) => response_404(*code),
+                    _ => response_500(e),
+                },
+            }
+        }
+        None => response_401(),
+    }
+}
+
+/// The restored item comes back to other tabs and devices as created. PublishChanges cannot be
+/// used since the resource depends on the path. Publishing failures are only logged.
+async fn publish_restoration(
+    redis_pool: &Pool,
+    user_id: uuid::Uuid,
+    kind: TrashKind,
+    id: uuid::Uuid,
+) {
+    let event = ChangeEvent {
+        resource: match kind {
+            TrashKind::Ambition => ChangedResource::Ambition,
+            TrashKind::Direction => ChangedResource::Direction,
+            TrashKind::Action => ChangedResource::Action,
+            TrashKind::Diary => ChangedResource::Diary,
+            TrashKind::ReadingNote => ChangedResource::ReadingNote,
+            TrashKind::ThinkingNote => ChangedResource::ThinkingNote,
+        },
+        kind: ChangeKind::Created,
+        id: Some(id),
+        data: None,
+    };
+    if let Err(e) = publish(redis_pool, user_id, &event).await {
+        tracing::event!(target: "redis", tracing::Level::WARN, "Error on publishing change event: {:?}", e);
+    }
+}
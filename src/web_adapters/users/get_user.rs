@@ -3,8 +3,14 @@ use actix_web::{get, web::ReqData, HttpResponse};
 use entities::user as user_entity;
 use use_cases::users::types::UserVisible;
 
-use crate::utils::response_401;
+use crate::utils::{response_401, ProblemDetails};
 
+#[utoipa::path(
+    responses(
+        (status = 200, body = UserVisible),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[get("/me")]
 pub async fn get_user(user: Option<ReqData<user_entity::Model>>) -> HttpResponse {
     match user {
@@ -1,33 +1,45 @@
 use actix_session::SessionInsertError;
 use actix_web::{
+    http::StatusCode,
     post,
     web::{Data, Json},
     HttpResponse,
 };
-use common::settings::types::Settings;
+use common::{password::verify_password, settings::types::Settings};
 use db_adapters::user_adapter::{UserAdapter, UserFilter, UserQuery};
 use deadpool_redis::{
     redis::{AsyncCommands, SetExpiry, SetOptions},
     Connection, Pool,
 };
 use sea_orm::DbConn;
-use use_cases::users::types::{LoginRequest, UserVisible};
+use use_cases::{
+    users::types::{LoginRequest, UserVisible},
+    ErrorCode,
+};
 
 use crate::{
     users::types::{USER_EMAIL_KEY, USER_ID_KEY},
-    utils::{auth::password::verify_password, response_404, response_500},
+    utils::{response_404, response_500, response_problem, ProblemDetails},
 };
 
+#[utoipa::path(
+    security(()),
+    responses(
+        (status = 200, body = UserVisible),
+        (status = 401, body = ProblemDetails, content_type = "application/problem+json", description = "Too many failed attempts"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Logging a user in", skip(db, redis_pool, req_user, session, settings), fields(user_email = &req_user.email))]
 #[post("/login")]
-async fn login_user(
+pub async fn login_user(
     db: Data<DbConn>,
     redis_pool: Data<Pool>,
     req_user: Json<LoginRequest>,
     session: actix_session::Session,
     settings: Data<Settings>,
 ) -> HttpResponse {
-    let not_found_message = "A user with these details does not exist. If you registered with these details, ensure you activate your account by clicking on the link sent to your e-mail address.";
     match redis_pool.get().await {
         Ok(ref mut redis_con) => {
             match validate_request_count(redis_con, &req_user.email, &settings).await {
@@ -71,7 +83,7 @@ async fn login_user(
                                             &settings,
                                         )
                                         .await;
-                                        response_404(not_found_message)
+                                        response_404(ErrorCode::LoginUserNotFound)
                                     }
                                 }
                             }
@@ -83,14 +95,13 @@ async fn login_user(
                                     &settings,
                                 )
                                 .await;
-                                response_404(not_found_message)
+                                response_404(ErrorCode::LoginUserNotFound)
                             }
                         },
                         Err(e) => response_500(e),
                     }
                 }
-                Err(_) => HttpResponse::Unauthorized()
-                    .json("Your account is temporarily locked. Please wait for 1 hour."),
+                Err(_) => response_problem(StatusCode::UNAUTHORIZED, ErrorCode::LoginLocked),
             }
         }
         Err(e) => response_500(e),
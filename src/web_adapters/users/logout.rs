@@ -1,5 +1,11 @@
 use actix_web::{post, HttpResponse};
 
+#[utoipa::path(
+    security(()),
+    responses(
+        (status = 200, body = String),
+    )
+)]
 #[tracing::instrument(name = "Log out user", skip(session))]
 #[post("/logout")]
 pub async fn log_out(session: actix_session::Session) -> HttpResponse {
@@ -1,8 +1,8 @@
-use actix_web::web::{scope, ServiceConfig};
 use password_change::{
     request_password_change, submit_password_change, verify_password_change_token,
 };
 use registration::{confirm_factory, register_factory, resend_email_factory};
+use utoipa_actix_web::{scope, service_config::ServiceConfig};
 
 mod get_user;
 mod login;
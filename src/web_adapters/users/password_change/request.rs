@@ -1,4 +1,5 @@
 use actix_web::{
+    post,
     web::{Data, Json},
     HttpResponse,
 };
@@ -6,16 +7,32 @@ use common::settings::types::Settings;
 use db_adapters::user_adapter::{UserAdapter, UserFilter, UserQuery};
 use deadpool_redis::Pool;
 use sea_orm::DbConn;
+use use_cases::ErrorCode;
 
-use crate::utils::{emails::send_multipart_email, response_404, response_500};
+use crate::{
+    middlewares::rate_limit::{RateLimitScope, RateLimiter},
+    utils::{emails::send_multipart_email, response_404, response_500, ProblemDetails},
+};
 
-#[derive(serde::Deserialize, Debug)]
+#[derive(serde::Deserialize, Debug, utoipa::ToSchema)]
 struct UserEmail {
     email: String,
 }
 
+#[utoipa::path(
+    security(()),
+    responses(
+        (status = 200, body = String),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 429, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Requesting a password change", skip(db, redis_pool, settings))]
-#[actix_web::post("/email-verification")]
+#[post(
+    "/email-verification",
+    wrap = "RateLimiter::new(RateLimitScope::PasswordChangeRequest)"
+)]
 pub async fn request_password_change(
     db: Data<DbConn>,
     redis_pool: Data<Pool>,
@@ -46,9 +63,9 @@ pub async fn request_password_change(
                 }
                 Err(e) => response_500(e),
             },
-            None => response_404("An active user with this email does not exist."),
+            None => response_404(ErrorCode::UserWithEmailNotFound),
         },
-        Err(_) => response_404("An active user with this email does not exist."),
+        Err(_) => response_404(ErrorCode::UserWithEmailNotFound),
     }
 }
 
@@ -3,22 +3,33 @@ use actix_web::{
     web::{Data, Json},
     HttpResponse,
 };
-use common::settings::types::Settings;
+use common::{password, settings::types::Settings};
 use db_adapters::user_adapter::{UserAdapter, UserMutation, UserQuery};
 use deadpool_redis::Pool;
 use sea_orm::DbConn;
+use use_cases::ErrorCode;
 
 use crate::utils::{
-    auth::{password, tokens::verify_confirmation_token_pasetor},
-    response_400, response_404, response_500,
+    auth::tokens::verify_confirmation_token_pasetor, response_400, response_404, response_500,
+    ProblemDetails,
 };
 
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+#[schema(as = PasswordChangeSubmitRequest)]
 struct Parameters {
     token: String,
     password: String,
 }
 
+#[utoipa::path(
+    security(()),
+    responses(
+        (status = 200, body = String),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Changing user's password", skip(db, redis_pool, req, settings))]
 #[post("")]
 pub async fn submit_password_change(
@@ -38,14 +49,17 @@ pub async fn submit_password_change(
             .await
             {
                 Ok(confirmation_token) => {
-                    let hashed_password = password::hash(req.password.as_bytes()).await;
+                    let hashed_password = match password::hash(req.password.as_bytes()) {
+                        Ok(hashed_password) => hashed_password,
+                        Err(e) => return response_500(e),
+                    };
                     let user = match UserAdapter::init(&db)
                         .get_by_id(confirmation_token.user_id)
                         .await
                     {
                         Ok(user) => match user {
                             Some(user) => user,
-                            None => return response_404("User not found"),
+                            None => return response_404(ErrorCode::UserNotFound),
                         },
                         Err(e) => return response_500(e),
                     };
@@ -56,9 +70,7 @@ pub async fn submit_password_change(
                         Err(e) => response_500(e)
                     }
                 }
-                Err(_) => response_400(
-                    "It appears that your password request token has expired or previously used",
-                ),
+                Err(_) => response_400(ErrorCode::InvalidToken),
             }
         }
         Err(e) => response_500(e),
@@ -11,11 +11,18 @@ use crate::utils::auth::tokens::{
     issue_confirmation_token_pasetors, verify_confirmation_token_pasetor,
 };
 
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, utoipa::IntoParams)]
 struct Parameters {
     token: String,
 }
 
+#[utoipa::path(
+    security(()),
+    params(Parameters),
+    responses(
+        (status = 303, description = "Redirects to the frontend with the result"),
+    )
+)]
 #[tracing::instrument(
     name = "Confirming change password token",
     skip(query, redis_pool, settings)
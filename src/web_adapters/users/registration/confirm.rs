@@ -1,6 +1,6 @@
 use actix_web::{
     get,
-    http::header,
+    http::{header, StatusCode},
     web::{Data, Query},
     HttpResponse,
 };
@@ -8,14 +8,25 @@ use common::settings::types::Settings;
 use db_adapters::user_adapter::{UserAdapter, UserMutation, UserQuery};
 use deadpool_redis::Pool;
 use sea_orm::DbConn;
+use use_cases::ErrorCode;
 
-use crate::{utils::auth::tokens::verify_confirmation_token_pasetor, utils::ErrorResponse};
+use crate::{
+    utils::auth::tokens::verify_confirmation_token_pasetor,
+    utils::{ProblemDetails, PROBLEM_JSON},
+};
 
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, utoipa::IntoParams)]
 pub struct Parameters {
     token: String,
 }
 
+#[utoipa::path(
+    security(()),
+    params(Parameters),
+    responses(
+        (status = 303, description = "Redirects to the frontend with the result"),
+    )
+)]
 #[tracing::instrument(
     name = "Activating a new user",
     skip(db, redis_pool, parameters, settings)
@@ -63,10 +74,11 @@ pub async fn confirm(
                                                 settings.application.frontend_url
                                             ),
                                         ))
-                                        .json(ErrorResponse {
-                                            error: "We cannot activate your account at the moment"
-                                                .to_string(),
-                                        })
+                                        .content_type(PROBLEM_JSON)
+                                        .json(ProblemDetails::new(
+                                            StatusCode::SEE_OTHER,
+                                            ErrorCode::UserActivationFailed,
+                                        ))
                                 }
                             },
                             None => HttpResponse::SeeOther()
@@ -77,10 +89,11 @@ pub async fn confirm(
                                         settings.application.frontend_url
                                     ),
                                 ))
-                                .json(ErrorResponse {
-                                    error: "We cannot activate your account at the moment"
-                                        .to_string(),
-                                }),
+                                .content_type(PROBLEM_JSON)
+                                .json(ProblemDetails::new(
+                                    StatusCode::SEE_OTHER,
+                                    ErrorCode::UserActivationFailed,
+                                )),
                         },
                         Err(e) => {
                             tracing::event!(target: "backend", tracing::Level::ERROR, "Cannot activate account: {}", e);
@@ -92,10 +105,11 @@ pub async fn confirm(
                                         settings.application.frontend_url
                                     ),
                                 ))
-                                .json(ErrorResponse {
-                                    error: "We cannot activate your account at the moment"
-                                        .to_string(),
-                                })
+                                .content_type(PROBLEM_JSON)
+                                .json(ProblemDetails::new(
+                                    StatusCode::SEE_OTHER,
+                                    ErrorCode::UserActivationFailed,
+                                ))
                         }
                     }
                 }
@@ -104,8 +118,16 @@ pub async fn confirm(
                     HttpResponse::SeeOther()
                         .insert_header((
                             header::LOCATION,
-                            format!("{}/auth/regenerate-token", settings.application.frontend_url),
-                        )).json(ErrorResponse {error: "It appears that your confirmation token has expired or previously used. Kindly generate a new token".to_string()})
+                            format!(
+                                "{}/auth/regenerate-token",
+                                settings.application.frontend_url
+                            ),
+                        ))
+                        .content_type(PROBLEM_JSON)
+                        .json(ProblemDetails::new(
+                            StatusCode::SEE_OTHER,
+                            ErrorCode::InvalidToken,
+                        ))
                 }
             }
         }
@@ -116,9 +138,11 @@ pub async fn confirm(
                     header::LOCATION,
                     format!("{}/auth/error", settings.application.frontend_url),
                 ))
-                .json(ErrorResponse {
-                    error: "We cannot activate your account at the moment".to_string(),
-                })
+                .content_type(PROBLEM_JSON)
+                .json(ProblemDetails::new(
+                    StatusCode::SEE_OTHER,
+                    ErrorCode::UserActivationFailed,
+                ))
         }
     }
 }
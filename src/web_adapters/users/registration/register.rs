@@ -3,20 +3,32 @@ use actix_web::{
     web::{Data, Json},
     HttpResponse,
 };
-use common::settings::types::Settings;
+use common::{password, settings::types::Settings};
 use db_adapters::user_adapter::{CreateUserParams, UserAdapter, UserMutation};
 use deadpool_redis::Pool;
 use sea_orm::DbConn;
 
-use crate::utils::{auth::password, emails::send_multipart_email, response_500};
+use crate::{
+    middlewares::rate_limit::{RateLimitScope, RateLimiter},
+    utils::{emails::send_multipart_email, response_500, ProblemDetails},
+};
 
-#[derive(serde::Deserialize, Debug, serde::Serialize)]
+#[derive(serde::Deserialize, Debug, serde::Serialize, utoipa::ToSchema)]
+#[schema(as = RegisterRequest)]
 struct RequestBody {
     email: String,
     password: String,
     first_name: String,
     last_name: String,
 }
+#[utoipa::path(
+    security(()),
+    responses(
+        (status = 200, body = String),
+        (status = 429, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(name = "Adding a new user",
 skip(db, redis_pool, new_user, settings),
 fields(
@@ -24,14 +36,17 @@ fields(
     new_user_first_name = %new_user.first_name,
     new_user_last_name = %new_user.last_name
 ))]
-#[post("")]
+#[post("", wrap = "RateLimiter::new(RateLimitScope::Registration)")]
 pub async fn register(
     db: Data<DbConn>,
     redis_pool: Data<Pool>,
     new_user: Json<RequestBody>,
     settings: Data<Settings>,
 ) -> HttpResponse {
-    let hashed_password = password::hash(new_user.0.password.as_bytes()).await;
+    let hashed_password = match password::hash(new_user.0.password.as_bytes()) {
+        Ok(hashed_password) => hashed_password,
+        Err(e) => return response_500(e),
+    };
 
     match UserAdapter::init(&db)
         .create(CreateUserParams {
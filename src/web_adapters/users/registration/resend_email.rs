@@ -7,51 +7,70 @@ use common::settings::types::Settings;
 use db_adapters::user_adapter::{UserAdapter, UserFilter, UserQuery};
 use deadpool_redis::Pool;
 use sea_orm::DbConn;
+use use_cases::ErrorCode;
 
-use crate::utils::{emails::send_multipart_email, response_404, response_500};
+use crate::{
+    middlewares::rate_limit::{RateLimitScope, RateLimiter},
+    utils::{emails::send_multipart_email, response_404, response_500, ProblemDetails},
+};
 
-#[derive(serde::Deserialize, Debug, serde::Serialize)]
+#[derive(serde::Deserialize, Debug, serde::Serialize, utoipa::ToSchema)]
+#[schema(as = ResendEmailRequest)]
 struct RequestBody {
     email: String,
 }
+#[utoipa::path(
+    security(()),
+    responses(
+        (status = 200, body = String),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 429, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
 #[tracing::instrument(
     name = "Resending registration confirmation email",
     skip(db, redis_pool, req, settings)
 )]
-#[post("/resend-email")]
+#[post(
+    "/resend-email",
+    wrap = "RateLimiter::new(RateLimitScope::ResendEmail)"
+)]
 pub async fn resend_email(
     db: Data<DbConn>,
     redis_pool: Data<Pool>,
     req: Json<RequestBody>,
     settings: Data<Settings>,
 ) -> HttpResponse {
-    match UserAdapter::init(&db).filter_eq_is_active(false).get_by_email(req.email.clone()).await {
+    match UserAdapter::init(&db)
+        .filter_eq_is_active(false)
+        .get_by_email(req.email.clone())
+        .await
+    {
         Ok(user) => match user {
-            Some(user) => {
-                match redis_pool.get().await {
-                    Ok(ref mut redis_con) => {
-                        send_multipart_email(
-                            "Let's get you verified".to_string(),
-                            user.id,
-                            user.email,
-                            user.first_name,
-                            user.last_name,
-                            "verification_email.html",
-                            redis_con,
-                            &settings,
-                        )
-                        .await
-                        .unwrap();
+            Some(user) => match redis_pool.get().await {
+                Ok(ref mut redis_con) => {
+                    send_multipart_email(
+                        "Let's get you verified".to_string(),
+                        user.id,
+                        user.email,
+                        user.first_name,
+                        user.last_name,
+                        "verification_email.html",
+                        redis_con,
+                        &settings,
+                    )
+                    .await
+                    .unwrap();
 
-                        tracing::event!(target: "backend", tracing::Level::INFO, "Verification email re-sent successfully.");
-                        HttpResponse::Ok().json("Account activation link has been sent to your email address. Kindly take action before its expiration")
-                    },
-                    Err(e) => response_500(e)
+                    tracing::event!(target: "backend", tracing::Level::INFO, "Verification email re-sent successfully.");
+                    HttpResponse::Ok().json("Account activation link has been sent to your email address. Kindly take action before its expiration")
                 }
+                Err(e) => response_500(e),
             },
-            None => response_404("User with this email was not found. This happens if you have already activated this user.")
+            None => response_404(ErrorCode::UserToResendEmailNotFound),
         },
-        Err(e) => response_500(e)
+        Err(e) => response_500(e),
     }
 }
 
@@ -3,5 +3,8 @@ pub struct ConfirmationToken {
     pub user_id: uuid::Uuid,
 }
 
+/// Name of the session cookie. CSRF checks only apply to requests carrying it.
+pub const SESSION_COOKIE_NAME: &str = "sessionId";
+
 pub const USER_ID_KEY: &str = "user_id";
 pub const USER_EMAIL_KEY: &str = "user_email";
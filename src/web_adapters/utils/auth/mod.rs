@@ -1,3 +1,2 @@
-pub mod password;
 pub mod session;
 pub mod tokens;
@@ -1,49 +1,182 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
 
-use actix_web::HttpResponse;
+use actix_web::{
+    error::{InternalError, JsonPayloadError, PathError, QueryPayloadError},
+    http::{
+        header::{HeaderValue, RETRY_AFTER},
+        StatusCode,
+    },
+    HttpRequest, HttpResponse,
+};
 use serde::{Deserialize, Serialize};
 use tracing::{event, Level};
+use use_cases::{pagination::Page, validation::FieldViolation, ErrorCode};
+use utoipa::ToSchema;
+
+use crate::middlewares::request_tracing::current_request_id;
 
 pub mod auth;
 pub mod emails;
 
-#[derive(Serialize, Deserialize)]
-pub struct ErrorResponse {
-    pub error: String,
+pub const PROBLEM_JSON: &str = "application/problem+json";
+pub const NEXT_CURSOR: &str = "x-next-cursor";
+
+/// Error body in RFC 7807 problem details, served as application/problem+json.
+/// Clients should match on `code`, see use_cases::ErrorCode for the list.
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct ProblemDetails {
+    /// Always "about:blank"; `code` tells the problems apart.
+    pub r#type: String,
+    pub title: String,
+    pub status: u16,
+    pub detail: String,
+    #[schema(example = "tag.not_found")]
+    pub code: String,
+    /// Fields of the request which caused the problem.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FieldError>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct FieldError {
+    pub field: String,
+    pub code: String,
+    pub detail: String,
+}
+
+impl ProblemDetails {
+    pub fn new(status: StatusCode, code: ErrorCode) -> Self {
+        Self {
+            r#type: "about:blank".to_string(),
+            title: status.canonical_reason().unwrap_or_default().to_string(),
+            status: status.as_u16(),
+            detail: code.message().to_string(),
+            code: code.as_str().to_string(),
+            errors: code
+                .field()
+                .map(|field| FieldError {
+                    field: field.to_string(),
+                    code: code.as_str().to_string(),
+                    detail: code.message().to_string(),
+                })
+                .into_iter()
+                .collect(),
+            request_id: current_request_id(),
+        }
+    }
+
+    pub fn from_violations(violations: Vec<FieldViolation>) -> Self {
+        let mut problem = Self::new(StatusCode::BAD_REQUEST, ErrorCode::ValidationFailed);
+        problem.errors = violations
+            .into_iter()
+            .map(|violation| FieldError {
+                field: violation.field.to_string(),
+                code: violation.code.as_str().to_string(),
+                detail: violation.detail,
+            })
+            .collect();
+        problem
+    }
+}
+
+pub fn response_problem(status: StatusCode, code: ErrorCode) -> HttpResponse {
+    HttpResponse::build(status)
+        .content_type(PROBLEM_JSON)
+        .json(ProblemDetails::new(status, code))
+}
+
+/// OK: items as the body, and the cursor of the next page in x-next-cursor if any
+pub fn response_page<T: Serialize>(page: Page<T>) -> HttpResponse {
+    let mut res = HttpResponse::Ok();
+    if let Some(next_cursor) = page.next_cursor {
+        res.insert_header((NEXT_CURSOR, next_cursor));
+    }
+    res.json(page.items)
 }
 
 ///Bad Request
-pub fn response_400(error_message: &str) -> HttpResponse {
-    HttpResponse::BadRequest().json(ErrorResponse {
-        error: error_message.to_string(),
-    })
+pub fn response_400(code: ErrorCode) -> HttpResponse {
+    response_problem(StatusCode::BAD_REQUEST, code)
+}
+
+/// Bad Request: lists every invalid field of the payload
+pub fn response_validation_error(violations: Vec<FieldViolation>) -> HttpResponse {
+    HttpResponse::BadRequest()
+        .content_type(PROBLEM_JSON)
+        .json(ProblemDetails::from_violations(violations))
+}
+
+/// Bad Request for payloads which cannot be extracted at all, e.g. a missing field.
+/// The extractor's message is kept in `detail` since it tells which part is wrong.
+fn response_extraction_error<E: std::fmt::Display>(code: ErrorCode, e: &E) -> HttpResponse {
+    let mut problem = ProblemDetails::new(StatusCode::BAD_REQUEST, code);
+    problem.detail = format!("{} {}", code.message(), e);
+    HttpResponse::BadRequest()
+        .content_type(PROBLEM_JSON)
+        .json(problem)
+}
+
+/// For JsonConfig::error_handler
+pub fn json_error_handler(e: JsonPayloadError, _req: &HttpRequest) -> actix_web::Error {
+    let res = match &e {
+        JsonPayloadError::OverflowKnownLength { .. } | JsonPayloadError::Overflow { .. } => {
+            response_problem(StatusCode::PAYLOAD_TOO_LARGE, ErrorCode::InvalidBody)
+        }
+        _ => response_extraction_error(ErrorCode::InvalidBody, &e),
+    };
+    InternalError::from_response(e, res).into()
+}
+
+/// For QueryConfig::error_handler
+pub fn query_error_handler(e: QueryPayloadError, _req: &HttpRequest) -> actix_web::Error {
+    let res = response_extraction_error(ErrorCode::InvalidQuery, &e);
+    InternalError::from_response(e, res).into()
+}
+
+/// For PathConfig::error_handler
+pub fn path_error_handler(e: PathError, _req: &HttpRequest) -> actix_web::Error {
+    let res = response_extraction_error(ErrorCode::InvalidPath, &e);
+    InternalError::from_response(e, res).into()
 }
 
 /// Unauthorized
 pub fn response_401() -> HttpResponse {
-    HttpResponse::Unauthorized().json(ErrorResponse {
-        error: "You are not logged in.".to_string(),
-    })
+    response_problem(StatusCode::UNAUTHORIZED, ErrorCode::NotLoggedIn)
+}
+
+/// Forbidden
+pub fn response_403(code: ErrorCode) -> HttpResponse {
+    response_problem(StatusCode::FORBIDDEN, code)
 }
 
 /// NotFound
-pub fn response_404(error_message: &str) -> HttpResponse {
-    HttpResponse::NotFound().json(ErrorResponse {
-        error: error_message.to_string(),
-    })
+pub fn response_404(code: ErrorCode) -> HttpResponse {
+    response_problem(StatusCode::NOT_FOUND, code)
 }
 
 /// Conflict
-pub fn response_409(error_message: &str) -> HttpResponse {
-    HttpResponse::Conflict().json(ErrorResponse {
-        error: error_message.to_string(),
-    })
+pub fn response_409(code: ErrorCode) -> HttpResponse {
+    response_problem(StatusCode::CONFLICT, code)
+}
+
+/// Unprocessable Content
+pub fn response_422(code: ErrorCode) -> HttpResponse {
+    response_problem(StatusCode::UNPROCESSABLE_ENTITY, code)
+}
+
+/// Too Many Requests: Retry-After is rounded up to whole seconds
+pub fn response_429(retry_after: Duration) -> HttpResponse {
+    let seconds = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+    let mut res = response_problem(StatusCode::TOO_MANY_REQUESTS, ErrorCode::RateLimited);
+    res.headers_mut()
+        .insert(RETRY_AFTER, HeaderValue::from(seconds.max(1)));
+    res
 }
 
 /// Internal Server Error: with logging
 pub fn response_500<T: Debug>(e: T) -> HttpResponse {
     event!(target: "backend", Level::ERROR, "{:?}", e);
-    HttpResponse::InternalServerError().json(ErrorResponse {
-        error: "Some unexpected error happened. Please try again later.".to_string(),
-    })
+    response_problem(StatusCode::INTERNAL_SERVER_ERROR, ErrorCode::InternalError)
 }
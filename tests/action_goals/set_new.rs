@@ -247,6 +247,9 @@ mod bad_request {
 
         let res = test::call_service(&app, req).await;
         assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+        let res: serde_json::Value = test::read_body_json(res).await;
+        assert_eq!(res["code"], "action_goal.duration_seconds_required");
+        assert_eq!(res["errors"][0]["field"], "duration_seconds");
 
         Ok(())
     }
@@ -152,3 +152,59 @@ async fn unauthorized_if_not_logged_in() -> Result<(), DbErr> {
 
     Ok(())
 }
+
+#[actix_web::test]
+async fn happy_path_paginated() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let action = factory::action(user.id).insert(&db).await?;
+    let started_at = Utc::now().trunc_subsecs(0);
+    let mut action_tracks = vec![];
+    // NOTE: Two tracks of different actions start at the same time.
+    for seconds in [0, 1, 1, 2, 3] {
+        let action = match action_tracks.len() {
+            2 => factory::action(user.id).insert(&db).await?,
+            _ => action.clone(),
+        };
+        let action_track = factory::action_track(user.id)
+            .started_at((started_at + TimeDelta::seconds(seconds)).into())
+            .action_id(action.id)
+            .insert(&db)
+            .await?;
+        action_tracks.push(action_track);
+    }
+    let mut expected = action_tracks
+        .into_iter()
+        .map(ActionTrackVisible::from)
+        .collect::<Vec<_>>();
+    expected.sort_by_key(|at| std::cmp::Reverse((at.started_at, at.id)));
+
+    let mut pages = vec![];
+    let mut cursor: Option<String> = None;
+    loop {
+        let uri = match &cursor {
+            Some(cursor) => format!("/api/action_tracks?limit=2&cursor={cursor}"),
+            None => "/api/action_tracks?limit=2".to_string(),
+        };
+        let req = test::TestRequest::get().uri(&uri).to_request();
+        req.extensions_mut().insert(user.clone());
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        let next_cursor = resp
+            .headers()
+            .get("x-next-cursor")
+            .map(|cursor| cursor.to_str().unwrap().to_string());
+        let page: Vec<ActionTrackVisible> = test::read_body_json(resp).await;
+        assert!(page.len() <= 2);
+        pages.push(page);
+        match next_cursor {
+            Some(next_cursor) => cursor = Some(next_cursor),
+            None => break,
+        }
+    }
+
+    assert_eq!(pages.len(), 3);
+    assert_eq!(pages.into_iter().flatten().collect::<Vec<_>>(), expected);
+
+    Ok(())
+}
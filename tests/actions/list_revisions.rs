@@ -0,0 +1,96 @@
+use actix_web::{http, test, HttpMessage};
+use sea_orm::{ActiveModelTrait, DbErr};
+use use_cases::my_way::actions::types::{ActionRevisionVisible, ActionUpdateRequest};
+
+use crate::utils::Connections;
+
+use super::super::utils::init_app;
+use common::factory::{self, *};
+
+#[actix_web::test]
+async fn happy_path() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let action = factory::action(user.id)
+        .name("first".to_string())
+        .insert(&db)
+        .await?;
+    let req = test::TestRequest::put()
+        .uri(&format!("/api/actions/{}", action.id))
+        .set_json(ActionUpdateRequest {
+            name: "second".to_string(),
+            discipline: Some("second discipline".to_string()),
+            memo: None,
+            color: None,
+        })
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/api/actions/{}", action.id))
+        .set_json(ActionUpdateRequest {
+            name: "third".to_string(),
+            discipline: Some("third discipline".to_string()),
+            memo: None,
+            color: None,
+        })
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/actions/{}/revisions", action.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+
+    let res: Vec<ActionRevisionVisible> = test::read_body_json(res).await;
+    assert_eq!(res.len(), 2);
+    assert_eq!(res[0].name, "second".to_string());
+    assert_eq!(res[0].discipline, Some("second discipline".to_string()));
+    assert_eq!(res[1].name, "first".to_string());
+    assert_eq!(res[1].discipline, action.discipline);
+    assert_eq!(res[1].updated_at, action.updated_at);
+    assert!(res[0].replaced_at >= res[1].replaced_at);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn not_found_if_other_users_action() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let other_user = factory::user().insert(&db).await?;
+    let action = factory::action(other_user.id).insert(&db).await?;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/actions/{}/revisions", action.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::NOT_FOUND);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn unauthorized_if_not_logged_in() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let action = factory::action(user.id).insert(&db).await?;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/actions/{}/revisions", action.id))
+        .to_request();
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
@@ -5,5 +5,7 @@ mod create;
 mod delete;
 mod get;
 mod list;
+mod list_revisions;
+mod restore_revision;
 mod unarchive;
 mod update;
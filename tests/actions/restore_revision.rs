@@ -0,0 +1,107 @@
+use actix_web::{http, test, HttpMessage};
+use sea_orm::{ActiveModelTrait, DbErr, EntityTrait};
+use use_cases::my_way::actions::types::{
+    ActionRevisionVisible, ActionUpdateRequest, ActionVisible,
+};
+
+use crate::utils::Connections;
+
+use super::super::utils::init_app;
+use common::factory::{self, *};
+use entities::action;
+
+#[actix_web::test]
+async fn happy_path() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let action = factory::action(user.id)
+        .name("first".to_string())
+        .insert(&db)
+        .await?;
+    let req = test::TestRequest::put()
+        .uri(&format!("/api/actions/{}", action.id))
+        .set_json(ActionUpdateRequest {
+            name: "second".to_string(),
+            discipline: Some("second discipline".to_string()),
+            memo: None,
+            color: None,
+        })
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/actions/{}/revisions", action.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let revisions: Vec<ActionRevisionVisible> = test::call_and_read_body_json(&app, req).await;
+
+    let req = test::TestRequest::put()
+        .uri(&format!(
+            "/api/actions/{}/revisions/{}/restore",
+            action.id, revisions[0].id
+        ))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+
+    let action_in_db = action::Entity::find_by_id(action.id)
+        .one(&db)
+        .await?
+        .unwrap();
+    assert_eq!(action_in_db.name, "first".to_string());
+    assert_eq!(action_in_db.discipline, action.discipline);
+
+    let res: ActionVisible = test::read_body_json(res).await;
+    assert_eq!(ActionVisible::from(action_in_db), res);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/actions/{}/revisions", action.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let revisions: Vec<ActionRevisionVisible> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(revisions.len(), 2);
+    assert_eq!(revisions[0].name, "second".to_string());
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn not_found_if_revision_of_other_action() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let action = factory::action(user.id).insert(&db).await?;
+    let other_action = factory::action(user.id).insert(&db).await?;
+    let req = test::TestRequest::put()
+        .uri(&format!("/api/actions/{}", other_action.id))
+        .set_json(ActionUpdateRequest {
+            name: "second".to_string(),
+            discipline: Some("second discipline".to_string()),
+            memo: None,
+            color: None,
+        })
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/actions/{}/revisions", other_action.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let revisions: Vec<ActionRevisionVisible> = test::call_and_read_body_json(&app, req).await;
+
+    let req = test::TestRequest::put()
+        .uri(&format!(
+            "/api/actions/{}/revisions/{}/restore",
+            action.id, revisions[0].id
+        ))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::NOT_FOUND);
+
+    Ok(())
+}
@@ -174,4 +174,43 @@ mod validation_errors {
 
         Ok(())
     }
+
+    #[actix_web::test]
+    async fn every_invalid_field_is_listed() -> Result<(), DbErr> {
+        let Connections { app, db, .. } = init_app().await?;
+        let user = factory::user().insert(&db).await?;
+        let action = factory::action(user.id).insert(&db).await?;
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/actions/{}", action.id))
+            .set_json(ActionUpdateRequest {
+                name: " ".to_string(),
+                discipline: None,
+                memo: Some("a".repeat(5_001)),
+                color: Some("#gggggg".to_string()),
+            })
+            .to_request();
+        req.extensions_mut().insert(user.clone());
+
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+        let res: serde_json::Value = test::read_body_json(res).await;
+        assert_eq!(res["code"], "validation.failed");
+        assert_eq!(
+            res["errors"],
+            serde_json::json!([
+                {"field": "name", "code": "validation.blank", "detail": "must not be blank."},
+                {"field": "memo", "code": "validation.too_long", "detail": "must be at most 5000 characters long."},
+                {"field": "color", "code": "validation.not_hex_color", "detail": "must be a hex color code like #212121."},
+            ])
+        );
+
+        let action_in_db = action::Entity::find_by_id(action.id)
+            .one(&db)
+            .await?
+            .unwrap();
+        assert_eq!(action_in_db.name, action.name);
+
+        Ok(())
+    }
 }
@@ -22,10 +22,10 @@ async fn happy_path() -> Result<(), DbErr> {
     assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
 
     let ambition_in_db = ambition::Entity::find_by_id(ambition.id).one(&db).await?;
-    assert!(ambition_in_db.is_none());
+    assert!(ambition_in_db.unwrap().deleted_at.is_some());
 
     let tag_in_db = tag::Entity::find_by_id(tag.id).one(&db).await?;
-    assert!(tag_in_db.is_none());
+    assert!(tag_in_db.is_some());
 
     Ok(())
 }
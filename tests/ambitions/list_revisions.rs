@@ -0,0 +1,92 @@
+use actix_web::{http, test, HttpMessage};
+use sea_orm::{ActiveModelTrait, DbErr};
+use use_cases::my_way::ambitions::types::{AmbitionRevisionVisible, AmbitionUpdateRequest};
+
+use crate::utils::Connections;
+
+use super::super::utils::init_app;
+use common::factory::{self, *};
+
+#[actix_web::test]
+async fn happy_path() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let ambition = factory::ambition(user.id)
+        .name("first".to_string())
+        .insert(&db)
+        .await?;
+    let req = test::TestRequest::put()
+        .uri(&format!("/api/ambitions/{}", ambition.id))
+        .set_json(AmbitionUpdateRequest {
+            name: "second".to_string(),
+            description: Some("second description".to_string()),
+        })
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/api/ambitions/{}", ambition.id))
+        .set_json(AmbitionUpdateRequest {
+            name: "third".to_string(),
+            description: Some("third description".to_string()),
+        })
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/ambitions/{}/revisions", ambition.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+
+    let res: Vec<AmbitionRevisionVisible> = test::read_body_json(res).await;
+    assert_eq!(res.len(), 2);
+    assert_eq!(res[0].name, "second".to_string());
+    assert_eq!(res[0].description, Some("second description".to_string()));
+    assert_eq!(res[1].name, "first".to_string());
+    assert_eq!(res[1].description, ambition.description);
+    assert_eq!(res[1].updated_at, ambition.updated_at);
+    assert!(res[0].replaced_at >= res[1].replaced_at);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn not_found_if_other_users_ambition() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let other_user = factory::user().insert(&db).await?;
+    let ambition = factory::ambition(other_user.id).insert(&db).await?;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/ambitions/{}/revisions", ambition.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::NOT_FOUND);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn unauthorized_if_not_logged_in() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let ambition = factory::ambition(user.id).insert(&db).await?;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/ambitions/{}/revisions", ambition.id))
+        .to_request();
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
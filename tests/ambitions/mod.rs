@@ -4,5 +4,7 @@ mod create;
 mod delete;
 mod get;
 mod list;
+mod list_revisions;
+mod restore_revision;
 mod unarchive;
 mod update;
\ No newline at end of file
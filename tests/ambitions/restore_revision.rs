@@ -0,0 +1,103 @@
+use actix_web::{http, test, HttpMessage};
+use sea_orm::{ActiveModelTrait, DbErr, EntityTrait};
+use use_cases::my_way::ambitions::types::{
+    AmbitionRevisionVisible, AmbitionUpdateRequest, AmbitionVisible,
+};
+
+use crate::utils::Connections;
+
+use super::super::utils::init_app;
+use common::factory::{self, *};
+use entities::ambition;
+
+#[actix_web::test]
+async fn happy_path() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let ambition = factory::ambition(user.id)
+        .name("first".to_string())
+        .insert(&db)
+        .await?;
+    let req = test::TestRequest::put()
+        .uri(&format!("/api/ambitions/{}", ambition.id))
+        .set_json(AmbitionUpdateRequest {
+            name: "second".to_string(),
+            description: Some("second description".to_string()),
+        })
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/ambitions/{}/revisions", ambition.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let revisions: Vec<AmbitionRevisionVisible> = test::call_and_read_body_json(&app, req).await;
+
+    let req = test::TestRequest::put()
+        .uri(&format!(
+            "/api/ambitions/{}/revisions/{}/restore",
+            ambition.id, revisions[0].id
+        ))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+
+    let ambition_in_db = ambition::Entity::find_by_id(ambition.id)
+        .one(&db)
+        .await?
+        .unwrap();
+    assert_eq!(ambition_in_db.name, "first".to_string());
+    assert_eq!(ambition_in_db.description, ambition.description);
+
+    let res: AmbitionVisible = test::read_body_json(res).await;
+    assert_eq!(AmbitionVisible::from(ambition_in_db), res);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/ambitions/{}/revisions", ambition.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let revisions: Vec<AmbitionRevisionVisible> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(revisions.len(), 2);
+    assert_eq!(revisions[0].name, "second".to_string());
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn not_found_if_revision_of_other_ambition() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let ambition = factory::ambition(user.id).insert(&db).await?;
+    let other_ambition = factory::ambition(user.id).insert(&db).await?;
+    let req = test::TestRequest::put()
+        .uri(&format!("/api/ambitions/{}", other_ambition.id))
+        .set_json(AmbitionUpdateRequest {
+            name: "second".to_string(),
+            description: Some("second description".to_string()),
+        })
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/ambitions/{}/revisions", other_ambition.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let revisions: Vec<AmbitionRevisionVisible> = test::call_and_read_body_json(&app, req).await;
+
+    let req = test::TestRequest::put()
+        .uri(&format!(
+            "/api/ambitions/{}/revisions/{}/restore",
+            ambition.id, revisions[0].id
+        ))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::NOT_FOUND);
+
+    Ok(())
+}
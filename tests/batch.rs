@@ -0,0 +1,263 @@
+use std::{future::poll_fn, pin::Pin, time::Duration};
+
+use actix_web::{body::MessageBody, http, test, HttpMessage};
+use sea_orm::{ActiveModelTrait, ColumnTrait, DbErr, EntityTrait, QueryFilter};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use crate::utils::{init_app, Connections};
+use common::factory;
+use entities::{action, diaries_tags, diary, tag};
+
+#[actix_web::test]
+async fn happy_path_refers_to_earlier_outputs() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let action = factory::action(user.id).insert(&db).await?;
+
+    let req = test::TestRequest::post()
+        .uri("/api/batch")
+        .set_json(json!({
+            "operations": [
+                {"name": "new_tag", "op": "create_tag", "body": {"name": "Batch"}},
+                {
+                    "name": "new_diary",
+                    "op": "create_diary",
+                    "body": {
+                        "text": "Created in a batch.",
+                        "date": "2026-10-19",
+                        "tag_ids": [{"$ref": "new_tag.id"}],
+                    },
+                },
+                {"op": "archive_action", "id": action.id},
+            ],
+        }))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+    let res: Value = test::read_body_json(res).await;
+    assert_eq!(res["committed"], true);
+    let statuses: Vec<u64> = res["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|result| result["status"].as_u64().unwrap())
+        .collect();
+    assert_eq!(statuses, vec![201, 201, 200]);
+
+    let tag_id: Uuid = serde_json::from_value(res["results"][0]["body"]["id"].clone()).unwrap();
+    let diary_id: Uuid = serde_json::from_value(res["results"][1]["body"]["id"].clone()).unwrap();
+    let link_in_db = diaries_tags::Entity::find()
+        .filter(diaries_tags::Column::DiaryId.eq(diary_id))
+        .filter(diaries_tags::Column::TagId.eq(tag_id))
+        .one(&db)
+        .await?;
+    assert!(link_in_db.is_some());
+    let action_in_db = action::Entity::find_by_id(action.id)
+        .one(&db)
+        .await?
+        .unwrap();
+    assert!(action_in_db.archived);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn happy_path_publishes_change_events_of_committed_operations() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let action = factory::action(user.id).insert(&db).await?;
+
+    let req = test::TestRequest::get().uri("/api/events").to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+    let mut events = res.into_body();
+
+    // A rolled back batch publishes nothing, so the first events are those of the next batch.
+    for operations in [
+        json!([
+            {"op": "create_tag", "body": {"name": "Rolled back"}},
+            {"op": "archive_action", "id": Uuid::now_v7()},
+        ]),
+        json!([
+            {"op": "create_tag", "body": {"name": "Committed"}},
+            {"op": "archive_action", "id": action.id},
+        ]),
+    ] {
+        let req = test::TestRequest::post()
+            .uri("/api/batch")
+            .set_json(json!({"all_or_nothing": true, "operations": operations}))
+            .to_request();
+        req.extensions_mut().insert(user.clone());
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), http::StatusCode::OK);
+    }
+
+    let mut chunks = vec![];
+    while chunks.len() < 2 {
+        let chunk = tokio::time::timeout(
+            Duration::from_secs(5),
+            poll_fn(|cx| Pin::new(&mut events).poll_next(cx)),
+        )
+        .await
+        .expect("No event was streamed.")
+        .unwrap()
+        .unwrap();
+        chunks.extend(
+            String::from_utf8(chunk.to_vec())
+                .unwrap()
+                .split_terminator("\n\n")
+                .map(|event| event.to_string()),
+        );
+    }
+    assert!(chunks[0].starts_with("event: tag.created\ndata: "));
+    assert!(chunks[0].contains("Committed"));
+    assert!(chunks[1].starts_with("event: action.updated\ndata: "));
+    assert!(chunks[1].contains(&action.id.to_string()));
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn all_or_nothing_rolls_back_everything_on_failure() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+
+    let req = test::TestRequest::post()
+        .uri("/api/batch")
+        .set_json(json!({
+            "all_or_nothing": true,
+            "operations": [
+                {"op": "create_tag", "body": {"name": "Rolled back"}},
+                {"op": "archive_action", "id": Uuid::now_v7()},
+                {"op": "create_tag", "body": {"name": "Skipped"}},
+            ],
+        }))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+    let res: Value = test::read_body_json(res).await;
+    assert_eq!(res["committed"], false);
+    assert_eq!(res["results"][0]["status"], 201);
+    assert_eq!(res["results"][1]["status"], 404);
+    assert_eq!(res["results"][1]["body"]["code"], "action.not_found");
+    assert_eq!(res["results"][2]["status"], 424);
+    assert_eq!(res["results"][2]["body"]["code"], "batch.skipped");
+
+    let tags_in_db = tag::Entity::find()
+        .filter(tag::Column::UserId.eq(user.id))
+        .all(&db)
+        .await?;
+    assert!(tags_in_db.is_empty());
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn without_all_or_nothing_only_failed_operations_are_rolled_back() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let diary = factory::diary(user.id).insert(&db).await?;
+
+    let req = test::TestRequest::post()
+        .uri("/api/batch")
+        .set_json(json!({
+            "operations": [
+                {"op": "delete_diary", "id": diary.id},
+                {"op": "create_tag", "body": {"name": " "}},
+                {"op": "create_tag", "body": {"name": "Committed"}},
+            ],
+        }))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+    let res: Value = test::read_body_json(res).await;
+    assert_eq!(res["committed"], true);
+    assert_eq!(res["results"][0]["status"], 204);
+    assert_eq!(res["results"][1]["status"], 400);
+    assert_eq!(res["results"][1]["body"]["errors"][0]["field"], "name");
+    assert_eq!(res["results"][2]["status"], 201);
+
+    let diary_in_db = diary::Entity::find_by_id(diary.id).one(&db).await?;
+    assert!(diary_in_db.unwrap().deleted_at.is_some());
+    let tags_in_db = tag::Entity::find()
+        .filter(tag::Column::UserId.eq(user.id))
+        .all(&db)
+        .await?;
+    assert_eq!(tags_in_db.len(), 1);
+    assert_eq!(tags_in_db[0].name, Some("Committed".to_string()));
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn bad_request_result_on_reference_to_failed_operation() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+
+    let req = test::TestRequest::post()
+        .uri("/api/batch")
+        .set_json(json!({
+            "operations": [
+                {"name": "missing", "op": "archive_action", "id": Uuid::now_v7()},
+                {"op": "unarchive_action", "id": {"$ref": "missing.id"}},
+            ],
+        }))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+    let res: Value = test::read_body_json(res).await;
+    assert_eq!(res["results"][0]["status"], 404);
+    assert_eq!(res["results"][1]["status"], 400);
+    assert_eq!(res["results"][1]["body"]["code"], "batch.invalid_reference");
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn bad_request_on_duplicate_names() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+
+    let req = test::TestRequest::post()
+        .uri("/api/batch")
+        .set_json(json!({
+            "operations": [
+                {"name": "tag", "op": "create_tag", "body": {"name": "First"}},
+                {"name": "tag", "op": "create_tag", "body": {"name": "Second"}},
+            ],
+        }))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+    let res: Value = test::read_body_json(res).await;
+    assert_eq!(res["code"], "batch.duplicate_name");
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn unauthorized_if_not_logged_in() -> Result<(), DbErr> {
+    let Connections { app, .. } = init_app().await?;
+
+    let req = test::TestRequest::post()
+        .uri("/api/batch")
+        .set_json(json!({"operations": []}))
+        .to_request();
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
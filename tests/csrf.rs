@@ -0,0 +1,71 @@
+use actix_web::{cookie::Cookie, http, test};
+use sea_orm::{ActiveModelTrait, DbErr};
+use use_cases::users::types::LoginRequest;
+
+use crate::utils::{init_app, Connections};
+use common::factory::{self, *};
+
+#[actix_web::test]
+async fn cross_site_mutation_with_session_cookie_is_rejected() -> Result<(), DbErr> {
+    let Connections {
+        app, db, settings, ..
+    } = init_app().await?;
+    let hashed_password = "$argon2id$v=19$m=19456,t=2,p=1$r07vWFCaKrbNPrSgUrG/+Q$/2lBaeRWeox6ROMu6qAwOYmttdGXA3o4Uw2YHC/fvfY";
+    let user = factory::user()
+        .password(hashed_password)
+        .insert(&db)
+        .await?;
+
+    let login_req = test::TestRequest::post()
+        .uri("/api/users/login")
+        .set_json(LoginRequest {
+            email: user.email.to_string(),
+            password: "password".to_string(),
+        })
+        .to_request();
+    let res = test::call_service(&app, login_req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+    let session_set_cookie = res
+        .headers()
+        .get_all("set-cookie")
+        .find(|sc| sc.to_str().unwrap().starts_with("sessionId="))
+        .unwrap();
+    let session_cookie =
+        Cookie::parse(urlencoding::decode(session_set_cookie.to_str().unwrap()).unwrap())
+            .unwrap()
+            .into_owned();
+
+    for req in [
+        test::TestRequest::post()
+            .uri("/api/ambitions")
+            .cookie(session_cookie.clone())
+            .insert_header((http::header::ORIGIN, "https://evil.example.com")),
+        test::TestRequest::post()
+            .uri("/api/ambitions")
+            .cookie(session_cookie.clone())
+            .insert_header((http::header::REFERER, "https://evil.example.com/")),
+        test::TestRequest::post()
+            .uri("/api/ambitions")
+            .cookie(session_cookie.clone()),
+    ] {
+        let req = req
+            .set_json(serde_json::json!({"name": "ambition", "description": null}))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), http::StatusCode::FORBIDDEN);
+    }
+
+    let req = test::TestRequest::post()
+        .uri("/api/ambitions")
+        .cookie(session_cookie.clone())
+        .insert_header((
+            http::header::ORIGIN,
+            settings.application.frontend_url.as_str(),
+        ))
+        .set_json(serde_json::json!({"name": "ambition", "description": null}))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::CREATED);
+
+    Ok(())
+}
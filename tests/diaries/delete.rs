@@ -24,14 +24,14 @@ async fn happy_path() -> Result<(), DbErr> {
     assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
 
     let diary_in_db = diary::Entity::find_by_id(diary.id).one(&db).await?;
-    assert!(diary_in_db.is_none());
+    assert!(diary_in_db.unwrap().deleted_at.is_some());
 
     let diaries_tags_in_db = diaries_tags::Entity::find()
         .filter(diaries_tags::Column::DiaryId.eq(diary.id))
         .filter(diaries_tags::Column::TagId.eq(tag.id))
         .one(&db)
         .await?;
-    assert!(diaries_tags_in_db.is_none());
+    assert!(diaries_tags_in_db.is_some());
 
     Ok(())
 }
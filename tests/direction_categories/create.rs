@@ -23,7 +23,10 @@ async fn happy_path() -> Result<(), DbErr> {
 
     let req = test::TestRequest::post()
         .uri("/api/direction_categories")
-        .set_json(DirectionCategoryCreateRequest { name: name.clone() })
+        .set_json(DirectionCategoryCreateRequest {
+            name: name.clone(),
+            parent_id: None,
+        })
         .to_request();
     req.extensions_mut().insert(user.clone());
 
@@ -43,3 +46,92 @@ async fn happy_path() -> Result<(), DbErr> {
 
     Ok(())
 }
+
+#[actix_web::test]
+async fn happy_path_with_parent() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let parent = factory::direction_category(user.id)
+        .ordering(Some(1))
+        .insert(&db)
+        .await?;
+    let _sibling = factory::direction_category(user.id)
+        .ordering(Some(1))
+        .parent_id(Some(parent.id))
+        .insert(&db)
+        .await?;
+
+    let req = test::TestRequest::post()
+        .uri("/api/direction_categories")
+        .set_json(DirectionCategoryCreateRequest {
+            name: "child".to_string(),
+            parent_id: Some(parent.id),
+        })
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::CREATED);
+
+    let res: DirectionCategoryVisible = test::read_body_json(res).await;
+    assert_eq!(res.parent_id, Some(parent.id));
+
+    let category_in_db = direction_category::Entity::find_by_id(res.id)
+        .one(&db)
+        .await?
+        .unwrap();
+    // Ordering is within the parent.
+    assert_eq!(category_in_db.ordering, Some(2));
+
+    Ok(())
+}
+
+mod not_found {
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[actix_web::test]
+    async fn parent_of_other_user() -> Result<(), DbErr> {
+        let Connections { app, db, .. } = init_app().await?;
+        let user = factory::user().insert(&db).await?;
+        let other_user = factory::user().insert(&db).await?;
+        let other_users_category = factory::direction_category(other_user.id)
+            .insert(&db)
+            .await?;
+
+        let req = test::TestRequest::post()
+            .uri("/api/direction_categories")
+            .set_json(DirectionCategoryCreateRequest {
+                name: "child".to_string(),
+                parent_id: Some(other_users_category.id),
+            })
+            .to_request();
+        req.extensions_mut().insert(user.clone());
+
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), http::StatusCode::NOT_FOUND);
+
+        Ok(())
+    }
+
+    #[actix_web::test]
+    async fn non_existent_parent() -> Result<(), DbErr> {
+        let Connections { app, db, .. } = init_app().await?;
+        let user = factory::user().insert(&db).await?;
+
+        let req = test::TestRequest::post()
+            .uri("/api/direction_categories")
+            .set_json(DirectionCategoryCreateRequest {
+                name: "child".to_string(),
+                parent_id: Some(Uuid::now_v7()),
+            })
+            .to_request();
+        req.extensions_mut().insert(user.clone());
+
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), http::StatusCode::NOT_FOUND);
+
+        Ok(())
+    }
+}
@@ -5,7 +5,7 @@ use uuid::Uuid;
 use crate::utils::Connections;
 
 use super::super::utils::init_app;
-use common::factory;
+use common::factory::{self, *};
 use entities::direction_category;
 
 #[actix_web::test]
@@ -30,6 +30,38 @@ async fn happy_path() -> Result<(), DbErr> {
     Ok(())
 }
 
+#[actix_web::test]
+async fn children_move_up_to_the_parent() -> Result<(), DbErr> {
+    let Connections { app, db, ..} = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let parent = factory::direction_category(user.id).insert(&db).await?;
+    let category = factory::direction_category(user.id)
+        .parent_id(Some(parent.id))
+        .insert(&db)
+        .await?;
+    let child = factory::direction_category(user.id)
+        .parent_id(Some(category.id))
+        .insert(&db)
+        .await?;
+
+    let req = test::TestRequest::delete()
+        .uri(&format!("/api/direction_categories/{}", category.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::NO_CONTENT);
+
+    let child_in_db = direction_category::Entity::find_by_id(child.id)
+        .one(&db)
+        .await?
+        .unwrap();
+    assert_eq!(child_in_db.parent_id, Some(parent.id));
+    assert!(child_in_db.updated_at > child.updated_at);
+
+    Ok(())
+}
+
 #[actix_web::test]
 async fn do_nothing_for_other_user_category() -> Result<(), DbErr> {
     let Connections { app, db, ..} = init_app().await?;
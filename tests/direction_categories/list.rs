@@ -1,6 +1,8 @@
 use actix_web::{http, test, HttpMessage};
 use sea_orm::{ActiveModelTrait, DbErr};
-use use_cases::my_way::direction_categories::types::DirectionCategoryVisible;
+use use_cases::my_way::direction_categories::types::{
+    DirectionCategoryNode, DirectionCategoryVisible,
+};
 
 use crate::utils::Connections;
 
@@ -32,11 +34,74 @@ async fn happy_path() -> Result<(), DbErr> {
     let resp = test::call_service(&app, req).await;
     assert_eq!(resp.status(), http::StatusCode::OK);
 
-    let res: Vec<DirectionCategoryVisible> = test::read_body_json(resp).await;
+    let res: Vec<DirectionCategoryNode> = test::read_body_json(resp).await;
+    let expected = [category_1, category_0, category_2]
+        .into_iter()
+        .map(|category| DirectionCategoryNode {
+            category: DirectionCategoryVisible::from(category),
+            children: vec![],
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(res, expected);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn happy_path_nested() -> Result<(), DbErr> {
+    let Connections { app, db, ..} = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let health = factory::direction_category(user.id)
+        .ordering(Some(1))
+        .insert(&db)
+        .await?;
+    let career = factory::direction_category(user.id)
+        .ordering(Some(2))
+        .insert(&db)
+        .await?;
+    // Ordering is within the parent, so children share numbers with top-level categories.
+    let exercise = factory::direction_category(user.id)
+        .ordering(Some(2))
+        .parent_id(Some(health.id))
+        .insert(&db)
+        .await?;
+    let sleep = factory::direction_category(user.id)
+        .ordering(Some(1))
+        .parent_id(Some(health.id))
+        .insert(&db)
+        .await?;
+    let naps = factory::direction_category(user.id)
+        .ordering(Some(1))
+        .parent_id(Some(sleep.id))
+        .insert(&db)
+        .await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/direction_categories")
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), http::StatusCode::OK);
+
+    let res: Vec<DirectionCategoryNode> = test::read_body_json(resp).await;
+    let leaf = |category| DirectionCategoryNode {
+        category: DirectionCategoryVisible::from(category),
+        children: vec![],
+    };
     let expected = vec![
-        DirectionCategoryVisible::from(category_1),
-        DirectionCategoryVisible::from(category_0),
-        DirectionCategoryVisible::from(category_2),
+        DirectionCategoryNode {
+            category: DirectionCategoryVisible::from(health),
+            children: vec![
+                DirectionCategoryNode {
+                    category: DirectionCategoryVisible::from(sleep),
+                    children: vec![leaf(naps)],
+                },
+                leaf(exercise),
+            ],
+        },
+        leaf(career),
     ];
 
     assert_eq!(res, expected);
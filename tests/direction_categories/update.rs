@@ -8,7 +8,7 @@ use uuid::Uuid;
 use crate::utils::Connections;
 
 use super::super::utils::init_app;
-use common::factory;
+use common::factory::{self, *};
 use entities::direction_category;
 
 #[actix_web::test]
@@ -23,6 +23,7 @@ async fn happy_path() -> Result<(), DbErr> {
         .uri(&format!("/api/direction_categories/{}", category.id))
         .set_json(DirectionCategoryUpdateRequest {
             name: new_name.clone(),
+            parent_id: None,
         })
         .to_request();
     req.extensions_mut().insert(user.clone());
@@ -53,6 +54,7 @@ async fn unauthorized_if_not_logged_in() -> Result<(), DbErr> {
         .uri(&format!("/api/direction_categories/{}", Uuid::now_v7()))
         .set_json(DirectionCategoryUpdateRequest {
             name: String::default(),
+            parent_id: None,
         })
         .to_request();
 
@@ -62,6 +64,104 @@ async fn unauthorized_if_not_logged_in() -> Result<(), DbErr> {
     Ok(())
 }
 
+#[actix_web::test]
+async fn happy_path_moving_under_another_category() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let parent = factory::direction_category(user.id).insert(&db).await?;
+    let _sibling = factory::direction_category(user.id)
+        .ordering(Some(3))
+        .parent_id(Some(parent.id))
+        .insert(&db)
+        .await?;
+    let category = factory::direction_category(user.id)
+        .ordering(Some(1))
+        .insert(&db)
+        .await?;
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/api/direction_categories/{}", category.id))
+        .set_json(DirectionCategoryUpdateRequest {
+            name: category.name.clone(),
+            parent_id: Some(parent.id),
+        })
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+
+    let category_in_db = direction_category::Entity::find_by_id(category.id)
+        .one(&db)
+        .await?
+        .unwrap();
+    assert_eq!(category_in_db.parent_id, Some(parent.id));
+    // Last among the new siblings.
+    assert_eq!(category_in_db.ordering, Some(4));
+
+    Ok(())
+}
+
+mod bad_request {
+    use super::*;
+
+    #[actix_web::test]
+    async fn parent_is_itself() -> Result<(), DbErr> {
+        let Connections { app, db, .. } = init_app().await?;
+        let user = factory::user().insert(&db).await?;
+        let category = factory::direction_category(user.id).insert(&db).await?;
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/direction_categories/{}", category.id))
+            .set_json(DirectionCategoryUpdateRequest {
+                name: category.name.clone(),
+                parent_id: Some(category.id),
+            })
+            .to_request();
+        req.extensions_mut().insert(user.clone());
+
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+
+        Ok(())
+    }
+
+    #[actix_web::test]
+    async fn parent_is_a_descendant() -> Result<(), DbErr> {
+        let Connections { app, db, .. } = init_app().await?;
+        let user = factory::user().insert(&db).await?;
+        let category = factory::direction_category(user.id).insert(&db).await?;
+        let child = factory::direction_category(user.id)
+            .parent_id(Some(category.id))
+            .insert(&db)
+            .await?;
+        let grandchild = factory::direction_category(user.id)
+            .parent_id(Some(child.id))
+            .insert(&db)
+            .await?;
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/direction_categories/{}", category.id))
+            .set_json(DirectionCategoryUpdateRequest {
+                name: category.name.clone(),
+                parent_id: Some(grandchild.id),
+            })
+            .to_request();
+        req.extensions_mut().insert(user.clone());
+
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+
+        let category_in_db = direction_category::Entity::find_by_id(category.id)
+            .one(&db)
+            .await?
+            .unwrap();
+        assert_eq!(category_in_db.parent_id, None);
+
+        Ok(())
+    }
+}
+
 mod not_found {
     use super::*;
 
@@ -80,7 +180,8 @@ mod not_found {
                 other_user_category.id
             ))
             .set_json(DirectionCategoryUpdateRequest {
-                name: String::default(),
+                name: "Test".to_string(),
+                parent_id: None,
             })
             .to_request();
         req.extensions_mut().insert(user.clone());
@@ -90,6 +191,59 @@ mod not_found {
 
         Ok(())
     }
+
+    #[actix_web::test]
+    async fn parent_of_other_user() -> Result<(), DbErr> {
+        let Connections { app, db, .. } = init_app().await?;
+        let user = factory::user().insert(&db).await?;
+        let category = factory::direction_category(user.id).insert(&db).await?;
+        let other_user = factory::user().insert(&db).await?;
+        let other_user_category = factory::direction_category(other_user.id)
+            .insert(&db)
+            .await?;
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/direction_categories/{}", category.id))
+            .set_json(DirectionCategoryUpdateRequest {
+                name: category.name.clone(),
+                parent_id: Some(other_user_category.id),
+            })
+            .to_request();
+        req.extensions_mut().insert(user.clone());
+
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), http::StatusCode::NOT_FOUND);
+
+        let category_in_db = direction_category::Entity::find_by_id(category.id)
+            .one(&db)
+            .await?
+            .unwrap();
+        assert_eq!(category_in_db.parent_id, None);
+
+        Ok(())
+    }
+
+    #[actix_web::test]
+    async fn non_existent_parent() -> Result<(), DbErr> {
+        let Connections { app, db, .. } = init_app().await?;
+        let user = factory::user().insert(&db).await?;
+        let category = factory::direction_category(user.id).insert(&db).await?;
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/direction_categories/{}", category.id))
+            .set_json(DirectionCategoryUpdateRequest {
+                name: category.name.clone(),
+                parent_id: Some(Uuid::now_v7()),
+            })
+            .to_request();
+        req.extensions_mut().insert(user.clone());
+
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), http::StatusCode::NOT_FOUND);
+
+        Ok(())
+    }
+
     #[actix_web::test]
     async fn non_existent_id() -> Result<(), DbErr> {
         let Connections { app, db, .. } = init_app().await?;
@@ -98,7 +252,8 @@ mod not_found {
         let req = test::TestRequest::put()
             .uri(&format!("/api/direction_categories/{}", Uuid::now_v7()))
             .set_json(DirectionCategoryUpdateRequest {
-                name: String::default(),
+                name: "Test".to_string(),
+                parent_id: None,
             })
             .to_request();
         req.extensions_mut().insert(user.clone());
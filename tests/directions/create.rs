@@ -74,7 +74,7 @@ async fn no_category_cases() -> Result<(), DbErr> {
         let req = test::TestRequest::post()
             .uri("/api/directions")
             .set_json(DirectionCreateRequest {
-                name: String::default(),
+                name: "Test".to_string(),
                 description: None,
                 category_id: Some(category_id),
             })
@@ -91,7 +91,7 @@ async fn no_category_cases() -> Result<(), DbErr> {
             .await?
             .unwrap();
         assert_eq!(direction_in_db.user_id, user.id);
-        assert_eq!(direction_in_db.name, String::default());
+        assert_eq!(direction_in_db.name, "Test".to_string());
         assert_eq!(direction_in_db.description, None);
         assert_eq!(direction_in_db.archived, false);
         assert_eq!(direction_in_db.ordering, None);
@@ -24,10 +24,10 @@ async fn happy_path() -> Result<(), DbErr> {
     let direction_in_db = direction::Entity::find_by_id(direction.id)
         .one(&db)
         .await?;
-    assert!(direction_in_db.is_none());
+    assert!(direction_in_db.unwrap().deleted_at.is_some());
 
     let tag_in_db = tag::Entity::find_by_id(tag.id).one(&db).await?;
-    assert!(tag_in_db.is_none());
+    assert!(tag_in_db.is_some());
 
     Ok(())
 }
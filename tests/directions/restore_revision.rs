@@ -0,0 +1,105 @@
+use actix_web::{http, test, HttpMessage};
+use sea_orm::{ActiveModelTrait, DbErr, EntityTrait};
+use use_cases::my_way::directions::types::{
+    DirectionRevisionVisible, DirectionUpdateRequest, DirectionVisible,
+};
+
+use crate::utils::Connections;
+
+use super::super::utils::init_app;
+use common::factory::{self, *};
+use entities::direction;
+
+#[actix_web::test]
+async fn happy_path() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let direction = factory::direction(user.id)
+        .name("first".to_string())
+        .insert(&db)
+        .await?;
+    let req = test::TestRequest::put()
+        .uri(&format!("/api/directions/{}", direction.id))
+        .set_json(DirectionUpdateRequest {
+            name: "second".to_string(),
+            description: Some("second description".to_string()),
+            category_id: None,
+        })
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/directions/{}/revisions", direction.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let revisions: Vec<DirectionRevisionVisible> = test::call_and_read_body_json(&app, req).await;
+
+    let req = test::TestRequest::put()
+        .uri(&format!(
+            "/api/directions/{}/revisions/{}/restore",
+            direction.id, revisions[0].id
+        ))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+
+    let direction_in_db = direction::Entity::find_by_id(direction.id)
+        .one(&db)
+        .await?
+        .unwrap();
+    assert_eq!(direction_in_db.name, "first".to_string());
+    assert_eq!(direction_in_db.description, direction.description);
+
+    let res: DirectionVisible = test::read_body_json(res).await;
+    assert_eq!(DirectionVisible::from(direction_in_db), res);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/directions/{}/revisions", direction.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let revisions: Vec<DirectionRevisionVisible> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(revisions.len(), 2);
+    assert_eq!(revisions[0].name, "second".to_string());
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn not_found_if_revision_of_other_direction() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let direction = factory::direction(user.id).insert(&db).await?;
+    let other_direction = factory::direction(user.id).insert(&db).await?;
+    let req = test::TestRequest::put()
+        .uri(&format!("/api/directions/{}", other_direction.id))
+        .set_json(DirectionUpdateRequest {
+            name: "second".to_string(),
+            description: Some("second description".to_string()),
+            category_id: None,
+        })
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/directions/{}/revisions", other_direction.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let revisions: Vec<DirectionRevisionVisible> = test::call_and_read_body_json(&app, req).await;
+
+    let req = test::TestRequest::put()
+        .uri(&format!(
+            "/api/directions/{}/revisions/{}/restore",
+            direction.id, revisions[0].id
+        ))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::NOT_FOUND);
+
+    Ok(())
+}
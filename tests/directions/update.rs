@@ -70,7 +70,7 @@ async fn no_category_cases() -> Result<(), DbErr> {
         let req = test::TestRequest::put()
             .uri(&format!("/api/directions/{}", direction.id))
             .set_json(DirectionUpdateRequest {
-                name: String::default(),
+                name: "Test".to_string(),
                 description: None,
                 category_id: Some(category_id),
             })
@@ -90,7 +90,7 @@ async fn no_category_cases() -> Result<(), DbErr> {
             .unwrap();
         assert_eq!(direction_in_db.id, direction.id);
         assert_eq!(direction_in_db.user_id, user.id);
-        assert_eq!(direction_in_db.name, String::default());
+        assert_eq!(direction_in_db.name, "Test".to_string());
         assert_eq!(direction_in_db.created_at, direction.created_at);
         assert!(direction_in_db.updated_at > direction.updated_at);
         assert_eq!(direction_in_db.description, None);
@@ -0,0 +1,64 @@
+use std::{future::poll_fn, pin::Pin, time::Duration};
+
+use actix_web::{body::MessageBody, http, test, HttpMessage};
+use chrono::Utc;
+use sea_orm::{ActiveModelTrait, DbErr};
+use use_cases::my_way::action_tracks::types::{ActionTrackCreateRequest, ActionTrackVisible};
+
+use crate::utils::{init_app, Connections};
+use common::factory;
+
+#[actix_web::test]
+async fn happy_path_streams_action_track_start() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let action = factory::action(user.id).insert(&db).await?;
+
+    let req = test::TestRequest::get().uri("/api/events").to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+    assert_eq!(
+        res.headers().get(http::header::CONTENT_TYPE).unwrap(),
+        "text/event-stream"
+    );
+    let mut events = res.into_body();
+
+    let req = test::TestRequest::post()
+        .uri("/api/action_tracks")
+        .set_json(ActionTrackCreateRequest {
+            started_at: Utc::now().into(),
+            action_id: action.id,
+        })
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::CREATED);
+    let action_track: ActionTrackVisible = test::read_body_json(res).await;
+
+    let chunk = tokio::time::timeout(
+        Duration::from_secs(5),
+        poll_fn(|cx| Pin::new(&mut events).poll_next(cx)),
+    )
+    .await
+    .expect("No event was streamed.")
+    .unwrap()
+    .unwrap();
+    let chunk = String::from_utf8(chunk.to_vec()).unwrap();
+    assert!(chunk.starts_with("event: action_track.created\ndata: "));
+    assert!(chunk.contains(&action_track.id.to_string()));
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn unauthorized_if_not_logged_in() -> Result<(), DbErr> {
+    let Connections { app, .. } = init_app().await?;
+
+    let req = test::TestRequest::get().uri("/api/events").to_request();
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
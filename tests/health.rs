@@ -0,0 +1,44 @@
+use actix_web::{http, test};
+use sea_orm::DbErr;
+
+use crate::utils::{init_app, Connections};
+
+#[actix_web::test]
+async fn liveness() -> Result<(), DbErr> {
+    let Connections { app, .. } = init_app().await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/health/live")
+        .to_request();
+    let res = test::call_service(&app, req).await;
+
+    assert_eq!(res.status(), http::StatusCode::OK);
+    let res: serde_json::Value = test::read_body_json(res).await;
+    assert_eq!(res["status"], "ok");
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn readiness_reports_each_component() -> Result<(), DbErr> {
+    let Connections { app, .. } = init_app().await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/health/ready")
+        .to_request();
+    let res = test::call_service(&app, req).await;
+
+    // Cron scheduler is not run by the test app, so overall status depends on the environment.
+    assert!([http::StatusCode::OK, http::StatusCode::SERVICE_UNAVAILABLE].contains(&res.status()));
+    let res: serde_json::Value = test::read_body_json(res).await;
+    assert_eq!(res["components"]["database"]["status"], "ok");
+    assert_eq!(res["components"]["migrations"]["status"], "ok");
+    assert_eq!(res["components"]["redis"]["status"], "ok");
+    assert!(res["components"]["cron"]["status"].is_string());
+    // Error details are logged, not exposed.
+    for component in ["database", "migrations", "redis", "cron"] {
+        assert_eq!(res["components"][component].as_object().unwrap().len(), 1);
+    }
+
+    Ok(())
+}
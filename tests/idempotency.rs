@@ -0,0 +1,154 @@
+use actix_web::{http, test, HttpMessage};
+use chrono::Utc;
+use sea_orm::{ActiveModelTrait, ColumnTrait, DbErr, EntityTrait, PaginatorTrait, QueryFilter};
+use use_cases::my_way::{
+    action_tracks::types::{ActionTrackCreateRequest, ActionTrackVisible},
+    ambitions::types::AmbitionCreateRequest,
+};
+
+use crate::utils::{init_app, Connections};
+use common::factory;
+use entities::{action_track, ambition};
+
+#[actix_web::test]
+async fn retry_with_same_key_replays_first_response() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let action = factory::action(user.id).insert(&db).await?;
+    let idempotency_key = uuid::Uuid::now_v7().to_string();
+    let started_at: chrono::DateTime<chrono::FixedOffset> = Utc::now().into();
+
+    let mut responses = vec![];
+    for _ in 0..2 {
+        let req = test::TestRequest::post()
+            .uri("/api/action_tracks")
+            .insert_header(("Idempotency-Key", idempotency_key.as_str()))
+            .set_json(ActionTrackCreateRequest {
+                started_at,
+                action_id: action.id,
+            })
+            .to_request();
+        req.extensions_mut().insert(user.clone());
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), http::StatusCode::CREATED);
+        let is_replayed = res.headers().contains_key("idempotency-replayed");
+        let res: ActionTrackVisible = test::read_body_json(res).await;
+        responses.push((is_replayed, res));
+    }
+    assert!(!responses[0].0);
+    assert!(responses[1].0);
+    assert_eq!(responses[0].1, responses[1].1);
+
+    let count = action_track::Entity::find()
+        .filter(action_track::Column::UserId.eq(user.id))
+        .count(&db)
+        .await?;
+    assert_eq!(count, 1);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn same_key_for_another_request_is_rejected() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let idempotency_key = uuid::Uuid::now_v7().to_string();
+
+    for (name, status) in [
+        ("first", http::StatusCode::CREATED),
+        ("second", http::StatusCode::UNPROCESSABLE_ENTITY),
+    ] {
+        let req = test::TestRequest::post()
+            .uri("/api/ambitions")
+            .insert_header(("Idempotency-Key", idempotency_key.as_str()))
+            .set_json(AmbitionCreateRequest {
+                name: name.to_string(),
+                description: None,
+            })
+            .to_request();
+        req.extensions_mut().insert(user.clone());
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), status);
+    }
+
+    let ambitions = ambition::Entity::find()
+        .filter(ambition::Column::UserId.eq(user.id))
+        .all(&db)
+        .await?;
+    assert_eq!(ambitions.len(), 1);
+    assert_eq!(ambitions[0].name, "first");
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn keys_are_per_user() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let another_user = factory::user().insert(&db).await?;
+    let idempotency_key = uuid::Uuid::now_v7().to_string();
+
+    for user in [&user, &another_user] {
+        let req = test::TestRequest::post()
+            .uri("/api/ambitions")
+            .insert_header(("Idempotency-Key", idempotency_key.as_str()))
+            .set_json(AmbitionCreateRequest {
+                name: "ambition".to_string(),
+                description: None,
+            })
+            .to_request();
+        req.extensions_mut().insert(user.clone());
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), http::StatusCode::CREATED);
+        assert!(!res.headers().contains_key("idempotency-replayed"));
+    }
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn requests_without_key_are_not_deduplicated() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+
+    for _ in 0..2 {
+        let req = test::TestRequest::post()
+            .uri("/api/ambitions")
+            .set_json(AmbitionCreateRequest {
+                name: "ambition".to_string(),
+                description: None,
+            })
+            .to_request();
+        req.extensions_mut().insert(user.clone());
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), http::StatusCode::CREATED);
+    }
+
+    let count = ambition::Entity::find()
+        .filter(ambition::Column::UserId.eq(user.id))
+        .count(&db)
+        .await?;
+    assert_eq!(count, 2);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn invalid_key_is_rejected() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+
+    let req = test::TestRequest::post()
+        .uri("/api/ambitions")
+        .insert_header(("Idempotency-Key", "a".repeat(256)))
+        .set_json(AmbitionCreateRequest {
+            name: "ambition".to_string(),
+            description: None,
+        })
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+
+    Ok(())
+}
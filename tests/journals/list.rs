@@ -151,6 +151,115 @@ async fn test_tag_query() -> Result<(), DbErr> {
     Ok(())
 }
 
+#[actix_web::test]
+async fn pages_follow_the_same_order_as_the_whole_list() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let tag = factory::tag(user.id).insert(&db).await?;
+    let now = Utc::now();
+    for days in [1, 5] {
+        factory::thinking_note(user.id)
+            .updated_at((now - Duration::days(days)).into())
+            .insert(&db)
+            .await?;
+    }
+    for days in [0, 2, 3] {
+        let diary = factory::diary(user.id)
+            .date((now - Duration::days(days)).date_naive())
+            .insert(&db)
+            .await?;
+        factory::link_diary_tag(&db, diary.id, tag.id).await?;
+    }
+    // NOTE: Same dates as diaries to make ties across kinds.
+    for days in [2, 2, 4] {
+        factory::reading_note(user.id)
+            .date((now - Duration::days(days)).date_naive())
+            .insert(&db)
+            .await?;
+    }
+    for days in [2, 3] {
+        factory::thinking_note(user.id)
+            .updated_at((now - Duration::days(days)).into())
+            .resolved_at(Some((now - Duration::days(days)).into()))
+            .insert(&db)
+            .await?;
+    }
+
+    let req = test::TestRequest::get().uri("/api/journals").to_request();
+    req.extensions_mut().insert(user.clone());
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), http::StatusCode::OK);
+    assert!(resp.headers().get("x-next-cursor").is_none());
+    let whole_list: Vec<JournalVisibleWithTags> = test::read_body_json(resp).await;
+    assert_eq!(whole_list.len(), 10);
+
+    for limit in [1, 3, 4, 10] {
+        let mut pages = vec![];
+        let mut cursor: Option<String> = None;
+        loop {
+            let uri = match &cursor {
+                Some(cursor) => format!("/api/journals?limit={limit}&cursor={cursor}"),
+                None => format!("/api/journals?limit={limit}"),
+            };
+            let req = test::TestRequest::get().uri(&uri).to_request();
+            req.extensions_mut().insert(user.clone());
+            let resp = test::call_service(&app, req).await;
+            assert_eq!(resp.status(), http::StatusCode::OK);
+            let next_cursor = resp
+                .headers()
+                .get("x-next-cursor")
+                .map(|cursor| cursor.to_str().unwrap().to_string());
+            let page: Vec<JournalVisibleWithTags> = test::read_body_json(resp).await;
+            assert!(page.len() <= limit);
+            pages.extend(page);
+            match next_cursor {
+                Some(next_cursor) => cursor = Some(next_cursor),
+                None => break,
+            }
+        }
+        dbg!(limit);
+        assert_eq!(pages, whole_list);
+    }
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn bad_request_on_invalid_cursor() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/journals?limit=10&cursor=invalid")
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    let res: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(res["code"], "page.invalid_cursor");
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn bad_request_on_too_large_limit() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/journals?limit=101")
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    let res: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(res["code"], "page.invalid_limit");
+
+    Ok(())
+}
+
 #[actix_web::test]
 async fn unauthorized_if_not_logged_in() -> Result<(), DbErr> {
     let Connections { app, .. } = init_app().await?;
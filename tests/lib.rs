@@ -3,14 +3,27 @@ mod action_goals;
 mod action_tracks;
 mod actions;
 mod ambitions;
+mod batch;
+mod csrf;
 mod diaries;
 mod direction_categories;
 mod directions;
+mod events;
+mod health;
+mod idempotency;
 mod journals;
+mod my_way_tree;
 mod notification;
+mod openapi;
+mod rate_limit;
 mod reading_notes;
+mod request_tracing;
+mod row_level_security;
+mod search;
+mod sync;
 mod tags;
 mod thinking_notes;
+mod trash;
 mod users;
 
 mod utils;
@@ -0,0 +1,147 @@
+use actix_web::{http, test, HttpMessage};
+use sea_orm::{ActiveModelTrait, DbErr};
+use use_cases::my_way::{
+    actions::types::ActionVisible,
+    ambitions::types::AmbitionVisible,
+    directions::types::DirectionVisible,
+    tree::types::{AmbitionNode, DirectionNode, MyWayTree},
+};
+
+use crate::utils::Connections;
+
+use super::super::utils::init_app;
+use common::factory::{self, *};
+
+#[actix_web::test]
+async fn happy_path() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let ambition = factory::ambition(user.id).insert(&db).await?;
+    let direction = factory::direction(user.id).insert(&db).await?;
+    let unlinked_direction = factory::direction(user.id).insert(&db).await?;
+    let action = factory::action(user.id).insert(&db).await?;
+    let unlinked_action = factory::action(user.id).insert(&db).await?;
+    factory::link_ambition_direction(&db, ambition.id, direction.id).await?;
+    factory::link_direction_action(&db, direction.id, action.id).await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/my_way/tree")
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), http::StatusCode::OK);
+
+    let res: MyWayTree = test::read_body_json(resp).await;
+    assert_eq!(
+        res,
+        MyWayTree {
+            ambitions: vec![AmbitionNode {
+                ambition: AmbitionVisible::from(ambition),
+                directions: vec![DirectionNode {
+                    direction: DirectionVisible::from(direction),
+                    actions: vec![ActionVisible::from(action)],
+                }],
+            }],
+            unlinked_directions: vec![DirectionNode {
+                direction: DirectionVisible::from(unlinked_direction),
+                actions: vec![],
+            }],
+            unlinked_actions: vec![ActionVisible::from(unlinked_action)],
+        }
+    );
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn archived_items_are_left_out() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let ambition = factory::ambition(user.id).insert(&db).await?;
+    let archived_ambition = factory::ambition(user.id)
+        .archived(true)
+        .insert(&db)
+        .await?;
+    let direction = factory::direction(user.id).insert(&db).await?;
+    let archived_direction = factory::direction(user.id)
+        .archived(true)
+        .insert(&db)
+        .await?;
+    let archived_action = factory::action(user.id).archived(true).insert(&db).await?;
+    factory::link_ambition_direction(&db, ambition.id, archived_direction.id).await?;
+    factory::link_ambition_direction(&db, archived_ambition.id, direction.id).await?;
+    factory::link_direction_action(&db, direction.id, archived_action.id).await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/my_way/tree")
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), http::StatusCode::OK);
+
+    let res: MyWayTree = test::read_body_json(resp).await;
+    assert_eq!(
+        res,
+        MyWayTree {
+            ambitions: vec![AmbitionNode {
+                ambition: AmbitionVisible::from(ambition),
+                directions: vec![],
+            }],
+            // Its only ambition is archived.
+            unlinked_directions: vec![DirectionNode {
+                direction: DirectionVisible::from(direction),
+                actions: vec![],
+            }],
+            unlinked_actions: vec![],
+        }
+    );
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn items_of_other_users_are_left_out() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let other_user = factory::user().insert(&db).await?;
+    let ambition = factory::ambition(other_user.id).insert(&db).await?;
+    let direction = factory::direction(other_user.id).insert(&db).await?;
+    factory::action(other_user.id).insert(&db).await?;
+    factory::link_ambition_direction(&db, ambition.id, direction.id).await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/my_way/tree")
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), http::StatusCode::OK);
+
+    let res: MyWayTree = test::read_body_json(resp).await;
+    assert_eq!(
+        res,
+        MyWayTree {
+            ambitions: vec![],
+            unlinked_directions: vec![],
+            unlinked_actions: vec![],
+        }
+    );
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn unauthorized_if_not_logged_in() -> Result<(), DbErr> {
+    let Connections { app, .. } = init_app().await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/my_way/tree")
+        .to_request();
+
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use actix_web::{http, test, HttpMessage};
+use sea_orm::{ActiveModelTrait, DbErr, EntityTrait};
+
+use crate::utils::Connections;
+
+use super::super::utils::init_app;
+use common::factory;
+use entities::directions_actions;
+
+#[actix_web::test]
+async fn happy_path() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let direction = factory::direction(user.id).insert(&db).await?;
+    let action = factory::action(user.id).insert(&db).await?;
+
+    let req = test::TestRequest::put()
+        .uri(&format!(
+            "/api/my_way/directions/{}/actions/{}",
+            direction.id, action.id
+        ))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+
+    let link_in_db = directions_actions::Entity::find_by_id((direction.id, action.id))
+        .one(&db)
+        .await?;
+    assert!(link_in_db.is_some());
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn no_content_if_already_linked() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let direction = factory::direction(user.id).insert(&db).await?;
+    let action = factory::action(user.id).insert(&db).await?;
+    factory::link_direction_action(&db, direction.id, action.id).await?;
+
+    let req = test::TestRequest::put()
+        .uri(&format!(
+            "/api/my_way/directions/{}/actions/{}",
+            direction.id, action.id
+        ))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn not_found_if_other_users_action() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let other_user = factory::user().insert(&db).await?;
+    let direction = factory::direction(user.id).insert(&db).await?;
+    let action = factory::action(other_user.id).insert(&db).await?;
+
+    let req = test::TestRequest::put()
+        .uri(&format!(
+            "/api/my_way/directions/{}/actions/{}",
+            direction.id, action.id
+        ))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+
+    let link_in_db = directions_actions::Entity::find_by_id((direction.id, action.id))
+        .one(&db)
+        .await?;
+    assert!(link_in_db.is_none());
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn unauthorized_if_not_logged_in() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let direction = factory::direction(user.id).insert(&db).await?;
+    let action = factory::action(user.id).insert(&db).await?;
+
+    let req = test::TestRequest::put()
+        .uri(&format!(
+            "/api/my_way/directions/{}/actions/{}",
+            direction.id, action.id
+        ))
+        .to_request();
+
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
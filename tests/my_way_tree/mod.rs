@@ -0,0 +1,6 @@
+mod get;
+mod link_action;
+mod link_direction;
+mod progress;
+mod unlink_action;
+mod unlink_direction;
@@ -0,0 +1,273 @@
+use actix_web::{http, test, HttpMessage};
+use chrono::{DateTime, NaiveDate};
+use sea_orm::{ActiveModelTrait, DbErr};
+use use_cases::my_way::tree::types::{
+    ActionProgress, AmbitionProgress, DirectionCategoryProgress, DirectionProgress, MyWayProgress,
+    Progress,
+};
+
+use crate::utils::Connections;
+
+use super::super::utils::init_app;
+use common::factory::{self, *};
+use entities::sea_orm_active_enums::ActionTrackType;
+
+#[actix_web::test]
+async fn happy_path() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let category = factory::direction_category(user.id).insert(&db).await?;
+    let ambition = factory::ambition(user.id).insert(&db).await?;
+    let direction = factory::direction(user.id)
+        .category_id(Some(category.id))
+        .insert(&db)
+        .await?;
+    let uncategorized_direction = factory::direction(user.id).insert(&db).await?;
+    let action = factory::action(user.id).insert(&db).await?;
+    let count_action = factory::action(user.id)
+        .track_type(ActionTrackType::Count)
+        .insert(&db)
+        .await?;
+    let archived_action = factory::action(user.id).archived(true).insert(&db).await?;
+    factory::link_ambition_direction(&db, ambition.id, direction.id).await?;
+    factory::link_ambition_direction(&db, ambition.id, uncategorized_direction.id).await?;
+    for direction_id in [direction.id, uncategorized_direction.id] {
+        factory::link_direction_action(&db, direction_id, action.id).await?;
+    }
+    factory::link_direction_action(&db, uncategorized_direction.id, count_action.id).await?;
+    factory::link_direction_action(&db, uncategorized_direction.id, archived_action.id).await?;
+
+    // NOTE: The user lives in Asia/Tokyo, so 2025-06-01 starts at 2025-05-31T15:00:00Z.
+    for (started_at, duration) in [
+        ("2025-05-31T15:30:00Z", 1800),
+        ("2025-06-02T01:00:00Z", 600),
+        ("2025-06-02T15:30:00Z", 3600),
+    ] {
+        factory::action_track(user.id)
+            .action_id(action.id)
+            .started_at(DateTime::parse_from_rfc3339(started_at).unwrap())
+            .duration(Some(duration))
+            .insert(&db)
+            .await?;
+    }
+    for started_at in ["2025-06-01T01:00:00Z", "2025-06-01T02:00:00Z"] {
+        factory::action_track(user.id)
+            .action_id(count_action.id)
+            .started_at(DateTime::parse_from_rfc3339(started_at).unwrap())
+            .duration(Some(0))
+            .insert(&db)
+            .await?;
+    }
+    factory::action_track(user.id)
+        .action_id(archived_action.id)
+        .started_at(DateTime::parse_from_rfc3339("2025-06-01T01:00:00Z").unwrap())
+        .duration(Some(60))
+        .insert(&db)
+        .await?;
+    factory::action_goal(user.id, action.id)
+        .from_date(NaiveDate::from_ymd_opt(2025, 5, 1).unwrap())
+        .duration_seconds(Some(1200))
+        .insert(&db)
+        .await?;
+    factory::action_goal(user.id, count_action.id)
+        .from_date(NaiveDate::from_ymd_opt(2025, 6, 2).unwrap())
+        .count(Some(2))
+        .insert(&db)
+        .await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/my_way/progress?from=2025-06-01&to=2025-06-02")
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), http::StatusCode::OK);
+
+    let action_progress = Progress {
+        duration: 2400,
+        count: 2,
+        goal_days: 2,
+        achieved_goal_days: 1,
+    };
+    // The action linked through both directions counts once.
+    let both_actions_progress = Progress {
+        duration: 2400,
+        count: 4,
+        goal_days: 3,
+        achieved_goal_days: 1,
+    };
+    let res: MyWayProgress = test::read_body_json(resp).await;
+    assert_eq!(
+        res,
+        MyWayProgress {
+            ambitions: vec![AmbitionProgress {
+                ambition_id: ambition.id,
+                progress: both_actions_progress.clone(),
+            }],
+            directions: vec![
+                DirectionProgress {
+                    direction_id: direction.id,
+                    progress: action_progress.clone(),
+                },
+                DirectionProgress {
+                    direction_id: uncategorized_direction.id,
+                    progress: both_actions_progress.clone(),
+                },
+            ],
+            direction_categories: vec![
+                DirectionCategoryProgress {
+                    direction_category_id: Some(category.id),
+                    progress: action_progress.clone(),
+                },
+                DirectionCategoryProgress {
+                    direction_category_id: None,
+                    progress: both_actions_progress,
+                },
+            ],
+            actions: vec![
+                ActionProgress {
+                    action_id: action.id,
+                    progress: action_progress,
+                },
+                ActionProgress {
+                    action_id: count_action.id,
+                    progress: Progress {
+                        duration: 0,
+                        count: 2,
+                        goal_days: 1,
+                        achieved_goal_days: 0,
+                    },
+                },
+            ],
+        }
+    );
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn category_includes_progress_of_descendants() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let parent = factory::direction_category(user.id)
+        .ordering(Some(1))
+        .insert(&db)
+        .await?;
+    let child = factory::direction_category(user.id)
+        .parent_id(Some(parent.id))
+        .ordering(Some(1))
+        .insert(&db)
+        .await?;
+    let other_child = factory::direction_category(user.id)
+        .parent_id(Some(parent.id))
+        .ordering(Some(2))
+        .insert(&db)
+        .await?;
+    let _empty_category = factory::direction_category(user.id)
+        .ordering(Some(2))
+        .insert(&db)
+        .await?;
+    let direction = factory::direction(user.id)
+        .category_id(Some(child.id))
+        .insert(&db)
+        .await?;
+    let other_direction = factory::direction(user.id)
+        .category_id(Some(other_child.id))
+        .insert(&db)
+        .await?;
+    let action = factory::action(user.id).insert(&db).await?;
+    let other_action = factory::action(user.id).insert(&db).await?;
+    factory::link_direction_action(&db, direction.id, action.id).await?;
+    factory::link_direction_action(&db, other_direction.id, action.id).await?;
+    factory::link_direction_action(&db, other_direction.id, other_action.id).await?;
+    for (action_id, duration) in [(action.id, 600), (other_action.id, 1200)] {
+        factory::action_track(user.id)
+            .action_id(action_id)
+            .started_at(DateTime::parse_from_rfc3339("2025-06-01T01:00:00Z").unwrap())
+            .duration(Some(duration))
+            .insert(&db)
+            .await?;
+    }
+
+    let req = test::TestRequest::get()
+        .uri("/api/my_way/progress?from=2025-06-01&to=2025-06-01")
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), http::StatusCode::OK);
+
+    let progress = |duration: i64, count: i64| Progress {
+        duration,
+        count,
+        goal_days: 0,
+        achieved_goal_days: 0,
+    };
+    let res: MyWayProgress = test::read_body_json(resp).await;
+    // The action linked under both children counts once toward the parent.
+    assert_eq!(
+        res.direction_categories,
+        vec![
+            DirectionCategoryProgress {
+                direction_category_id: Some(parent.id),
+                progress: progress(1800, 2),
+            },
+            DirectionCategoryProgress {
+                direction_category_id: Some(child.id),
+                progress: progress(600, 1),
+            },
+            DirectionCategoryProgress {
+                direction_category_id: Some(other_child.id),
+                progress: progress(1800, 2),
+            },
+        ]
+    );
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn bad_request_if_to_is_before_from() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/my_way/progress?from=2025-06-02&to=2025-06-01")
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn bad_request_if_range_is_longer_than_a_year() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/my_way/progress?from=2024-01-01&to=2025-01-01")
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn unauthorized_if_not_logged_in() -> Result<(), DbErr> {
+    let Connections { app, .. } = init_app().await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/my_way/progress?from=2025-06-01&to=2025-06-02")
+        .to_request();
+
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
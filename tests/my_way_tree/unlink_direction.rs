@@ -0,0 +1,86 @@
+use actix_web::{http, test, HttpMessage};
+use sea_orm::{ActiveModelTrait, DbErr, EntityTrait};
+
+use crate::utils::Connections;
+
+use super::super::utils::init_app;
+use common::factory;
+use entities::{ambitions_directions, direction};
+
+#[actix_web::test]
+async fn happy_path() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let ambition = factory::ambition(user.id).insert(&db).await?;
+    let direction = factory::direction(user.id).insert(&db).await?;
+    factory::link_ambition_direction(&db, ambition.id, direction.id).await?;
+
+    let req = test::TestRequest::delete()
+        .uri(&format!(
+            "/api/my_way/ambitions/{}/directions/{}",
+            ambition.id, direction.id
+        ))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+
+    let link_in_db = ambitions_directions::Entity::find_by_id((ambition.id, direction.id))
+        .one(&db)
+        .await?;
+    assert!(link_in_db.is_none());
+
+    let direction_in_db = direction::Entity::find_by_id(direction.id).one(&db).await?;
+    assert!(direction_in_db.is_some());
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn not_found_if_other_users_ambition() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let other_user = factory::user().insert(&db).await?;
+    let ambition = factory::ambition(other_user.id).insert(&db).await?;
+    let direction = factory::direction(other_user.id).insert(&db).await?;
+    factory::link_ambition_direction(&db, ambition.id, direction.id).await?;
+
+    let req = test::TestRequest::delete()
+        .uri(&format!(
+            "/api/my_way/ambitions/{}/directions/{}",
+            ambition.id, direction.id
+        ))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+
+    let link_in_db = ambitions_directions::Entity::find_by_id((ambition.id, direction.id))
+        .one(&db)
+        .await?;
+    assert!(link_in_db.is_some());
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn unauthorized_if_not_logged_in() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let ambition = factory::ambition(user.id).insert(&db).await?;
+    let direction = factory::direction(user.id).insert(&db).await?;
+
+    let req = test::TestRequest::delete()
+        .uri(&format!(
+            "/api/my_way/ambitions/{}/directions/{}",
+            ambition.id, direction.id
+        ))
+        .to_request();
+
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use actix_web::{http, test};
+use sea_orm::DbErr;
+
+use crate::utils::{init_app, Connections};
+
+#[actix_web::test]
+async fn openapi_json() -> Result<(), DbErr> {
+    let Connections { app, .. } = init_app().await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/openapi.json")
+        .to_request();
+    let res = test::call_service(&app, req).await;
+
+    assert_eq!(res.status(), http::StatusCode::OK);
+    let res: serde_json::Value = test::read_body_json(res).await;
+    assert!(res["openapi"].is_string());
+    assert!(res["paths"]["/api/actions"]["get"].is_object());
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn docs() -> Result<(), DbErr> {
+    let Connections { app, .. } = init_app().await?;
+
+    let req = test::TestRequest::get().uri("/api/docs").to_request();
+    let res = test::call_service(&app, req).await;
+
+    assert_eq!(res.status(), http::StatusCode::OK);
+    assert!(res
+        .headers()
+        .get(http::header::CONTENT_TYPE)
+        .is_some_and(|value| value.to_str().unwrap().starts_with("text/html")));
+
+    Ok(())
+}
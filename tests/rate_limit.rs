@@ -0,0 +1,223 @@
+use std::net::{Ipv6Addr, SocketAddr};
+
+use actix_web::{http, test, HttpMessage};
+use common::{
+    factory,
+    settings::{
+        get_test_settings,
+        types::{RateLimit, Settings},
+    },
+};
+use sea_orm::{ActiveModelTrait, DbErr};
+
+use crate::utils::{init_app_with_settings, Connections};
+
+fn settings_with_limits(registration: RateLimit, api: RateLimit) -> Settings {
+    let mut settings = get_test_settings();
+    settings.rate_limit.enabled = true;
+    settings.rate_limit.registration = registration;
+    settings.rate_limit.api = api;
+    settings
+}
+
+/// Buckets live in Redis beyond a test run, so every run uses an address of its own.
+fn unique_peer_addr() -> SocketAddr {
+    SocketAddr::new(Ipv6Addr::from(uuid::Uuid::now_v7().as_u128()).into(), 12345)
+}
+
+#[actix_web::test]
+async fn registration_is_limited_per_ip() -> Result<(), DbErr> {
+    let Connections { app, .. } = init_app_with_settings(settings_with_limits(
+        RateLimit {
+            requests: 2,
+            period_seconds: 3600,
+        },
+        RateLimit::default(),
+    ))
+    .await?;
+    let peer_addr = unique_peer_addr();
+
+    // Invalid bodies are enough, as tokens are taken before the handler runs.
+    for _ in 0..2 {
+        let req = test::TestRequest::post()
+            .uri("/api/users/register")
+            .peer_addr(peer_addr)
+            .set_json(serde_json::json!({}))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    let req = test::TestRequest::post()
+        .uri("/api/users/register")
+        .peer_addr(peer_addr)
+        .set_json(serde_json::json!({}))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::TOO_MANY_REQUESTS);
+    let retry_after = res
+        .headers()
+        .get(http::header::RETRY_AFTER)
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .parse::<u64>()
+        .unwrap();
+    assert!(0 < retry_after && retry_after <= 1800);
+
+    let req = test::TestRequest::post()
+        .uri("/api/users/register")
+        .peer_addr(unique_peer_addr())
+        .set_json(serde_json::json!({}))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn forwarded_for_of_untrusted_peer_does_not_escape_limit() -> Result<(), DbErr> {
+    let Connections { app, .. } = init_app_with_settings(settings_with_limits(
+        RateLimit {
+            requests: 2,
+            period_seconds: 3600,
+        },
+        RateLimit::default(),
+    ))
+    .await?;
+    let peer_addr = unique_peer_addr();
+
+    for i in 0..3 {
+        let req = test::TestRequest::post()
+            .uri("/api/users/register")
+            .peer_addr(peer_addr)
+            .insert_header(("x-forwarded-for", format!("198.51.100.{}", i)))
+            .insert_header(("forwarded", format!("for=203.0.113.{}", i)))
+            .set_json(serde_json::json!({}))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        let expected = match i {
+            2 => http::StatusCode::TOO_MANY_REQUESTS,
+            _ => http::StatusCode::BAD_REQUEST,
+        };
+        assert_eq!(res.status(), expected);
+    }
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn client_behind_trusted_proxy_is_limited_by_forwarded_for() -> Result<(), DbErr> {
+    let mut settings = settings_with_limits(
+        RateLimit {
+            requests: 1,
+            period_seconds: 3600,
+        },
+        RateLimit::default(),
+    );
+    let proxy_addr = unique_peer_addr();
+    settings.rate_limit.trusted_proxies = vec![proxy_addr.ip()];
+    let Connections { app, .. } = init_app_with_settings(settings).await?;
+    let client_ip = unique_peer_addr().ip();
+
+    for (client_ip, expected) in [
+        (client_ip, http::StatusCode::BAD_REQUEST),
+        (client_ip, http::StatusCode::TOO_MANY_REQUESTS),
+        (unique_peer_addr().ip(), http::StatusCode::BAD_REQUEST),
+    ] {
+        let req = test::TestRequest::post()
+            .uri("/api/users/register")
+            .peer_addr(proxy_addr)
+            .insert_header(("x-forwarded-for", format!("192.0.2.1, {}", client_ip)))
+            .set_json(serde_json::json!({}))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), expected);
+    }
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn api_is_limited_per_user() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app_with_settings(settings_with_limits(
+        RateLimit::default(),
+        RateLimit {
+            requests: 2,
+            period_seconds: 60,
+        },
+    ))
+    .await?;
+    let user = factory::user().insert(&db).await?;
+    let another_user = factory::user().insert(&db).await?;
+
+    for _ in 0..2 {
+        let req = test::TestRequest::get().uri("/api/users/me").to_request();
+        req.extensions_mut().insert(user.clone());
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), http::StatusCode::OK);
+    }
+
+    let req = test::TestRequest::get().uri("/api/users/me").to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::TOO_MANY_REQUESTS);
+    assert!(res.headers().contains_key(http::header::RETRY_AFTER));
+    let res: serde_json::Value = test::read_body_json(res).await;
+    assert_eq!(res["code"], "request.rate_limited");
+
+    let req = test::TestRequest::get().uri("/api/users/me").to_request();
+    req.extensions_mut().insert(another_user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn health_probes_are_not_limited() -> Result<(), DbErr> {
+    let Connections { app, .. } = init_app_with_settings(settings_with_limits(
+        RateLimit::default(),
+        RateLimit {
+            requests: 1,
+            period_seconds: 60,
+        },
+    ))
+    .await?;
+    let peer_addr = unique_peer_addr();
+
+    for _ in 0..3 {
+        let req = test::TestRequest::get()
+            .uri("/api/health/live")
+            .peer_addr(peer_addr)
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), http::StatusCode::OK);
+    }
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn no_limit_when_disabled() -> Result<(), DbErr> {
+    let mut settings = settings_with_limits(
+        RateLimit::default(),
+        RateLimit {
+            requests: 1,
+            period_seconds: 60,
+        },
+    );
+    settings.rate_limit.enabled = false;
+    let Connections { app, db, .. } = init_app_with_settings(settings).await?;
+    let user = factory::user().insert(&db).await?;
+
+    for _ in 0..3 {
+        let req = test::TestRequest::get().uri("/api/users/me").to_request();
+        req.extensions_mut().insert(user.clone());
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), http::StatusCode::OK);
+    }
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use actix_web::{http, test, HttpMessage};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DbErr, DeriveColumn, EntityTrait, EnumIter, QueryFilter,
-    QuerySelect,
+    ActiveModelTrait, ColumnTrait, DbErr, DeriveColumn, EntityTrait, EnumIter, PaginatorTrait,
+    QueryFilter, QuerySelect,
 };
 use use_cases::journal::reading_notes::types::{ReadingNoteCreateRequest, ReadingNoteVisible};
 use uuid::Uuid;
@@ -118,3 +118,63 @@ async fn not_found_on_non_existent_tag_id() -> Result<(), DbErr> {
 
     Ok(())
 }
+
+#[actix_web::test]
+async fn bad_request_on_invalid_fields() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+
+    let req = test::TestRequest::post()
+        .uri("/api/reading_notes")
+        .set_json(ReadingNoteCreateRequest {
+            title: "".to_string(),
+            page_number: -1,
+            text: "This is a new reading note for testing create method.".to_string(),
+            date: chrono::Utc::now().date_naive(),
+            tag_ids: vec![],
+        })
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+    let res: serde_json::Value = test::read_body_json(res).await;
+    let fields: Vec<&str> = res["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|error| error["field"].as_str().unwrap())
+        .collect();
+    assert_eq!(fields, vec!["title", "page_number"]);
+
+    let count = reading_note::Entity::find()
+        .filter(reading_note::Column::UserId.eq(user.id))
+        .count(&db)
+        .await?;
+    assert_eq!(count, 0);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn bad_request_on_unparsable_body() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+
+    let req = test::TestRequest::post()
+        .uri("/api/reading_notes")
+        .set_json(serde_json::json!({"title": "New ReadingNote"}))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+    assert_eq!(
+        res.headers().get(http::header::CONTENT_TYPE).unwrap(),
+        "application/problem+json"
+    );
+    let res: serde_json::Value = test::read_body_json(res).await;
+    assert_eq!(res["code"], "request.invalid_body");
+
+    Ok(())
+}
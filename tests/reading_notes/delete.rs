@@ -26,14 +26,14 @@ async fn happy_path() -> Result<(), DbErr> {
     let reading_note_in_db = reading_note::Entity::find_by_id(reading_note.id)
         .one(&db)
         .await?;
-    assert!(reading_note_in_db.is_none());
+    assert!(reading_note_in_db.unwrap().deleted_at.is_some());
 
     let reading_notes_tags_in_db = reading_notes_tags::Entity::find()
         .filter(reading_notes_tags::Column::ReadingNoteId.eq(reading_note.id))
         .filter(reading_notes_tags::Column::TagId.eq(ambition_tag.id))
         .one(&db)
         .await?;
-    assert!(reading_notes_tags_in_db.is_none());
+    assert!(reading_notes_tags_in_db.is_some());
 
     Ok(())
 }
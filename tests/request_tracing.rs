@@ -0,0 +1,72 @@
+use actix_web::{http, test};
+use sea_orm::DbErr;
+
+use crate::utils::{init_app, Connections};
+
+#[actix_web::test]
+async fn echoes_request_id_from_header() -> Result<(), DbErr> {
+    let Connections { app, .. } = init_app().await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/health-check")
+        .insert_header(("X-Request-Id", "my-request-id"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+
+    assert_eq!(res.status(), http::StatusCode::OK);
+    assert_eq!(
+        res.headers().get("x-request-id").unwrap().to_str().unwrap(),
+        "my-request-id"
+    );
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn generates_request_id_if_not_given() -> Result<(), DbErr> {
+    let Connections { app, .. } = init_app().await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/health-check")
+        .to_request();
+    let res = test::call_service(&app, req).await;
+
+    assert_eq!(res.status(), http::StatusCode::OK);
+    let request_id = res.headers().get("x-request-id").unwrap().to_str().unwrap();
+    assert!(uuid::Uuid::parse_str(request_id).is_ok());
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn replaces_invalid_request_id() -> Result<(), DbErr> {
+    let Connections { app, .. } = init_app().await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/health-check")
+        .insert_header(("X-Request-Id", "not a valid id"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+
+    let request_id = res.headers().get("x-request-id").unwrap().to_str().unwrap();
+    assert!(uuid::Uuid::parse_str(request_id).is_ok());
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn error_response_contains_request_id() -> Result<(), DbErr> {
+    let Connections { app, .. } = init_app().await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/users/me")
+        .insert_header(("X-Request-Id", "my-request-id"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+
+    assert_eq!(res.status(), http::StatusCode::UNAUTHORIZED);
+    let res: serde_json::Value = test::read_body_json(res).await;
+    assert_eq!(res["request_id"], "my-request-id");
+
+    Ok(())
+}
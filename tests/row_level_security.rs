@@ -0,0 +1,95 @@
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, ConnectionTrait, DbBackend, DbErr, EntityTrait,
+    QueryFilter,
+};
+
+use crate::utils::{init_app, Connections};
+use common::{
+    db::{test_db::connect_test_db_as, with_app_user_id, DbRole},
+    factory,
+};
+use db_adapters::diary_adapter::{DiaryAdapter, DiaryQuery};
+use entities::diary;
+
+#[actix_web::test]
+async fn app_role_reads_records_of_its_user_only() -> Result<(), DbErr> {
+    let Connections { db, settings, .. } = init_app().await?;
+    // Row-level security is Postgres only.
+    if db.get_database_backend() != DbBackend::Postgres {
+        return Ok(());
+    }
+    let user = factory::user().insert(&db).await?;
+    let other_user = factory::user().insert(&db).await?;
+    let diary = factory::diary(user.id).insert(&db).await?;
+    factory::diary(other_user.id).insert(&db).await?;
+    let app_db = connect_test_db_as(&settings, &db, DbRole::App).await;
+
+    // NOTE: No filter_eq_user, which is what row-level security backs up.
+    let ids = with_app_user_id(user.id, DiaryAdapter::init(&app_db).get_all_ids()).await?;
+    assert_eq!(ids, vec![diary.id]);
+
+    // The pooled connection does not keep the user of the previous query.
+    let ids = DiaryAdapter::init(&app_db).get_all_ids().await?;
+    assert!(ids.is_empty());
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn app_role_cannot_write_records_of_other_users() -> Result<(), DbErr> {
+    let Connections { db, settings, .. } = init_app().await?;
+    if db.get_database_backend() != DbBackend::Postgres {
+        return Ok(());
+    }
+    let user = factory::user().insert(&db).await?;
+    let other_user = factory::user().insert(&db).await?;
+    let other_users_diary = factory::diary(other_user.id).insert(&db).await?;
+    let app_db = connect_test_db_as(&settings, &db, DbRole::App).await;
+
+    let inserted = with_app_user_id(user.id, factory::diary(other_user.id).insert(&app_db)).await;
+    assert!(inserted.is_err());
+
+    let updated = with_app_user_id(
+        user.id,
+        diary::Entity::update_many()
+            .col_expr(diary::Column::Text, Expr::value("overwritten"))
+            .filter(diary::Column::Id.eq(other_users_diary.id))
+            .exec(&app_db),
+    )
+    .await?;
+    assert_eq!(updated.rows_affected, 0);
+
+    let deleted = with_app_user_id(
+        user.id,
+        diary::Entity::delete_by_id(other_users_diary.id).exec(&app_db),
+    )
+    .await?;
+    assert_eq!(deleted.rows_affected, 0);
+    assert_eq!(
+        diary::Entity::find_by_id(other_users_diary.id)
+            .one(&db)
+            .await?,
+        Some(other_users_diary)
+    );
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn bypass_role_reads_records_of_every_user() -> Result<(), DbErr> {
+    let Connections { db, settings, .. } = init_app().await?;
+    if db.get_database_backend() != DbBackend::Postgres {
+        return Ok(());
+    }
+    let user = factory::user().insert(&db).await?;
+    let other_user = factory::user().insert(&db).await?;
+    let diary = factory::diary(user.id).insert(&db).await?;
+    let other_users_diary = factory::diary(other_user.id).insert(&db).await?;
+    let bypass_db = connect_test_db_as(&settings, &db, DbRole::BypassRls).await;
+
+    let mut ids = DiaryAdapter::init(&bypass_db).get_all_ids().await?;
+    ids.sort();
+    assert_eq!(ids, vec![diary.id, other_users_diary.id]);
+
+    Ok(())
+}
@@ -0,0 +1,211 @@
+use actix_web::{http, test, HttpMessage};
+use chrono::Utc;
+use sea_orm::{ActiveModelTrait, DbErr, Set};
+use use_cases::search::types::SearchResultsVisible;
+
+use crate::utils::{init_app, Connections};
+use common::factory::{self, *};
+
+#[actix_web::test]
+async fn happy_path() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let ambition = factory::ambition(user.id)
+        .name("Marathon".to_string())
+        .description(Some(
+            "Run a full marathon before turning forty.".to_string(),
+        ))
+        .insert(&db)
+        .await?;
+    let direction = factory::direction(user.id)
+        .name("Healthy body".to_string())
+        .description(Some("Running every morning.".to_string()))
+        .insert(&db)
+        .await?;
+    let action = factory::action(user.id)
+        .name("Morning run".to_string())
+        .insert(&db)
+        .await?;
+    let tag = factory::tag(user.id)
+        .name(Some("running".to_string()))
+        .insert(&db)
+        .await?;
+    let diary = factory::diary(user.id)
+        .text(Some("Went for a run <before> breakfast.".to_string()))
+        .insert(&db)
+        .await?;
+    let mut reading_note = factory::reading_note(user.id).title("Born to Run".to_string());
+    reading_note.text = Set("Running is what we were made for.".to_string());
+    let reading_note = reading_note.insert(&db).await?;
+    let mut thinking_note = factory::thinking_note(user.id).question(None);
+    thinking_note.answer = Set(Some("Keep running.".to_string()));
+    let thinking_note = thinking_note.insert(&db).await?;
+    let _unmatched_diary = factory::diary(user.id)
+        .text(Some("Stayed at home.".to_string()))
+        .insert(&db)
+        .await?;
+    let other_user = factory::user().insert(&db).await?;
+    let _other_users_ambition = factory::ambition(other_user.id)
+        .name("Run".to_string())
+        .insert(&db)
+        .await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/search?q=Run")
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+
+    let res: SearchResultsVisible = test::read_body_json(res).await;
+    assert_eq!(res.ambitions.len(), 1);
+    assert_eq!(res.ambitions[0].id, ambition.id);
+    assert_eq!(res.ambitions[0].title, Some("Marathon".to_string()));
+    assert_eq!(
+        res.ambitions[0].snippet,
+        "<mark>Run</mark> a full marathon before turning forty."
+    );
+    assert_eq!(res.directions.len(), 1);
+    assert_eq!(res.directions[0].id, direction.id);
+    assert_eq!(
+        res.directions[0].snippet,
+        "<mark>Run</mark>ning every morning."
+    );
+    assert_eq!(res.actions.len(), 1);
+    assert_eq!(res.actions[0].id, action.id);
+    assert_eq!(res.actions[0].snippet, "Morning <mark>run</mark>");
+    assert_eq!(res.tags.len(), 1);
+    assert_eq!(res.tags[0].id, tag.id);
+    assert_eq!(res.diaries.len(), 1);
+    assert_eq!(res.diaries[0].id, diary.id);
+    assert_eq!(res.diaries[0].title, Some(diary.date.to_string()));
+    assert_eq!(
+        res.diaries[0].snippet,
+        "Went for a <mark>run</mark> &lt;before&gt; breakfast."
+    );
+    assert_eq!(res.reading_notes.len(), 1);
+    assert_eq!(res.reading_notes[0].id, reading_note.id);
+    assert_eq!(res.thinking_notes.len(), 1);
+    assert_eq!(res.thinking_notes[0].id, thinking_note.id);
+    assert_eq!(res.thinking_notes[0].title, None);
+    assert_eq!(res.thinking_notes[0].snippet, "Keep <mark>run</mark>ning.");
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn japanese_text() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let diary = factory::diary(user.id)
+        .text(Some("今日は東京都庁まで歩いた。".to_string()))
+        .insert(&db)
+        .await?;
+    let _unmatched_diary = factory::diary(user.id)
+        .text(Some("京都で東の空を見た。".to_string()))
+        .insert(&db)
+        .await?;
+
+    for (query, snippet) in [
+        ("東京都", "今日は<mark>東京都</mark>庁まで歩いた。"),
+        (
+            "都庁 歩い",
+            "今日は東京<mark>都庁</mark>まで<mark>歩い</mark>た。",
+        ),
+        ("庁", "今日は東京都<mark>庁</mark>まで歩いた。"),
+    ] {
+        let req = test::TestRequest::get()
+            .uri(&format!("/api/search?q={}", urlencoding::encode(query)))
+            .to_request();
+        req.extensions_mut().insert(user.clone());
+
+        let res: SearchResultsVisible = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(res.diaries.len(), 1, "{query}");
+        assert_eq!(res.diaries[0].id, diary.id);
+        assert_eq!(res.diaries[0].snippet, snippet);
+    }
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn name_matches_rank_first() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let description_match = factory::ambition(user.id)
+        .name("Career".to_string())
+        .description(Some("Become a writer.".to_string()))
+        .insert(&db)
+        .await?;
+    let name_match = factory::ambition(user.id)
+        .name("Writer".to_string())
+        .insert(&db)
+        .await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/search?q=writer")
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res: SearchResultsVisible = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(res.ambitions.len(), 2);
+    assert_eq!(res.ambitions[0].id, name_match.id);
+    assert_eq!(res.ambitions[1].id, description_match.id);
+    assert!(res.ambitions[0].rank > res.ambitions[1].rank);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn trashed_records_are_not_searched() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let _trashed_ambition = factory::ambition(user.id)
+        .name("Trashed".to_string())
+        .deleted_at(Some(Utc::now().into()))
+        .insert(&db)
+        .await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/search?q=trashed")
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res: SearchResultsVisible = test::call_and_read_body_json(&app, req).await;
+    assert!(res.ambitions.is_empty());
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn bad_request_without_words() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/search?q={}", urlencoding::encode(" !? ")))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    let res: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(res["code"], "search.no_terms");
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn unauthorized_if_not_logged_in() -> Result<(), DbErr> {
+    let Connections { app, .. } = init_app().await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/search?q=run")
+        .to_request();
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use actix_web::{http, test, HttpMessage};
+use chrono::{Duration, SecondsFormat, Utc};
+use sea_orm::{ActiveModelTrait, DbErr, Set};
+use use_cases::sync::types::{DeletedRecordVisible, SyncChanges};
+
+use crate::utils::{init_app, Connections};
+use common::factory::{self, *};
+
+#[actix_web::test]
+async fn happy_path_first_sync() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let (ambition, ambition_tag) = factory::ambition(user.id).insert_with_tag(&db).await?;
+    let diary = factory::diary(user.id).insert(&db).await?;
+    factory::link_diary_tag(&db, diary.id, ambition_tag.id).await?;
+    let other_user = factory::user().insert(&db).await?;
+    let _other_users_ambition = factory::ambition(other_user.id).insert(&db).await?;
+
+    let req = test::TestRequest::get().uri("/api/sync").to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+
+    let res: SyncChanges = test::read_body_json(res).await;
+    assert_eq!(res.ambitions.len(), 1);
+    assert_eq!(res.ambitions[0].id, ambition.id);
+    assert_eq!(res.tags.len(), 1);
+    assert_eq!(res.tags[0].id, ambition_tag.id);
+    assert_eq!(res.diaries.len(), 1);
+    assert_eq!(res.diaries[0].id, diary.id);
+    assert_eq!(res.diaries[0].tag_ids, vec![ambition_tag.id]);
+    assert!(res.deleted.is_empty());
+    assert!(chrono::DateTime::parse_from_rfc3339(&res.cursor).is_ok());
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn happy_path_returns_only_records_changed_since_cursor() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let mut old_ambition = factory::ambition(user.id);
+    old_ambition.updated_at = Set((Utc::now() - Duration::hours(2)).into());
+    let _old_ambition = old_ambition.insert(&db).await?;
+    let new_ambition = factory::ambition(user.id).insert(&db).await?;
+    let since = (Utc::now() - Duration::hours(1)).to_rfc3339_opts(SecondsFormat::Micros, true);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/sync?since={}", urlencoding::encode(&since)))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+
+    let res: SyncChanges = test::read_body_json(res).await;
+    assert_eq!(res.ambitions.len(), 1);
+    assert_eq!(res.ambitions[0].id, new_ambition.id);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn happy_path_returns_tombstones_of_deleted_records() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let (ambition, ambition_tag) = factory::ambition(user.id).insert_with_tag(&db).await?;
+    let since = Utc::now().to_rfc3339_opts(SecondsFormat::Micros, true);
+
+    let req = test::TestRequest::delete()
+        .uri(&format!("/api/ambitions/{}", ambition.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::NO_CONTENT);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/sync?since={}", urlencoding::encode(&since)))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+
+    let res: SyncChanges = test::read_body_json(res).await;
+    assert!(res.ambitions.is_empty());
+    let mut deleted: Vec<(String, uuid::Uuid)> = res
+        .deleted
+        .into_iter()
+        .map(|DeletedRecordVisible { table_name, id, .. }| (table_name, id))
+        .collect();
+    deleted.sort();
+    assert_eq!(
+        deleted,
+        vec![
+            ("ambition".to_string(), ambition.id),
+            ("tag".to_string(), ambition_tag.id),
+        ]
+    );
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn bad_request_if_cursor_is_invalid() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+
+    let req = test::TestRequest::get()
+        .uri("/api/sync?since=yesterday")
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn bad_request_if_cursor_is_older_than_tombstones() -> Result<(), DbErr> {
+    let Connections {
+        app, db, settings, ..
+    } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let since = (Utc::now() - Duration::days(settings.application.sync_cursor_max_age_days + 1))
+        .to_rfc3339_opts(SecondsFormat::Micros, true);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/api/sync?since={}", urlencoding::encode(&since)))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+    let res: serde_json::Value = test::read_body_json(res).await;
+    assert_eq!(res["code"], "sync.full_resync_required");
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn unauthorized_if_not_logged_in() -> Result<(), DbErr> {
+    let Connections { app, .. } = init_app().await?;
+
+    let req = test::TestRequest::get().uri("/api/sync").to_request();
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
@@ -40,6 +40,13 @@ async fn bad_request_if_not_plain_tag() -> Result<(), DbErr> {
 
     let resp = test::call_service(&app, req).await;
     assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    assert_eq!(
+        resp.headers().get(http::header::CONTENT_TYPE).unwrap(),
+        "application/problem+json"
+    );
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(body["status"], 400);
+    assert_eq!(body["code"], "tag.not_plain");
 
     let tag_in_db = tag::Entity::find_by_id(ambition_tag.id).one(&db).await?;
     assert!(tag_in_db.is_some());
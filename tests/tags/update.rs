@@ -65,7 +65,7 @@ async fn not_found_if_non_existent_tag_id() -> Result<(), DbErr> {
     let req = test::TestRequest::put()
         .uri(&format!("/api/tags/plain/{}", uuid::Uuid::now_v7()))
         .set_json(TagUpdateRequest {
-            name: "".to_string(),
+            name: "Updated".to_string(),
         })
         .to_request();
     req.extensions_mut().insert(user.clone());
@@ -85,7 +85,7 @@ async fn bad_request_if_not_plain_tag() -> Result<(), DbErr> {
     let req = test::TestRequest::put()
         .uri(&format!("/api/tags/plain/{}", ambition_tag.id))
         .set_json(TagUpdateRequest {
-            name: "".to_string(),
+            name: "Updated".to_string(),
         })
         .to_request();
     req.extensions_mut().insert(user.clone());
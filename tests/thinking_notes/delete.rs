@@ -26,14 +26,14 @@ async fn happy_path() -> Result<(), DbErr> {
     let thinking_note_in_db = thinking_note::Entity::find_by_id(thinking_note.id)
         .one(&db)
         .await?;
-    assert!(thinking_note_in_db.is_none());
+    assert!(thinking_note_in_db.unwrap().deleted_at.is_some());
 
     let thinking_note_tags_in_db = thinking_note_tags::Entity::find()
         .filter(thinking_note_tags::Column::ThinkingNoteId.eq(thinking_note.id))
         .filter(thinking_note_tags::Column::TagId.eq(ambition_tag.id))
         .one(&db)
         .await?;
-    assert!(thinking_note_tags_in_db.is_none());
+    assert!(thinking_note_tags_in_db.is_some());
 
     Ok(())
 }
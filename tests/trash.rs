@@ -0,0 +1,296 @@
+use std::{future::poll_fn, pin::Pin};
+
+use actix_web::{body::MessageBody, http, test, HttpMessage};
+use chrono::{Duration, Utc};
+use sea_orm::{ActiveModelTrait, ColumnTrait, DbErr, EntityTrait, QueryFilter};
+use use_cases::{
+    journal::diaries::types::{DiaryCreateRequest, DiaryVisible},
+    my_way::actions::types::ActionVisibleWithGoal,
+    sync::types::SyncChanges,
+    tags::types::TagVisible,
+    trash::types::TrashItemVisible,
+};
+
+use crate::utils::{init_app, Connections};
+use common::factory::{self, *};
+use db_adapters::trash_adapter::TrashKind;
+use entities::{action, diary, tombstone};
+
+#[actix_web::test]
+async fn happy_path_list() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let now = Utc::now();
+    let ambition = factory::ambition(user.id)
+        .name("Trashed ambition".to_string())
+        .deleted_at(Some((now - Duration::hours(1)).into()))
+        .insert(&db)
+        .await?;
+    let diary = factory::diary(user.id)
+        .deleted_at(Some(now.into()))
+        .insert(&db)
+        .await?;
+    let _live_ambition = factory::ambition(user.id).insert(&db).await?;
+    let other_user = factory::user().insert(&db).await?;
+    let _other_users_trashed_ambition = factory::ambition(other_user.id)
+        .deleted_at(Some(now.into()))
+        .insert(&db)
+        .await?;
+
+    let req = test::TestRequest::get().uri("/api/trash").to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+
+    let res: Vec<TrashItemVisible> = test::read_body_json(res).await;
+    assert_eq!(res.len(), 2);
+    assert_eq!(res[0].kind, TrashKind::Diary);
+    assert_eq!(res[0].id, diary.id);
+    assert_eq!(res[0].summary, Some(diary.date.to_string()));
+    assert_eq!(res[1].kind, TrashKind::Ambition);
+    assert_eq!(res[1].id, ambition.id);
+    assert_eq!(res[1].summary, Some("Trashed ambition".to_string()));
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn deleted_action_is_hidden_with_its_tag() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let (action, action_tag) = factory::action(user.id).insert_with_tag(&db).await?;
+
+    let req = test::TestRequest::delete()
+        .uri(&format!("/api/actions/{}", action.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::NO_CONTENT);
+
+    let req = test::TestRequest::get().uri("/api/actions").to_request();
+    req.extensions_mut().insert(user.clone());
+    let res: Vec<ActionVisibleWithGoal> = test::call_and_read_body_json(&app, req).await;
+    assert!(res.iter().all(|a| a.id != action.id));
+
+    let req = test::TestRequest::get().uri("/api/tags").to_request();
+    req.extensions_mut().insert(user.clone());
+    let res: Vec<TagVisible> = test::call_and_read_body_json(&app, req).await;
+    assert!(res.iter().all(|tag| tag.id != action_tag.id));
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn happy_path_restore() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let (action, action_tag) = factory::action(user.id).insert_with_tag(&db).await?;
+    let action_track = factory::action_track(user.id)
+        .action_id(action.id)
+        .insert(&db)
+        .await?;
+
+    let req = test::TestRequest::delete()
+        .uri(&format!("/api/actions/{}", action.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::NO_CONTENT);
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/api/trash/action/{}/restore", action.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::NO_CONTENT);
+
+    let action_in_db = action::Entity::find_by_id(action.id)
+        .one(&db)
+        .await?
+        .unwrap();
+    assert!(action_in_db.deleted_at.is_none());
+    let tombstones_in_db = tombstone::Entity::find()
+        .filter(tombstone::Column::RecordId.is_in([action.id, action_tag.id, action_track.id]))
+        .all(&db)
+        .await?;
+    assert!(tombstones_in_db.is_empty());
+
+    let req = test::TestRequest::get().uri("/api/sync").to_request();
+    req.extensions_mut().insert(user.clone());
+    let res: SyncChanges = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(res.actions.len(), 1);
+    assert_eq!(res.actions[0].id, action.id);
+    assert_eq!(res.action_tracks.len(), 1);
+    assert_eq!(res.action_tracks[0].id, action_track.id);
+    assert_eq!(res.tags.len(), 1);
+    assert_eq!(res.tags[0].id, action_tag.id);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn restore_publishes_change_event() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let diary = factory::diary(user.id)
+        .deleted_at(Some(Utc::now().into()))
+        .insert(&db)
+        .await?;
+
+    let req = test::TestRequest::get().uri("/api/events").to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::OK);
+    let mut events = res.into_body();
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/api/trash/diary/{}/restore", diary.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::NO_CONTENT);
+
+    let chunk = tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        poll_fn(|cx| Pin::new(&mut events).poll_next(cx)),
+    )
+    .await
+    .expect("No event was streamed.")
+    .unwrap()
+    .unwrap();
+    let chunk = String::from_utf8(chunk.to_vec()).unwrap();
+    assert!(chunk.starts_with("event: diary.created\ndata: "));
+    assert!(chunk.contains(&diary.id.to_string()));
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn diary_can_be_created_on_date_of_trashed_diary() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let trashed_diary = factory::diary(user.id).insert(&db).await?;
+
+    let req = test::TestRequest::delete()
+        .uri(&format!("/api/diaries/{}", trashed_diary.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::NO_CONTENT);
+
+    let req = test::TestRequest::post()
+        .uri("/api/diaries")
+        .set_json(DiaryCreateRequest {
+            text: Some("After trashing.".to_string()),
+            date: trashed_diary.date,
+            tag_ids: vec![],
+        })
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::CREATED);
+    let created_diary: DiaryVisible = test::read_body_json(res).await;
+    assert_eq!(created_diary.date, trashed_diary.date);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn diary_can_be_restored_next_to_live_diary_on_same_date() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let trashed_diary = factory::diary(user.id)
+        .deleted_at(Some(Utc::now().into()))
+        .insert(&db)
+        .await?;
+    let live_diary = factory::diary(user.id)
+        .date(trashed_diary.date)
+        .insert(&db)
+        .await?;
+
+    // NOTE: Diaries are not unique per date since m20250712_000001_remove_score_from_diaries_table.
+    let req = test::TestRequest::put()
+        .uri(&format!("/api/trash/diary/{}/restore", trashed_diary.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::NO_CONTENT);
+
+    let diaries_in_db = diary::Entity::find()
+        .filter(diary::Column::UserId.eq(user.id))
+        .filter(diary::Column::Date.eq(trashed_diary.date))
+        .filter(diary::Column::DeletedAt.is_null())
+        .all(&db)
+        .await?;
+    assert_eq!(diaries_in_db.len(), 2);
+    assert!(diaries_in_db.iter().any(|diary| diary.id == live_diary.id));
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn not_found_if_not_in_trash() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let ambition = factory::ambition(user.id).insert(&db).await?;
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/api/trash/ambition/{}/restore", ambition.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::NOT_FOUND);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn not_found_if_trashed_by_other_user() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+    let other_user = factory::user().insert(&db).await?;
+    let diary = factory::diary(other_user.id)
+        .deleted_at(Some(Utc::now().into()))
+        .insert(&db)
+        .await?;
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/api/trash/diary/{}/restore", diary.id))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::NOT_FOUND);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn bad_request_on_unknown_kind() -> Result<(), DbErr> {
+    let Connections { app, db, .. } = init_app().await?;
+    let user = factory::user().insert(&db).await?;
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/api/trash/tag/{}/restore", uuid::Uuid::now_v7()))
+        .to_request();
+    req.extensions_mut().insert(user.clone());
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+
+    Ok(())
+}
+
+#[actix_web::test]
+async fn unauthorized_if_not_logged_in() -> Result<(), DbErr> {
+    let Connections { app, .. } = init_app().await?;
+
+    let req = test::TestRequest::get().uri("/api/trash").to_request();
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), http::StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
@@ -7,7 +7,9 @@ use common::factory::{self, *};
 
 #[actix_web::test]
 async fn login_to_get_me_to_logout() -> Result<(), DbErr> {
-    let Connections { app, db, .. } = init_app().await?;
+    let Connections {
+        app, db, settings, ..
+    } = init_app().await?;
     let password = "password";
     let hashed_password = "$argon2id$v=19$m=19456,t=2,p=1$r07vWFCaKrbNPrSgUrG/+Q$/2lBaeRWeox6ROMu6qAwOYmttdGXA3o4Uw2YHC/fvfY";
     let user = factory::user()
@@ -44,6 +46,10 @@ async fn login_to_get_me_to_logout() -> Result<(), DbErr> {
     let logout_req = test::TestRequest::post()
         .uri("/api/users/logout")
         .cookie(session_cookie.clone())
+        .insert_header((
+            http::header::ORIGIN,
+            settings.application.frontend_url.as_str(),
+        ))
         .to_request();
     let res = test::call_service(&app, logout_req).await;
     assert_eq!(res.status(), http::StatusCode::OK);
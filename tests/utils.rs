@@ -9,18 +9,26 @@ use actix_web::{
     App, Error,
 };
 use common::{
-    db::init_db,
+    db::{
+        test_db::{connect_test_db_as, init_test_db},
+        DbRole,
+    },
     redis::init_redis_pool,
     settings::{get_test_settings, types::Settings},
 };
 use sea_orm::{DbConn, DbErr};
 use server::{
-    auth_middleware::AuthenticateUser, get_preps_for_redis_session_store, get_routes,
+    auth_middleware::AuthenticateUser, csrf_middleware::CsrfProtection, get_health_routes,
+    get_preps_for_redis_session_store, get_routes, request_tracing_middleware::RequestTracing,
     setup_session_middleware_builder,
 };
 
 pub struct Connections<
-    S: Service<Request, Response = ServiceResponse<EitherBody<Encoder<BoxBody>>>, Error = Error>,
+    S: Service<
+        Request,
+        Response = ServiceResponse<EitherBody<EitherBody<Encoder<BoxBody>>>>,
+        Error = Error,
+    >,
 > {
     pub app: S,
     pub db: DbConn,
@@ -29,13 +37,33 @@ pub struct Connections<
 
 pub async fn init_app() -> Result<
     Connections<
-        impl Service<Request, Response = ServiceResponse<EitherBody<Encoder<BoxBody>>>, Error = Error>,
+        impl Service<
+            Request,
+            Response = ServiceResponse<EitherBody<EitherBody<Encoder<BoxBody>>>>,
+            Error = Error,
+        >,
+    >,
+    DbErr,
+> {
+    init_app_with_settings(get_test_settings()).await
+}
+
+pub async fn init_app_with_settings(
+    settings: Settings,
+) -> Result<
+    Connections<
+        impl Service<
+            Request,
+            Response = ServiceResponse<EitherBody<EitherBody<Encoder<BoxBody>>>>,
+            Error = Error,
+        >,
     >,
     DbErr,
 > {
-    let settings = get_test_settings();
     // let _ = env_logger::try_init();
-    let db = init_db(&settings).await;
+    let db = init_test_db(&settings).await;
+    // The app runs under row-level security as in production, while tests see every record.
+    let app_db = connect_test_db_as(&settings, &db, DbRole::App).await;
     let redis_pool = init_redis_pool(&settings)
         .await
         .expect("Error on getting Redis pool.");
@@ -47,6 +75,7 @@ pub async fn init_app() -> Result<
         App::new()
             .wrap(Compress::default())
             .wrap(AuthenticateUser)
+            .wrap(CsrfProtection)
             .wrap(
                 setup_session_middleware_builder(
                     SessionMiddleware::builder(redis_store.clone(), secret_key.clone()),
@@ -54,8 +83,10 @@ pub async fn init_app() -> Result<
                 )
                 .build(),
             )
+            .wrap(RequestTracing)
+            .service(get_health_routes())
             .service(get_routes())
-            .app_data(Data::new(db.clone()))
+            .app_data(Data::new(app_db))
             .app_data(Data::new(redis_pool.clone()))
             .app_data(Data::new(settings.clone())),
     )